@@ -1,11 +1,11 @@
 use ezpc::*;
 use std::str::FromStr;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum JsonValue {
-    Object(Vec<(String, JsonValue)>),
+    Object(Vec<(Substr, JsonValue)>),
     Array(Vec<JsonValue>),
-    String(String),
+    String(Substr),
     Number(f64),
     Bool(bool),
     Null,
@@ -31,7 +31,7 @@ fn value() -> Parser<impl Parse<Output = JsonValue>> {
     ws() + value_inner.wrap(100).fatal(error_msg::UNKNOWN_VALUE) + ws()
 }
 
-fn object() -> Parser<impl Parse<Output = Vec<(String, JsonValue)>>> {
+fn object() -> Parser<impl Parse<Output = Vec<(Substr, JsonValue)>>> {
     let member = ws() + string() + ws() + tag(":") + value();
     let members = list(member, tag(","), error_msg::MISSING_OBJECT_MEMBER);
     tag("{")
@@ -57,18 +57,20 @@ fn number() -> Parser<impl Parse<Output = f64>> {
         .convert(f64::from_str, error_msg::PARSE_ERROR)
 }
 
-fn string() -> Parser<impl Parse<Output = String>> {
-    (tag("\"")
-        + (char_str() | utf16_str() | esc_str()).repeat(0..)
-        + tag("\"").fatal(error_msg::UNCLOSED_STRING))
-    .map(|strs| strs.concat())
+fn string() -> Parser<impl Parse<Output = Substr>> {
+    // Strings without escape sequences are sliced from the source, only the
+    // others are decoded into a new String
+    let plain = tag("\"") + chars().opt().slice() + tag("\"");
+    let decoded = tag("\"")
+        + (chars().string() | utf16_str() | esc_str()).repeat(0..)
+        + tag("\"").fatal(error_msg::UNCLOSED_STRING);
+    plain | decoded.map(|strs| Substr::from(strs.concat()))
 }
 
-fn char_str() -> Parser<impl Parse<Output = String>> {
+fn chars() -> Matcher<impl Match> {
     (is_a(|c| matches!(c, '\0'..='\u{1F}')).reject(error_msg::UNESCAPED_CTRL_CHAR)
         + none_of("\\\""))
     .repeat(1..)
-    .map(|s| s.to_owned())
 }
 
 fn utf16_str() -> Parser<impl Parse<Output = String>> {
@@ -0,0 +1,394 @@
+//! `#[derive(Parse)]` for ezpc, see the documentation of the re-export in ezpc.
+//! The input is parsed by hand with the proc_macro API, so no dependencies are
+//! needed, and the generated code is built as text.
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+/// Generates `fn parser() -> Parser<impl Parse<Output = Self>>` for a struct
+/// or an enum, whose variants become ordered alternatives:
+/// ```
+/// use ezpc::*;
+///
+/// fn name() -> Parser<impl Parse<Output = String>> {
+///     is_a(char::is_alphabetic).repeat(1..).map(|s| s.to_owned())
+/// }
+///
+/// #[derive(Parse, Debug, PartialEq)]
+/// struct Assignment {
+///     #[ezpc(with = "name")]
+///     key: String,
+///     #[ezpc(tag = "=", sep = ",")]
+///     values: Vec<Value>,
+/// }
+///
+/// #[derive(Parse, Debug, PartialEq)]
+/// enum Value {
+///     #[ezpc(tag = "yes")]
+///     Yes,
+///     #[ezpc(tag = "no")]
+///     No,
+/// }
+///
+/// let assignment = Assignment::parser().parse_all("answers=yes,no").unwrap();
+/// assert_eq!(assignment.values, [Value::Yes, Value::No]);
+/// ```
+/// Fields are parsed in order with `<Type>::parser()`, options are set with
+/// `#[ezpc(...)]`:
+/// - `tag = "..."` is matched before the field, or before all fields of a
+///   struct or variant. `after = "..."` is matched after them.
+/// - `with = "path::to::parser"` is the function that builds the field parser
+/// - `sep = ","` parses a Vec field as list, otherwise the element is repeated
+///
+/// Option fields are optional, including their tags. Boxed fields and fields
+/// of the derived type itself are wrapped with a max depth of 100, so types
+/// can be recursive, which requires them to be Clone.
+#[proc_macro_derive(Parse, attributes(ezpc))]
+pub fn derive_parse(input: TokenStream) -> TokenStream {
+    let code = match expand(input) {
+        Ok(code) => code,
+        Err(message) => format!("::core::compile_error!({message:?});"),
+    };
+    code.parse().unwrap()
+}
+
+type Result<T> = std::result::Result<T, String>;
+
+/// Options of the ezpc attribute. Literals are kept as written in the source.
+#[derive(Default)]
+struct Attrs {
+    tag: Option<String>,
+    after: Option<String>,
+    sep: Option<String>,
+    with: Option<String>,
+}
+
+struct Field {
+    name: Option<String>,
+    ty: Vec<TokenTree>,
+    attrs: Attrs,
+}
+
+enum Fields {
+    Named(Vec<Field>),
+    Unnamed(Vec<Field>),
+    Unit,
+}
+
+fn expand(input: TokenStream) -> Result<String> {
+    let tokens: Vec<_> = input.into_iter().collect();
+    let mut i = 0;
+    let attrs = take_attrs(&tokens, &mut i)?;
+    let vis = take_vis(&tokens, &mut i);
+    let kind = ident(tokens.get(i)).ok_or("expected a struct or an enum")?;
+    let name = ident(tokens.get(i + 1)).ok_or("expected a type name")?;
+    i += 2;
+    if is_punct(tokens.get(i), '<') {
+        return Err("#[derive(Parse)] does not support generic types".to_owned());
+    }
+
+    let body = match kind.as_str() {
+        "struct" => {
+            let fields = take_fields(&tokens, &mut i)?;
+            sequence(&attrs, &fields, "Self", &name)?
+        }
+        "enum" => {
+            let Some(TokenTree::Group(group)) = tokens.get(i) else {
+                return Err("expected the variants of the enum".to_owned());
+            };
+            let mut variants = Vec::new();
+            for variant in split(group.stream()) {
+                let mut i = 0;
+                let attrs = take_attrs(&variant, &mut i)?;
+                let variant_name = ident(variant.get(i)).ok_or("expected a variant name")?;
+                i += 1;
+                let fields = take_fields(&variant, &mut i)?;
+                let path = format!("Self::{variant_name}");
+                variants.push(sequence(&attrs, &fields, &path, &name)?);
+            }
+            if variants.is_empty() {
+                return Err("#[derive(Parse)] needs at least one variant".to_owned());
+            }
+            variants.join(" | ")
+        }
+        _ => return Err("#[derive(Parse)] only supports structs and enums".to_owned()),
+    };
+
+    Ok(format!(
+        "impl {name} {{\n\
+         \x20   /// Parser generated by #[derive(Parse)]\n\
+         \x20   {vis} fn parser() -> ::ezpc::Parser<impl ::ezpc::Parse<Output = Self>> {{\n\
+         \x20       {body}\n\
+         \x20   }}\n\
+         }}"
+    ))
+}
+
+/// Parser of the fields in order, mapped to the struct or variant at path.
+/// Tags are matchers, so the output only consists of the field values.
+fn sequence(attrs: &Attrs, fields: &Fields, path: &str, own: &str) -> Result<String> {
+    if attrs.sep.is_some() || attrs.with.is_some() {
+        return Err(format!(
+            "`sep` and `with` are field options, found on {path}"
+        ));
+    }
+    let list = match fields {
+        Fields::Named(list) | Fields::Unnamed(list) => &list[..],
+        Fields::Unit => &[],
+    };
+
+    let mut parts = Vec::new();
+    parts.extend(attrs.tag.iter().map(|tag| format!("::ezpc::tag({tag})")));
+    for (index, field) in list.iter().enumerate() {
+        let label = field.name.clone().unwrap_or_else(|| index.to_string());
+        parts.push(field_parser(field, &label, own)?);
+    }
+    parts.extend(
+        attrs
+            .after
+            .iter()
+            .map(|after| format!("::ezpc::tag({after})")),
+    );
+    if parts.is_empty() {
+        return Err(format!(
+            "{path} has no fields, it needs #[ezpc(tag = \"...\")]"
+        ));
+    }
+    let parser = format!("({})", parts.join(" + "));
+
+    // Sequences of parsers have left nested tuples as output: ((f0, f1), f2)
+    let mut pattern = String::new();
+    for index in 0..list.len() {
+        pattern = match index {
+            0 => "f0".to_owned(),
+            _ => format!("({pattern}, f{index})"),
+        };
+    }
+    let value = match fields {
+        Fields::Named(list) => {
+            let values: Vec<_> = list
+                .iter()
+                .enumerate()
+                .map(|(index, field)| format!("{}: f{index}", field.name.as_ref().unwrap()))
+                .collect();
+            format!("{path} {{ {} }}", values.join(", "))
+        }
+        Fields::Unnamed(list) => {
+            let values: Vec<_> = (0..list.len()).map(|index| format!("f{index}")).collect();
+            format!("{path}({})", values.join(", "))
+        }
+        Fields::Unit => path.to_owned(),
+    };
+    if list.is_empty() {
+        Ok(format!("{parser}.map(|_| {value})"))
+    } else {
+        Ok(format!("{parser}.map(|{pattern}| {value})"))
+    }
+}
+
+/// Parser of a field with its tags. A Vec with a separator is parsed as list,
+/// the tags of an Option are optional as well.
+fn field_parser(field: &Field, label: &str, own: &str) -> Result<String> {
+    let with = field.attrs.with.as_deref();
+    let (ty, optional) = match container(&field.ty) {
+        Some(("Option", inner)) => (inner, true),
+        _ => (&field.ty[..], false),
+    };
+    let parser = match (&field.attrs.sep, container(ty)) {
+        (None, _) => element(ty, with, own, false),
+        (Some(sep), Some(("Vec", inner))) => format!(
+            "::ezpc::list({}, ::ezpc::tag({sep}), {:?}).opt().map(|items| items.unwrap_or_default())",
+            element(inner, with, own, false),
+            format!("Expected {label} after {sep}")
+        ),
+        (Some(_), _) => return Err(format!("`sep` needs a Vec field, {label} is none")),
+    };
+
+    let mut parts = Vec::new();
+    parts.extend(
+        field
+            .attrs
+            .tag
+            .iter()
+            .map(|tag| format!("::ezpc::tag({tag})")),
+    );
+    parts.push(parser);
+    parts.extend(
+        field
+            .attrs
+            .after
+            .iter()
+            .map(|after| format!("::ezpc::tag({after})")),
+    );
+    Ok(match (parts.len(), optional) {
+        (1, false) => parts.remove(0),
+        (_, false) => format!("({})", parts.join(" + ")),
+        (_, true) => format!("({}).opt()", parts.join(" + ")),
+    })
+}
+
+/// Parser of a value of the given type: Option, Box and Vec are parsed from
+/// their element, other types with their parser() function or `with`.
+/// Boxed types and the derived type itself are recursive, so they are wrapped.
+fn element(ty: &[TokenTree], with: Option<&str>, own: &str, boxed: bool) -> String {
+    match container(ty) {
+        Some(("Vec", inner)) => format!("{}.repeat(0..)", element(inner, with, own, false)),
+        Some(("Option", inner)) => format!("{}.opt()", element(inner, with, own, false)),
+        Some(("Box", inner)) => format!(
+            "{}.map(::std::boxed::Box::new)",
+            element(inner, with, own, true)
+        ),
+        _ => {
+            let ty = to_string(ty);
+            match with {
+                Some(with) => format!("{with}()"),
+                None if boxed || ty == own || ty == "Self" => {
+                    format!("::ezpc::WrapParser::wrap(<{ty}>::parser, 100)")
+                }
+                None => format!("<{ty}>::parser()"),
+            }
+        }
+    }
+}
+
+/// The element type of Vec, Option or Box
+fn container(ty: &[TokenTree]) -> Option<(&'static str, &[TokenTree])> {
+    let [TokenTree::Ident(outer), open, inner @ .., close] = ty else {
+        return None;
+    };
+    if !is_punct(Some(open), '<') || !is_punct(Some(close), '>') {
+        return None;
+    }
+    ["Vec", "Option", "Box"]
+        .into_iter()
+        .find(|name| outer.to_string() == *name)
+        .map(|name| (name, inner))
+}
+
+/// Fields of a struct or variant, followed by a `;` for tuple and unit structs
+fn take_fields(tokens: &[TokenTree], i: &mut usize) -> Result<Fields> {
+    let (named, stream) = match tokens.get(*i) {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            (true, group.stream())
+        }
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            (false, group.stream())
+        }
+        _ => return Ok(Fields::Unit),
+    };
+    *i += 1;
+
+    let mut fields = Vec::new();
+    for field in split(stream) {
+        let mut i = 0;
+        let attrs = take_attrs(&field, &mut i)?;
+        take_vis(&field, &mut i);
+        let name = if named {
+            let name = ident(field.get(i)).ok_or("expected a field name")?;
+            i += 2;
+            Some(name)
+        } else {
+            None
+        };
+        fields.push(Field {
+            name,
+            ty: field[i..].to_vec(),
+            attrs,
+        });
+    }
+    Ok(if named {
+        Fields::Named(fields)
+    } else {
+        Fields::Unnamed(fields)
+    })
+}
+
+/// Outer attributes, only the ezpc ones are kept
+fn take_attrs(tokens: &[TokenTree], i: &mut usize) -> Result<Attrs> {
+    let mut attrs = Attrs::default();
+    while is_punct(tokens.get(*i), '#') {
+        let Some(TokenTree::Group(group)) = tokens.get(*i + 1) else {
+            break;
+        };
+        *i += 2;
+        let attr: Vec<_> = group.stream().into_iter().collect();
+        let [TokenTree::Ident(name), TokenTree::Group(options)] = &attr[..] else {
+            continue;
+        };
+        if name.to_string() != "ezpc" {
+            continue;
+        }
+        for option in split(options.stream()) {
+            let [TokenTree::Ident(key), eq, TokenTree::Literal(value)] = &option[..] else {
+                return Err("expected options like #[ezpc(tag = \"...\")]".to_owned());
+            };
+            if !is_punct(Some(eq), '=') {
+                return Err("expected options like #[ezpc(tag = \"...\")]".to_owned());
+            }
+            let value = value.to_string();
+            match key.to_string().as_str() {
+                "tag" => attrs.tag = Some(value),
+                "after" => attrs.after = Some(value),
+                "sep" => attrs.sep = Some(value),
+                "with" => attrs.with = Some(value.trim_matches('"').to_owned()),
+                key => return Err(format!("unknown ezpc option `{key}`")),
+            }
+        }
+    }
+    Ok(attrs)
+}
+
+fn take_vis(tokens: &[TokenTree], i: &mut usize) -> String {
+    if ident(tokens.get(*i)).as_deref() != Some("pub") {
+        return String::new();
+    }
+    *i += 1;
+    match tokens.get(*i) {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            *i += 1;
+            format!("pub{group}")
+        }
+        _ => "pub".to_owned(),
+    }
+}
+
+/// Splits at commas that are not inside of angle brackets, like in `Map<K, V>`
+fn split(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut parts = vec![Vec::new()];
+    let mut depth = 0usize;
+    let mut arrow = false;
+    for token in stream {
+        if let TokenTree::Punct(punct) = &token {
+            match punct.as_char() {
+                ',' if depth == 0 => {
+                    parts.push(Vec::new());
+                    continue;
+                }
+                '<' => depth += 1,
+                // The > of -> does not close an angle bracket
+                '>' if !arrow => depth = depth.saturating_sub(1),
+                _ => (),
+            }
+            arrow = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+        } else {
+            arrow = false;
+        }
+        parts.last_mut().unwrap().push(token);
+    }
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+fn ident(token: Option<&TokenTree>) -> Option<String> {
+    match token {
+        Some(TokenTree::Ident(ident)) => Some(ident.to_string()),
+        _ => None,
+    }
+}
+
+fn is_punct(token: Option<&TokenTree>, c: char) -> bool {
+    matches!(token, Some(TokenTree::Punct(punct)) if punct.as_char() == c)
+}
+
+fn to_string(tokens: &[TokenTree]) -> String {
+    tokens.iter().cloned().collect::<TokenStream>().to_string()
+}
@@ -0,0 +1,176 @@
+#!/usr/bin/env python3
+"""Generates src/parser/unicode_tables.rs from the Unicode Character Database.
+
+Usage: scripts/unicode_tables.py VERSION [UCD_DIR]
+
+VERSION must be the Unicode version of the Rust standard library, which is
+char::UNICODE_VERSION, so that the tables agree with char::is_alphabetic and
+the other char methods. UnicodeData.txt, DerivedCoreProperties.txt and
+CaseFolding.txt are read from UCD_DIR, or downloaded from unicode.org.
+"""
+
+import os
+import sys
+import urllib.request
+
+OUTPUT = os.path.join(os.path.dirname(__file__), '..', 'src', 'parser', 'unicode_tables.rs')
+
+# Order of the general categories in CATEGORY_NAMES, Cn is not listed
+CATEGORY_NAMES = [
+    'Lu', 'Ll', 'Lt', 'Lm', 'Lo', 'Mn', 'Mc', 'Me', 'Nd', 'Nl', 'No', 'Pc', 'Pd', 'Ps', 'Pe', 'Pi',
+    'Pf', 'Po', 'Sm', 'Sc', 'Sk', 'So', 'Zs', 'Zl', 'Zp', 'Cc', 'Cf', 'Cs', 'Co',
+]
+
+HANGUL_SYLLABLES = range(0xAC00, 0xD7A4)
+
+
+def read(version, ucd_dir, name):
+    """Lines of a UCD file without comments and empty lines, split at `;`"""
+    if ucd_dir:
+        with open(os.path.join(ucd_dir, name), encoding='utf-8') as f:
+            text = f.read()
+    else:
+        url = f'https://www.unicode.org/Public/{version}/ucd/{name}'
+        with urllib.request.urlopen(url) as f:
+            text = f.read().decode('utf-8')
+    for line in text.splitlines():
+        line = line.split('#', 1)[0].strip()
+        if line:
+            yield [field.strip() for field in line.split(';')]
+
+
+def code_points(field):
+    """The code points of a field like `0041` or `0041..005A`"""
+    first, _, last = field.partition('..')
+    return range(int(first, 16), int(last or first, 16) + 1)
+
+
+def ranges(values):
+    """Merges consecutive code points with equal values: (first, last, value)"""
+    merged = []
+    for cp, value in sorted(values.items()):
+        if merged and merged[-1][1] == cp - 1 and merged[-1][2] == value:
+            merged[-1][1] = cp
+        else:
+            merged.append([cp, cp, value])
+    return merged
+
+
+def unicode_data(version, ucd_dir):
+    """General category, combining class and canonical decomposition mapping"""
+    categories, classes, mappings = {}, {}, {}
+    first = None
+    for fields in read(version, ucd_dir, 'UnicodeData.txt'):
+        cp, name, category, combining_class, mapping = (
+            int(fields[0], 16), fields[1], fields[2], int(fields[3]), fields[5])
+        # Large blocks like CJK ideographs are given by their first and last line
+        if name.endswith(', First>'):
+            first = cp
+            continue
+        for cp in range(first if name.endswith(', Last>') else cp, cp + 1):
+            categories[cp] = CATEGORY_NAMES.index(category)
+            if combining_class:
+                classes[cp] = combining_class
+            # Compatibility mappings start with a tag like <font>
+            if mapping and not mapping.startswith('<'):
+                mappings[cp] = [int(c, 16) for c in mapping.split()]
+    return categories, classes, mappings
+
+
+def decompose(cp, mappings, classes):
+    """Full canonical decomposition in canonical order"""
+    if cp not in mappings:
+        return [cp]
+    decomposed = [d for c in mappings[cp] for d in decompose(c, mappings, classes)]
+    # Stable sort of the runs of non-starters by their combining class
+    start = 0
+    for end in range(len(decomposed) + 1):
+        if end == len(decomposed) or classes.get(decomposed[end], 0) == 0:
+            decomposed[start:end] = sorted(decomposed[start:end], key=lambda c: classes[c])
+            start = end + 1
+    return decomposed
+
+
+def xid(version, ucd_dir):
+    start, cont = {}, {}
+    for fields in read(version, ucd_dir, 'DerivedCoreProperties.txt'):
+        table = {'XID_Start': start, 'XID_Continue': cont}.get(fields[1])
+        if table is not None:
+            table.update((cp, True) for cp in code_points(fields[0]))
+    return [r[:2] for r in ranges(start)], [r[:2] for r in ranges(cont)]
+
+
+def case_folding(version, ucd_dir):
+    """Full case folding: status C, and F instead of S"""
+    folding = {}
+    for cp, status, mapping, *_ in read(version, ucd_dir, 'CaseFolding.txt'):
+        if status in ('C', 'F'):
+            folding[int(cp, 16)] = [int(c, 16) for c in mapping.split()]
+    return folding
+
+
+def string(chars):
+    """Rust string literal, only printable ASCII is not escaped"""
+    return '"%s"' % ''.join(
+        chr(c) if 0x20 <= c < 0x7F and chr(c) not in '"\\' else '\\u{%X}' % c for c in chars)
+
+
+def table(doc, name, ty, items):
+    """A static table with as many items per line as fit into 100 columns"""
+    lines, line = [], '   '
+    for item in items:
+        if len(line) + len(item) + 2 > 100:
+            lines.append(line)
+            line = '   '
+        line += ' ' + item + ','
+    lines.append(line)
+    doc = ''.join(f'/// {line}\n' for line in doc)
+    return '%s#[rustfmt::skip]\npub(super) static %s: &[%s] = &[\n%s\n];\n' % (
+        doc, name, ty, '\n'.join(lines))
+
+
+def main():
+    version = sys.argv[1]
+    ucd_dir = sys.argv[2] if len(sys.argv) > 2 else None
+    categories, classes, mappings = unicode_data(version, ucd_dir)
+    xid_start, xid_continue = xid(version, ucd_dir)
+    folding = case_folding(version, ucd_dir)
+    decompositions = {}
+    for cp in mappings:
+        if cp not in HANGUL_SYLLABLES:
+            decompositions[cp] = decompose(cp, mappings, classes)
+
+    names = ',\n    '.join(', '.join(f'"{name}"' for name in CATEGORY_NAMES[i:i + 16])
+                            for i in range(0, len(CATEGORY_NAMES), 16))
+    tables = [
+        '// Unicode %s character tables, generated from the Unicode Character Database\n'
+        '// by scripts/unicode_tables.py. All ranges are inclusive and sorted.\n'
+        % '.'.join(version.split('.')[:2]),
+        '/// Two letter names of the general categories, indexed by CATEGORIES. Cn\n'
+        '/// (unassigned) is not listed, it is everything not in CATEGORIES.\n'
+        'pub(super) static CATEGORY_NAMES: [&str; %d] = [\n'
+        '    %s,\n];\n' % (len(CATEGORY_NAMES), names),
+        table(['General category of all assigned code points: (first, last, category)'],
+              'CATEGORIES', '(u32, u32, u8)',
+              ['(0x%X, 0x%X, %d)' % tuple(r) for r in ranges(categories)]),
+        table([], 'XID_START', '(u32, u32)', ['(0x%X, 0x%X)' % tuple(r) for r in xid_start]),
+        table([], 'XID_CONTINUE', '(u32, u32)',
+              ['(0x%X, 0x%X)' % tuple(r) for r in xid_continue]),
+        table(['Full case folding (status C and F of CaseFolding.txt) of the characters',
+               'that change'],
+              'CASE_FOLDING', '(u32, &str)',
+              ['(0x%X, %s)' % (cp, string(folding[cp])) for cp in sorted(folding)]),
+        table(['Full canonical decomposition of the characters that change, except for',
+               'Hangul syllables, which are decomposed algorithmically'],
+              'DECOMPOSITIONS', '(u32, &str)',
+              ['(0x%X, %s)' % (cp, string(decompositions[cp])) for cp in sorted(decompositions)]),
+        table(['Canonical combining class of the non-starters: (first, last, class)'],
+              'COMBINING_CLASSES', '(u32, u32, u8)',
+              ['(0x%X, 0x%X, %d)' % tuple(r) for r in ranges(classes)]),
+    ]
+    with open(OUTPUT, 'w', encoding='utf-8') as f:
+        f.write('\n'.join(tables))
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,203 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::result::{EzpcError, LineIndex, Span};
+
+/// Renders an EzpcError like a compiler would: the error position (or a
+/// whole span) is underlined in the source, with surrounding context lines
+/// and secondary labels. Build it with the methods below and print it:
+/// `println!("{}", Diagnostic::new(&err, source).context(2).color(true))`
+pub struct Diagnostic<'a> {
+    source: &'a str,
+    message: String,
+    annotations: Vec<Annotation>,
+    context: usize,
+    color: bool,
+}
+
+struct Annotation {
+    start: usize,
+    end: usize,
+    label: String,
+    primary: bool,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// The source must be the one the error was returned for
+    pub fn new(err: &EzpcError, source: &'a str) -> Self {
+        let message = err.message();
+        // Without a span, the character at the error position is underlined
+        let start = err.position().offset().min(source.len());
+        let end = source[start..]
+            .chars()
+            .next()
+            .map_or(start, |c| start + c.len_utf8());
+
+        Self {
+            source,
+            message,
+            annotations: vec![Annotation {
+                start,
+                end,
+                label: String::new(),
+                primary: true,
+            }],
+            context: 1,
+            color: false,
+        }
+    }
+
+    /// Underline the whole span instead of the error position
+    pub fn span(mut self, span: Span) -> Self {
+        let primary = &mut self.annotations[0];
+        primary.start = span.start;
+        primary.end = span.end;
+        self
+    }
+
+    /// Add a secondary label, e.g. pointing to where an unclosed string started
+    pub fn label(mut self, span: Span, label: impl Into<String>) -> Self {
+        self.annotations.push(Annotation {
+            start: span.start,
+            end: span.end,
+            label: label.into(),
+            primary: false,
+        });
+        self
+    }
+
+    /// Number of lines shown before and after every annotated line, default 1
+    pub fn context(mut self, lines: usize) -> Self {
+        self.context = lines;
+        self
+    }
+
+    /// Use ANSI escape codes to color the output, off by default
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+const TAB_WIDTH: usize = 4;
+
+/// ANSI styles, which are empty if color is disabled
+struct Style {
+    error: &'static str,
+    secondary: &'static str,
+    bold: &'static str,
+    reset: &'static str,
+}
+
+impl Style {
+    fn new(color: bool) -> Self {
+        if color {
+            Self {
+                error: "\x1b[1;31m",
+                secondary: "\x1b[1;34m",
+                bold: "\x1b[1m",
+                reset: "\x1b[0m",
+            }
+        } else {
+            Self {
+                error: "",
+                secondary: "",
+                bold: "",
+                reset: "",
+            }
+        }
+    }
+}
+
+impl Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let style = Style::new(self.color);
+        let lines: Vec<&str> = self.source.split('\n').collect();
+        let line_index = LineIndex::new(self.source);
+        // Index of the line containing the byte offset
+        let line_of = |offset: usize| line_index.line(offset).0 - 1;
+        // Display column of the byte offset in its line
+        let column_of = |offset: usize| {
+            let (_, start) = line_index.line(offset);
+            width(self.source.get(start..offset).unwrap_or_default())
+        };
+
+        // Lines that contain an annotation and their context
+        let mut shown = Vec::new();
+        for ann in &self.annotations {
+            let first = line_of(ann.start).saturating_sub(self.context);
+            let last = line_of(ann.end.max(ann.start + 1) - 1) + self.context;
+            shown.extend(first..=last.min(lines.len() - 1));
+        }
+        shown.sort_unstable();
+        shown.dedup();
+
+        let primary = &self.annotations[0];
+        let pad = " ".repeat((shown.last().unwrap() + 1).ilog10() as usize + 1);
+        let (error, secondary, bold, reset) =
+            (style.error, style.secondary, style.bold, style.reset);
+
+        writeln!(f, "{error}error{reset}{bold}: {}{reset}", self.message)?;
+        writeln!(
+            f,
+            "{pad}{secondary}-->{reset} line {}, column {}",
+            line_of(primary.start) + 1,
+            column_of(primary.start) + 1
+        )?;
+        writeln!(f, "{pad} {secondary}|{reset}")?;
+
+        let mut previous = None;
+        for &index in &shown {
+            if previous.is_some_and(|previous| previous + 1 < index) {
+                writeln!(f, "{secondary}...{reset}")?;
+            }
+            previous = Some(index);
+
+            let line = lines[index].strip_suffix('\r').unwrap_or(lines[index]);
+            let number = (index + 1).to_string();
+            writeln!(
+                f,
+                "{secondary}{number:<width$} |{reset} {}",
+                line.replace('\t', &" ".repeat(TAB_WIDTH)),
+                width = pad.len()
+            )?;
+
+            // Underline the part of every annotation that is on this line
+            for ann in &self.annotations {
+                let last_char = ann.end.max(ann.start + 1) - 1;
+                if index < line_of(ann.start) || index > line_of(last_char) {
+                    continue;
+                }
+                let start = if index == line_of(ann.start) {
+                    column_of(ann.start)
+                } else {
+                    0
+                };
+                let end = if index == line_of(last_char) {
+                    column_of(ann.end.min(line_index.line(last_char).1 + line.len()))
+                } else {
+                    width(line)
+                };
+                let (mark, color) = if ann.primary {
+                    ('^', error)
+                } else {
+                    ('-', secondary)
+                };
+                let marks = mark.to_string().repeat(end.saturating_sub(start).max(1));
+                write!(f, "{pad} {secondary}|{reset} {}", " ".repeat(start))?;
+                if index == line_of(last_char) && !ann.label.is_empty() {
+                    writeln!(f, "{color}{marks} {}{reset}", ann.label)?;
+                } else {
+                    writeln!(f, "{color}{marks}{reset}")?;
+                }
+            }
+        }
+        write!(f, "{pad} {secondary}|{reset}")
+    }
+}
+
+/// Number of columns the text takes up when printed
+fn width(text: &str) -> usize {
+    text.chars()
+        .map(|c| if c == '\t' { TAB_WIDTH } else { 1 })
+        .sum()
+}
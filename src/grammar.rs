@@ -0,0 +1,348 @@
+use std::fmt::{Display, Formatter, Result, Write};
+
+/// Describes the grammar of a parser or matcher, which is why it is required
+/// by Parse and Match. Use Parser::grammar / Matcher::grammar to get the
+/// grammar and print it as EBNF or as railroad diagram.
+pub trait Grammar {
+    /// Describe the syntax this parser accepts. Wrapped parsers are added to
+    /// rules and referenced by name, which also ends their recursion.
+    /// Parsers that don't implement it are described by their type name.
+    fn describe(&self, _rules: &mut Rules) -> GrammarNode {
+        GrammarNode::Special(std::any::type_name::<Self>().to_owned())
+    }
+}
+
+/// Syntax tree of a grammar, see Grammar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrammarNode {
+    /// Literal text, like a tag
+    Terminal(String),
+    /// Set of characters or bytes in EBNF notation, like `[0-9]` or `#x00`
+    Class(String),
+    /// Something that can't be expressed in EBNF, like an is_a predicate
+    Special(String),
+    /// Reference to a named rule
+    Rule(String),
+    Sequence(Vec<GrammarNode>),
+    /// Alternatives, which are tried in order
+    Choice(Vec<GrammarNode>),
+    Optional(Box<GrammarNode>),
+    /// Repetition, max is None if unbounded
+    Repeat {
+        node: Box<GrammarNode>,
+        min: usize,
+        max: Option<usize>,
+    },
+    /// Input that must not match here, see reject() and the ! operator
+    Not(Box<GrammarNode>),
+    /// Input that must match here without being consumed, see peek()
+    Peek(Box<GrammarNode>),
+}
+
+impl GrammarNode {
+    /// Sequence of both nodes, nested sequences are flattened
+    pub(crate) fn then(self, next: GrammarNode) -> GrammarNode {
+        let mut nodes = match self {
+            GrammarNode::Sequence(nodes) => nodes,
+            node => vec![node],
+        };
+        match next {
+            GrammarNode::Sequence(next) => nodes.extend(next),
+            next => nodes.push(next),
+        }
+        GrammarNode::Sequence(nodes)
+    }
+
+    /// Choice between both nodes, nested choices are flattened
+    pub(crate) fn or(self, other: GrammarNode) -> GrammarNode {
+        let mut nodes = match self {
+            GrammarNode::Choice(nodes) => nodes,
+            node => vec![node],
+        };
+        match other {
+            GrammarNode::Choice(other) => nodes.extend(other),
+            other => nodes.push(other),
+        }
+        GrammarNode::Choice(nodes)
+    }
+
+    pub(crate) fn repeat(self, min: usize, max: Option<usize>) -> GrammarNode {
+        GrammarNode::Repeat {
+            node: Box::new(self),
+            min,
+            max,
+        }
+    }
+}
+
+/// The named rules of a grammar: the start rule, followed by all wrapped
+/// parsers and matchers in the order they were found. Display prints them
+/// as EBNF in the notation of the XML specification, one rule per line:
+/// `value ::= number | "[" value ("," value)* "]"`
+/// Bounded repetitions are written like `x{2,4}`, rejected input as `!x` and
+/// lookahead as `&x`.
+#[derive(Debug, Clone, Default)]
+pub struct Rules {
+    rules: Vec<(String, GrammarNode)>,
+    /// Rule name of each builder function path
+    names: Vec<(String, String)>,
+}
+
+impl Rules {
+    /// Grammar with a start rule of the given name for the parser
+    pub(crate) fn new(name: &str, parser: &impl Grammar) -> Self {
+        let mut rules = Rules::default();
+        rules
+            .rules
+            .push((name.to_owned(), GrammarNode::Sequence(Vec::new())));
+        let start = parser.describe(&mut rules);
+        rules.rules[0].1 = start;
+        rules
+    }
+
+    /// Reference the rule named after the type name of a wrapped parser's
+    /// builder function. Its syntax is described the first time it is found.
+    /// Functions of the same name in different modules get distinct rules:
+    /// the first one is `value`, the next ones are qualified like `toml.value`.
+    /// A function named like the start rule is referenced as the start rule.
+    pub fn rule(
+        &mut self,
+        type_name: &str,
+        describe: impl FnOnce(&mut Rules) -> GrammarNode,
+    ) -> GrammarNode {
+        let path = rule_path(type_name);
+        if let Some((_, name)) = self.names.iter().find(|(known, _)| known == path) {
+            return GrammarNode::Rule(name.clone());
+        }
+        // The start rule is usually named after the function it describes
+        let start = self.rules.first().map(|(start, _)| start.as_str());
+        let claimed = |start| self.names.iter().any(|(_, name)| name == start);
+        if let Some(start) = start.filter(|&start| start == rule_name(path) && !claimed(start)) {
+            let name = start.to_owned();
+            self.names.push((path.to_owned(), name.clone()));
+            return GrammarNode::Rule(name);
+        }
+        let name = self.unique_name(path);
+        self.names.push((path.to_owned(), name.clone()));
+        // Add a placeholder first, so recursive references end here
+        self.rules
+            .push((name.clone(), GrammarNode::Sequence(Vec::new())));
+        let index = self.rules.len() - 1;
+        self.rules[index].1 = describe(self);
+        GrammarNode::Rule(name)
+    }
+
+    /// The shortest suffix of the path that isn't the name of another rule
+    fn unique_name(&self, path: &str) -> String {
+        let segments: Vec<_> = path.split("::").collect();
+        let mut name = String::new();
+        for start in (0..segments.len()).rev() {
+            name = segments[start..].join(".");
+            if self.get(&name).is_none() {
+                return name;
+            }
+        }
+        // The full path is the name of the start rule
+        (2..)
+            .map(|n| format!("{name}_{n}"))
+            .find(|name| self.get(name).is_none())
+            .unwrap()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&GrammarNode> {
+        self.rules
+            .iter()
+            .find(|(rule, _)| rule == name)
+            .map(|(_, node)| node)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &GrammarNode)> {
+        self.rules.iter().map(|(name, node)| (name.as_str(), node))
+    }
+
+    /// The rules as EBNF text, same as Display
+    pub fn to_ebnf(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Rules {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let width = self.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        for (name, node) in self.iter() {
+            writeln!(f, "{name:width$} ::= {}", Ebnf(node, Context::Rule))?;
+        }
+        Ok(())
+    }
+}
+
+/// The node as EBNF expression
+impl Display for GrammarNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", Ebnf(self, Context::Rule))
+    }
+}
+
+/// Where a node is written, to decide if it needs parentheses
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+enum Context {
+    Rule,
+    Sequence,
+    Postfix,
+}
+
+struct Ebnf<'a>(&'a GrammarNode, Context);
+
+impl Display for Ebnf<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let Ebnf(node, context) = *self;
+        let parens = match node {
+            GrammarNode::Choice(_) => context > Context::Rule,
+            GrammarNode::Sequence(nodes) => nodes.len() > 1 && context == Context::Postfix,
+            GrammarNode::Terminal(text) => {
+                let pieces = text.chars().any(char::is_control) && text.chars().count() > 1;
+                pieces && context == Context::Postfix
+            }
+            GrammarNode::Optional(_)
+            | GrammarNode::Repeat { .. }
+            | GrammarNode::Not(_)
+            | GrammarNode::Peek(_) => context == Context::Postfix,
+            _ => false,
+        };
+        if parens {
+            return write!(f, "({})", Ebnf(node, Context::Rule));
+        }
+
+        match node {
+            GrammarNode::Terminal(text) => write_terminal(f, text),
+            GrammarNode::Class(class) => f.write_str(class),
+            GrammarNode::Special(text) => write!(f, "? {text} ?"),
+            GrammarNode::Rule(name) => f.write_str(name),
+            GrammarNode::Sequence(nodes) if nodes.is_empty() => f.write_str("\"\""),
+            GrammarNode::Sequence(nodes) => write_joined(f, nodes, " ", Context::Sequence),
+            GrammarNode::Choice(nodes) => write_joined(f, nodes, " | ", Context::Sequence),
+            GrammarNode::Optional(node) => write!(f, "{}?", Ebnf(node, Context::Postfix)),
+            GrammarNode::Repeat { node, min, max } => {
+                write!(f, "{}", Ebnf(node, Context::Postfix))?;
+                match (min, max) {
+                    (0, None) => f.write_str("*"),
+                    (1, None) => f.write_str("+"),
+                    (0, Some(1)) => f.write_str("?"),
+                    (min, None) => write!(f, "{{{min},}}"),
+                    (min, Some(max)) if min == max => write!(f, "{{{min}}}"),
+                    (min, Some(max)) => write!(f, "{{{min},{max}}}"),
+                }
+            }
+            GrammarNode::Not(node) => write!(f, "!{}", Ebnf(node, Context::Postfix)),
+            GrammarNode::Peek(node) => write!(f, "&{}", Ebnf(node, Context::Postfix)),
+        }
+    }
+}
+
+fn write_joined(
+    f: &mut Formatter<'_>,
+    nodes: &[GrammarNode],
+    sep: &str,
+    context: Context,
+) -> Result {
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            f.write_str(sep)?;
+        }
+        write!(f, "{}", Ebnf(node, context))?;
+    }
+    Ok(())
+}
+
+/// Quoted text. Control characters, the quote that can't be used and `\`
+/// are written as character references, EBNF strings have no escapes.
+fn write_terminal(f: &mut Formatter<'_>, text: &str) -> Result {
+    if text.is_empty() {
+        return f.write_str("\"\"");
+    }
+    let quote = if text.contains('"') && !text.contains('\'') {
+        '\''
+    } else {
+        '"'
+    };
+    let mut quoted = false;
+    for (i, c) in text.chars().enumerate() {
+        if c.is_control() || c == quote || c == '\\' {
+            if quoted {
+                f.write_char(quote)?;
+                quoted = false;
+            }
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+            write!(f, "#x{:X}", c as u32)?;
+        } else {
+            if !quoted {
+                if i > 0 {
+                    f.write_char(' ')?;
+                }
+                f.write_char(quote)?;
+                quoted = true;
+            }
+            f.write_char(c)?;
+        }
+    }
+    if quoted {
+        f.write_char(quote)?;
+    }
+    Ok(())
+}
+
+/// Character class of the characters in bag, like `[abc]` or `[^abc]`
+pub(crate) fn char_class(bag: &str, negated: bool) -> GrammarNode {
+    let mut class = String::from(if negated { "[^" } else { "[" });
+    for c in bag.chars() {
+        push_class_char(&mut class, c);
+    }
+    class.push(']');
+    GrammarNode::Class(class)
+}
+
+/// Character class of the characters from start to end, like `[a-z]`
+pub(crate) fn char_range(start: char, end: char) -> GrammarNode {
+    char_ranges(&[(start, end)])
+}
+
+/// Character class of several ranges, like `[0-9a-f]`
+pub(crate) fn char_ranges(ranges: &[(char, char)]) -> GrammarNode {
+    let mut class = String::from("[");
+    for &(start, end) in ranges {
+        push_class_char(&mut class, start);
+        if end != start {
+            class.push('-');
+            push_class_char(&mut class, end);
+        }
+    }
+    class.push(']');
+    GrammarNode::Class(class)
+}
+
+/// Characters with a special meaning in classes are written as references
+fn push_class_char(class: &mut String, c: char) {
+    if c.is_control() || c.is_whitespace() || "[]^-#\\".contains(c) {
+        write!(class, "#x{:X}", c as u32).unwrap();
+    } else {
+        class.push(c);
+    }
+}
+
+/// Short name for a type name, like `value` for `my_crate::json::value` or
+/// for the closures inside of it
+pub(crate) fn rule_name(type_name: &str) -> &str {
+    let path = rule_path(type_name);
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// Path of the function of a type name, without the closures inside of it
+fn rule_path(type_name: &str) -> &str {
+    let mut path = type_name;
+    while let Some(outer) = path.strip_suffix("::{{closure}}") {
+        path = outer;
+    }
+    path
+}
@@ -0,0 +1,90 @@
+use crate::result::Position;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for str {}
+    impl Sealed for [u8] {}
+}
+
+/// Types of input that parsers and matchers can be applied to: text (str)
+/// or binary data ([u8]). Parse and Match are generic over the input with
+/// str as default, so all combinators and modifiers work with both.
+pub trait Input: private::Sealed + 'static {
+    /// Distinguishes text from binary input, which has no lines or characters
+    const TEXT: bool;
+
+    fn as_ptr(&self) -> *const u8;
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Part of the input starting at the byte offset
+    fn tail(&self, offset: usize) -> &Self;
+
+    /// Part of the input up to the byte offset
+    fn head(&self, offset: usize) -> &Self;
+
+    /// Byte offsets of all chars (text) or bytes (binary), in order
+    fn offsets(&self) -> impl Iterator<Item = usize> + '_;
+
+    /// Printable position of ptr, which must point into this input
+    fn position(&self, ptr: *const u8) -> Position;
+}
+
+impl Input for str {
+    const TEXT: bool = true;
+
+    fn as_ptr(&self) -> *const u8 {
+        str::as_ptr(self)
+    }
+
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    fn tail(&self, offset: usize) -> &Self {
+        &self[offset..]
+    }
+
+    fn head(&self, offset: usize) -> &Self {
+        &self[..offset]
+    }
+
+    fn offsets(&self) -> impl Iterator<Item = usize> + '_ {
+        self.char_indices().map(|(i, _)| i)
+    }
+
+    fn position(&self, ptr: *const u8) -> Position {
+        Position::from_ptr(self, ptr)
+    }
+}
+
+impl Input for [u8] {
+    const TEXT: bool = false;
+
+    fn as_ptr(&self) -> *const u8 {
+        <[u8]>::as_ptr(self)
+    }
+
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    fn tail(&self, offset: usize) -> &Self {
+        &self[offset..]
+    }
+
+    fn head(&self, offset: usize) -> &Self {
+        &self[..offset]
+    }
+
+    fn offsets(&self) -> impl Iterator<Item = usize> + '_ {
+        0..self.len()
+    }
+
+    fn position(&self, ptr: *const u8) -> Position {
+        Position::from_byte_ptr(self, ptr)
+    }
+}
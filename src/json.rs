@@ -0,0 +1,122 @@
+use std::fmt::Write;
+
+use crate::result::{ErrorKind, EzpcError, Position};
+
+// Errors are serialized by hand, as ezpc has no dependencies
+
+impl Position {
+    /// Serialize as JSON object with the byte offset, line and column
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"offset":{},"line":{},"column":{}}}"#,
+            self.offset, self.line, self.column
+        )
+    }
+}
+
+impl EzpcError {
+    /// Serialize as JSON object, file is the name of the parsed source:
+    /// `{"file":"a.toml","kind":"fatal","message":"..","offset":4,"line":1,"column":5}`
+    /// Partial parse errors additionally contain the list of expected inputs.
+    pub fn to_json(&self, file: &str) -> String {
+        let pos = self.position();
+        let mut json = format!(
+            r#"{{"file":{},"kind":"{}","message":{},"offset":{},"line":{},"column":{}"#,
+            string(file),
+            kind(self),
+            string(&self.message()),
+            pos.offset,
+            pos.line,
+            pos.column
+        );
+        if let EzpcError::PartialParse { expected, .. } = self {
+            let expected: Vec<_> = expected
+                .iter()
+                .map(|exp| string(&exp.to_string()))
+                .collect();
+            write!(json, r#","expected":[{}]"#, expected.join(",")).unwrap();
+        }
+        json + "}"
+    }
+}
+
+/// Build a SARIF 2.1.0 log of the errors found by the tool in the given files.
+/// Columns are reported in characters (unicodeCodePoints), like in Position.
+/// The file paths are percent-encoded into relative URIs, use `/` to separate
+/// directories.
+pub fn to_sarif<'a>(
+    tool: &str,
+    errors: impl IntoIterator<Item = (&'a str, &'a EzpcError)>,
+) -> String {
+    let results: Vec<_> = errors
+        .into_iter()
+        .map(|(file, err)| {
+            let pos = err.position();
+            format!(
+                concat!(
+                    r#"{{"ruleId":"{}","level":"error","message":{{"text":{}}},"#,
+                    r#""locations":[{{"physicalLocation":{{"artifactLocation":{{"uri":{}}},"#,
+                    r#""region":{{"startLine":{},"startColumn":{},"byteOffset":{}}}}}}}]}}"#
+                ),
+                kind(err),
+                string(&err.message()),
+                string(&uri(file)),
+                pos.line,
+                pos.column,
+                pos.offset
+            )
+        })
+        .collect();
+
+    format!(
+        concat!(
+            r#"{{"version":"2.1.0","$schema":"https://json.schemastore.org/sarif-2.1.0.json","#,
+            r#""runs":[{{"tool":{{"driver":{{"name":{},"rules":["#,
+            r#"{{"id":"partial"}},{{"id":"fatal"}},{{"id":"recursion"}}]}}}},"#,
+            r#""columnKind":"unicodeCodePoints","results":[{}]}}]}}"#
+        ),
+        string(tool),
+        results.join(",")
+    )
+}
+
+pub(crate) fn kind(err: &EzpcError) -> &'static str {
+    match err.kind() {
+        ErrorKind::PartialParse => "partial",
+        ErrorKind::Fatal => "fatal",
+        ErrorKind::Recursion => "recursion",
+    }
+}
+
+/// Percent-encodes all bytes of the path except unreserved characters and `/`
+fn uri(path: &str) -> String {
+    let mut uri = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                uri.push(byte as char)
+            }
+            byte => write!(uri, "%{byte:02X}").unwrap(),
+        }
+    }
+    uri
+}
+
+/// Helper function that quotes and escapes a JSON string
+pub(crate) fn string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c < ' ' => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
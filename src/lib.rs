@@ -1,11 +1,38 @@
+mod diagnostic;
+mod grammar;
+mod input;
+mod json;
+#[cfg(feature = "lsp")]
+pub mod lsp;
+mod macros;
 mod parser;
+mod railroad;
 mod range;
 mod result;
+mod state;
+mod trace;
 
+pub use diagnostic::Diagnostic;
+#[cfg(feature = "derive")]
+pub use ezpc_derive::Parse;
+pub use grammar::{Grammar, GrammarNode, Rules};
+pub use input::Input;
+pub use json::to_sarif;
 pub use parser::{
-    combinators::list,
-    matchers::{eof, is_a, none_of, one_of, tag},
-    wrap::{WrapMatcher, WrapParser},
-    Match, Matcher, Parse, Parser,
+    bytes, classes,
+    combinators::{list, precedence, skip_until, Assoc},
+    matchers::{char_in, eof, is_a, none_of, one_of, tag},
+    regex::regex,
+    seq::seq,
+    stream::{ParseStream, StreamError},
+    tags::{keyword, keyword_ci, one_of_tags, tag_ascii_ci, tag_ci, tag_normalized},
+    wrap::{
+        SyncWrappedMatcher, SyncWrappedParser, WrapMatcher, WrapMatcherSync, WrapParser,
+        WrapParserSync,
+    },
+    Match, Matcher, Parse, Parser, Part, StrPart,
 };
-pub use result::{EzpcError, MatchResult, ParseResult};
+pub use result::{
+    ErrorKind, Expected, EzpcError, LineIndex, MatchResult, ParseResult, Position, Span, Substr,
+};
+pub use trace::{Outcome, Trace, TraceEvent};
@@ -0,0 +1,308 @@
+//! Language server that publishes the errors of an ezpc parser as diagnostics.
+//!
+//! The server speaks the Language Server Protocol over any reader and writer,
+//! use `serve_stdio` when the editor starts it as a process:
+//! ```no_run
+//! # use ezpc::*;
+//! let parser = (tag("a") | tag("b")).repeat(0..).map(|s| s.len());
+//! ezpc::lsp::LanguageServer::new("ab-lsp", parser).serve_stdio().unwrap();
+//! ```
+//! Documents are synced in full and reparsed on every change.
+
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::json::{kind, string};
+use crate::parser::{combinators::list, matchers::*, wrap::WrapParser, Match, Matcher};
+use crate::{EzpcError, Parse, Parser};
+
+/// Parses a document and returns the errors that were found
+type Check = Box<dyn Fn(&str) -> Vec<EzpcError>>;
+
+/// Serves diagnostics for documents parsed with a parser or matcher
+pub struct LanguageServer {
+    name: String,
+    check: Check,
+}
+
+impl LanguageServer {
+    /// Server that reports the errors of parse_all_recover, the name is shown
+    /// as source of the diagnostics
+    pub fn new<P: Parse + 'static>(name: &str, parser: Parser<P>) -> Self {
+        Self::with_check(name, move |text| parser.parse_all_recover(text).1)
+    }
+
+    /// Server that reports the errors of match_all_recover
+    pub fn for_matcher<M: Match + 'static>(name: &str, matcher: Matcher<M>) -> Self {
+        Self::with_check(name, move |text| matcher.match_all_recover(text))
+    }
+
+    fn with_check(name: &str, check: impl Fn(&str) -> Vec<EzpcError> + 'static) -> Self {
+        Self {
+            name: name.to_owned(),
+            check: Box::new(check),
+        }
+    }
+
+    /// Serve on stdin and stdout until the client sends exit
+    pub fn serve_stdio(&self) -> io::Result<()> {
+        self.serve(io::stdin().lock(), io::stdout().lock())
+    }
+
+    /// Serve the messages read from input until the client sends exit or
+    /// closes the input. Messages are framed with Content-Length headers.
+    pub fn serve(&self, mut input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        while let Some(content) = read_message(&mut input)? {
+            let Ok(message) = value().parse_all(&content) else {
+                let error = r#"{"code":-32700,"message":"Invalid JSON"}"#;
+                write_message(&mut output, &response("null", "error", error))?;
+                continue;
+            };
+            if message.get("method").and_then(Json::as_str) == Some("exit") {
+                break;
+            }
+            for reply in self.handle(&message) {
+                write_message(&mut output, &reply)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the responses and notifications to send for the message
+    fn handle(&self, message: &Json) -> Vec<String> {
+        let method = message.get("method").and_then(Json::as_str).unwrap_or("");
+        let params = message.get("params").unwrap_or(&Json::Null);
+        let document = params.get("textDocument");
+        let uri = document
+            .and_then(|doc| doc.get("uri"))
+            .and_then(Json::as_str);
+
+        let result = match (method, uri) {
+            ("initialize", _) => format!(
+                r#"{{"capabilities":{{"textDocumentSync":1}},"serverInfo":{{"name":{}}}}}"#,
+                string(&self.name)
+            ),
+            ("shutdown", _) => "null".to_owned(),
+            ("textDocument/didOpen", Some(uri)) => {
+                let text = document.and_then(|doc| doc.get("text"));
+                return text
+                    .and_then(Json::as_str)
+                    .map(|text| self.publish(uri, text))
+                    .into_iter()
+                    .collect();
+            }
+            ("textDocument/didChange", Some(uri)) => {
+                // Documents are synced in full, so the last change is the whole text
+                let changes = params.get("contentChanges").and_then(Json::as_array);
+                let text = changes.and_then(|changes| changes.last()?.get("text"));
+                return text
+                    .and_then(Json::as_str)
+                    .map(|text| self.publish(uri, text))
+                    .into_iter()
+                    .collect();
+            }
+            // Clear the diagnostics of closed documents
+            ("textDocument/didClose", Some(uri)) => return vec![notification(uri, &[])],
+            _ => match message.get("id") {
+                // Requests are answered, unknown notifications are ignored
+                Some(_) => {
+                    let error = format!(
+                        r#"{{"code":-32601,"message":{}}}"#,
+                        string(&format!("Unknown method `{method}`"))
+                    );
+                    return vec![response(&id(message), "error", &error)];
+                }
+                None => return Vec::new(),
+            },
+        };
+        vec![response(&id(message), "result", &result)]
+    }
+
+    /// Parse the text and build the publishDiagnostics notification
+    fn publish(&self, uri: &str, text: &str) -> String {
+        let diagnostics: Vec<_> = (self.check)(text)
+            .iter()
+            .map(|err| self.diagnostic(err, text))
+            .collect();
+        notification(uri, &diagnostics)
+    }
+
+    /// LSP diagnostic that marks the character at the error position
+    fn diagnostic(&self, err: &EzpcError, text: &str) -> String {
+        let pos = err.position();
+        let line = pos.line() - 1;
+        let start = pos.utf16_column() - 1;
+        // Line breaks and the end of the text have no width
+        let end = match text[pos.offset()..].chars().next() {
+            Some(c) if c != '\n' && c != '\r' => start + c.len_utf16(),
+            _ => start,
+        };
+        format!(
+            concat!(
+                r#"{{"range":{{"start":{{"line":{},"character":{}}},"#,
+                r#""end":{{"line":{},"character":{}}}}},"#,
+                r#""severity":1,"code":"{}","source":{},"message":{}}}"#
+            ),
+            line,
+            start,
+            line,
+            end,
+            kind(err),
+            string(&self.name),
+            string(&err.message())
+        )
+    }
+}
+
+fn response(id: &str, key: &str, value: &str) -> String {
+    format!(r#"{{"jsonrpc":"2.0","id":{id},"{key}":{value}}}"#)
+}
+
+fn notification(uri: &str, diagnostics: &[String]) -> String {
+    format!(
+        concat!(
+            r#"{{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","#,
+            r#""params":{{"uri":{},"diagnostics":[{}]}}}}"#
+        ),
+        string(uri),
+        diagnostics.join(",")
+    )
+}
+
+/// The id of the request, serialized again for the response
+fn id(message: &Json) -> String {
+    match message.get("id") {
+        Some(Json::Number(id)) => id.to_string(),
+        Some(Json::String(id)) => string(id),
+        _ => "null".to_owned(),
+    }
+}
+
+/// Read the content of the next message, None if the input is closed.
+/// A header without Content-Length is an InvalidData error, the end of its
+/// content can't be found.
+fn read_message(input: &mut impl BufRead) -> io::Result<Option<String>> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg);
+    let mut header = String::new();
+    let mut length = None;
+    loop {
+        header.clear();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                let value = value.trim().parse();
+                length = Some(value.map_err(|_| invalid("Invalid Content-Length"))?);
+            }
+        }
+    }
+    let length: usize = length.ok_or_else(|| invalid("Message without Content-Length"))?;
+    let mut content = vec![0; length];
+    input.read_exact(&mut content)?;
+    String::from_utf8(content)
+        .map(Some)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+fn write_message(output: &mut impl Write, content: &str) -> io::Result<()> {
+    write!(output, "Content-Length: {}\r\n\r\n{content}", content.len())?;
+    output.flush()
+}
+
+/// The JSON values of the received messages
+#[derive(Clone, Debug, PartialEq)]
+enum Json {
+    Object(Vec<(String, Json)>),
+    Array(Vec<Json>),
+    String(String),
+    Number(f64),
+    Bool(bool),
+    Null,
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(elements) => Some(elements),
+            _ => None,
+        }
+    }
+}
+
+// A JSON parser, messages are written by the client so errors are not detailed
+
+fn value_inner() -> Parser<impl Parse<Output = Json>> {
+    let member = ws() + string_value() + ws() + tag(":") + value();
+    let object = tag("{")
+        + ((ws() + tag("}")).val(Vec::new()) | (list(member, tag(","), INVALID) + tag("}")));
+    let array = tag("[")
+        + ((ws() + tag("]")).val(Vec::new()) | (list(value(), tag(","), INVALID) + tag("]")));
+
+    let digits = || one_of("0123456789").repeat(1..);
+    let number = (tag("-").opt()
+        + digits()
+        + (tag(".") + digits()).opt()
+        + (one_of("eE") + one_of("+-").opt() + digits()).opt())
+    .convert(f64::from_str, INVALID);
+
+    ws() + (object.map(Json::Object)
+        | array.map(Json::Array)
+        | string_value().map(Json::String)
+        | number.map(Json::Number)
+        | tag("true").val(Json::Bool(true))
+        | tag("false").val(Json::Bool(false))
+        | tag("null").val(Json::Null))
+        + ws()
+}
+
+fn value() -> Parser<impl Parse<Output = Json>> {
+    value_inner.wrap(100)
+}
+
+fn string_value() -> Parser<impl Parse<Output = String>> {
+    let chars = none_of("\\\"").repeat(1..).map(|s| s.to_owned());
+    let hex = is_a(|c| c.is_ascii_hexdigit())
+        .repeat(4)
+        .map(|s| u16::from_str_radix(s, 16).unwrap());
+    let utf16 = (tag("\\u") + hex).repeat(1..).map(|units| {
+        char::decode_utf16(units)
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    });
+    let esc = tag("\"").val("\"")
+        | tag("\\").val("\\")
+        | tag("/").val("/")
+        | tag("b").val("\x08")
+        | tag("f").val("\x0C")
+        | tag("n").val("\n")
+        | tag("r").val("\r")
+        | tag("t").val("\t");
+    let esc = (tag("\\") + esc).map(|s| s.to_owned());
+
+    (tag("\"") + (chars | utf16 | esc).repeat(0..) + tag("\"")).map(|strs| strs.concat())
+}
+
+fn ws() -> Matcher<impl Match> {
+    one_of("\n\r\t ").repeat(0..)
+}
+
+const INVALID: &str = "Invalid JSON:";
@@ -0,0 +1,160 @@
+/// Defines matchers and parsers with a PEG-like syntax, one function per rule:
+/// ```
+/// ezpc::grammar! {
+///     pub value = number / array;
+///     array = "[" ws (value ws ("," ws value ws)*)? "]";
+///     number = "-"? ['0'..='9']+ ("." ['0'..='9']+)?;
+///     ws = [" \t\n"]*;
+/// }
+/// assert!(value().match_all("[1, [-2.5], []]").is_ok());
+/// ```
+/// A rule is a sequence of expressions, alternatives are separated by `/`:
+/// - `"lit"` matches the text, like tag()
+/// - `["abc"]` matches one of the characters, `[^"abc"]` none of them
+/// - `['a'..='z']` matches a character in the range, like char_in().
+///   Several ranges and strings in one class match any of them: `['a'..='z' "_"]`
+/// - `name` refers to a rule or to any other function returning a Matcher
+///   or Parser
+/// - `(...)` groups expressions
+/// - `*`, `+` and `?` repeat the previous expression or make it optional
+///
+/// A rule with an output type is a parser. Its output is built by the action
+/// after `=>`, which gets the output of the expression or the matched text:
+/// ```
+/// ezpc::grammar! {
+///     pub sum -> i64 = number ("+" number)* => |(first, rest): (i64, Vec<i64>)| {
+///         first + rest.iter().sum::<i64>()
+///     };
+///     number -> i64 = ['0'..='9']+ => |s: &str| s.parse().unwrap();
+/// }
+/// assert_eq!(sum().parse_all("1+20+300").unwrap(), 321);
+/// ```
+///
+/// References to the rules of the same grammar are wrapped, so rules can be
+/// mutually recursive. They are wrapped with a max depth of 100, which can be
+/// set with a leading `#![max_depth = 20]`. Large grammars may need a higher
+/// `recursion_limit`.
+#[macro_export]
+macro_rules! grammar {
+    // The names of the rules are collected first, then the rules one by one.
+    // The expression is built up in out and its last element in atom, so
+    // that suffixes can apply to it. ctx holds the max depth and the names.
+    (#![max_depth = $depth:expr] $($rules:tt)*) => {
+        $crate::grammar!(@names $depth [] [$($rules)*] $($rules)*);
+    };
+    (@names $depth:tt $names:tt $rules:tt) => {
+        $crate::grammar!(@rules ($depth $names) $rules);
+    };
+    (@names $depth:tt [$($names:tt)*] $rules:tt
+        $(#[$attr:meta])* $vis:vis $name:ident = $($rest:tt)*) => {
+        $crate::grammar!(@skip $depth [$($names)* ($name WrapMatcher)] $rules $($rest)*);
+    };
+    (@names $depth:tt [$($names:tt)*] $rules:tt
+        $(#[$attr:meta])* $vis:vis $name:ident -> $ty:ty = $($rest:tt)*) => {
+        $crate::grammar!(@skip $depth [$($names)* ($name WrapParser<$ty>)] $rules $($rest)*);
+    };
+    (@skip $depth:tt $names:tt $rules:tt ; $($rest:tt)*) => {
+        $crate::grammar!(@names $depth $names $rules $($rest)*);
+    };
+    (@skip $depth:tt $names:tt $rules:tt $token:tt $($rest:tt)*) => {
+        $crate::grammar!(@skip $depth $names $rules $($rest)*);
+    };
+
+    (@rules $ctx:tt [$($rules:tt)*]) => {
+        $crate::grammar!(@rule_list $ctx $($rules)*);
+    };
+    (@rule_list $ctx:tt) => {};
+    (@rule_list $ctx:tt $(#[$attr:meta])* $vis:vis $name:ident = $($rest:tt)*) => {
+        $crate::grammar!(@expr $ctx { $(#[$attr])* $vis $name } [] [] $($rest)*);
+    };
+    (@rule_list $ctx:tt $(#[$attr:meta])* $vis:vis $name:ident -> $ty:ty = $($rest:tt)*) => {
+        $crate::grammar!(@expr $ctx { $(#[$attr])* $vis $name -> $ty } [] [] $($rest)*);
+    };
+
+    // End of a rule
+    (@expr $ctx:tt { $($head:tt)* } [$($out:tt)*] [$($atom:tt)+] ; $($rest:tt)*) => {
+        $crate::grammar!(@rule $ctx { $($head)* } $($out)* $($atom)+);
+        $crate::grammar!(@rule_list $ctx $($rest)*);
+    };
+    (@expr $ctx:tt { $($head:tt)* } [$($out:tt)*] [$($atom:tt)+] => $action:expr ; $($rest:tt)*) => {
+        $crate::grammar!(@rule $ctx { $($head)* } ($($out)* $($atom)+).map($action));
+        $crate::grammar!(@rule_list $ctx $($rest)*);
+    };
+    // End of a group
+    (@expr $ctx:tt {} [$($out:tt)*] [$($atom:tt)+]) => {
+        $($out)* $($atom)+
+    };
+    (@expr $ctx:tt $head:tt [$($out:tt)*] [$($atom:tt)+] * $($rest:tt)*) => {
+        $crate::grammar! { @expr $ctx $head [$($out)*] [$($atom)+.repeat(0..)] $($rest)* }
+    };
+    (@expr $ctx:tt $head:tt [$($out:tt)*] [$($atom:tt)+] + $($rest:tt)*) => {
+        $crate::grammar! { @expr $ctx $head [$($out)*] [$($atom)+.repeat(1..)] $($rest)* }
+    };
+    (@expr $ctx:tt $head:tt [$($out:tt)*] [$($atom:tt)+] ? $($rest:tt)*) => {
+        $crate::grammar! { @expr $ctx $head [$($out)*] [$($atom)+.opt()] $($rest)* }
+    };
+    (@expr $ctx:tt $head:tt [$($out:tt)*] [$($atom:tt)+] / $($rest:tt)*) => {
+        $crate::grammar! { @expr $ctx $head [$($out)* $($atom)+ |] [] $($rest)* }
+    };
+    // A new atom follows the previous one in sequence
+    (@expr $ctx:tt $head:tt [$($out:tt)*] [$($atom:tt)+] $($rest:tt)+) => {
+        $crate::grammar! { @expr $ctx $head [$($out)* $($atom)+ +] [] $($rest)+ }
+    };
+    (@expr $ctx:tt $head:tt $out:tt [] [$($class:tt)+] $($rest:tt)*) => {
+        $crate::grammar! { @expr $ctx $head $out [$crate::grammar!(@class $($class)+)] $($rest)* }
+    };
+    (@expr $ctx:tt $head:tt $out:tt [] ($($group:tt)+) $($rest:tt)*) => {
+        $crate::grammar! {
+            @expr $ctx $head $out [($crate::grammar!(@expr $ctx {} [] [] $($group)+))] $($rest)*
+        }
+    };
+    (@expr $ctx:tt $head:tt $out:tt [] $tag:literal $($rest:tt)*) => {
+        $crate::grammar! { @expr $ctx $head $out [$crate::tag($tag)] $($rest)* }
+    };
+    (@expr $ctx:tt $head:tt $out:tt [] $rule:ident $($rest:tt)*) => {
+        $crate::grammar! { @expr $ctx $head $out [$rule()] $($rest)* }
+    };
+
+    (@rule $ctx:tt { $(#[$attr:meta])* $vis:vis $name:ident } $($body:tt)+) => {
+        $(#[$attr])*
+        $vis fn $name() -> $crate::Matcher<impl $crate::Match> {
+            $crate::grammar!(@wrap $ctx);
+            $($body)+
+        }
+    };
+    (@rule $ctx:tt { $(#[$attr:meta])* $vis:vis $name:ident -> $ty:ty } $($body:tt)+) => {
+        $(#[$attr])*
+        $vis fn $name() -> $crate::Parser<impl $crate::Parse<Output = $ty>> {
+            $crate::grammar!(@wrap $ctx);
+            $($body)+
+        }
+    };
+    // The rules of the grammar are shadowed by closures that wrap them
+    (@wrap ($depth:tt [$(($rule:ident $($wrap:tt)+))*])) => {
+        $(
+            #[allow(unused_variables)]
+            let $rule = || <_ as $crate::$($wrap)+>::wrap($rule, $depth);
+        )*
+    };
+
+    // Character classes, several items are alternatives
+    (@class ^ $bag:literal) => {
+        $crate::none_of($bag)
+    };
+    (@class $start:literal ..= $end:literal) => {
+        $crate::char_in($start..=$end)
+    };
+    (@class $start:literal ..= $end:literal $($rest:tt)+) => {
+        $crate::char_in($start..=$end) | $crate::grammar!(@class $($rest)+)
+    };
+    (@class $bag:literal) => {
+        $crate::one_of($bag)
+    };
+    (@class $bag:literal $($rest:tt)+) => {
+        $crate::one_of($bag) | $crate::grammar!(@class $($rest)+)
+    };
+
+    ($(#[$attr:meta])* $vis:vis $name:ident $($rest:tt)*) => {
+        $crate::grammar!(#![max_depth = 100] $(#[$attr])* $vis $name $($rest)*);
+    };
+}
@@ -0,0 +1,222 @@
+//! Matchers and parsers for binary input. They implement Match<[u8]> and
+//! Parse<[u8]>, so they combine with each other and all modifiers like the
+//! ones for text. Apply them with Parser::parse_bytes / Matcher::match_bytes.
+
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+use super::{Match, Matcher, Parse, Parser};
+use crate::grammar::{Grammar, GrammarNode, Rules};
+use crate::result::{Expected, MatchResult, ParseResult, RawEzpcError};
+use crate::state;
+
+pub struct Tag(&'static [u8]);
+pub fn tag(tag: &'static [u8]) -> Matcher<Tag> {
+    Matcher(Tag(tag))
+}
+
+pub struct ByteIn {
+    start: u8,
+    end: u8,
+}
+/// Matches a single byte in the range, e.g. `byte_in(b'0'..=b'9')`
+pub fn byte_in<R: RangeBounds<u8>>(range: R) -> Matcher<ByteIn> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("empty byte range"),
+        Bound::Unbounded => u8::MIN,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.checked_sub(1).expect("empty byte range"),
+        Bound::Unbounded => u8::MAX,
+    };
+    Matcher(ByteIn { start, end })
+}
+
+pub struct Take(usize);
+/// Matches exactly count bytes, no matter their value
+pub fn take(count: usize) -> Matcher<Take> {
+    Matcher(Take(count))
+}
+
+pub struct LengthPrefixed<P>(P);
+/// Applies the length parser and then outputs as many bytes as it returned,
+/// without the length prefix, e.g. `length_prefixed(be::<u16>())`
+pub fn length_prefixed<P>(length: Parser<P>) -> Parser<LengthPrefixed<P>>
+where
+    P: Parse<[u8]>,
+    P::Output: TryInto<usize>,
+{
+    Parser(LengthPrefixed(length.0))
+}
+
+pub struct Number<T> {
+    big_endian: bool,
+    number: PhantomData<fn() -> T>,
+}
+/// Parses a big-endian number, e.g. `be::<u32>()`
+pub fn be<T: FromBytes>() -> Parser<Number<T>> {
+    Parser(Number {
+        big_endian: true,
+        number: PhantomData,
+    })
+}
+/// Parses a little-endian number, e.g. `le::<f64>()`
+pub fn le<T: FromBytes>() -> Parser<Number<T>> {
+    Parser(Number {
+        big_endian: false,
+        number: PhantomData,
+    })
+}
+
+/// Numbers that can be parsed with be() and le()
+pub trait FromBytes: Sized {
+    const SIZE: usize;
+    /// Both functions are called with exactly SIZE bytes
+    fn from_be(bytes: &[u8]) -> Self;
+    fn from_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_from_bytes {
+    ($($ty:ty)*) => {$(
+        impl FromBytes for $ty {
+            const SIZE: usize = std::mem::size_of::<$ty>();
+
+            fn from_be(bytes: &[u8]) -> Self {
+                <$ty>::from_be_bytes(bytes.try_into().unwrap())
+            }
+
+            fn from_le(bytes: &[u8]) -> Self {
+                <$ty>::from_le_bytes(bytes.try_into().unwrap())
+            }
+        }
+    )*};
+}
+
+impl_from_bytes!(u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 f32 f64);
+
+// All the Match and Parse implementations for the binary input above
+
+impl Match<[u8]> for Tag {
+    fn apply<'a>(&self, input: &'a [u8]) -> MatchResult<'a, [u8]> {
+        if let Some(rest) = input.strip_prefix(self.0) {
+            Ok(rest)
+        } else {
+            if self.0.starts_with(input) {
+                state::hit_end();
+            }
+            state::expected(input.as_ptr(), Expected::ByteTag(self.0));
+            Err(RawEzpcError::Mismatch {
+                pos: input.as_ptr(),
+            })
+        }
+    }
+}
+
+impl Match<[u8]> for ByteIn {
+    fn apply<'a>(&self, input: &'a [u8]) -> MatchResult<'a, [u8]> {
+        match input.split_first() {
+            Some((byte, rest)) if (self.start..=self.end).contains(byte) => return Ok(rest),
+            Some(_) => (),
+            None => state::hit_end(),
+        }
+        state::expected(input.as_ptr(), Expected::ByteIn(self.start, self.end));
+        Err(RawEzpcError::Mismatch {
+            pos: input.as_ptr(),
+        })
+    }
+}
+
+impl Match<[u8]> for Take {
+    fn apply<'a>(&self, input: &'a [u8]) -> MatchResult<'a, [u8]> {
+        split(input, self.0).map(|(_, rest)| rest)
+    }
+}
+
+impl<P> Parse<[u8]> for LengthPrefixed<P>
+where
+    P: Parse<[u8]>,
+    P::Output: TryInto<usize>,
+{
+    type Output = Vec<u8>;
+
+    fn apply<'a>(&self, input: &'a [u8]) -> ParseResult<'a, Self::Output, [u8]> {
+        let (length, rest) = self.0.apply(input)?;
+        // A length that does not fit in usize is longer than any input
+        let length = length.try_into().unwrap_or(usize::MAX);
+        split(rest, length).map(|(payload, rest)| (payload.to_vec(), rest))
+    }
+}
+
+impl<T: FromBytes> Parse<[u8]> for Number<T> {
+    type Output = T;
+
+    fn apply<'a>(&self, input: &'a [u8]) -> ParseResult<'a, Self::Output, [u8]> {
+        let (bytes, rest) = split(input, T::SIZE)?;
+        if self.big_endian {
+            Ok((T::from_be(bytes), rest))
+        } else {
+            Ok((T::from_le(bytes), rest))
+        }
+    }
+}
+
+/// Helper function that splits off the first count bytes of the input
+fn split(input: &[u8], count: usize) -> Result<(&[u8], &[u8]), RawEzpcError> {
+    if count <= input.len() {
+        Ok(input.split_at(count))
+    } else {
+        state::hit_end();
+        state::expected(input.as_ptr(), Expected::Bytes(count));
+        Err(RawEzpcError::Mismatch {
+            pos: input.as_ptr(),
+        })
+    }
+}
+
+// Grammar of the binary matchers and parsers, bytes are written as #xNN
+
+impl Grammar for Tag {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        let bytes = self
+            .0
+            .iter()
+            .map(|byte| byte_class(&format!("#x{byte:02X}")));
+        match self.0 {
+            [_] => bytes.into_iter().next().unwrap(),
+            _ => GrammarNode::Sequence(bytes.collect()),
+        }
+    }
+}
+
+impl Grammar for ByteIn {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        byte_class(&format!("[#x{:02X}-#x{:02X}]", self.start, self.end))
+    }
+}
+
+impl Grammar for Take {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        byte_class("[#x00-#xFF]").repeat(self.0, Some(self.0))
+    }
+}
+
+impl<P: Grammar> Grammar for LengthPrefixed<P> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let bytes = GrammarNode::Special("bytes of that length".to_owned());
+        self.0.describe(rules).then(bytes)
+    }
+}
+
+impl<T> Grammar for Number<T> {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        let endian = if self.big_endian { "big" } else { "little" };
+        let name = std::any::type_name::<T>();
+        GrammarNode::Special(format!("{name} {endian} endian"))
+    }
+}
+
+fn byte_class(class: &str) -> GrammarNode {
+    GrammarNode::Class(class.to_owned())
+}
@@ -0,0 +1,262 @@
+use std::ops::RangeInclusive;
+
+use super::matchers::pop_char;
+use super::unicode_tables::{CATEGORIES, CATEGORY_NAMES, XID_CONTINUE, XID_START};
+use super::{Match, Matcher};
+use crate::grammar::{char_ranges, Grammar, GrammarNode, Rules};
+use crate::result::{Expected, MatchResult, RawEzpcError};
+use crate::state;
+
+// Matchers for a single character of a class, like digits or letters. The
+// classes are sets of ranges, so they can be combined with union(). ASCII
+// characters are looked up in a bit set, all others with a binary search.
+
+pub struct CharClass {
+    ascii: u128,
+    /// Sorted ranges that neither overlap nor touch
+    ranges: Vec<(u32, u32)>,
+    expected: Vec<Expected>,
+    names: Vec<&'static str>,
+}
+
+pub struct Newline;
+
+/// Matches a digit of the radix, like char::is_digit, e.g. `digit(10)`.
+/// Panics if the radix is not in 1..=36.
+pub fn digit(radix: u32) -> Matcher<CharClass> {
+    assert!((1..=36).contains(&radix), "radix must be in 1..=36");
+    let mut ranges = vec![('0', char::from_digit(radix.min(10) - 1, 10).unwrap())];
+    if radix > 10 {
+        let last = radix - 11;
+        ranges.push(('a', (b'a' + last as u8) as char));
+        ranges.push(('A', (b'A' + last as u8) as char));
+    }
+    class(ranges, Expected::Label("a digit"), "digit")
+}
+
+pub fn hex_digit() -> Matcher<CharClass> {
+    let ranges = [('0', '9'), ('a', 'f'), ('A', 'F')];
+    class(ranges, Expected::Label("a hex digit"), "hex digit")
+}
+
+/// Matches an ASCII letter, see general_category() for all letters
+pub fn alpha() -> Matcher<CharClass> {
+    class(
+        [('a', 'z'), ('A', 'Z')],
+        Expected::Label("a letter"),
+        "letter",
+    )
+}
+
+/// Matches an ASCII letter or digit
+pub fn alnum() -> Matcher<CharClass> {
+    let ranges = [('0', '9'), ('a', 'z'), ('A', 'Z')];
+    class(
+        ranges,
+        Expected::Label("a letter or digit"),
+        "letter or digit",
+    )
+}
+
+/// Matches ASCII whitespace, like char::is_ascii_whitespace
+pub fn ws() -> Matcher<CharClass> {
+    let ranges = [('\t', '\n'), ('\x0C', '\r'), (' ', ' ')];
+    class(ranges, Expected::Label("whitespace"), "whitespace")
+}
+
+/// Matches a line break, `\n` or `\r\n`
+pub fn newline() -> Matcher<Newline> {
+    Matcher(Newline)
+}
+
+/// Matches a character in the range. Unlike char_in, it can be combined
+/// with other classes: `char_range('a'..='z').union(char_range('A'..='Z'))`
+pub fn char_range(range: RangeInclusive<char>) -> Matcher<CharClass> {
+    let (start, end) = range.into_inner();
+    class([(start, end)], Expected::CharIn(start, end), "")
+}
+
+/// Matches a character of the Unicode general category, given by its
+/// abbreviation like `Lu` for uppercase letters, or `L` for all letters.
+/// Panics if the category doesn't exist.
+pub fn general_category(name: &'static str) -> Matcher<CharClass> {
+    let Some(ranges) = category_ranges(name) else {
+        panic!("unknown general category `{name}`");
+    };
+    Matcher(CharClass::new(
+        ranges,
+        vec![Expected::Label(name)],
+        vec![name],
+    ))
+}
+
+/// The unsorted ranges of a general category, also used by regex()
+pub(super) fn category_ranges(name: &str) -> Option<Vec<(u32, u32)>> {
+    if !(1..=2).contains(&name.len()) {
+        return None;
+    }
+    let mut ranges: Vec<_> = CATEGORIES
+        .iter()
+        .filter(|(_, _, category)| CATEGORY_NAMES[*category as usize].starts_with(name))
+        .map(|&(start, end, _)| (start, end))
+        .collect();
+    // Unassigned code points are not in the table
+    if name == "C" || name == "Cn" {
+        ranges.extend(unassigned());
+    }
+    (!ranges.is_empty()).then_some(ranges)
+}
+
+/// Matches a character that can start an identifier, see Unicode Standard Annex #31
+pub fn xid_start() -> Matcher<CharClass> {
+    let ranges = XID_START.to_vec();
+    let expected = vec![Expected::Label("XID_Start")];
+    Matcher(CharClass::new(ranges, expected, vec!["XID_Start"]))
+}
+
+/// Matches a character that can continue an identifier
+pub fn xid_continue() -> Matcher<CharClass> {
+    let ranges = XID_CONTINUE.to_vec();
+    let expected = vec![Expected::Label("XID_Continue")];
+    Matcher(CharClass::new(ranges, expected, vec!["XID_Continue"]))
+}
+
+impl Matcher<CharClass> {
+    /// Matches characters of both classes
+    pub fn union(self, other: Matcher<CharClass>) -> Matcher<CharClass> {
+        let (mut class, other) = (self.0, other.0);
+        class.ranges.extend(other.ranges);
+        for expected in other.expected {
+            if !class.expected.contains(&expected) {
+                class.expected.push(expected);
+            }
+        }
+        class.names.extend(other.names);
+        Matcher(CharClass::new(class.ranges, class.expected, class.names))
+    }
+}
+
+fn class(
+    ranges: impl IntoIterator<Item = (char, char)>,
+    expected: Expected,
+    name: &'static str,
+) -> Matcher<CharClass> {
+    let ranges = ranges
+        .into_iter()
+        .map(|(start, end)| (start as u32, end as u32));
+    Matcher(CharClass::new(ranges.collect(), vec![expected], vec![name]))
+}
+
+impl CharClass {
+    fn new(
+        mut ranges: Vec<(u32, u32)>,
+        expected: Vec<Expected>,
+        mut names: Vec<&'static str>,
+    ) -> Self {
+        ranges.retain(|(start, end)| start <= end);
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        let mut ascii = 0;
+        for &(start, end) in merged.iter().take_while(|(start, _)| *start < 128) {
+            for c in start..=end.min(127) {
+                ascii |= 1 << c;
+            }
+        }
+        names.retain(|name| !name.is_empty());
+        CharClass {
+            ascii,
+            ranges: merged,
+            expected,
+            names,
+        }
+    }
+
+    fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+        if c < 128 {
+            return self.ascii & (1 << c) != 0;
+        }
+        let index = self.ranges.partition_point(|&(_, end)| end < c);
+        self.ranges.get(index).is_some_and(|&(start, _)| start <= c)
+    }
+}
+
+/// The code points between the assigned ones
+fn unassigned() -> Vec<(u32, u32)> {
+    let mut gaps = Vec::new();
+    let mut next = 0;
+    for &(start, end, _) in CATEGORIES {
+        if start > next {
+            gaps.push((next, start - 1));
+        }
+        next = end + 1;
+    }
+    if next <= char::MAX as u32 {
+        gaps.push((next, char::MAX as u32));
+    }
+    gaps
+}
+
+impl Match for CharClass {
+    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+        if let Some((c, rest)) = pop_char(input) {
+            if self.contains(c) {
+                return Ok(rest);
+            }
+        }
+        for expected in &self.expected {
+            state::expected(input.as_ptr(), *expected);
+        }
+        Err(RawEzpcError::Mismatch {
+            pos: input.as_ptr(),
+        })
+    }
+}
+
+impl Match for Newline {
+    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+        if let Some(rest) = input.strip_prefix('\n') {
+            return Ok(rest);
+        }
+        if let Some(rest) = input.strip_prefix("\r\n") {
+            return Ok(rest);
+        }
+        if input.is_empty() || input == "\r" {
+            state::hit_end();
+        }
+        state::expected(input.as_ptr(), Expected::Label("a newline"));
+        Err(RawEzpcError::Mismatch {
+            pos: input.as_ptr(),
+        })
+    }
+}
+
+// Grammar of the classes: small ones are written as EBNF class, large ones
+// like Unicode categories by name
+
+impl Grammar for CharClass {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        if self.ranges.len() > 8 && !self.names.is_empty() {
+            return GrammarNode::Special(self.names.join(" | "));
+        }
+        let ranges: Vec<_> = self
+            .ranges
+            .iter()
+            .filter_map(|&(start, end)| Some((char::from_u32(start)?, char::from_u32(end)?)))
+            .collect();
+        char_ranges(&ranges)
+    }
+}
+
+impl Grammar for Newline {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        GrammarNode::Terminal("\n".to_owned()).or(GrammarNode::Terminal("\r\n".to_owned()))
+    }
+}
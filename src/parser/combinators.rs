@@ -1,11 +1,11 @@
 use super::{Match, Matcher, Parse, Parser};
-use crate::result::{ParseResult, RawEzpcError};
+use crate::grammar::{Grammar, GrammarNode, Rules};
+use crate::input::Input;
+use crate::result::{MatchResult, ParseResult, RawEzpcError};
+use crate::state;
+use crate::trace;
 
-pub struct List<P, M>
-where
-    P: Parse,
-    M: Match,
-{
+pub struct List<P, M> {
     element: P,
     separator: M,
     item_mismatch_error_msg: &'static str,
@@ -15,11 +15,7 @@ pub fn list<P, M>(
     element: Parser<P>,
     separator: Matcher<M>,
     item_mismatch_error_msg: &'static str,
-) -> Parser<List<P, M>>
-where
-    P: Parse,
-    M: Match,
-{
+) -> Parser<List<P, M>> {
     Parser(List {
         element: element.0,
         separator: separator.0,
@@ -27,44 +23,316 @@ where
     })
 }
 
-impl<P, M> Parse for List<P, M>
+impl<I, P, M> Parse<I> for List<P, M>
 where
-    P: Parse,
-    M: Match,
+    I: Input + ?Sized,
+    P: Parse<I>,
+    M: Match<I>,
 {
     type Output = Vec<P::Output>;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
-        // A list contains at least one element - if this errors the error is returned.
-        // If it has success, we try to repeately parse separator + element
-        self.element.apply(input).and_then(|(item, mut input)| {
-            let mut items = vec![item];
-            loop {
-                // Parse as many list elements as possible
-                match self.separator.apply(input) {
-                    // Separator did not apply, list is finished, return
-                    Err(RawEzpcError::Mismatch { .. }) => return Ok((items, input)),
-                    // Separator returned some other error, forward it
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        trace::parse("list", input, || {
+            // A list contains at least one element - if this errors the error is returned.
+            // If it has success, we try to repeately parse separator + element
+            self.element.apply(input).and_then(|(item, mut input)| {
+                let mut items = vec![item];
+                loop {
+                    // Parse as many list elements as possible
+                    match self.separator.apply(input) {
+                        // Separator did not apply, list is finished, return
+                        Err(RawEzpcError::Mismatch { .. }) => return Ok((items, input)),
+                        // Separator returned some other error, forward it
+                        Err(err) => return Err(err),
+                        // Separator applied, now we expect a list element
+                        Ok(rest) => match self.element.apply(rest) {
+                            Ok((item, rest)) => {
+                                items.push(item);
+                                input = rest;
+                            }
+                            // List element didn't apply even though we had a separator -> fatal
+                            Err(err) => {
+                                return Err(match err {
+                                    RawEzpcError::Mismatch { pos } => RawEzpcError::Fatal {
+                                        message: self.item_mismatch_error_msg.into(),
+                                        pos,
+                                        source: None,
+                                    },
+                                    _ => err,
+                                })
+                            }
+                        },
+                    }
+                }
+            })
+        })
+    }
+}
+
+impl<P: Grammar, M: Grammar> Grammar for List<P, M> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let element = self.element.describe(rules);
+        let separator = self.separator.describe(rules);
+        let rest = separator.then(element.clone()).repeat(0, None);
+        element.then(rest)
+    }
+}
+
+pub struct SkipUntil<M>(M);
+
+/// Matches all input up to (but not including) the first position where the
+/// matcher applies, or up to the end of the input. Meant for recover_with().
+pub fn skip_until<M>(matcher: Matcher<M>) -> Matcher<SkipUntil<M>> {
+    Matcher(SkipUntil(matcher.0))
+}
+
+impl<I: Input + ?Sized, M: Match<I>> Match<I> for SkipUntil<M> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        for i in input.offsets() {
+            // Not finding the end yet is not an error the user should see
+            match state::silent(|| self.0.apply(input.tail(i))) {
+                Ok(_) => return Ok(input.tail(i)),
+                Err(RawEzpcError::Mismatch { .. }) => (),
+                Err(err) => return Err(err),
+            }
+        }
+        state::hit_end();
+        Ok(input.tail(input.len()))
+    }
+}
+
+impl<M: Grammar> Grammar for SkipUntil<M> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let any = GrammarNode::Special("any".to_owned());
+        let skipped = GrammarNode::Not(Box::new(self.0.describe(rules))).then(any);
+        skipped.repeat(0, None)
+    }
+}
+
+/// Associativity of infix operators with the same precedence level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Assoc {
+    Left,
+    Right,
+}
+
+// Operators have different matcher types, so they are stored as trait objects.
+// They are Send + Sync, so that precedence parsers can be used in wrap_sync().
+
+struct PrefixOp<O, I: ?Sized> {
+    op: Box<dyn Match<I> + Send + Sync>,
+    right_bp: usize,
+    fold: Box<dyn Fn(O) -> O + Send + Sync>,
+}
+
+struct InfixOp<O, I: ?Sized> {
+    op: Box<dyn Match<I> + Send + Sync>,
+    left_bp: usize,
+    right_bp: usize,
+    fold: Box<dyn Fn(O, O) -> O + Send + Sync>,
+}
+
+struct PostfixOp<O, I: ?Sized> {
+    op: Box<dyn Match<I> + Send + Sync>,
+    left_bp: usize,
+    fold: Box<dyn Fn(O) -> O + Send + Sync>,
+}
+
+pub struct Precedence<P: Parse<I>, I: Input + ?Sized = str> {
+    atom: P,
+    max_depth: usize,
+    prefix: Vec<PrefixOp<P::Output, I>>,
+    infix: Vec<InfixOp<P::Output, I>>,
+    postfix: Vec<PostfixOp<P::Output, I>>,
+}
+
+/// Operator precedence (Pratt) parser for expressions made of atoms and
+/// prefix, infix and postfix operators, which are added with the methods of
+/// the returned parser. Operators with a higher precedence level bind
+/// tighter, operators are tried in the order they were added.
+/// If an operator matches but is not followed by an operand, the next one is
+/// tried, if none fits the expression ends before the operator. Operators and
+/// folds must be Send + Sync. max_depth limits the nesting of operands.
+pub fn precedence<I, P>(atom: Parser<P>, max_depth: usize) -> Parser<Precedence<P, I>>
+where
+    I: Input + ?Sized,
+    P: Parse<I>,
+{
+    Parser(Precedence {
+        atom: atom.0,
+        max_depth,
+        prefix: Vec::new(),
+        infix: Vec::new(),
+        postfix: Vec::new(),
+    })
+}
+
+// Every level has two binding powers, so that the associativity of infix
+// operators can be expressed by giving one side a slightly stronger binding.
+
+impl<I: Input + ?Sized, P: Parse<I>> Parser<Precedence<P, I>> {
+    pub fn prefix<M, F>(mut self, op: Matcher<M>, level: usize, fold: F) -> Self
+    where
+        M: Match<I> + Send + Sync + 'static,
+        F: Fn(P::Output) -> P::Output + Send + Sync + 'static,
+    {
+        self.0.prefix.push(PrefixOp {
+            op: Box::new(op.0),
+            right_bp: 2 * level + 1,
+            fold: Box::new(fold),
+        });
+        self
+    }
+
+    pub fn infix<M, F>(mut self, op: Matcher<M>, level: usize, assoc: Assoc, fold: F) -> Self
+    where
+        M: Match<I> + Send + Sync + 'static,
+        F: Fn(P::Output, P::Output) -> P::Output + Send + Sync + 'static,
+    {
+        let (left_bp, right_bp) = match assoc {
+            Assoc::Left => (2 * level, 2 * level + 1),
+            Assoc::Right => (2 * level + 1, 2 * level),
+        };
+        self.0.infix.push(InfixOp {
+            op: Box::new(op.0),
+            left_bp,
+            right_bp,
+            fold: Box::new(fold),
+        });
+        self
+    }
+
+    pub fn postfix<M, F>(mut self, op: Matcher<M>, level: usize, fold: F) -> Self
+    where
+        M: Match<I> + Send + Sync + 'static,
+        F: Fn(P::Output) -> P::Output + Send + Sync + 'static,
+    {
+        self.0.postfix.push(PostfixOp {
+            op: Box::new(op.0),
+            left_bp: 2 * level,
+            fold: Box::new(fold),
+        });
+        self
+    }
+}
+
+impl<I: Input + ?Sized, P: Parse<I>> Precedence<P, I> {
+    fn apply_bp<'a>(
+        &self,
+        input: &'a I,
+        min_bp: usize,
+        depth: usize,
+    ) -> ParseResult<'a, P::Output, I> {
+        if depth > self.max_depth {
+            return Err(RawEzpcError::Recursion {
+                max_depth: self.max_depth,
+                parser_name: "precedence",
+                pos: input.as_ptr(),
+            });
+        }
+
+        let (mut lhs, mut input) = match self.apply_prefix(input, depth)? {
+            Some(prefixed) => prefixed,
+            None => self.atom.apply(input)?,
+        };
+
+        'operators: loop {
+            for op in &self.postfix {
+                if op.left_bp < min_bp {
+                    continue;
+                }
+                match op.op.apply(input) {
+                    Ok(rest) => {
+                        lhs = (op.fold)(lhs);
+                        input = rest;
+                        continue 'operators;
+                    }
+                    Err(RawEzpcError::Mismatch { .. }) => (),
                     Err(err) => return Err(err),
-                    // Separator applied, now we expect a list element
-                    Ok(rest) => match self.element.apply(rest) {
-                        Ok((item, rest)) => {
-                            items.push(item);
-                            input = rest;
-                        }
-                        // List element didn't apply even though we had a separator -> fatal
-                        Err(err) => {
-                            return Err(match err {
-                                RawEzpcError::Mismatch { pos } => RawEzpcError::Fatal {
-                                    message: self.item_mismatch_error_msg,
-                                    pos,
-                                },
-                                _ => err,
-                            })
-                        }
-                    },
                 }
             }
-        })
+
+            for op in &self.infix {
+                if op.left_bp < min_bp {
+                    continue;
+                }
+                let rest = match op.op.apply(input) {
+                    Ok(rest) => rest,
+                    Err(RawEzpcError::Mismatch { .. }) => continue,
+                    Err(err) => return Err(err),
+                };
+                match self.apply_bp(rest, op.right_bp, depth + 1) {
+                    Ok((rhs, rest)) => {
+                        lhs = (op.fold)(lhs, rhs);
+                        input = rest;
+                        continue 'operators;
+                    }
+                    // No operand, so the operator is not part of the expression,
+                    // but a later one might be, like `**` after `*`
+                    Err(RawEzpcError::Mismatch { .. }) => continue,
+                    Err(err) => return Err(err),
+                }
+            }
+
+            break;
+        }
+
+        Ok((lhs, input))
+    }
+
+    fn apply_prefix<'a>(
+        &self,
+        input: &'a I,
+        depth: usize,
+    ) -> Result<Option<(P::Output, &'a I)>, RawEzpcError> {
+        for op in &self.prefix {
+            let rest = match op.op.apply(input) {
+                Ok(rest) => rest,
+                Err(RawEzpcError::Mismatch { .. }) => continue,
+                Err(err) => return Err(err),
+            };
+            match self.apply_bp(rest, op.right_bp, depth + 1) {
+                Ok((operand, rest)) => return Ok(Some(((op.fold)(operand), rest))),
+                Err(RawEzpcError::Mismatch { .. }) => (),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<I: Input + ?Sized, P: Parse<I>> Parse<I> for Precedence<P, I> {
+    type Output = P::Output;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.apply_bp(input, 0, 0)
+    }
+}
+
+/// Operands with any prefix operators, followed by postfix operators or
+/// infix operators and further operands. Precedence levels are not shown.
+impl<I: Input + ?Sized, P: Parse<I>> Grammar for Precedence<P, I> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let mut choice = |ops: Vec<&(dyn Match<I> + Send + Sync)>| {
+            let ops = ops.into_iter().map(|op| op.describe(rules));
+            ops.reduce(GrammarNode::or)
+        };
+        let prefix = choice(self.prefix.iter().map(|op| op.op.as_ref()).collect());
+        let infix = choice(self.infix.iter().map(|op| op.op.as_ref()).collect());
+        let postfix = choice(self.postfix.iter().map(|op| op.op.as_ref()).collect());
+
+        let mut operand = self.atom.describe(rules);
+        if let Some(prefix) = prefix {
+            operand = prefix.repeat(0, None).then(operand);
+        }
+        let suffix = match (infix, postfix) {
+            (Some(infix), Some(postfix)) => Some(postfix.or(infix.then(operand.clone()))),
+            (Some(infix), None) => Some(infix.then(operand.clone())),
+            (None, postfix) => postfix,
+        };
+        match suffix {
+            Some(suffix) => operand.then(suffix.repeat(0, None)),
+            None => operand,
+        }
     }
 }
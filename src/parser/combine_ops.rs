@@ -1,23 +1,38 @@
 use std::ops::Add;
+use std::ops::BitAnd;
 use std::ops::BitOr;
+use std::ops::Not;
 
-use super::{Match, Matcher, Parse, Parser};
+use super::modifiers::{MatchPart, ParsePart};
+use super::{Match, Matcher, Parse, Parser, Part, StrPart};
+use crate::grammar::{Grammar, GrammarNode, Rules};
+use crate::input::Input;
 use crate::result::RawEzpcError;
 use crate::result::{MatchResult, ParseResult};
+use crate::state;
+use crate::trace;
 
 // Sequence of parsers or matchers, produced by adding (+) them
-pub struct AndPP<P1: Parse, P2: Parse>(P1, P2);
-pub struct AndPM<P1: Parse, M2: Match>(P1, M2);
-pub struct AndMP<M1: Match, P2: Parse>(M1, P2);
-pub struct AndMM<M1: Match, M2: Match>(M1, M2);
+pub struct AndPP<P1, P2>(P1, P2);
+pub struct AndPM<P1, M2>(P1, M2);
+pub struct AndMP<M1, P2>(M1, P2);
+pub struct AndMM<M1, M2>(M1, M2);
 
 // Ordered choice of parsers or matchers, produced by bit or'ing (|) them
-pub struct OrPP<P1: Parse, P2: Parse>(P1, P2);
-pub struct OrMM<M1: Match, M2: Match>(M1, M2);
+pub struct OrPP<P1, P2>(P1, P2);
+pub struct OrMM<M1, M2>(M1, M2);
+pub struct OrPart<T1, T2>(T1, T2);
+
+// Lookahead: both matchers at the same input with &, the negation of a matcher
+// with !, and peek() which applies a parser or matcher without consuming input
+pub struct IntersectMM<M1, M2>(M1, M2);
+pub struct NotM<M>(M);
+pub struct PeekM<M>(pub(super) M);
+pub struct PeekP<P>(pub(super) P);
 
 // Implement Add operator for sequence
 
-impl<P1: Parse, P2: Parse> Add<Parser<P2>> for Parser<P1> {
+impl<P1, P2> Add<Parser<P2>> for Parser<P1> {
     type Output = Parser<AndPP<P1, P2>>;
 
     fn add(self, rhs: Parser<P2>) -> Self::Output {
@@ -25,7 +40,7 @@ impl<P1: Parse, P2: Parse> Add<Parser<P2>> for Parser<P1> {
     }
 }
 
-impl<P1: Parse, M2: Match> Add<Matcher<M2>> for Parser<P1> {
+impl<P1, M2> Add<Matcher<M2>> for Parser<P1> {
     type Output = Parser<AndPM<P1, M2>>;
 
     fn add(self, rhs: Matcher<M2>) -> Self::Output {
@@ -33,7 +48,7 @@ impl<P1: Parse, M2: Match> Add<Matcher<M2>> for Parser<P1> {
     }
 }
 
-impl<M1: Match, P2: Parse> Add<Parser<P2>> for Matcher<M1> {
+impl<M1, P2> Add<Parser<P2>> for Matcher<M1> {
     type Output = Parser<AndMP<M1, P2>>;
 
     fn add(self, rhs: Parser<P2>) -> Self::Output {
@@ -41,7 +56,7 @@ impl<M1: Match, P2: Parse> Add<Parser<P2>> for Matcher<M1> {
     }
 }
 
-impl<M1: Match, M2: Match> Add<Matcher<M2>> for Matcher<M1> {
+impl<M1, M2> Add<Matcher<M2>> for Matcher<M1> {
     type Output = Matcher<AndMM<M1, M2>>;
 
     fn add(self, rhs: Matcher<M2>) -> Self::Output {
@@ -51,11 +66,7 @@ impl<M1: Match, M2: Match> Add<Matcher<M2>> for Matcher<M1> {
 
 // Implement BitOr operator for ordered choice
 
-impl<P1, P2> BitOr<Parser<P2>> for Parser<P1>
-where
-    P1: Parse,
-    P2: Parse<Output = P1::Output>,
-{
+impl<P1, P2> BitOr<Parser<P2>> for Parser<P1> {
     type Output = Parser<OrPP<P1, P2>>;
 
     fn bitor(self, rhs: Parser<P2>) -> Self::Output {
@@ -63,7 +74,7 @@ where
     }
 }
 
-impl<M1: Match, M2: Match> BitOr<Matcher<M2>> for Matcher<M1> {
+impl<M1, M2> BitOr<Matcher<M2>> for Matcher<M1> {
     type Output = Matcher<OrMM<M1, M2>>;
 
     fn bitor(self, rhs: Matcher<M2>) -> Self::Output {
@@ -71,55 +82,101 @@ impl<M1: Match, M2: Match> BitOr<Matcher<M2>> for Matcher<M1> {
     }
 }
 
+impl<T1, T2> BitOr<Part<T2>> for Part<T1> {
+    type Output = Part<OrPart<T1, T2>>;
+
+    fn bitor(self, rhs: Part<T2>) -> Self::Output {
+        Part(OrPart(self.0, rhs.0))
+    }
+}
+
+impl<T1, P2> BitOr<Parser<P2>> for Part<T1> {
+    type Output = Part<OrPart<T1, ParsePart<P2>>>;
+
+    fn bitor(self, rhs: Parser<P2>) -> Self::Output {
+        Part(OrPart(self.0, ParsePart(rhs.0)))
+    }
+}
+
+impl<T1, M2> BitOr<Matcher<M2>> for Part<T1> {
+    type Output = Part<OrPart<T1, MatchPart<M2>>>;
+
+    fn bitor(self, rhs: Matcher<M2>) -> Self::Output {
+        Part(OrPart(self.0, MatchPart(rhs.0)))
+    }
+}
+
+// Implement BitAnd and Not operators for lookahead
+
+impl<M1, M2> BitAnd<Matcher<M2>> for Matcher<M1> {
+    type Output = Matcher<IntersectMM<M1, M2>>;
+
+    fn bitand(self, rhs: Matcher<M2>) -> Self::Output {
+        Matcher(IntersectMM(self.0, rhs.0))
+    }
+}
+
+impl<M> Not for Matcher<M> {
+    type Output = Matcher<NotM<M>>;
+
+    fn not(self) -> Self::Output {
+        Matcher(NotM(self.0))
+    }
+}
+
 // Implement Parse and Match for Add (Sequence)
 
-impl<P1: Parse, P2: Parse> Parse for AndPP<P1, P2> {
+impl<I: Input + ?Sized, P1: Parse<I>, P2: Parse<I>> Parse<I> for AndPP<P1, P2> {
     type Output = (P1::Output, P2::Output);
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
-        self.0
-            .apply(input)
-            .and_then(|(out1, rest)| self.1.apply(rest).map(|(out2, rest)| ((out1, out2), rest)))
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        trace::parse("and", input, || {
+            self.0.apply(input).and_then(|(out1, rest)| {
+                self.1.apply(rest).map(|(out2, rest)| ((out1, out2), rest))
+            })
+        })
     }
 }
 
-impl<P1: Parse, M2: Match> Parse for AndPM<P1, M2> {
+impl<I: Input + ?Sized, P1: Parse<I>, M2: Match<I>> Parse<I> for AndPM<P1, M2> {
     type Output = P1::Output;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         self.0
             .apply(input)
             .and_then(|(out, rest)| self.1.apply(rest).map(|rest| (out, rest)))
     }
 }
 
-impl<M1: Match, P2: Parse> Parse for AndMP<M1, P2> {
+impl<I: Input + ?Sized, M1: Match<I>, P2: Parse<I>> Parse<I> for AndMP<M1, P2> {
     type Output = P2::Output;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         self.0
             .apply(input)
             .and_then(|rest| self.1.apply(rest))
     }
 }
 
-impl<M1: Match, M2: Match> Match for AndMM<M1, M2> {
-    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
-        self.0.apply(input).and_then(|rest| self.1.apply(rest))
+impl<I: Input + ?Sized, M1: Match<I>, M2: Match<I>> Match<I> for AndMM<M1, M2> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        trace::matches("and", input, || {
+            self.0.apply(input).and_then(|rest| self.1.apply(rest))
+        })
     }
 }
 
 // Implement Parse and Match for Or (Ordered choice)
 
-impl<P1, P2> Parse for OrPP<P1, P2>
+impl<I: Input + ?Sized, P1, P2> Parse<I> for OrPP<P1, P2>
 where
-    P1: Parse,
-    P2: Parse<Output = P1::Output>,
+    P1: Parse<I>,
+    P2: Parse<I, Output = P1::Output>,
 {
     type Output = P1::Output;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
-        match self.0.apply(input) {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        trace::parse("or", input, || match self.0.apply(input) {
             Ok((out, rest)) => Ok((out, rest)),
             Err(err) => match err {
                 RawEzpcError::Mismatch { pos: pos1 } => {
@@ -132,13 +189,13 @@ where
                 }
                 _ => Err(err),
             },
-        }
+        })
     }
 }
 
-impl<M1: Match, M2: Match> Match for OrMM<M1, M2> {
-    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
-        match self.0.apply(input) {
+impl<I: Input + ?Sized, M1: Match<I>, M2: Match<I>> Match<I> for OrMM<M1, M2> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        trace::matches("or", input, || match self.0.apply(input) {
             Ok(rest) => Ok(rest),
             Err(err) => match err {
                 RawEzpcError::Mismatch { pos: pos1 } => {
@@ -151,6 +208,115 @@ impl<M1: Match, M2: Match> Match for OrMM<M1, M2> {
                 }
                 _ => Err(err),
             },
+        })
+    }
+}
+
+impl<T1: StrPart, T2: StrPart> StrPart for OrPart<T1, T2> {
+    fn push_to<'a>(&self, input: &'a str, buffer: &mut String) -> MatchResult<'a> {
+        trace::matches("or", input, || match self.0.push_to(input, buffer) {
+            Ok(rest) => Ok(rest),
+            Err(err) => match err {
+                RawEzpcError::Mismatch { pos: pos1 } => {
+                    self.1.push_to(input, buffer).map_err(|err| match err {
+                        RawEzpcError::Mismatch { pos: pos2 } => RawEzpcError::Mismatch {
+                            pos: pos1.max(pos2),
+                        },
+                        _ => err,
+                    })
+                }
+                _ => Err(err),
+            },
+        })
+    }
+}
+
+// Implement Parse and Match for lookahead
+
+impl<I: Input + ?Sized, M1: Match<I>, M2: Match<I>> Match<I> for IntersectMM<M1, M2> {
+    /// Consumes what the first matcher matched, if the second matches as well
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        let rest = self.0.apply(input)?;
+        self.1.apply(input).map(|_| rest)
+    }
+}
+
+impl<I: Input + ?Sized, M: Match<I>> Match<I> for NotM<M> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        // The matcher failing is the expected case here, so it shouldn't
+        // show up as an expectation in error messages
+        match state::silent(|| self.0.apply(input)) {
+            Ok(_) => Err(RawEzpcError::Mismatch {
+                pos: input.as_ptr(),
+            }),
+            Err(RawEzpcError::Mismatch { .. }) => Ok(input),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<I: Input + ?Sized, M: Match<I>> Match<I> for PeekM<M> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        self.0.apply(input).map(|_| input)
+    }
+}
+
+impl<I: Input + ?Sized, P: Parse<I>> Parse<I> for PeekP<P> {
+    type Output = P::Output;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.0.apply(input).map(|(out, _)| (out, input))
+    }
+}
+
+// Grammar of sequences and choices, nested ones are flattened
+
+macro_rules! describe_combined {
+    ($($combined:ident.$combine:ident)*) => {$(
+        impl<T1: Grammar, T2: Grammar> Grammar for $combined<T1, T2> {
+            fn describe(&self, rules: &mut Rules) -> GrammarNode {
+                let first = self.0.describe(rules);
+                first.$combine(self.1.describe(rules))
+            }
         }
+    )*};
+}
+
+describe_combined! {
+    AndPP.then
+    AndPM.then
+    AndMP.then
+    AndMM.then
+    OrPP.or
+    OrMM.or
+    OrPart.or
+}
+
+// The intersection is described like in PEG: `&b a` matches b without consuming
+// it, then a
+
+impl<M1: Grammar, M2: Grammar> Grammar for IntersectMM<M1, M2> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let first = self.0.describe(rules);
+        let second = GrammarNode::Peek(Box::new(self.1.describe(rules)));
+        second.then(first)
+    }
+}
+
+impl<M: Grammar> Grammar for NotM<M> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        GrammarNode::Not(Box::new(self.0.describe(rules)))
+    }
+}
+
+impl<M: Grammar> Grammar for PeekM<M> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        GrammarNode::Peek(Box::new(self.0.describe(rules)))
+    }
+}
+
+impl<P: Grammar> Grammar for PeekP<P> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        GrammarNode::Peek(Box::new(self.0.describe(rules)))
     }
 }
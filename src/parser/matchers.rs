@@ -1,12 +1,18 @@
+use std::any::type_name;
+use std::ops::RangeInclusive;
+
 use super::{Match, Matcher};
-use crate::result::{MatchResult, RawEzpcError};
+use crate::grammar::{char_class, char_range, rule_name, Grammar, GrammarNode, Rules};
+use crate::input::Input;
+use crate::result::{Expected, MatchResult, RawEzpcError};
+use crate::state;
 
 pub struct Eof;
 pub fn eof() -> Matcher<Eof> {
     Matcher(Eof)
 }
 
-pub struct Tag(&'static str);
+pub struct Tag(pub(super) &'static str);
 pub fn tag(tag: &'static str) -> Matcher<Tag> {
     Matcher(Tag(tag))
 }
@@ -21,6 +27,18 @@ pub fn none_of(bag: &'static str) -> Matcher<NoneOf> {
     Matcher(NoneOf(bag))
 }
 
+pub struct CharIn {
+    start: char,
+    end: char,
+}
+/// Matches a single character in the range, e.g. `char_in('a'..='z')`
+pub fn char_in(range: RangeInclusive<char>) -> Matcher<CharIn> {
+    Matcher(CharIn {
+        start: *range.start(),
+        end: *range.end(),
+    })
+}
+
 pub struct IsA<F>(F);
 pub fn is_a<F>(predicate: F) -> Matcher<IsA<F>>
 where
@@ -31,11 +49,14 @@ where
 
 // All the Match implementations for the Matchers above
 
-impl Match for Eof {
-    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+impl<I: Input + ?Sized> Match<I> for Eof {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
         if input.is_empty() {
+            // More input might follow if the input is streamed
+            state::hit_end();
             Ok(input)
         } else {
+            state::expected(input.as_ptr(), Expected::Eof);
             Err(RawEzpcError::Mismatch {
                 pos: input.as_ptr(),
             })
@@ -48,6 +69,10 @@ impl Match for Tag {
         if let Some(rest) = input.strip_prefix(self.0) {
             Ok(rest)
         } else {
+            if self.0.starts_with(input) {
+                state::hit_end();
+            }
+            state::expected(input.as_ptr(), Expected::Tag(self.0));
             Err(RawEzpcError::Mismatch {
                 pos: input.as_ptr(),
             })
@@ -62,6 +87,7 @@ impl Match for OneOf {
                 return Ok(rest);
             }
         }
+        state::expected(input.as_ptr(), Expected::OneOf(self.0));
         Err(RawEzpcError::Mismatch {
             pos: input.as_ptr(),
         })
@@ -75,6 +101,21 @@ impl Match for NoneOf {
                 return Ok(rest);
             }
         }
+        state::expected(input.as_ptr(), Expected::NoneOf(self.0));
+        Err(RawEzpcError::Mismatch {
+            pos: input.as_ptr(),
+        })
+    }
+}
+
+impl Match for CharIn {
+    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+        if let Some((c, rest)) = pop_char(input) {
+            if (self.start..=self.end).contains(&c) {
+                return Ok(rest);
+            }
+        }
+        state::expected(input.as_ptr(), Expected::CharIn(self.start, self.end));
         Err(RawEzpcError::Mismatch {
             pos: input.as_ptr(),
         })
@@ -97,7 +138,56 @@ where
     }
 }
 
+// Grammar of the matchers above
+
+impl Grammar for Eof {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        GrammarNode::Special("end of input".to_owned())
+    }
+}
+
+impl Grammar for Tag {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        GrammarNode::Terminal(self.0.to_owned())
+    }
+}
+
+impl Grammar for OneOf {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        char_class(self.0, false)
+    }
+}
+
+impl Grammar for NoneOf {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        char_class(self.0, true)
+    }
+}
+
+impl Grammar for CharIn {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        char_range(self.start, self.end)
+    }
+}
+
+impl<F> Grammar for IsA<F> {
+    /// Named after the predicate if it is a function, like `is_alphabetic`
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        let name = type_name::<F>();
+        let name = if name.ends_with("{{closure}}") {
+            "predicate"
+        } else {
+            rule_name(name)
+        };
+        GrammarNode::Special(name.to_owned())
+    }
+}
+
 /// Helper function that splits a string into the first char and rest
-fn pop_char(s: &str) -> Option<(char, &str)> {
-    s.chars().next().map(|c| (c, &s[c.len_utf8()..]))
+pub(super) fn pop_char(s: &str) -> Option<(char, &str)> {
+    let popped = s.chars().next().map(|c| (c, &s[c.len_utf8()..]));
+    if popped.is_none() {
+        state::hit_end();
+    }
+    popped
 }
@@ -1,37 +1,162 @@
+pub mod bytes;
+pub mod classes;
 pub mod combinators;
 pub mod combine_ops;
 pub mod matchers;
 pub mod modifiers;
+pub mod regex;
+pub mod seq;
+pub mod stream;
+pub mod tags;
+mod unicode_tables;
 pub mod wrap;
 
 use crate::{
+    grammar::{Grammar, Rules},
+    input::Input,
     range::RangeArgument,
-    result::{EzpcError, MatchResult, ParseResult, Position},
+    result::{Expected, EzpcError, MatchResult, ParseResult, RawEzpcError},
+    state,
+    trace::Trace,
 };
+use combine_ops::{PeekM, PeekP};
 use modifiers::{
-    ConvertMatch, ConvertParse, Fatal, MapMatch, MapParse, Opt, Reject, Repeat, ValMatch, ValParse,
+    CharMatch, ConvertMatch, ConvertParse, ConvertSourceMatch, ConvertSourceParse, Fatal,
+    FatalWith, Label, MapMatch, MapParse, MatchPart, Memo, Named, Opt, ParsePart, PushStr,
+    RecoverMatch, RecoverParse, Reject, RejectWith, Repeat, RepeatString, SliceMatch, SpannedMatch,
+    SpannedParse, StringMatch, ValMatch, ValParse,
 };
 
-pub trait Parse {
+/// Builds the error for a parse that stopped at pos. If some matcher failed
+/// further into the input, that position is reported with what it expected.
+/// Must be called inside state::run, like collect_errors.
+fn partial_parse(pos: *const u8) -> EzpcError {
+    let (furthest, expected) = state::furthest_expected();
+    if furthest >= pos {
+        EzpcError::PartialParse {
+            pos: state::position(furthest),
+            expected,
+        }
+    } else {
+        EzpcError::PartialParse {
+            pos: state::position(pos),
+            expected: Vec::new(),
+        }
+    }
+}
+
+/// Collects the errors of a parse that stopped at rest or failed, starting
+/// with all errors that were recovered from during the parse. Must be called
+/// inside state::run, the positions are built with the line index of its frame.
+fn collect_errors<I: Input + ?Sized>(result: MatchResult<I>) -> Vec<EzpcError> {
+    let error = match result {
+        Ok(rest) if rest.is_empty() => None,
+        Ok(rest) => {
+            state::expected(rest.as_ptr(), Expected::Eof);
+            Some(partial_parse(rest.as_ptr()))
+        }
+        Err(RawEzpcError::Mismatch { pos }) => Some(partial_parse(pos)),
+        Err(raw) => Some(EzpcError::from_raw_with(raw, state::position)),
+    };
+
+    let mut errors: Vec<_> = state::take_diagnostics()
+        .into_iter()
+        .map(|raw| EzpcError::from_raw_with(raw, state::position))
+        .collect();
+    errors.extend(error);
+    errors
+}
+
+/// Parsers are applied to text (str) by default, parsers for binary data
+/// implement Parse<[u8]>, see the bytes module.
+fn parse_input<I: Input + ?Sized, P: Parse<I>>(
+    parser: &P,
+    source: &I,
+) -> (Option<P::Output>, Vec<EzpcError>) {
+    state::run(source, || apply_input(parser, source))
+}
+
+/// Applies the parser to the source, must be called inside state::run
+fn apply_input<I: Input + ?Sized, P: Parse<I>>(
+    parser: &P,
+    source: &I,
+) -> (Option<P::Output>, Vec<EzpcError>) {
+    match parser.apply(source) {
+        Ok((out, rest)) => (Some(out), collect_errors(Ok(rest))),
+        Err(err) => (None, collect_errors::<I>(Err(err))),
+    }
+}
+
+pub trait Parse<I: Input + ?Sized = str>: Grammar {
     type Output;
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output>;
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I>;
+
+    /// Identifies the parser across its instances, used as memo() cache key.
+    /// All references to a wrapped parser have the same id, others have none.
+    #[doc(hidden)]
+    fn id(&self) -> Option<usize> {
+        None
+    }
 }
 
-pub struct Parser<T: Parse>(T);
-
-impl<P: Parse> Parser<P> {
-    pub fn parse_all(&self, source: &str) -> Result<P::Output, EzpcError> {
-        match self.0.apply(source) {
-            Ok((out, rest)) => {
-                if rest.is_empty() {
-                    Ok(out)
-                } else {
-                    Err(EzpcError::PartialParse {
-                        pos: Position::from_ptr(source, rest.as_ptr()),
-                    })
-                }
-            }
-            Err(raw) => Err(EzpcError::from_raw(raw, source)),
+pub struct Parser<T>(T);
+
+impl<P> Parser<P> {
+    pub fn parse_all(&self, source: &str) -> Result<P::Output, EzpcError>
+    where
+        P: Parse,
+    {
+        match self.parse_all_recover(source) {
+            (Some(out), errors) if errors.is_empty() => Ok(out),
+            (_, errors) => Err(errors.into_iter().next().unwrap()),
+        }
+    }
+
+    /// Like parse_all, but does not stop at the first error if the parser
+    /// recovers from it (see recover_with). Returns all errors in the order
+    /// they occurred and the output, if the parser returned one.
+    pub fn parse_all_recover(&self, source: &str) -> (Option<P::Output>, Vec<EzpcError>)
+    where
+        P: Parse,
+    {
+        parse_input(&self.0, source)
+    }
+
+    /// Like parse_all, but also records which parsers were applied where and
+    /// if they matched, e.g. to find out why a choice took the wrong branch.
+    /// Give the interesting parsers a name with named() to find them in it.
+    pub fn parse_all_traced(&self, source: &str) -> (Result<P::Output, EzpcError>, Trace)
+    where
+        P: Parse,
+    {
+        let ((out, errors), events) = state::run(source, || {
+            state::trace_start();
+            let result = apply_input(&self.0, source);
+            (result, state::trace_take())
+        });
+        let result = match (out, errors) {
+            (Some(out), errors) if errors.is_empty() => Ok(out),
+            (_, errors) => Err(errors.into_iter().next().unwrap()),
+        };
+        (result, Trace::new(events))
+    }
+
+    /// The grammar of the parser, with the given name for its start rule
+    pub fn grammar(&self, name: &str) -> Rules
+    where
+        P: Grammar,
+    {
+        Rules::new(name, &self.0)
+    }
+
+    /// Like parse_all, but for parsers of binary data
+    pub fn parse_bytes(&self, source: &[u8]) -> Result<P::Output, EzpcError>
+    where
+        P: Parse<[u8]>,
+    {
+        match parse_input(&self.0, source) {
+            (Some(out), errors) if errors.is_empty() => Ok(out),
+            (_, errors) => Err(errors.into_iter().next().unwrap()),
         }
     }
 
@@ -42,6 +167,72 @@ impl<P: Parse> Parser<P> {
         })
     }
 
+    /// Like fatal, but the message is built at runtime from the input
+    /// starting at the error position, e.g. to include the offending token
+    pub fn fatal_with<I: Input + ?Sized, F>(self, message: F) -> Parser<FatalWith<P, F>>
+    where
+        P: Parse<I>,
+        F: Fn(&I) -> String,
+    {
+        Parser(FatalWith {
+            parser_or_matcher: self.0,
+            message,
+        })
+    }
+
+    /// Cache the result of this parser for every input position during a
+    /// single parse_all call. Applying it again at the same position returns
+    /// the cached result, which bounds the cost of backtracking in ordered
+    /// choices to one application per position. Typically used on wrapped
+    /// (recursive) parsers: `value.wrap(100).memo()`. All memoized references
+    /// to the same wrapped parser share their cached results.
+    pub fn memo<I: Input + ?Sized>(self) -> Parser<Memo<P>>
+    where
+        P: Parse<I>,
+        P::Output: Clone + 'static,
+    {
+        Parser(Memo(self.0))
+    }
+
+    /// On a fatal error, record it and apply the recovery matcher from the
+    /// error position on. If it matches, the placeholder is returned instead
+    /// and parsing continues. The recorded errors are returned by
+    /// parse_all_recover, parse_all returns the first one.
+    /// NOTE: errors stay recorded even if an ordered choice later discards the
+    /// recovered output, so only recover where the input is unambiguous.
+    pub fn recover_with<I: Input + ?Sized, R: Match<I>>(
+        self,
+        recovery: Matcher<R>,
+        placeholder: P::Output,
+    ) -> Parser<RecoverParse<P, R, P::Output>>
+    where
+        P: Parse<I>,
+        P::Output: Clone,
+    {
+        Parser(RecoverParse {
+            parser: self.0,
+            recovery: recovery.0,
+            placeholder,
+        })
+    }
+
+    /// Name the parser in traces, see parse_all_traced
+    pub fn named(self, name: &'static str) -> Parser<Named<P>> {
+        Parser(Named {
+            parser_or_matcher: self.0,
+            name,
+        })
+    }
+
+    /// Replace what the parser reports as expected in errors with the label,
+    /// if it fails without getting past its first character.
+    pub fn label(self, label: &'static str) -> Parser<Label<P>> {
+        Parser(Label {
+            parser_or_matcher: self.0,
+            label,
+        })
+    }
+
     pub fn repeat<R: RangeArgument>(self, range: R) -> Parser<Repeat<P>> {
         Parser(Repeat {
             parser_or_matcher: self.0,
@@ -50,10 +241,40 @@ impl<P: Parse> Parser<P> {
         })
     }
 
+    /// Apply the parser without consuming input, only its output is returned
+    pub fn peek(self) -> Parser<PeekP<P>> {
+        Parser(PeekP(self.0))
+    }
+
+    /// Repeat the parser and push its outputs into a single String, instead
+    /// of collecting them in a Vec. Outputs can be String, &str, char, Substr
+    /// or an Option of them. Choices with matchers are built with part().
+    pub fn repeat_string<R: RangeArgument>(self, range: R) -> Parser<RepeatString<ParsePart<P>>>
+    where
+        P: Parse,
+        P::Output: PushStr,
+    {
+        self.part().repeat_string(range)
+    }
+
+    /// The output as Part of a repeat_string(), to combine it with matchers
+    pub fn part(self) -> Part<ParsePart<P>>
+    where
+        P: Parse,
+        P::Output: PushStr,
+    {
+        Part(ParsePart(self.0))
+    }
+
     pub fn opt(self) -> Parser<Opt<P>> {
         Parser(Opt(self.0))
     }
 
+    /// Additionally return the Span of the input that was consumed by the parser
+    pub fn spanned(self) -> Parser<SpannedParse<P>> {
+        Parser(SpannedParse(self.0))
+    }
+
     pub fn val<O: Clone>(self, value: O) -> Parser<ValParse<P, O>> {
         Parser(ValParse {
             parser: self.0,
@@ -61,8 +282,9 @@ impl<P: Parse> Parser<P> {
         })
     }
 
-    pub fn map<F, O>(self, f: F) -> Parser<MapParse<P, F>>
+    pub fn map<I: Input + ?Sized, F, O>(self, f: F) -> Parser<MapParse<P, F>>
     where
+        P: Parse<I>,
         F: Fn(P::Output) -> O + 'static,
     {
         Parser(MapParse {
@@ -71,8 +293,15 @@ impl<P: Parse> Parser<P> {
         })
     }
 
-    pub fn convert<F, O, E>(self, f: F, error_msg: &'static str) -> Parser<ConvertParse<P, F>>
+    /// Map the output with a function that can fail. If it fails, parsing
+    /// stops with a fatal error_msg.
+    pub fn convert<I: Input + ?Sized, F, O, E>(
+        self,
+        f: F,
+        error_msg: &'static str,
+    ) -> Parser<ConvertParse<P, F>>
     where
+        P: Parse<I>,
         F: Fn(P::Output) -> Result<O, E> + 'static,
         E: std::error::Error + 'static,
     {
@@ -82,27 +311,93 @@ impl<P: Parse> Parser<P> {
             error_msg,
         })
     }
+
+    /// Like convert, but the error of f is kept as source() of the fatal
+    /// error. It must be Send + Sync like EzpcError.
+    pub fn convert_source<I: Input + ?Sized, F, O, E>(
+        self,
+        f: F,
+        error_msg: &'static str,
+    ) -> Parser<ConvertSourceParse<P, F>>
+    where
+        P: Parse<I>,
+        F: Fn(P::Output) -> Result<O, E> + 'static,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        Parser(ConvertSourceParse {
+            parser: self.0,
+            map_func: f,
+            error_msg,
+        })
+    }
 }
 
-pub trait Match {
-    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a>;
+pub trait Match<I: Input + ?Sized = str>: Grammar {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I>;
+
+    /// See Parse::id
+    #[doc(hidden)]
+    fn id(&self) -> Option<usize> {
+        None
+    }
 }
 
-pub struct Matcher<M: Match>(M);
-
-impl<M: Match> Matcher<M> {
-    pub fn match_all(&self, source: &str) -> Result<(), EzpcError> {
-        match self.0.apply(source) {
-            Ok(rest) => {
-                if rest.is_empty() {
-                    Ok(())
-                } else {
-                    Err(EzpcError::PartialParse {
-                        pos: Position::from_ptr(source, rest.as_ptr()),
-                    })
-                }
-            }
-            Err(raw) => Err(EzpcError::from_raw(raw, source)),
+pub struct Matcher<M>(M);
+
+impl<M> Matcher<M> {
+    pub fn match_all(&self, source: &str) -> Result<(), EzpcError>
+    where
+        M: Match,
+    {
+        match self.match_all_recover(source).into_iter().next() {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+
+    /// Like match_all, but returns all errors the matcher recovered from.
+    /// Matching was successful if the returned list is empty.
+    pub fn match_all_recover(&self, source: &str) -> Vec<EzpcError>
+    where
+        M: Match,
+    {
+        state::run(source, || collect_errors(self.0.apply(source)))
+    }
+
+    /// Matcher version of Parser::parse_all_traced
+    pub fn match_all_traced(&self, source: &str) -> (Result<(), EzpcError>, Trace)
+    where
+        M: Match,
+    {
+        let (errors, events) = state::run(source, || {
+            state::trace_start();
+            let errors = collect_errors(self.0.apply(source));
+            (errors, state::trace_take())
+        });
+        let result = match errors.into_iter().next() {
+            None => Ok(()),
+            Some(err) => Err(err),
+        };
+        (result, Trace::new(events))
+    }
+
+    /// The grammar of the matcher, with the given name for its start rule
+    pub fn grammar(&self, name: &str) -> Rules
+    where
+        M: Grammar,
+    {
+        Rules::new(name, &self.0)
+    }
+
+    /// Like match_all, but for matchers of binary data
+    pub fn match_bytes(&self, source: &[u8]) -> Result<(), EzpcError>
+    where
+        M: Match<[u8]>,
+    {
+        let errors = state::run(source, || collect_errors(self.0.apply(source)));
+        match errors.into_iter().next() {
+            None => Ok(()),
+            Some(err) => Err(err),
         }
     }
 
@@ -113,6 +408,18 @@ impl<M: Match> Matcher<M> {
         })
     }
 
+    /// Matcher version of Parser::fatal_with
+    pub fn fatal_with<I: Input + ?Sized, F>(self, message: F) -> Matcher<FatalWith<M, F>>
+    where
+        M: Match<I>,
+        F: Fn(&I) -> String,
+    {
+        Matcher(FatalWith {
+            parser_or_matcher: self.0,
+            message,
+        })
+    }
+
     pub fn reject(self, expected: &'static str) -> Matcher<Reject<M>> {
         Matcher(Reject {
             matcher: self.0,
@@ -120,6 +427,50 @@ impl<M: Match> Matcher<M> {
         })
     }
 
+    /// Like reject, but the message is built at runtime from the input that
+    /// the rejected matcher matched
+    pub fn reject_with<I: Input + ?Sized, F>(self, message: F) -> Matcher<RejectWith<M, F>>
+    where
+        M: Match<I>,
+        F: Fn(&I) -> String,
+    {
+        Matcher(RejectWith {
+            matcher: self.0,
+            message,
+        })
+    }
+
+    /// Cache the result of this matcher for every input position during a
+    /// single match_all or parse_all call, see Parser::memo
+    pub fn memo(self) -> Matcher<Memo<M>> {
+        Matcher(Memo(self.0))
+    }
+
+    /// Matcher version of Parser::recover_with, which has no placeholder output
+    pub fn recover_with<R>(self, recovery: Matcher<R>) -> Matcher<RecoverMatch<M, R>> {
+        Matcher(RecoverMatch {
+            matcher: self.0,
+            recovery: recovery.0,
+        })
+    }
+
+    /// Name the matcher in traces, see parse_all_traced
+    pub fn named(self, name: &'static str) -> Matcher<Named<M>> {
+        Matcher(Named {
+            parser_or_matcher: self.0,
+            name,
+        })
+    }
+
+    /// Replace what the matcher reports as expected in errors with the label,
+    /// if it fails without getting past its first character.
+    pub fn label(self, label: &'static str) -> Matcher<Label<M>> {
+        Matcher(Label {
+            parser_or_matcher: self.0,
+            label,
+        })
+    }
+
     pub fn repeat<R: RangeArgument>(self, range: R) -> Matcher<Repeat<M>> {
         Matcher(Repeat {
             parser_or_matcher: self.0,
@@ -132,6 +483,51 @@ impl<M: Match> Matcher<M> {
         Matcher(Opt(self.0))
     }
 
+    /// Return the Span of the input that was consumed by the matcher
+    pub fn spanned(self) -> Parser<SpannedMatch<M>> {
+        Parser(SpannedMatch(self.0))
+    }
+
+    /// Positive lookahead: match without consuming input. Negative lookahead
+    /// is written `!matcher`, the intersection of matchers `a & b`.
+    pub fn peek(self) -> Matcher<PeekM<M>> {
+        Matcher(PeekM(self.0))
+    }
+
+    /// The matched text as String, same as map(|s| s.to_owned())
+    pub fn string(self) -> Parser<StringMatch<M>> {
+        Parser(StringMatch(self.0))
+    }
+
+    /// The matched text as a Substr of the source, without allocating a
+    /// String for it like string() does
+    pub fn slice(self) -> Parser<SliceMatch<M>> {
+        Parser(SliceMatch(self.0))
+    }
+
+    /// Repeat the matcher and push the matched text into a single String
+    pub fn repeat_string<R: RangeArgument>(self, range: R) -> Parser<RepeatString<MatchPart<M>>>
+    where
+        M: Match,
+    {
+        self.part().repeat_string(range)
+    }
+
+    /// The matched text as Part of a repeat_string(), it is pushed without
+    /// allocating a String for it
+    pub fn part(self) -> Part<MatchPart<M>>
+    where
+        M: Match,
+    {
+        Part(MatchPart(self.0))
+    }
+
+    /// The first character of the matched text, for matchers of a single
+    /// character like one_of. It is a mismatch if nothing was matched.
+    pub fn char(self) -> Parser<CharMatch<M>> {
+        Parser(CharMatch(self.0))
+    }
+
     pub fn val<O: Clone>(self, value: O) -> Parser<ValMatch<M, O>> {
         Parser(ValMatch {
             matcher: self.0,
@@ -139,9 +535,10 @@ impl<M: Match> Matcher<M> {
         })
     }
 
-    pub fn map<F, O>(self, map_func: F) -> Parser<MapMatch<M, F>>
+    pub fn map<I: Input + ?Sized, F, O>(self, map_func: F) -> Parser<MapMatch<M, F>>
     where
-        F: Fn(&str) -> O + 'static,
+        M: Match<I>,
+        F: Fn(&I) -> O + 'static,
     {
         Parser(MapMatch {
             matcher: self.0,
@@ -149,13 +546,15 @@ impl<M: Match> Matcher<M> {
         })
     }
 
-    pub fn convert<F, T, E>(
+    /// Matcher version of Parser::convert, f gets the matched input
+    pub fn convert<I: Input + ?Sized, F, T, E>(
         self,
         map_func: F,
         error_msg: &'static str,
     ) -> Parser<ConvertMatch<M, F>>
     where
-        F: Fn(&str) -> Result<T, E> + 'static,
+        M: Match<I>,
+        F: Fn(&I) -> Result<T, E> + 'static,
         E: std::error::Error + 'static,
     {
         Parser(ConvertMatch {
@@ -164,4 +563,43 @@ impl<M: Match> Matcher<M> {
             error_msg,
         })
     }
+
+    /// Matcher version of Parser::convert_source, f gets the matched input
+    pub fn convert_source<I: Input + ?Sized, F, T, E>(
+        self,
+        map_func: F,
+        error_msg: &'static str,
+    ) -> Parser<ConvertSourceMatch<M, F>>
+    where
+        M: Match<I>,
+        F: Fn(&I) -> Result<T, E> + 'static,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        Parser(ConvertSourceMatch {
+            matcher: self.0,
+            map_func,
+            error_msg,
+        })
+    }
+}
+
+/// Text pushed into the String of repeat_string(), see Part
+pub trait StrPart: Grammar {
+    fn push_to<'a>(&self, input: &'a str, buffer: &mut String) -> MatchResult<'a>;
+}
+
+/// Part of the String built by repeat_string(): matchers push the text they
+/// matched, parsers their output. Parts, parsers and matchers are combined
+/// into a choice of parts with `|`.
+pub struct Part<T>(T);
+
+impl<T: StrPart> Part<T> {
+    /// Repeat the parts and push their text into a single String
+    pub fn repeat_string<R: RangeArgument>(self, range: R) -> Parser<RepeatString<T>> {
+        Parser(RepeatString {
+            part: self.0,
+            start: range.start(),
+            end: range.end(),
+        })
+    }
 }
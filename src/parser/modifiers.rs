@@ -1,5 +1,11 @@
-use super::{Match, Parse};
-use crate::result::{MatchResult, ParseResult, RawEzpcError};
+use std::sync::Arc;
+
+use super::{Match, Parse, StrPart};
+use crate::grammar::{Grammar, GrammarNode, Rules};
+use crate::input::Input;
+use crate::result::{MatchResult, ParseResult, RawEzpcError, Span, Substr};
+use crate::state;
+use crate::trace;
 
 // These structs are returned by Parser / Matcher methods, so the fields must
 // be public to the parent mod.
@@ -9,11 +15,31 @@ pub struct Fatal<T> {
     pub(super) expected: &'static str,
 }
 
+pub struct FatalWith<T, F> {
+    pub(super) parser_or_matcher: T,
+    pub(super) message: F,
+}
+
+pub struct Label<T> {
+    pub(super) parser_or_matcher: T,
+    pub(super) label: &'static str,
+}
+
+pub struct Named<T> {
+    pub(super) parser_or_matcher: T,
+    pub(super) name: &'static str,
+}
+
 pub struct Reject<M> {
     pub(super) matcher: M,
     pub(super) expected: &'static str,
 }
 
+pub struct RejectWith<M, F> {
+    pub(super) matcher: M,
+    pub(super) message: F,
+}
+
 pub struct Repeat<T> {
     pub(super) parser_or_matcher: T,
     pub(super) start: usize,
@@ -54,57 +80,182 @@ pub struct ConvertParse<P, F> {
     pub(super) error_msg: &'static str,
 }
 
+pub struct ConvertSourceMatch<M, F> {
+    pub(super) matcher: M,
+    pub(super) map_func: F,
+    pub(super) error_msg: &'static str,
+}
+
+pub struct ConvertSourceParse<P, F> {
+    pub(super) parser: P,
+    pub(super) map_func: F,
+    pub(super) error_msg: &'static str,
+}
+
+pub struct Memo<T>(pub(super) T);
+
+pub struct RecoverMatch<M, R> {
+    pub(super) matcher: M,
+    pub(super) recovery: R,
+}
+
+pub struct RecoverParse<P, R, T> {
+    pub(super) parser: P,
+    pub(super) recovery: R,
+    pub(super) placeholder: T,
+}
+
+pub struct SpannedMatch<M>(pub(super) M);
+
+pub struct SpannedParse<P>(pub(super) P);
+
+pub struct StringMatch<M>(pub(super) M);
+
+pub struct CharMatch<M>(pub(super) M);
+
+pub struct SliceMatch<M>(pub(super) M);
+
+pub struct RepeatString<T> {
+    pub(super) part: T,
+    pub(super) start: usize,
+    pub(super) end: usize,
+}
+
+/// Parts of a repeat_string(), see Matcher::part and Parser::part
+pub struct MatchPart<M>(pub(super) M);
+pub struct ParsePart<P>(pub(super) P);
+
+/// Output of a parser that is repeated into a String, see repeat_string()
+pub trait PushStr {
+    fn push_to(self, buffer: &mut String);
+}
+
+impl PushStr for String {
+    fn push_to(self, buffer: &mut String) {
+        buffer.push_str(&self);
+    }
+}
+
+impl PushStr for &str {
+    fn push_to(self, buffer: &mut String) {
+        buffer.push_str(self);
+    }
+}
+
+impl PushStr for char {
+    fn push_to(self, buffer: &mut String) {
+        buffer.push(self);
+    }
+}
+
+impl PushStr for Substr {
+    fn push_to(self, buffer: &mut String) {
+        buffer.push_str(&self);
+    }
+}
+
+impl<T: PushStr> PushStr for Option<T> {
+    fn push_to(self, buffer: &mut String) {
+        if let Some(text) = self {
+            text.push_to(buffer);
+        }
+    }
+}
+
 // Implementations for modified Parsers
 
-impl<P: Parse> Parse for Fatal<P> {
+impl<I: Input + ?Sized, P: Parse<I>> Parse<I> for Fatal<P> {
     type Output = P::Output;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         self.parser_or_matcher
             .apply(input)
             .map_err(|err| match err {
                 RawEzpcError::Mismatch { pos } => RawEzpcError::Fatal {
-                    message: self.expected,
+                    message: self.expected.into(),
                     pos,
+                    source: None,
                 },
                 _ => err,
             })
     }
 }
 
-impl<T: Parse> Parse for Repeat<T> {
-    type Output = Vec<T::Output>;
+impl<I: Input + ?Sized, P: Parse<I>, F: Fn(&I) -> String> Parse<I> for FatalWith<P, F> {
+    type Output = P::Output;
 
-    fn apply<'a>(&self, mut input: &'a str) -> ParseResult<'a, Self::Output> {
-        let mut items = Vec::new();
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.parser_or_matcher
+            .apply(input)
+            .map_err(|err| match err {
+                RawEzpcError::Mismatch { pos } => RawEzpcError::Fatal {
+                    message: (self.message)(remaining(input, pos)).into(),
+                    pos,
+                    source: None,
+                },
+                _ => err,
+            })
+    }
+}
+
+impl<I: Input + ?Sized, P: Parse<I>> Parse<I> for Label<P> {
+    type Output = P::Output;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        let mark = state::mark();
+        self.parser_or_matcher.apply(input).map_err(|err| {
+            if let RawEzpcError::Mismatch { .. } = err {
+                state::relabel(mark, input.as_ptr(), self.label);
+            }
+            err
+        })
+    }
+}
+
+impl<I: Input + ?Sized, P: Parse<I>> Parse<I> for Named<P> {
+    type Output = P::Output;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        trace::parse(self.name, input, || self.parser_or_matcher.apply(input))
+    }
+}
+
+impl<I: Input + ?Sized, T: Parse<I>> Parse<I> for Repeat<T> {
+    type Output = Vec<T::Output>;
 
-        for _ in 0..=self.end {
-            match self.parser_or_matcher.apply(input) {
-                Ok((out, rest)) => {
-                    items.push(out);
-                    input = rest;
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        trace::parse("repeat", input, || {
+            let mut input = input;
+            let mut items = Vec::new();
+
+            for _ in 0..=self.end {
+                match self.parser_or_matcher.apply(input) {
+                    Ok((out, rest)) => {
+                        items.push(out);
+                        input = rest;
+                    }
+                    Err(err) => match err {
+                        RawEzpcError::Mismatch { .. } => break,
+                        _ => return Err(err),
+                    },
                 }
-                Err(err) => match err {
-                    RawEzpcError::Mismatch { .. } => break,
-                    _ => return Err(err),
-                },
             }
-        }
 
-        if items.len() < self.start {
-            Err(RawEzpcError::Mismatch {
-                pos: input.as_ptr(),
-            })
-        } else {
-            Ok((items, input))
-        }
+            if items.len() < self.start {
+                Err(RawEzpcError::Mismatch {
+                    pos: input.as_ptr(),
+                })
+            } else {
+                Ok((items, input))
+            }
+        })
     }
 }
 
-impl<T: Parse> Parse for Opt<T> {
+impl<I: Input + ?Sized, T: Parse<I>> Parse<I> for Opt<T> {
     type Output = Option<T::Output>;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         match self.0.apply(input) {
             Ok((out, rest)) => Ok((Some(out), rest)),
             Err(err) => match err {
@@ -117,26 +268,63 @@ impl<T: Parse> Parse for Opt<T> {
 
 // Implementations for modified Matchers
 
-impl<M: Match> Match for Fatal<M> {
-    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+impl<I: Input + ?Sized, M: Match<I>> Match<I> for Fatal<M> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        self.parser_or_matcher
+            .apply(input)
+            .map_err(|err| match err {
+                RawEzpcError::Mismatch { pos } => RawEzpcError::Fatal {
+                    message: self.expected.into(),
+                    pos,
+                    source: None,
+                },
+                _ => err,
+            })
+    }
+}
+
+impl<I: Input + ?Sized, M: Match<I>, F: Fn(&I) -> String> Match<I> for FatalWith<M, F> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
         self.parser_or_matcher
             .apply(input)
             .map_err(|err| match err {
                 RawEzpcError::Mismatch { pos } => RawEzpcError::Fatal {
-                    message: self.expected,
+                    message: (self.message)(remaining(input, pos)).into(),
                     pos,
+                    source: None,
                 },
                 _ => err,
             })
     }
 }
 
-impl<M: Match> Match for Reject<M> {
-    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
-        match self.matcher.apply(input) {
+impl<I: Input + ?Sized, M: Match<I>> Match<I> for Named<M> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        trace::matches(self.name, input, || self.parser_or_matcher.apply(input))
+    }
+}
+
+impl<I: Input + ?Sized, M: Match<I>> Match<I> for Label<M> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        let mark = state::mark();
+        self.parser_or_matcher.apply(input).map_err(|err| {
+            if let RawEzpcError::Mismatch { .. } = err {
+                state::relabel(mark, input.as_ptr(), self.label);
+            }
+            err
+        })
+    }
+}
+
+impl<I: Input + ?Sized, M: Match<I>> Match<I> for Reject<M> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        // The matcher failing is the expected case here, so it shouldn't
+        // show up as an expectation in error messages
+        match state::silent(|| self.matcher.apply(input)) {
             Ok(_) => Err(RawEzpcError::Fatal {
-                message: self.expected,
+                message: self.expected.into(),
                 pos: input.as_ptr(),
+                source: None,
             }),
             Err(err) => match err {
                 RawEzpcError::Mismatch { .. } => Ok(input),
@@ -146,35 +334,54 @@ impl<M: Match> Match for Reject<M> {
     }
 }
 
-impl<T: Match> Match for Repeat<T> {
-    fn apply<'a>(&self, mut input: &'a str) -> MatchResult<'a> {
-        let mut item_count = 0;
+impl<I: Input + ?Sized, M: Match<I>, F: Fn(&I) -> String> Match<I> for RejectWith<M, F> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        match state::silent(|| self.matcher.apply(input)) {
+            Ok(rest) => Err(RawEzpcError::Fatal {
+                message: (self.message)(consumed(input, rest)).into(),
+                pos: input.as_ptr(),
+                source: None,
+            }),
+            Err(err) => match err {
+                RawEzpcError::Mismatch { .. } => Ok(input),
+                _ => Err(err),
+            },
+        }
+    }
+}
 
-        for _ in 0..=self.end {
-            match self.parser_or_matcher.apply(input) {
-                Ok(rest) => {
-                    item_count += 1;
-                    input = rest;
+impl<I: Input + ?Sized, T: Match<I>> Match<I> for Repeat<T> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        trace::matches("repeat", input, || {
+            let mut input = input;
+            let mut item_count = 0;
+
+            for _ in 0..=self.end {
+                match self.parser_or_matcher.apply(input) {
+                    Ok(rest) => {
+                        item_count += 1;
+                        input = rest;
+                    }
+                    Err(err) => match err {
+                        RawEzpcError::Mismatch { .. } => break,
+                        _ => return Err(err),
+                    },
                 }
-                Err(err) => match err {
-                    RawEzpcError::Mismatch { .. } => break,
-                    _ => return Err(err),
-                },
             }
-        }
 
-        if item_count < self.start {
-            Err(RawEzpcError::Mismatch {
-                pos: input.as_ptr(),
-            })
-        } else {
-            Ok(input)
-        }
+            if item_count < self.start {
+                Err(RawEzpcError::Mismatch {
+                    pos: input.as_ptr(),
+                })
+            } else {
+                Ok(input)
+            }
+        })
     }
 }
 
-impl<T: Match> Match for Opt<T> {
-    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+impl<I: Input + ?Sized, T: Match<I>> Match<I> for Opt<T> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
         match self.0.apply(input) {
             Ok(rest) => Ok(rest),
             Err(err) => match err {
@@ -187,103 +394,434 @@ impl<T: Match> Match for Opt<T> {
 
 // Implementations for Mappers and Converters
 
-impl<M: Match, T: Clone> Parse for ValMatch<M, T> {
+impl<I: Input + ?Sized, M: Match<I>, T: Clone> Parse<I> for ValMatch<M, T> {
     type Output = T;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         self.matcher
             .apply(input)
             .map(|rest| (self.value.clone(), rest))
     }
 }
 
-impl<P: Parse, T: Clone> Parse for ValParse<P, T> {
+impl<I: Input + ?Sized, P: Parse<I>, T: Clone> Parse<I> for ValParse<P, T> {
     type Output = T;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         self.parser
             .apply(input)
             .map(|(_, rest)| (self.value.clone(), rest))
     }
 }
 
-impl<M, F, O> Parse for MapMatch<M, F>
+impl<I: Input + ?Sized, M, F, O> Parse<I> for MapMatch<M, F>
 where
-    M: Match,
-    F: Fn(&str) -> O,
+    M: Match<I>,
+    F: Fn(&I) -> O,
 {
     type Output = O;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         self.matcher
             .apply(input)
             .map(|rest| ((self.map_func)(consumed(input, rest)), rest))
     }
 }
 
-impl<P, F, O> Parse for MapParse<P, F>
+impl<I: Input + ?Sized, P, F, O> Parse<I> for MapParse<P, F>
 where
-    P: Parse,
+    P: Parse<I>,
     F: Fn(P::Output) -> O + 'static,
 {
     type Output = O;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         self.parser
             .apply(input)
             .map(|(tmp, rest)| ((self.map_func)(tmp), rest))
     }
 }
 
-impl<M, F, O, E> Parse for ConvertMatch<M, F>
+impl<I: Input + ?Sized, M, F, O, E> Parse<I> for ConvertMatch<M, F>
 where
-    M: Match,
-    F: Fn(&str) -> Result<O, E> + 'static,
+    M: Match<I>,
+    F: Fn(&I) -> Result<O, E> + 'static,
     E: std::error::Error + 'static,
 {
     type Output = O;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         self.matcher
             .apply(input)
             .and_then(|rest| match (self.map_func)(consumed(input, rest)) {
                 Ok(out) => Ok((out, rest)),
                 Err(_) => Err(RawEzpcError::Fatal {
-                    message: self.error_msg,
+                    message: self.error_msg.into(),
                     pos: rest.as_ptr(),
+                    source: None,
                 }),
             })
     }
 }
 
-impl<P, F, O, E> Parse for ConvertParse<P, F>
+impl<I: Input + ?Sized, P, F, O, E> Parse<I> for ConvertParse<P, F>
 where
-    P: Parse,
+    P: Parse<I>,
     F: Fn(P::Output) -> Result<O, E> + 'static,
     E: std::error::Error + 'static,
 {
     type Output = O;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         self.parser
             .apply(input)
             .and_then(|(tmp, rest)| match (self.map_func)(tmp) {
                 Ok(out) => Ok((out, rest)),
                 Err(_) => Err(RawEzpcError::Fatal {
-                    message: self.error_msg,
+                    message: self.error_msg.into(),
                     pos: rest.as_ptr(),
+                    source: None,
                 }),
             })
     }
 }
 
-/// Helper function that returns the parsed part of the source str
-/// NOTE: returned string can be empty if an optional Matcher didn't apply
-fn consumed<'a>(source: &'a str, substr: &'a str) -> &'a str {
+impl<I: Input + ?Sized, M, F, O, E> Parse<I> for ConvertSourceMatch<M, F>
+where
+    M: Match<I>,
+    F: Fn(&I) -> Result<O, E> + 'static,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    type Output = O;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.matcher
+            .apply(input)
+            .and_then(|rest| match (self.map_func)(consumed(input, rest)) {
+                Ok(out) => Ok((out, rest)),
+                Err(err) => Err(RawEzpcError::Fatal {
+                    message: self.error_msg.into(),
+                    pos: rest.as_ptr(),
+                    source: Some(Arc::new(err)),
+                }),
+            })
+    }
+}
+
+impl<I: Input + ?Sized, P, F, O, E> Parse<I> for ConvertSourceParse<P, F>
+where
+    P: Parse<I>,
+    F: Fn(P::Output) -> Result<O, E> + 'static,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    type Output = O;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.parser
+            .apply(input)
+            .and_then(|(tmp, rest)| match (self.map_func)(tmp) {
+                Ok(out) => Ok((out, rest)),
+                Err(err) => Err(RawEzpcError::Fatal {
+                    message: self.error_msg.into(),
+                    pos: rest.as_ptr(),
+                    source: Some(Arc::new(err)),
+                }),
+            })
+    }
+}
+
+// Implementations for Spanned
+
+impl<I: Input + ?Sized, M: Match<I>> Parse<I> for SpannedMatch<M> {
+    type Output = Span;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.0
+            .apply(input)
+            .map(|rest| (state::span(input.as_ptr(), rest.as_ptr()), rest))
+    }
+}
+
+impl<I: Input + ?Sized, P: Parse<I>> Parse<I> for SpannedParse<P> {
+    type Output = (P::Output, Span);
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.0
+            .apply(input)
+            .map(|(out, rest)| ((out, state::span(input.as_ptr(), rest.as_ptr())), rest))
+    }
+}
+
+// Implementations for text building
+
+impl<M: Match> Parse for StringMatch<M> {
+    type Output = String;
+
+    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+        self.0
+            .apply(input)
+            .map(|rest| (consumed(input, rest).to_owned(), rest))
+    }
+}
+
+impl<M: Match> Parse for SliceMatch<M> {
+    type Output = Substr;
+
+    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+        self.0
+            .apply(input)
+            .map(|rest| (state::substr(input.as_ptr(), rest.as_ptr()), rest))
+    }
+}
+
+impl<M: Match> Parse for CharMatch<M> {
+    type Output = char;
+
+    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
+        let rest = self.0.apply(input)?;
+        match consumed(input, rest).chars().next() {
+            Some(c) => Ok((c, rest)),
+            // Nothing was matched, so there is no char
+            None => Err(RawEzpcError::Mismatch {
+                pos: input.as_ptr(),
+            }),
+        }
+    }
+}
+
+impl<M: Match> StrPart for MatchPart<M> {
+    fn push_to<'a>(&self, input: &'a str, buffer: &mut String) -> MatchResult<'a> {
+        let rest = self.0.apply(input)?;
+        buffer.push_str(consumed(input, rest));
+        Ok(rest)
+    }
+}
+
+impl<P> StrPart for ParsePart<P>
+where
+    P: Parse,
+    P::Output: PushStr,
+{
+    fn push_to<'a>(&self, input: &'a str, buffer: &mut String) -> MatchResult<'a> {
+        let (out, rest) = self.0.apply(input)?;
+        out.push_to(buffer);
+        Ok(rest)
+    }
+}
+
+impl<T: StrPart> Parse for RepeatString<T> {
+    type Output = String;
+
+    fn apply<'a>(&self, mut input: &'a str) -> ParseResult<'a, Self::Output> {
+        trace::parse("repeat", input, || {
+            let mut buffer = String::new();
+            let mut item_count = 0;
+
+            for _ in 0..=self.end {
+                match self.part.push_to(input, &mut buffer) {
+                    Ok(rest) => {
+                        item_count += 1;
+                        input = rest;
+                    }
+                    Err(err) => match err {
+                        RawEzpcError::Mismatch { .. } => break,
+                        _ => return Err(err),
+                    },
+                }
+            }
+
+            if item_count < self.start {
+                Err(RawEzpcError::Mismatch {
+                    pos: input.as_ptr(),
+                })
+            } else {
+                Ok((buffer, input))
+            }
+        })
+    }
+}
+
+// Implementations for Memo, which cache (consumed length, output) or error and
+// the expectations. The key is the id of the parser if it has one, so memoized
+// references to the same wrapped parser share results, else the Memo address.
+
+impl<I: Input + ?Sized, M: Match<I>> Match<I> for Memo<M> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        let id = self.0.id().unwrap_or(self as *const Self as usize);
+        let key = (id, input.as_ptr());
+        type Cached = (Result<usize, RawEzpcError>, state::Expectations);
+        if let Some((result, expectations)) = state::memo_get::<Cached>(key) {
+            state::replay(&expectations);
+            return result.map(|len| input.tail(len));
+        }
+
+        let (result, expectations) = state::recording(|| self.0.apply(input));
+        if !state::lr_involved(input.as_ptr()) {
+            let len = result.clone().map(|rest| input.len() - rest.len());
+            state::memo_insert(key, (len, expectations));
+        }
+        result
+    }
+}
+
+impl<I: Input + ?Sized, P: Parse<I>> Parse<I> for Memo<P>
+where
+    P::Output: Clone + 'static,
+{
+    type Output = P::Output;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        let id = self.0.id().unwrap_or(self as *const Self as usize);
+        let key = (id, input.as_ptr());
+        type Cached<O> = (Result<(O, usize), RawEzpcError>, state::Expectations);
+        if let Some((result, expectations)) = state::memo_get::<Cached<P::Output>>(key) {
+            state::replay(&expectations);
+            return result.map(|(out, len)| (out, input.tail(len)));
+        }
+
+        let (result, expectations) = state::recording(|| self.0.apply(input));
+        if !state::lr_involved(input.as_ptr()) {
+            let out = result
+                .clone()
+                .map(|(out, rest)| (out, input.len() - rest.len()));
+            state::memo_insert(key, (out, expectations));
+        }
+        result
+    }
+}
+
+// Implementations for Recover
+
+impl<I: Input + ?Sized, M: Match<I>, R: Match<I>> Match<I> for RecoverMatch<M, R> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        self.matcher
+            .apply(input)
+            .or_else(|err| recover(&self.recovery, input, err))
+    }
+}
+
+impl<I: Input + ?Sized, P: Parse<I>, R: Match<I>> Parse<I> for RecoverParse<P, R, P::Output>
+where
+    P::Output: Clone,
+{
+    type Output = P::Output;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.parser.apply(input).or_else(|err| {
+            recover(&self.recovery, input, err).map(|rest| (self.placeholder.clone(), rest))
+        })
+    }
+}
+
+/// Apply the recovery matcher at the position of a fatal error. If it
+/// matches, the error is recorded and the remaining input returned.
+fn recover<'a, I: Input + ?Sized, R: Match<I>>(
+    recovery: &R,
+    input: &'a I,
+    err: RawEzpcError,
+) -> MatchResult<'a, I> {
+    let RawEzpcError::Fatal { pos, .. } = err else {
+        return Err(err);
+    };
+    match recovery.apply(remaining(input, pos)) {
+        // Recovering without consuming anything would loop forever in a repeat
+        Ok(rest) if rest.as_ptr() > input.as_ptr() => {
+            state::diagnostic(err);
+            Ok(rest)
+        }
+        _ => Err(err),
+    }
+}
+
+/// Helper function that returns the part of the input starting at pos
+fn remaining<I: Input + ?Sized>(input: &I, pos: *const u8) -> &I {
+    let start_input = input.as_ptr() as usize;
+    let start_pos = pos as usize;
+    assert!(start_pos >= start_input);
+
+    input.tail(start_pos - start_input)
+}
+
+/// Helper function that returns the parsed part of the source
+/// NOTE: returned input can be empty if an optional Matcher didn't apply
+fn consumed<'a, I: Input + ?Sized>(source: &'a I, substr: &'a I) -> &'a I {
     let start_source = source.as_ptr() as usize;
     let start_substr = substr.as_ptr() as usize;
     assert!(start_substr >= start_source);
 
     let advanced_by = start_substr - start_source;
-    &source[..advanced_by]
+    source.head(advanced_by)
+}
+
+// Grammar of the modifiers. Most of them don't change what is accepted, only
+// the output or the errors, so they describe the modified parser or matcher.
+
+macro_rules! describe_inner {
+    ($($modifier:ident<$($param:ident),*>.$field:tt)*) => {$(
+        impl<T: Grammar, $($param),*> Grammar for $modifier<T, $($param),*> {
+            fn describe(&self, rules: &mut Rules) -> GrammarNode {
+                self.$field.describe(rules)
+            }
+        }
+    )*};
+}
+
+describe_inner! {
+    Fatal<>.parser_or_matcher
+    FatalWith<F>.parser_or_matcher
+    Label<>.parser_or_matcher
+    Named<>.parser_or_matcher
+    ValMatch<V>.matcher
+    ValParse<V>.parser
+    MapMatch<F>.matcher
+    MapParse<F>.parser
+    ConvertMatch<F>.matcher
+    ConvertParse<F>.parser
+    ConvertSourceMatch<F>.matcher
+    ConvertSourceParse<F>.parser
+    Memo<>.0
+    RecoverMatch<R>.matcher
+    RecoverParse<R, V>.parser
+    SpannedMatch<>.0
+    SpannedParse<>.0
+    StringMatch<>.0
+    SliceMatch<>.0
+    CharMatch<>.0
+    MatchPart<>.0
+    ParsePart<>.0
+}
+
+impl<M: Grammar> Grammar for Reject<M> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        GrammarNode::Not(Box::new(self.matcher.describe(rules)))
+    }
+}
+
+impl<M: Grammar, F> Grammar for RejectWith<M, F> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        GrammarNode::Not(Box::new(self.matcher.describe(rules)))
+    }
+}
+
+impl<T: Grammar> Grammar for Repeat<T> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        // Up to end + 1 items are applied, see apply
+        let max = self.end.checked_add(1);
+        self.parser_or_matcher
+            .describe(rules)
+            .repeat(self.start, max)
+    }
+}
+
+impl<T: Grammar> Grammar for RepeatString<T> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let max = self.end.checked_add(1);
+        self.part.describe(rules).repeat(self.start, max)
+    }
+}
+
+impl<T: Grammar> Grammar for Opt<T> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        GrammarNode::Optional(Box::new(self.0.describe(rules)))
+    }
 }
@@ -0,0 +1,573 @@
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use super::classes::category_ranges;
+use super::{Match, Matcher};
+use crate::grammar::{Grammar, GrammarNode, Rules};
+use crate::result::{Expected, MatchResult, RawEzpcError};
+use crate::state;
+
+// A small regex engine: the pattern is parsed into a syntax tree, which is
+// compiled to an NFA (Thompson's construction) and then to a DFA (subset
+// construction), all in regex(). Matching is a single pass over the input
+// without backtracking. The DFA works on classes of chars instead of chars:
+// the boundaries of all ranges in the pattern split the code points into
+// classes that every transition either contains completely or not at all.
+
+const MAX_REPEAT: u32 = 1000;
+const MAX_NFA_STATES: u64 = 100_000;
+const MAX_STATES: usize = 10_000;
+const LAST_CHAR: u32 = char::MAX as u32;
+
+pub struct Regex {
+    pattern: &'static str,
+    dfa: Dfa,
+}
+
+/// Matches the longest text at the start of the input that matches the
+/// pattern, e.g. `regex(r"[0-9]{4}-[0-9]{2}-[0-9]{2}")`. The regex is always
+/// anchored at the current position, so `^` and `$` are not supported.
+///
+/// The syntax is a subset of the common one:
+/// - `.` matches any character but `\n`
+/// - `[a-z_]` and `[^"\\]` match one of or none of the characters and ranges
+/// - `\d`, `\w` and `\s` match ASCII digits, word characters and whitespace,
+///   `\D`, `\W` and `\S` the rest
+/// - `\p{Lu}` matches the Unicode general category, `\P{Lu}` the rest
+/// - `\n`, `\r`, `\t` and `\u{1F600}` match the character, `\` followed by
+///   punctuation matches it literally
+/// - `(...)` and `(?:...)` group, `|` separates alternatives
+/// - `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}` repeat the previous item
+///
+/// Panics if the pattern is invalid or the DFA gets too large.
+pub fn regex(pattern: &'static str) -> Matcher<Regex> {
+    let node = match Syntax::parse(pattern) {
+        Ok(node) => node,
+        Err(message) => panic!("invalid regex `{pattern}`: {message}"),
+    };
+    if node.size() > MAX_NFA_STATES {
+        panic!("regex `{pattern}` needs more than {MAX_NFA_STATES} NFA states");
+    }
+    let Some(dfa) = Dfa::new(&Nfa::new(&node)) else {
+        panic!("regex `{pattern}` needs more than {MAX_STATES} DFA states");
+    };
+    Matcher(Regex { pattern, dfa })
+}
+
+impl Match for Regex {
+    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+        let dfa = &self.dfa;
+        let mut state = Dfa::START;
+        let mut longest = dfa.accepting[state].then_some(0);
+        for (pos, c) in input.char_indices() {
+            state = dfa.next(state, c);
+            if state == Dfa::DEAD {
+                break;
+            }
+            if dfa.accepting[state] {
+                longest = Some(pos + c.len_utf8());
+            }
+        }
+        // A longer match might follow if the input is streamed
+        if state != Dfa::DEAD && dfa.alive[state] {
+            state::hit_end();
+        }
+        if let Some(len) = longest {
+            return Ok(&input[len..]);
+        }
+        state::expected(input.as_ptr(), Expected::Regex(self.pattern));
+        Err(RawEzpcError::Mismatch {
+            pos: input.as_ptr(),
+        })
+    }
+}
+
+impl Grammar for Regex {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        GrammarNode::Special(format!("/{}/", self.pattern))
+    }
+}
+
+// Syntax tree
+
+enum Node {
+    /// Sorted ranges of chars that neither overlap nor touch
+    Class(Vec<(u32, u32)>),
+    Concat(Vec<Node>),
+    Alternate(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+impl Node {
+    /// The number of NFA states, which grows with nested repetitions
+    fn size(&self) -> u64 {
+        match self {
+            Node::Class(_) => 1,
+            Node::Concat(nodes) => nodes.iter().map(Node::size).sum(),
+            Node::Alternate(nodes) => nodes.iter().map(Node::size).sum::<u64>() + 1,
+            Node::Repeat { node, min, max } => {
+                let copies = max.unwrap_or(*min + 1) as u64;
+                (node.size() + 1) * copies
+            }
+        }
+    }
+}
+
+struct Syntax<'p> {
+    chars: Peekable<Chars<'p>>,
+}
+
+impl Syntax<'_> {
+    fn parse(pattern: &str) -> Result<Node, String> {
+        let mut syntax = Syntax {
+            chars: pattern.chars().peekable(),
+        };
+        let node = syntax.alternate()?;
+        match syntax.chars.next() {
+            None => Ok(node),
+            Some(_) => Err("unmatched `)`".to_owned()),
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.chars.next_if_eq(&c).is_some()
+    }
+
+    fn alternate(&mut self) -> Result<Node, String> {
+        let mut nodes = vec![self.concat()?];
+        while self.eat('|') {
+            nodes.push(self.concat()?);
+        }
+        Ok(match nodes.len() {
+            1 => nodes.pop().unwrap(),
+            _ => Node::Alternate(nodes),
+        })
+    }
+
+    fn concat(&mut self) -> Result<Node, String> {
+        let mut nodes = Vec::new();
+        while !matches!(self.chars.peek(), None | Some('|' | ')')) {
+            nodes.push(self.repeat()?);
+        }
+        Ok(Node::Concat(nodes))
+    }
+
+    fn repeat(&mut self) -> Result<Node, String> {
+        let mut node = self.atom()?;
+        loop {
+            let (min, max) = if self.eat('*') {
+                (0, None)
+            } else if self.eat('+') {
+                (1, None)
+            } else if self.eat('?') {
+                (0, Some(1))
+            } else if self.eat('{') {
+                self.counts()?
+            } else {
+                return Ok(node);
+            };
+            if matches!(self.chars.peek(), Some('?' | '+')) {
+                return Err("lazy and possessive quantifiers are not supported".to_owned());
+            }
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+            };
+        }
+    }
+
+    /// The counts of `{n}`, `{n,}` and `{n,m}` after the `{`
+    fn counts(&mut self) -> Result<(u32, Option<u32>), String> {
+        let min = self.number()?;
+        let max = if self.eat(',') {
+            match self.chars.peek() {
+                Some('}') => None,
+                _ => Some(self.number()?),
+            }
+        } else {
+            Some(min)
+        };
+        if !self.eat('}') {
+            return Err("unclosed `{`".to_owned());
+        }
+        if max.is_some_and(|max| max < min) {
+            return Err(format!("repetition `{{{min},{}}}` is empty", max.unwrap()));
+        }
+        Ok((min, max))
+    }
+
+    fn number(&mut self) -> Result<u32, String> {
+        let mut number = None;
+        while let Some(digit) = self.chars.peek().and_then(|c| c.to_digit(10)) {
+            self.chars.next();
+            let value = number.unwrap_or(0) * 10 + digit;
+            if value > MAX_REPEAT {
+                return Err(format!("repetition count is over {MAX_REPEAT}"));
+            }
+            number = Some(value);
+        }
+        number.ok_or_else(|| "expected a repetition count".to_owned())
+    }
+
+    fn atom(&mut self) -> Result<Node, String> {
+        let Some(c) = self.chars.next() else {
+            unreachable!("concat() checks for the end");
+        };
+        Ok(match c {
+            '(' => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err("only `(?:` groups are supported".to_owned());
+                }
+                let node = self.alternate()?;
+                if !self.eat(')') {
+                    return Err("unclosed group".to_owned());
+                }
+                node
+            }
+            '.' => Node::Class(complement(vec![('\n' as u32, '\n' as u32)])),
+            '[' => Node::Class(self.class()?),
+            '\\' => Node::Class(self.escape()?),
+            '^' | '$' => {
+                return Err(format!(
+                    "anchor `{c}` is not supported, the regex is anchored at the start"
+                ))
+            }
+            '*' | '+' | '?' | '{' => return Err(format!("nothing to repeat with `{c}`")),
+            c => Node::Class(vec![(c as u32, c as u32)]),
+        })
+    }
+
+    /// A class like `[a-z_]` after the `[`
+    fn class(&mut self) -> Result<Vec<(u32, u32)>, String> {
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        loop {
+            let start = match self.chars.next() {
+                None => return Err("unclosed character class".to_owned()),
+                Some(']') if !ranges.is_empty() => break,
+                Some(']') => return Err("empty character class".to_owned()),
+                Some('\\') => self.escape()?,
+                Some(c) => vec![(c as u32, c as u32)],
+            };
+            let single = start.len() == 1 && start[0].0 == start[0].1;
+            if !single || self.chars.peek() != Some(&'-') {
+                ranges.extend(start);
+                continue;
+            }
+            self.chars.next();
+            let end = match self.chars.next() {
+                None => return Err("unclosed character class".to_owned()),
+                // A `-` at the end is literal
+                Some(']') => {
+                    ranges.extend(start);
+                    ranges.push(('-' as u32, '-' as u32));
+                    break;
+                }
+                Some('\\') => match self.escape()?[..] {
+                    [(start, end)] if start == end => start,
+                    _ => return Err("a class can't end a range".to_owned()),
+                },
+                Some(c) => c as u32,
+            };
+            if end < start[0].0 {
+                return Err("range out of order in character class".to_owned());
+            }
+            ranges.push((start[0].0, end));
+        }
+        let ranges = normalize(ranges);
+        Ok(if negated { complement(ranges) } else { ranges })
+    }
+
+    /// The ranges of an escape sequence after the `\`
+    fn escape(&mut self) -> Result<Vec<(u32, u32)>, String> {
+        let digit = vec![(b'0' as u32, b'9' as u32)];
+        let word = normalize(vec![
+            (b'0' as u32, b'9' as u32),
+            (b'A' as u32, b'Z' as u32),
+            (b'_' as u32, b'_' as u32),
+            (b'a' as u32, b'z' as u32),
+        ]);
+        let space = vec![(b'\t' as u32, b'\r' as u32), (b' ' as u32, b' ' as u32)];
+        let char = |c: char| vec![(c as u32, c as u32)];
+        Ok(match self.chars.next() {
+            None => return Err("trailing `\\`".to_owned()),
+            Some('d') => digit,
+            Some('D') => complement(digit),
+            Some('w') => word,
+            Some('W') => complement(word),
+            Some('s') => space,
+            Some('S') => complement(space),
+            Some('n') => char('\n'),
+            Some('r') => char('\r'),
+            Some('t') => char('\t'),
+            Some('u') => {
+                let code = self.braced()?;
+                match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    Some(c) => char(c),
+                    None => return Err(format!("invalid code point `\\u{{{code}}}`")),
+                }
+            }
+            Some(p @ ('p' | 'P')) => {
+                let name = self.braced()?;
+                let Some(ranges) = category_ranges(&name) else {
+                    return Err(format!("unknown general category `{name}`"));
+                };
+                let ranges = normalize(ranges);
+                if p == 'P' {
+                    complement(ranges)
+                } else {
+                    ranges
+                }
+            }
+            Some(c) if c.is_ascii_punctuation() => char(c),
+            Some(c) => return Err(format!("unknown escape sequence `\\{c}`")),
+        })
+    }
+
+    /// The text in `{...}` of `\u{...}` and `\p{...}`
+    fn braced(&mut self) -> Result<String, String> {
+        if !self.eat('{') {
+            return Err("expected `{` after the escape".to_owned());
+        }
+        let mut text = String::new();
+        loop {
+            match self.chars.next() {
+                Some('}') => return Ok(text),
+                Some(c) => text.push(c),
+                None => return Err("unclosed `{`".to_owned()),
+            }
+        }
+    }
+}
+
+/// Sorts and merges the ranges
+fn normalize(mut ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    ranges.sort_unstable();
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// All chars that are not in the normalized ranges
+fn complement(ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    let mut gaps = Vec::new();
+    let mut next = 0;
+    for (start, end) in ranges {
+        if start > next {
+            gaps.push((next, start - 1));
+        }
+        next = end + 1;
+    }
+    if next <= LAST_CHAR {
+        gaps.push((next, LAST_CHAR));
+    }
+    gaps
+}
+
+// NFA, built backwards: every node is compiled with the state that follows it
+
+#[derive(Default)]
+struct NfaState {
+    /// States reached without consuming a char
+    epsilon: Vec<usize>,
+    /// Ranges of chars and the state reached by consuming one of them
+    class: Option<(Vec<(u32, u32)>, usize)>,
+}
+
+struct Nfa {
+    states: Vec<NfaState>,
+    start: usize,
+}
+
+impl Nfa {
+    const ACCEPT: usize = 0;
+
+    fn new(node: &Node) -> Self {
+        let mut nfa = Nfa {
+            states: vec![NfaState::default()],
+            start: Self::ACCEPT,
+        };
+        nfa.start = nfa.compile(node, Self::ACCEPT);
+        nfa
+    }
+
+    fn push(&mut self, state: NfaState) -> usize {
+        self.states.push(state);
+        self.states.len() - 1
+    }
+
+    fn epsilon(&mut self, epsilon: Vec<usize>) -> usize {
+        self.push(NfaState {
+            epsilon,
+            class: None,
+        })
+    }
+
+    fn compile(&mut self, node: &Node, next: usize) -> usize {
+        match node {
+            Node::Class(ranges) => self.push(NfaState {
+                epsilon: Vec::new(),
+                class: Some((ranges.clone(), next)),
+            }),
+            Node::Concat(nodes) => nodes
+                .iter()
+                .rev()
+                .fold(next, |next, node| self.compile(node, next)),
+            Node::Alternate(nodes) => {
+                let starts = nodes.iter().map(|node| self.compile(node, next)).collect();
+                self.epsilon(starts)
+            }
+            Node::Repeat { node, min, max } => {
+                let mut start = match max {
+                    None => {
+                        let repeat = self.epsilon(Vec::new());
+                        let body = self.compile(node, repeat);
+                        self.states[repeat].epsilon = vec![body, next];
+                        repeat
+                    }
+                    // Nested options like (a(a)?)?
+                    Some(max) => (*min..*max).fold(next, |start, _| {
+                        let body = self.compile(node, start);
+                        self.epsilon(vec![body, next])
+                    }),
+                };
+                for _ in 0..*min {
+                    start = self.compile(node, start);
+                }
+                start
+            }
+        }
+    }
+
+    /// The sorted states reachable from the states without consuming a char
+    fn closure(&self, states: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let mut seen = vec![false; self.states.len()];
+        let mut stack: Vec<usize> = states.into_iter().collect();
+        let mut closure = Vec::new();
+        while let Some(state) = stack.pop() {
+            if !std::mem::replace(&mut seen[state], true) {
+                closure.push(state);
+                stack.extend(&self.states[state].epsilon);
+            }
+        }
+        closure.sort_unstable();
+        closure
+    }
+}
+
+// DFA over classes of chars
+
+struct Dfa {
+    /// First char of each class, the first class starts with `\0`
+    class_starts: Vec<u32>,
+    ascii_classes: [u32; 128],
+    /// Next state of every state and class, at state * classes + class
+    transitions: Vec<u32>,
+    accepting: Vec<bool>,
+    /// Whether any transition of the state doesn't lead to DEAD
+    alive: Vec<bool>,
+}
+
+impl Dfa {
+    const DEAD: usize = 0;
+    const START: usize = 1;
+
+    fn new(nfa: &Nfa) -> Option<Self> {
+        let mut class_starts = vec![0];
+        for (ranges, _) in nfa.states.iter().filter_map(|state| state.class.as_ref()) {
+            for &(start, end) in ranges {
+                class_starts.push(start);
+                if end < LAST_CHAR {
+                    class_starts.push(end + 1);
+                }
+            }
+        }
+        class_starts.sort_unstable();
+        class_starts.dedup();
+        let classes = class_starts.len();
+        let class_of = |c: u32| class_starts.partition_point(|&start| start <= c) - 1;
+
+        let mut sets: Vec<Vec<usize>> = vec![Vec::new(), nfa.closure([nfa.start])];
+        let mut ids: HashMap<Vec<usize>, usize> = sets
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(id, set)| (set, id))
+            .collect();
+        let mut transitions = vec![Self::DEAD as u32; classes];
+        let mut state = Self::START;
+        while state < sets.len() {
+            let mut targets = vec![Vec::new(); classes];
+            for &nfa_state in &sets[state] {
+                let Some((ranges, next)) = &nfa.states[nfa_state].class else {
+                    continue;
+                };
+                for &(start, end) in ranges {
+                    for target in &mut targets[class_of(start)..=class_of(end)] {
+                        target.push(*next);
+                    }
+                }
+            }
+            let mut previous: Option<(Vec<usize>, u32)> = None;
+            for target in targets {
+                if target.is_empty() {
+                    transitions.push(Self::DEAD as u32);
+                    continue;
+                }
+                // Neighbouring classes often lead to the same states
+                if let Some((_, id)) = previous.as_ref().filter(|(last, _)| *last == target) {
+                    transitions.push(*id);
+                    continue;
+                }
+                let set = nfa.closure(target.iter().copied());
+                let id = *ids.entry(set).or_insert_with_key(|set| {
+                    sets.push(set.clone());
+                    sets.len() - 1
+                });
+                transitions.push(id as u32);
+                previous = Some((target, id as u32));
+            }
+            if sets.len() > MAX_STATES {
+                return None;
+            }
+            state += 1;
+        }
+
+        let accepting = sets.iter().map(|set| set.contains(&Nfa::ACCEPT)).collect();
+        let alive = transitions
+            .chunks(classes)
+            .map(|next| next.iter().any(|&state| state as usize != Self::DEAD))
+            .collect();
+        let mut ascii_classes = [0; 128];
+        for (c, class) in ascii_classes.iter_mut().enumerate() {
+            *class = class_of(c as u32) as u32;
+        }
+        Some(Dfa {
+            class_starts,
+            ascii_classes,
+            transitions,
+            accepting,
+            alive,
+        })
+    }
+
+    fn next(&self, state: usize, c: char) -> usize {
+        let c = c as u32;
+        let class = match self.ascii_classes.get(c as usize) {
+            Some(&class) => class as usize,
+            None => self.class_starts.partition_point(|&start| start <= c) - 1,
+        };
+        self.transitions[state * self.class_starts.len() + class] as usize
+    }
+}
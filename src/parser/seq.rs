@@ -0,0 +1,242 @@
+use super::{Match, Matcher, Parse, Parser};
+use crate::grammar::{Grammar, GrammarNode, Rules};
+use crate::input::Input;
+use crate::result::ParseResult;
+use crate::trace;
+
+// Sequences with flat outputs. Adding parsers nests their outputs to the left,
+// seq() instead turns the tuple of parts into a list of SeqCons, where every
+// part contributes a tuple to the output: parsers (output,) and matchers ().
+// These tuples are prepended to the output of the rest of the list and the
+// final tuple is flattened, so a single output is not wrapped in a tuple.
+
+pub struct Seq<L>(L);
+pub struct SeqCons<H, T>(H, T);
+pub struct SeqEnd;
+pub struct ParsePart<P>(P);
+pub struct MatchPart<M>(M);
+
+/// Sequence of up to 8 parsers and matchers, which outputs a flat tuple of
+/// the parser outputs. Matchers don't add to it, like they don't with `+`:
+/// `seq((digits(2), tag(":"), digits(2), tag(":"), digits(2)))` outputs
+/// `(hour, minute, second)` instead of `((hour, minute), second)`.
+/// If a single parser remains, its output is not wrapped in a tuple.
+pub fn seq<T: IntoSeq>(parts: T) -> Parser<Seq<T::List>> {
+    Parser(Seq(parts.into_seq()))
+}
+
+/// Tuple of parsers and matchers that seq() accepts
+pub trait IntoSeq {
+    type List;
+    fn into_seq(self) -> Self::List;
+}
+
+/// Parser or matcher in a seq()
+pub trait IntoPart {
+    type Part;
+    fn into_part(self) -> Self::Part;
+}
+
+impl<P> IntoPart for Parser<P> {
+    type Part = ParsePart<P>;
+
+    fn into_part(self) -> Self::Part {
+        ParsePart(self.0)
+    }
+}
+
+impl<M> IntoPart for Matcher<M> {
+    type Part = MatchPart<M>;
+
+    fn into_part(self) -> Self::Part {
+        MatchPart(self.0)
+    }
+}
+
+/// Applies a part of a sequence, its output is a tuple of zero or one values
+pub trait SeqPart<I: Input + ?Sized>: Grammar {
+    type Output;
+    fn apply_part<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I>;
+}
+
+impl<I: Input + ?Sized, P: Parse<I>> SeqPart<I> for ParsePart<P> {
+    type Output = (P::Output,);
+
+    fn apply_part<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.0.apply(input).map(|(out, rest)| ((out,), rest))
+    }
+}
+
+impl<I: Input + ?Sized, M: Match<I>> SeqPart<I> for MatchPart<M> {
+    type Output = ();
+
+    fn apply_part<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        self.0.apply(input).map(|rest| ((), rest))
+    }
+}
+
+impl<I: Input + ?Sized, H, T> SeqPart<I> for SeqCons<H, T>
+where
+    H: SeqPart<I>,
+    T: SeqPart<I>,
+    H::Output: Prepend<T::Output>,
+{
+    type Output = <H::Output as Prepend<T::Output>>::Output;
+
+    fn apply_part<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        let (head, rest) = self.0.apply_part(input)?;
+        let (tail, rest) = self.1.apply_part(rest)?;
+        Ok((head.prepend(tail), rest))
+    }
+}
+
+impl<I: Input + ?Sized> SeqPart<I> for SeqEnd {
+    type Output = ();
+
+    fn apply_part<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        Ok(((), input))
+    }
+}
+
+impl<I: Input + ?Sized, L> Parse<I> for Seq<L>
+where
+    L: SeqPart<I>,
+    L::Output: Flatten,
+{
+    type Output = <L::Output as Flatten>::Output;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        trace::parse("seq", input, || {
+            self.0
+                .apply_part(input)
+                .map(|(out, rest)| (out.flatten(), rest))
+        })
+    }
+}
+
+/// Output of the head of a sequence, which is prepended to the tail
+pub trait Prepend<T> {
+    type Output;
+    fn prepend(self, tail: T) -> Self::Output;
+}
+
+impl<T> Prepend<T> for () {
+    type Output = T;
+
+    fn prepend(self, tail: T) -> Self::Output {
+        tail
+    }
+}
+
+/// Unwraps a tuple with a single value
+pub trait Flatten {
+    type Output;
+    fn flatten(self) -> Self::Output;
+}
+
+impl<A> Flatten for (A,) {
+    type Output = A;
+
+    fn flatten(self) -> Self::Output {
+        self.0
+    }
+}
+
+macro_rules! impl_tuples {
+    ($($t:ident)*) => {
+        impl<X, $($t),*> Prepend<($($t,)*)> for (X,) {
+            type Output = (X, $($t),*);
+
+            #[allow(non_snake_case)]
+            fn prepend(self, ($($t,)*): ($($t,)*)) -> Self::Output {
+                (self.0, $($t),*)
+            }
+        }
+
+        impl<$($t: IntoPart),*> IntoSeq for ($($t,)*) {
+            type List = impl_tuples!(@list $($t)*);
+
+            #[allow(non_snake_case)]
+            fn into_seq(self) -> Self::List {
+                let ($($t,)*) = self;
+                impl_tuples!(@value $($t)*)
+            }
+        }
+    };
+    (@list $head:ident $($t:ident)*) => {
+        SeqCons<$head::Part, impl_tuples!(@list $($t)*)>
+    };
+    (@list) => {
+        SeqEnd
+    };
+    (@value $head:ident $($t:ident)*) => {
+        SeqCons($head.into_part(), impl_tuples!(@value $($t)*))
+    };
+    (@value) => {
+        SeqEnd
+    };
+}
+
+macro_rules! impl_flatten {
+    ($($t:ident)*) => {
+        impl<$($t),*> Flatten for ($($t,)*) {
+            type Output = Self;
+
+            fn flatten(self) -> Self::Output {
+                self
+            }
+        }
+    };
+}
+
+impl_tuples!();
+impl_tuples!(A);
+impl_tuples!(A B);
+impl_tuples!(A B C);
+impl_tuples!(A B C D);
+impl_tuples!(A B C D E);
+impl_tuples!(A B C D E F);
+impl_tuples!(A B C D E F G);
+impl_tuples!(A B C D E F G H);
+
+impl_flatten!();
+impl_flatten!(A B);
+impl_flatten!(A B C);
+impl_flatten!(A B C D);
+impl_flatten!(A B C D E);
+impl_flatten!(A B C D E F);
+impl_flatten!(A B C D E F G);
+impl_flatten!(A B C D E F G H);
+
+// Grammar of sequences, the parts are described in order
+
+impl<L: Grammar> Grammar for Seq<L> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        self.0.describe(rules)
+    }
+}
+
+impl<H: Grammar, T: Grammar> Grammar for SeqCons<H, T> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let head = self.0.describe(rules);
+        head.then(self.1.describe(rules))
+    }
+}
+
+impl Grammar for SeqEnd {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        GrammarNode::Sequence(Vec::new())
+    }
+}
+
+impl<P: Grammar> Grammar for ParsePart<P> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        self.0.describe(rules)
+    }
+}
+
+impl<M: Grammar> Grammar for MatchPart<M> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        self.0.describe(rules)
+    }
+}
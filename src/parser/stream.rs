@@ -0,0 +1,229 @@
+use std::collections::VecDeque;
+use std::io::Read;
+use std::sync::Arc;
+
+use super::{partial_parse, Parse, Parser};
+use crate::result::{EzpcError, RawEzpcError};
+use crate::state::{self, Base};
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(std::io::Error),
+    /// The input is not valid utf-8, offset is the byte position of the error
+    InvalidUtf8 {
+        offset: usize,
+    },
+    Parse(EzpcError),
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::InvalidUtf8 { .. } => None,
+            StreamError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl std::fmt::Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "Failed to read input: {err}"),
+            StreamError::InvalidUtf8 { offset } => {
+                write!(f, "Input is not valid utf-8 at byte offset {offset}")
+            }
+            StreamError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// Iterator over the records parsed from a reader, returned by Parser::stream
+pub struct ParseStream<'p, P: Parse, R: Read> {
+    parser: &'p Parser<P>,
+    reader: R,
+    buffer: String,
+    /// Start of the next record in the buffer, everything before it is
+    /// removed with the next read
+    start: usize,
+    /// Position of the next record in the whole input
+    base: Base,
+    /// Trailing bytes of an utf-8 character that was split between two reads
+    incomplete: Vec<u8>,
+    /// Copy of the buffer from the given offset on, shared by the substrings
+    /// of all records until the buffer changes with the next read
+    shared: Option<(Arc<str>, usize)>,
+    eof: bool,
+    done: bool,
+    /// Errors that were recovered from and the record they belong to
+    pending: VecDeque<Result<P::Output, StreamError>>,
+}
+
+impl<P: Parse> Parser<P> {
+    /// Apply the parser repeatedly to the input read from reader and return
+    /// an iterator over the parsed records. This works like parsing the whole
+    /// input with repeat(0..), but input is only read when the parser needs it
+    /// and released after every record.
+    /// Errors that were recovered from are returned before the record they
+    /// occurred in, any other error is returned last.
+    pub fn stream<R: Read>(&self, reader: R) -> ParseStream<'_, P, R> {
+        ParseStream {
+            parser: self,
+            reader,
+            buffer: String::new(),
+            start: 0,
+            base: Base::default(),
+            incomplete: Vec::new(),
+            shared: None,
+            eof: false,
+            done: false,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<P: Parse, R: Read> ParseStream<'_, P, R> {
+    /// Read at least min_len bytes of input into the buffer, or up to the end
+    /// of the input
+    fn fill(&mut self, min_len: usize) -> Result<(), StreamError> {
+        self.buffer.drain(..self.start);
+        self.start = 0;
+        self.shared = None;
+
+        let mut bytes = std::mem::take(&mut self.incomplete);
+        let old_len = bytes.len();
+        bytes.resize(old_len + min_len.max(CHUNK_SIZE), 0);
+        let mut len = old_len;
+        while len < old_len + min_len && !self.eof {
+            let read = loop {
+                match self.reader.read(&mut bytes[len..]) {
+                    Ok(read) => break read,
+                    Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(err) => return Err(StreamError::Io(err)),
+                }
+            };
+            len += read;
+            self.eof = read == 0;
+        }
+        bytes.truncate(len);
+
+        let valid = match std::str::from_utf8(&bytes) {
+            Ok(valid) => valid,
+            // The last character might be incomplete, the rest follows with the next read
+            Err(err) if err.error_len().is_none() && !self.eof => {
+                std::str::from_utf8(&bytes[..err.valid_up_to()]).unwrap()
+            }
+            Err(err) => {
+                return Err(StreamError::InvalidUtf8 {
+                    offset: self.base.offset + self.buffer.len() + err.valid_up_to(),
+                })
+            }
+        };
+        self.buffer.push_str(valid);
+        self.incomplete = bytes[valid.len()..].to_vec();
+        Ok(())
+    }
+
+    /// Move the start of the next record behind the parsed one at end
+    fn release(&mut self, end: usize) {
+        let released = &self.buffer[self.start..end];
+        self.base.offset += released.len();
+        match released.rfind('\n') {
+            Some(newline) => {
+                self.base.lines += released.matches('\n').count();
+                let line = &released[newline + 1..];
+                self.base.column = line.chars().count();
+                self.base.utf16_column = line.encode_utf16().count();
+            }
+            None => {
+                self.base.column += released.chars().count();
+                self.base.utf16_column += released.encode_utf16().count();
+            }
+        }
+        self.start = end;
+    }
+
+    /// Parse the next record from the buffer. Returns None if more input is needed.
+    fn parse_record(&mut self) -> Option<Result<P::Output, StreamError>> {
+        // Only the rest of the buffer is parsed, so that spans and positions
+        // don't have to index the records before it
+        let input = &self.buffer[self.start..];
+        let (base, start) = (self.base, self.start);
+        let shared = &mut self.shared;
+
+        let (result, end_reached, diagnostics) = state::run_chunk(input, self.base, || {
+            if let Some((text, text_start)) = shared {
+                state::share_source(text.clone(), start - *text_start);
+            }
+            let result = match self.parser.0.apply(input) {
+                Ok((out, rest)) if rest.len() < input.len() => Ok((out, rest.len())),
+                // A record that consumes nothing would be returned forever
+                Ok(_) => Err(partial_parse(input.as_ptr())),
+                Err(RawEzpcError::Mismatch { pos }) => Err(partial_parse(pos)),
+                Err(raw) => Err(EzpcError::from_raw_with(raw, state::position)),
+            };
+            let diagnostics: Vec<_> = state::take_diagnostics()
+                .into_iter()
+                .map(|raw| EzpcError::from_raw_with(raw, state::position).shift(base))
+                .collect();
+            *shared = state::shared_source().map(|(text, offset)| (text, start - offset));
+            (result, state::end_reached(), diagnostics)
+        });
+
+        if end_reached && !self.eof {
+            return None;
+        }
+
+        let diagnostics = diagnostics
+            .into_iter()
+            .map(|err| Err(StreamError::Parse(err)));
+        self.pending.extend(diagnostics);
+        match result {
+            Ok((out, rest_len)) => {
+                self.release(self.buffer.len() - rest_len);
+                Some(Ok(out))
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(StreamError::Parse(err.shift(base))))
+            }
+        }
+    }
+}
+
+impl<P: Parse, R: Read> Iterator for ParseStream<'_, P, R> {
+    type Item = Result<P::Output, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.pending.pop_front() {
+            return Some(item);
+        }
+
+        while !self.done {
+            if self.start == self.buffer.len() && self.eof {
+                self.done = true;
+                break;
+            }
+
+            // A record that reaches the end of the buffer is parsed again from
+            // its start, so the buffer is doubled each time to parse a record
+            // spanning many chunks only a logarithmic number of times
+            let mut min_len = 1;
+            if self.start < self.buffer.len() {
+                if let Some(item) = self.parse_record() {
+                    self.pending.push_back(item);
+                    return self.pending.pop_front();
+                }
+                min_len = self.buffer.len() - self.start;
+            }
+
+            if let Err(err) = self.fill(min_len) {
+                self.done = true;
+                return Some(Err(err));
+            }
+        }
+        None
+    }
+}
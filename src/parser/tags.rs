@@ -0,0 +1,359 @@
+use super::matchers::{pop_char, Tag};
+use super::unicode_tables::{CASE_FOLDING, COMBINING_CLASSES, DECOMPOSITIONS, XID_CONTINUE};
+use super::{Match, Matcher, Parse, Parser};
+use crate::grammar::{Grammar, GrammarNode, Rules};
+use crate::result::{Expected, MatchResult, ParseResult, RawEzpcError};
+use crate::state;
+use crate::trace;
+
+// Variants of tag() that ignore case or the Unicode normal form. Both the
+// tag and the input are brought into the normal form with canonical
+// decomposition (NFD), which is the same for composed and decomposed input.
+// Case folding is done in between, so `Å` matches `å` and `A\u{30A}`.
+
+pub struct TagAsciiCi(&'static str);
+
+pub struct TagNormalized {
+    tag: &'static str,
+    /// The tag in NFD, case folded if fold is set
+    normalized: Vec<char>,
+    fold: bool,
+}
+
+pub struct Keyword<M> {
+    matcher: M,
+    expected: Expected,
+}
+
+pub struct OneOfTags<T> {
+    /// Trie over the bytes of the tags, the root is the first node
+    nodes: Vec<TrieNode>,
+    tags: Vec<(&'static str, T)>,
+}
+
+#[derive(Default)]
+struct TrieNode {
+    /// Sorted by the byte
+    children: Vec<(u8, usize)>,
+    /// Index into tags if a tag ends here
+    tag: Option<usize>,
+}
+
+/// Matches the tag ignoring the case of ASCII letters, e.g. `tag_ascii_ci("select")`
+/// matches `SELECT` and `Select`, but `straße` doesn't match `STRASSE`
+pub fn tag_ascii_ci(tag: &'static str) -> Matcher<TagAsciiCi> {
+    Matcher(TagAsciiCi(tag))
+}
+
+/// Matches the tag ignoring case with full Unicode case folding, so `straße`
+/// matches `STRASSE`. Like tag_normalized(), the normal form doesn't matter.
+pub fn tag_ci(tag: &'static str) -> Matcher<TagNormalized> {
+    Matcher(TagNormalized::new(tag, true))
+}
+
+/// Matches text that is canonically equivalent to the tag, so `é` matches
+/// both the composed `\u{E9}` (NFC) and the decomposed `e\u{301}` (NFD)
+pub fn tag_normalized(tag: &'static str) -> Matcher<TagNormalized> {
+    Matcher(TagNormalized::new(tag, false))
+}
+
+/// Matches the tag only if no identifier character (XID_Continue) follows,
+/// so `keyword("true")` doesn't match the start of `trueish`
+pub fn keyword(tag: &'static str) -> Matcher<Keyword<Tag>> {
+    Matcher(Keyword {
+        matcher: Tag(tag),
+        expected: Expected::Tag(tag),
+    })
+}
+
+/// Case insensitive keyword(), with the case folding of tag_ci()
+pub fn keyword_ci(tag: &'static str) -> Matcher<Keyword<TagNormalized>> {
+    Matcher(Keyword {
+        matcher: TagNormalized::new(tag, true),
+        expected: Expected::TagCi(tag),
+    })
+}
+
+/// Matches the longest of the tags and outputs its value. Replaces long
+/// chains like `tag("n").val('\n') | tag("t").val('\t') | ...`, which try
+/// the tags one by one, with a single lookup in a trie:
+/// `one_of_tags(&[("n", '\n'), ("t", '\t'), ("\\", '\\')])`.
+/// Panics if a tag is given twice.
+pub fn one_of_tags<T: Clone>(tags: &[(&'static str, T)]) -> Parser<OneOfTags<T>> {
+    let mut nodes = vec![TrieNode::default()];
+    for (index, (tag, _)) in tags.iter().enumerate() {
+        let mut node = 0;
+        for &byte in tag.as_bytes() {
+            let children = &nodes[node].children;
+            node = match children.binary_search_by_key(&byte, |&(byte, _)| byte) {
+                Ok(child) => children[child].1,
+                Err(child) => {
+                    let new = nodes.len();
+                    nodes[node].children.insert(child, (byte, new));
+                    nodes.push(TrieNode::default());
+                    new
+                }
+            };
+        }
+        assert!(
+            nodes[node].tag.replace(index).is_none(),
+            "tag `{tag}` is given twice"
+        );
+    }
+    Parser(OneOfTags {
+        nodes,
+        tags: tags.to_vec(),
+    })
+}
+
+impl TagNormalized {
+    fn new(tag: &'static str, fold: bool) -> Self {
+        let mut normalized = Vec::with_capacity(tag.len());
+        for c in tag.chars() {
+            push_normalized(c, fold, &mut normalized);
+        }
+        canonical_order(&mut normalized);
+        TagNormalized {
+            tag,
+            normalized,
+            fold,
+        }
+    }
+
+    fn expected(&self) -> Expected {
+        if self.fold {
+            Expected::TagCi(self.tag)
+        } else {
+            Expected::Tag(self.tag)
+        }
+    }
+}
+
+impl Match for TagAsciiCi {
+    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+        let len = self.0.len();
+        match input.as_bytes().get(..len) {
+            // Non-ASCII bytes are equal, so len is at a char boundary
+            Some(prefix) if prefix.eq_ignore_ascii_case(self.0.as_bytes()) => Ok(&input[len..]),
+            prefix => {
+                if prefix.is_none()
+                    && self.0.as_bytes()[..input.len()].eq_ignore_ascii_case(input.as_bytes())
+                {
+                    state::hit_end();
+                }
+                state::expected(input.as_ptr(), Expected::TagCi(self.0));
+                Err(RawEzpcError::Mismatch {
+                    pos: input.as_ptr(),
+                })
+            }
+        }
+    }
+}
+
+impl Match for TagNormalized {
+    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+        // The input is compared segment by segment: a starter with the
+        // non-starters that follow it, which are reordered within it
+        let mut matched = 0;
+        let mut rest = input;
+        let mut segment = Vec::new();
+        while matched < self.normalized.len() {
+            let Some((c, mut next)) = pop_char(rest) else {
+                state::hit_end();
+                break;
+            };
+            segment.clear();
+            push_normalized(c, self.fold, &mut segment);
+            while let Some((c, after)) = pop_char(next) {
+                let len = segment.len();
+                push_normalized(c, self.fold, &mut segment);
+                if combining_class(segment[len]) == 0 {
+                    segment.truncate(len);
+                    break;
+                }
+                next = after;
+            }
+            canonical_order(&mut segment);
+            let expected = self.normalized[matched..].get(..segment.len());
+            if expected != Some(&segment[..]) {
+                break;
+            }
+            matched += segment.len();
+            rest = next;
+        }
+        if matched == self.normalized.len() {
+            return Ok(rest);
+        }
+        state::expected(input.as_ptr(), self.expected());
+        Err(RawEzpcError::Mismatch {
+            pos: input.as_ptr(),
+        })
+    }
+}
+
+impl<M: Match> Match for Keyword<M> {
+    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
+        let rest = self.matcher.apply(input)?;
+        match pop_char(rest) {
+            Some((c, _)) if is_word_char(c) => {
+                state::expected(input.as_ptr(), self.expected);
+                Err(RawEzpcError::Mismatch {
+                    pos: input.as_ptr(),
+                })
+            }
+            Some(_) => Ok(rest),
+            None => {
+                // The word might continue in streamed input
+                state::hit_end();
+                Ok(rest)
+            }
+        }
+    }
+}
+
+impl<T: Clone> Parse for OneOfTags<T> {
+    type Output = T;
+
+    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output, str> {
+        trace::parse("one_of_tags", input, || {
+            let mut node = &self.nodes[0];
+            let mut longest = node.tag.map(|tag| (tag, 0));
+            let mut end_of_input = true;
+            for (len, byte) in input.as_bytes().iter().enumerate() {
+                let Ok(child) = node.children.binary_search_by_key(byte, |&(byte, _)| byte) else {
+                    end_of_input = false;
+                    break;
+                };
+                node = &self.nodes[node.children[child].1];
+                if let Some(tag) = node.tag {
+                    longest = Some((tag, len + 1));
+                }
+            }
+            // A longer tag might match if the input is streamed
+            if end_of_input && !node.children.is_empty() {
+                state::hit_end();
+            }
+            // Tags are valid UTF-8, so they end at a char boundary
+            if let Some((tag, len)) = longest {
+                return Ok((self.tags[tag].1.clone(), &input[len..]));
+            }
+            for (tag, _) in &self.tags {
+                state::expected(input.as_ptr(), Expected::Tag(tag));
+            }
+            Err(RawEzpcError::Mismatch {
+                pos: input.as_ptr(),
+            })
+        })
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_alphanumeric() || c == '_';
+    }
+    let c = c as u32;
+    let index = XID_CONTINUE.partition_point(|&(_, end)| end < c);
+    XID_CONTINUE
+        .get(index)
+        .is_some_and(|&(start, _)| start <= c)
+}
+
+// Normalization of single characters, see chapter 3.11 and 3.13 of the
+// Unicode Standard
+
+const HANGUL_FIRST: u32 = 0xAC00;
+const HANGUL_LAST: u32 = 0xD7A3;
+
+/// Pushes the canonical decomposition of the char, case folded if fold is set
+fn push_normalized(c: char, fold: bool, out: &mut Vec<char>) {
+    if c.is_ascii() {
+        out.push(if fold { c.to_ascii_lowercase() } else { c });
+        return;
+    }
+    if !fold {
+        decompose(c, out);
+        return;
+    }
+    let mut decomposed = Vec::new();
+    decompose(c, &mut decomposed);
+    for c in decomposed {
+        match lookup(CASE_FOLDING, c) {
+            Some(folded) => folded.chars().for_each(|c| decompose(c, out)),
+            None => out.push(c),
+        }
+    }
+}
+
+fn decompose(c: char, out: &mut Vec<char>) {
+    let code = c as u32;
+    if (HANGUL_FIRST..=HANGUL_LAST).contains(&code) {
+        let index = code - HANGUL_FIRST;
+        let jamo = |code| char::from_u32(code).unwrap();
+        out.push(jamo(0x1100 + index / 588));
+        out.push(jamo(0x1161 + index % 588 / 28));
+        if !index.is_multiple_of(28) {
+            out.push(jamo(0x11A7 + index % 28));
+        }
+        return;
+    }
+    match lookup(DECOMPOSITIONS, c) {
+        Some(decomposed) => out.extend(decomposed.chars()),
+        None => out.push(c),
+    }
+}
+
+fn lookup(table: &[(u32, &'static str)], c: char) -> Option<&'static str> {
+    let index = table.binary_search_by_key(&(c as u32), |&(c, _)| c).ok()?;
+    Some(table[index].1)
+}
+
+fn combining_class(c: char) -> u8 {
+    let c = c as u32;
+    let index = COMBINING_CLASSES.partition_point(|&(_, end, _)| end < c);
+    match COMBINING_CLASSES.get(index) {
+        Some(&(start, _, class)) if start <= c => class,
+        _ => 0,
+    }
+}
+
+/// Sorts runs of non-starters by their combining class, keeping the order
+/// of equal classes
+fn canonical_order(chars: &mut [char]) {
+    for run in chars.split_mut(|&c| combining_class(c) == 0) {
+        run.sort_by_key(|&c| combining_class(c));
+    }
+}
+
+// Grammar of the tags: EBNF has no case insensitive terminals
+
+impl Grammar for TagAsciiCi {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        GrammarNode::Special(format!("{:?} in any case", self.0))
+    }
+}
+
+impl Grammar for TagNormalized {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        if self.fold {
+            GrammarNode::Special(format!("{:?} in any case", self.tag))
+        } else {
+            GrammarNode::Terminal(self.tag.to_owned())
+        }
+    }
+}
+
+impl<T> Grammar for OneOfTags<T> {
+    fn describe(&self, _: &mut Rules) -> GrammarNode {
+        let tags = self.tags.iter();
+        GrammarNode::Choice(
+            tags.map(|(tag, _)| GrammarNode::Terminal(tag.to_string()))
+                .collect(),
+        )
+    }
+}
+
+impl<M: Grammar> Grammar for Keyword<M> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        self.matcher.describe(rules)
+    }
+}
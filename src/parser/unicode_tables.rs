@@ -0,0 +1,2275 @@
+// Unicode 17.0 character tables, generated from the Unicode Character Database
+// by scripts/unicode_tables.py. All ranges are inclusive and sorted.
+
+/// Two letter names of the general categories, indexed by CATEGORIES. Cn
+/// (unassigned) is not listed, it is everything not in CATEGORIES.
+pub(super) static CATEGORY_NAMES: [&str; 29] = [
+    "Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps", "Pe", "Pi",
+    "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs", "Co",
+];
+
+/// General category of all assigned code points: (first, last, category)
+#[rustfmt::skip]
+pub(super) static CATEGORIES: &[(u32, u32, u8)] = &[
+    (0x0, 0x1F, 25), (0x20, 0x20, 22), (0x21, 0x23, 17), (0x24, 0x24, 19), (0x25, 0x27, 17),
+    (0x28, 0x28, 13), (0x29, 0x29, 14), (0x2A, 0x2A, 17), (0x2B, 0x2B, 18), (0x2C, 0x2C, 17),
+    (0x2D, 0x2D, 12), (0x2E, 0x2F, 17), (0x30, 0x39, 8), (0x3A, 0x3B, 17), (0x3C, 0x3E, 18),
+    (0x3F, 0x40, 17), (0x41, 0x5A, 0), (0x5B, 0x5B, 13), (0x5C, 0x5C, 17), (0x5D, 0x5D, 14),
+    (0x5E, 0x5E, 20), (0x5F, 0x5F, 11), (0x60, 0x60, 20), (0x61, 0x7A, 1), (0x7B, 0x7B, 13),
+    (0x7C, 0x7C, 18), (0x7D, 0x7D, 14), (0x7E, 0x7E, 18), (0x7F, 0x9F, 25), (0xA0, 0xA0, 22),
+    (0xA1, 0xA1, 17), (0xA2, 0xA5, 19), (0xA6, 0xA6, 21), (0xA7, 0xA7, 17), (0xA8, 0xA8, 20),
+    (0xA9, 0xA9, 21), (0xAA, 0xAA, 4), (0xAB, 0xAB, 15), (0xAC, 0xAC, 18), (0xAD, 0xAD, 26),
+    (0xAE, 0xAE, 21), (0xAF, 0xAF, 20), (0xB0, 0xB0, 21), (0xB1, 0xB1, 18), (0xB2, 0xB3, 10),
+    (0xB4, 0xB4, 20), (0xB5, 0xB5, 1), (0xB6, 0xB7, 17), (0xB8, 0xB8, 20), (0xB9, 0xB9, 10),
+    (0xBA, 0xBA, 4), (0xBB, 0xBB, 16), (0xBC, 0xBE, 10), (0xBF, 0xBF, 17), (0xC0, 0xD6, 0),
+    (0xD7, 0xD7, 18), (0xD8, 0xDE, 0), (0xDF, 0xF6, 1), (0xF7, 0xF7, 18), (0xF8, 0xFF, 1),
+    (0x100, 0x100, 0), (0x101, 0x101, 1), (0x102, 0x102, 0), (0x103, 0x103, 1), (0x104, 0x104, 0),
+    (0x105, 0x105, 1), (0x106, 0x106, 0), (0x107, 0x107, 1), (0x108, 0x108, 0), (0x109, 0x109, 1),
+    (0x10A, 0x10A, 0), (0x10B, 0x10B, 1), (0x10C, 0x10C, 0), (0x10D, 0x10D, 1), (0x10E, 0x10E, 0),
+    (0x10F, 0x10F, 1), (0x110, 0x110, 0), (0x111, 0x111, 1), (0x112, 0x112, 0), (0x113, 0x113, 1),
+    (0x114, 0x114, 0), (0x115, 0x115, 1), (0x116, 0x116, 0), (0x117, 0x117, 1), (0x118, 0x118, 0),
+    (0x119, 0x119, 1), (0x11A, 0x11A, 0), (0x11B, 0x11B, 1), (0x11C, 0x11C, 0), (0x11D, 0x11D, 1),
+    (0x11E, 0x11E, 0), (0x11F, 0x11F, 1), (0x120, 0x120, 0), (0x121, 0x121, 1), (0x122, 0x122, 0),
+    (0x123, 0x123, 1), (0x124, 0x124, 0), (0x125, 0x125, 1), (0x126, 0x126, 0), (0x127, 0x127, 1),
+    (0x128, 0x128, 0), (0x129, 0x129, 1), (0x12A, 0x12A, 0), (0x12B, 0x12B, 1), (0x12C, 0x12C, 0),
+    (0x12D, 0x12D, 1), (0x12E, 0x12E, 0), (0x12F, 0x12F, 1), (0x130, 0x130, 0), (0x131, 0x131, 1),
+    (0x132, 0x132, 0), (0x133, 0x133, 1), (0x134, 0x134, 0), (0x135, 0x135, 1), (0x136, 0x136, 0),
+    (0x137, 0x138, 1), (0x139, 0x139, 0), (0x13A, 0x13A, 1), (0x13B, 0x13B, 0), (0x13C, 0x13C, 1),
+    (0x13D, 0x13D, 0), (0x13E, 0x13E, 1), (0x13F, 0x13F, 0), (0x140, 0x140, 1), (0x141, 0x141, 0),
+    (0x142, 0x142, 1), (0x143, 0x143, 0), (0x144, 0x144, 1), (0x145, 0x145, 0), (0x146, 0x146, 1),
+    (0x147, 0x147, 0), (0x148, 0x149, 1), (0x14A, 0x14A, 0), (0x14B, 0x14B, 1), (0x14C, 0x14C, 0),
+    (0x14D, 0x14D, 1), (0x14E, 0x14E, 0), (0x14F, 0x14F, 1), (0x150, 0x150, 0), (0x151, 0x151, 1),
+    (0x152, 0x152, 0), (0x153, 0x153, 1), (0x154, 0x154, 0), (0x155, 0x155, 1), (0x156, 0x156, 0),
+    (0x157, 0x157, 1), (0x158, 0x158, 0), (0x159, 0x159, 1), (0x15A, 0x15A, 0), (0x15B, 0x15B, 1),
+    (0x15C, 0x15C, 0), (0x15D, 0x15D, 1), (0x15E, 0x15E, 0), (0x15F, 0x15F, 1), (0x160, 0x160, 0),
+    (0x161, 0x161, 1), (0x162, 0x162, 0), (0x163, 0x163, 1), (0x164, 0x164, 0), (0x165, 0x165, 1),
+    (0x166, 0x166, 0), (0x167, 0x167, 1), (0x168, 0x168, 0), (0x169, 0x169, 1), (0x16A, 0x16A, 0),
+    (0x16B, 0x16B, 1), (0x16C, 0x16C, 0), (0x16D, 0x16D, 1), (0x16E, 0x16E, 0), (0x16F, 0x16F, 1),
+    (0x170, 0x170, 0), (0x171, 0x171, 1), (0x172, 0x172, 0), (0x173, 0x173, 1), (0x174, 0x174, 0),
+    (0x175, 0x175, 1), (0x176, 0x176, 0), (0x177, 0x177, 1), (0x178, 0x179, 0), (0x17A, 0x17A, 1),
+    (0x17B, 0x17B, 0), (0x17C, 0x17C, 1), (0x17D, 0x17D, 0), (0x17E, 0x180, 1), (0x181, 0x182, 0),
+    (0x183, 0x183, 1), (0x184, 0x184, 0), (0x185, 0x185, 1), (0x186, 0x187, 0), (0x188, 0x188, 1),
+    (0x189, 0x18B, 0), (0x18C, 0x18D, 1), (0x18E, 0x191, 0), (0x192, 0x192, 1), (0x193, 0x194, 0),
+    (0x195, 0x195, 1), (0x196, 0x198, 0), (0x199, 0x19B, 1), (0x19C, 0x19D, 0), (0x19E, 0x19E, 1),
+    (0x19F, 0x1A0, 0), (0x1A1, 0x1A1, 1), (0x1A2, 0x1A2, 0), (0x1A3, 0x1A3, 1), (0x1A4, 0x1A4, 0),
+    (0x1A5, 0x1A5, 1), (0x1A6, 0x1A7, 0), (0x1A8, 0x1A8, 1), (0x1A9, 0x1A9, 0), (0x1AA, 0x1AB, 1),
+    (0x1AC, 0x1AC, 0), (0x1AD, 0x1AD, 1), (0x1AE, 0x1AF, 0), (0x1B0, 0x1B0, 1), (0x1B1, 0x1B3, 0),
+    (0x1B4, 0x1B4, 1), (0x1B5, 0x1B5, 0), (0x1B6, 0x1B6, 1), (0x1B7, 0x1B8, 0), (0x1B9, 0x1BA, 1),
+    (0x1BB, 0x1BB, 4), (0x1BC, 0x1BC, 0), (0x1BD, 0x1BF, 1), (0x1C0, 0x1C3, 4), (0x1C4, 0x1C4, 0),
+    (0x1C5, 0x1C5, 2), (0x1C6, 0x1C6, 1), (0x1C7, 0x1C7, 0), (0x1C8, 0x1C8, 2), (0x1C9, 0x1C9, 1),
+    (0x1CA, 0x1CA, 0), (0x1CB, 0x1CB, 2), (0x1CC, 0x1CC, 1), (0x1CD, 0x1CD, 0), (0x1CE, 0x1CE, 1),
+    (0x1CF, 0x1CF, 0), (0x1D0, 0x1D0, 1), (0x1D1, 0x1D1, 0), (0x1D2, 0x1D2, 1), (0x1D3, 0x1D3, 0),
+    (0x1D4, 0x1D4, 1), (0x1D5, 0x1D5, 0), (0x1D6, 0x1D6, 1), (0x1D7, 0x1D7, 0), (0x1D8, 0x1D8, 1),
+    (0x1D9, 0x1D9, 0), (0x1DA, 0x1DA, 1), (0x1DB, 0x1DB, 0), (0x1DC, 0x1DD, 1), (0x1DE, 0x1DE, 0),
+    (0x1DF, 0x1DF, 1), (0x1E0, 0x1E0, 0), (0x1E1, 0x1E1, 1), (0x1E2, 0x1E2, 0), (0x1E3, 0x1E3, 1),
+    (0x1E4, 0x1E4, 0), (0x1E5, 0x1E5, 1), (0x1E6, 0x1E6, 0), (0x1E7, 0x1E7, 1), (0x1E8, 0x1E8, 0),
+    (0x1E9, 0x1E9, 1), (0x1EA, 0x1EA, 0), (0x1EB, 0x1EB, 1), (0x1EC, 0x1EC, 0), (0x1ED, 0x1ED, 1),
+    (0x1EE, 0x1EE, 0), (0x1EF, 0x1F0, 1), (0x1F1, 0x1F1, 0), (0x1F2, 0x1F2, 2), (0x1F3, 0x1F3, 1),
+    (0x1F4, 0x1F4, 0), (0x1F5, 0x1F5, 1), (0x1F6, 0x1F8, 0), (0x1F9, 0x1F9, 1), (0x1FA, 0x1FA, 0),
+    (0x1FB, 0x1FB, 1), (0x1FC, 0x1FC, 0), (0x1FD, 0x1FD, 1), (0x1FE, 0x1FE, 0), (0x1FF, 0x1FF, 1),
+    (0x200, 0x200, 0), (0x201, 0x201, 1), (0x202, 0x202, 0), (0x203, 0x203, 1), (0x204, 0x204, 0),
+    (0x205, 0x205, 1), (0x206, 0x206, 0), (0x207, 0x207, 1), (0x208, 0x208, 0), (0x209, 0x209, 1),
+    (0x20A, 0x20A, 0), (0x20B, 0x20B, 1), (0x20C, 0x20C, 0), (0x20D, 0x20D, 1), (0x20E, 0x20E, 0),
+    (0x20F, 0x20F, 1), (0x210, 0x210, 0), (0x211, 0x211, 1), (0x212, 0x212, 0), (0x213, 0x213, 1),
+    (0x214, 0x214, 0), (0x215, 0x215, 1), (0x216, 0x216, 0), (0x217, 0x217, 1), (0x218, 0x218, 0),
+    (0x219, 0x219, 1), (0x21A, 0x21A, 0), (0x21B, 0x21B, 1), (0x21C, 0x21C, 0), (0x21D, 0x21D, 1),
+    (0x21E, 0x21E, 0), (0x21F, 0x21F, 1), (0x220, 0x220, 0), (0x221, 0x221, 1), (0x222, 0x222, 0),
+    (0x223, 0x223, 1), (0x224, 0x224, 0), (0x225, 0x225, 1), (0x226, 0x226, 0), (0x227, 0x227, 1),
+    (0x228, 0x228, 0), (0x229, 0x229, 1), (0x22A, 0x22A, 0), (0x22B, 0x22B, 1), (0x22C, 0x22C, 0),
+    (0x22D, 0x22D, 1), (0x22E, 0x22E, 0), (0x22F, 0x22F, 1), (0x230, 0x230, 0), (0x231, 0x231, 1),
+    (0x232, 0x232, 0), (0x233, 0x239, 1), (0x23A, 0x23B, 0), (0x23C, 0x23C, 1), (0x23D, 0x23E, 0),
+    (0x23F, 0x240, 1), (0x241, 0x241, 0), (0x242, 0x242, 1), (0x243, 0x246, 0), (0x247, 0x247, 1),
+    (0x248, 0x248, 0), (0x249, 0x249, 1), (0x24A, 0x24A, 0), (0x24B, 0x24B, 1), (0x24C, 0x24C, 0),
+    (0x24D, 0x24D, 1), (0x24E, 0x24E, 0), (0x24F, 0x293, 1), (0x294, 0x295, 4), (0x296, 0x2AF, 1),
+    (0x2B0, 0x2C1, 3), (0x2C2, 0x2C5, 20), (0x2C6, 0x2D1, 3), (0x2D2, 0x2DF, 20), (0x2E0, 0x2E4, 3),
+    (0x2E5, 0x2EB, 20), (0x2EC, 0x2EC, 3), (0x2ED, 0x2ED, 20), (0x2EE, 0x2EE, 3),
+    (0x2EF, 0x2FF, 20), (0x300, 0x36F, 5), (0x370, 0x370, 0), (0x371, 0x371, 1), (0x372, 0x372, 0),
+    (0x373, 0x373, 1), (0x374, 0x374, 3), (0x375, 0x375, 20), (0x376, 0x376, 0), (0x377, 0x377, 1),
+    (0x37A, 0x37A, 3), (0x37B, 0x37D, 1), (0x37E, 0x37E, 17), (0x37F, 0x37F, 0), (0x384, 0x385, 20),
+    (0x386, 0x386, 0), (0x387, 0x387, 17), (0x388, 0x38A, 0), (0x38C, 0x38C, 0), (0x38E, 0x38F, 0),
+    (0x390, 0x390, 1), (0x391, 0x3A1, 0), (0x3A3, 0x3AB, 0), (0x3AC, 0x3CE, 1), (0x3CF, 0x3CF, 0),
+    (0x3D0, 0x3D1, 1), (0x3D2, 0x3D4, 0), (0x3D5, 0x3D7, 1), (0x3D8, 0x3D8, 0), (0x3D9, 0x3D9, 1),
+    (0x3DA, 0x3DA, 0), (0x3DB, 0x3DB, 1), (0x3DC, 0x3DC, 0), (0x3DD, 0x3DD, 1), (0x3DE, 0x3DE, 0),
+    (0x3DF, 0x3DF, 1), (0x3E0, 0x3E0, 0), (0x3E1, 0x3E1, 1), (0x3E2, 0x3E2, 0), (0x3E3, 0x3E3, 1),
+    (0x3E4, 0x3E4, 0), (0x3E5, 0x3E5, 1), (0x3E6, 0x3E6, 0), (0x3E7, 0x3E7, 1), (0x3E8, 0x3E8, 0),
+    (0x3E9, 0x3E9, 1), (0x3EA, 0x3EA, 0), (0x3EB, 0x3EB, 1), (0x3EC, 0x3EC, 0), (0x3ED, 0x3ED, 1),
+    (0x3EE, 0x3EE, 0), (0x3EF, 0x3F3, 1), (0x3F4, 0x3F4, 0), (0x3F5, 0x3F5, 1), (0x3F6, 0x3F6, 18),
+    (0x3F7, 0x3F7, 0), (0x3F8, 0x3F8, 1), (0x3F9, 0x3FA, 0), (0x3FB, 0x3FC, 1), (0x3FD, 0x42F, 0),
+    (0x430, 0x45F, 1), (0x460, 0x460, 0), (0x461, 0x461, 1), (0x462, 0x462, 0), (0x463, 0x463, 1),
+    (0x464, 0x464, 0), (0x465, 0x465, 1), (0x466, 0x466, 0), (0x467, 0x467, 1), (0x468, 0x468, 0),
+    (0x469, 0x469, 1), (0x46A, 0x46A, 0), (0x46B, 0x46B, 1), (0x46C, 0x46C, 0), (0x46D, 0x46D, 1),
+    (0x46E, 0x46E, 0), (0x46F, 0x46F, 1), (0x470, 0x470, 0), (0x471, 0x471, 1), (0x472, 0x472, 0),
+    (0x473, 0x473, 1), (0x474, 0x474, 0), (0x475, 0x475, 1), (0x476, 0x476, 0), (0x477, 0x477, 1),
+    (0x478, 0x478, 0), (0x479, 0x479, 1), (0x47A, 0x47A, 0), (0x47B, 0x47B, 1), (0x47C, 0x47C, 0),
+    (0x47D, 0x47D, 1), (0x47E, 0x47E, 0), (0x47F, 0x47F, 1), (0x480, 0x480, 0), (0x481, 0x481, 1),
+    (0x482, 0x482, 21), (0x483, 0x487, 5), (0x488, 0x489, 7), (0x48A, 0x48A, 0), (0x48B, 0x48B, 1),
+    (0x48C, 0x48C, 0), (0x48D, 0x48D, 1), (0x48E, 0x48E, 0), (0x48F, 0x48F, 1), (0x490, 0x490, 0),
+    (0x491, 0x491, 1), (0x492, 0x492, 0), (0x493, 0x493, 1), (0x494, 0x494, 0), (0x495, 0x495, 1),
+    (0x496, 0x496, 0), (0x497, 0x497, 1), (0x498, 0x498, 0), (0x499, 0x499, 1), (0x49A, 0x49A, 0),
+    (0x49B, 0x49B, 1), (0x49C, 0x49C, 0), (0x49D, 0x49D, 1), (0x49E, 0x49E, 0), (0x49F, 0x49F, 1),
+    (0x4A0, 0x4A0, 0), (0x4A1, 0x4A1, 1), (0x4A2, 0x4A2, 0), (0x4A3, 0x4A3, 1), (0x4A4, 0x4A4, 0),
+    (0x4A5, 0x4A5, 1), (0x4A6, 0x4A6, 0), (0x4A7, 0x4A7, 1), (0x4A8, 0x4A8, 0), (0x4A9, 0x4A9, 1),
+    (0x4AA, 0x4AA, 0), (0x4AB, 0x4AB, 1), (0x4AC, 0x4AC, 0), (0x4AD, 0x4AD, 1), (0x4AE, 0x4AE, 0),
+    (0x4AF, 0x4AF, 1), (0x4B0, 0x4B0, 0), (0x4B1, 0x4B1, 1), (0x4B2, 0x4B2, 0), (0x4B3, 0x4B3, 1),
+    (0x4B4, 0x4B4, 0), (0x4B5, 0x4B5, 1), (0x4B6, 0x4B6, 0), (0x4B7, 0x4B7, 1), (0x4B8, 0x4B8, 0),
+    (0x4B9, 0x4B9, 1), (0x4BA, 0x4BA, 0), (0x4BB, 0x4BB, 1), (0x4BC, 0x4BC, 0), (0x4BD, 0x4BD, 1),
+    (0x4BE, 0x4BE, 0), (0x4BF, 0x4BF, 1), (0x4C0, 0x4C1, 0), (0x4C2, 0x4C2, 1), (0x4C3, 0x4C3, 0),
+    (0x4C4, 0x4C4, 1), (0x4C5, 0x4C5, 0), (0x4C6, 0x4C6, 1), (0x4C7, 0x4C7, 0), (0x4C8, 0x4C8, 1),
+    (0x4C9, 0x4C9, 0), (0x4CA, 0x4CA, 1), (0x4CB, 0x4CB, 0), (0x4CC, 0x4CC, 1), (0x4CD, 0x4CD, 0),
+    (0x4CE, 0x4CF, 1), (0x4D0, 0x4D0, 0), (0x4D1, 0x4D1, 1), (0x4D2, 0x4D2, 0), (0x4D3, 0x4D3, 1),
+    (0x4D4, 0x4D4, 0), (0x4D5, 0x4D5, 1), (0x4D6, 0x4D6, 0), (0x4D7, 0x4D7, 1), (0x4D8, 0x4D8, 0),
+    (0x4D9, 0x4D9, 1), (0x4DA, 0x4DA, 0), (0x4DB, 0x4DB, 1), (0x4DC, 0x4DC, 0), (0x4DD, 0x4DD, 1),
+    (0x4DE, 0x4DE, 0), (0x4DF, 0x4DF, 1), (0x4E0, 0x4E0, 0), (0x4E1, 0x4E1, 1), (0x4E2, 0x4E2, 0),
+    (0x4E3, 0x4E3, 1), (0x4E4, 0x4E4, 0), (0x4E5, 0x4E5, 1), (0x4E6, 0x4E6, 0), (0x4E7, 0x4E7, 1),
+    (0x4E8, 0x4E8, 0), (0x4E9, 0x4E9, 1), (0x4EA, 0x4EA, 0), (0x4EB, 0x4EB, 1), (0x4EC, 0x4EC, 0),
+    (0x4ED, 0x4ED, 1), (0x4EE, 0x4EE, 0), (0x4EF, 0x4EF, 1), (0x4F0, 0x4F0, 0), (0x4F1, 0x4F1, 1),
+    (0x4F2, 0x4F2, 0), (0x4F3, 0x4F3, 1), (0x4F4, 0x4F4, 0), (0x4F5, 0x4F5, 1), (0x4F6, 0x4F6, 0),
+    (0x4F7, 0x4F7, 1), (0x4F8, 0x4F8, 0), (0x4F9, 0x4F9, 1), (0x4FA, 0x4FA, 0), (0x4FB, 0x4FB, 1),
+    (0x4FC, 0x4FC, 0), (0x4FD, 0x4FD, 1), (0x4FE, 0x4FE, 0), (0x4FF, 0x4FF, 1), (0x500, 0x500, 0),
+    (0x501, 0x501, 1), (0x502, 0x502, 0), (0x503, 0x503, 1), (0x504, 0x504, 0), (0x505, 0x505, 1),
+    (0x506, 0x506, 0), (0x507, 0x507, 1), (0x508, 0x508, 0), (0x509, 0x509, 1), (0x50A, 0x50A, 0),
+    (0x50B, 0x50B, 1), (0x50C, 0x50C, 0), (0x50D, 0x50D, 1), (0x50E, 0x50E, 0), (0x50F, 0x50F, 1),
+    (0x510, 0x510, 0), (0x511, 0x511, 1), (0x512, 0x512, 0), (0x513, 0x513, 1), (0x514, 0x514, 0),
+    (0x515, 0x515, 1), (0x516, 0x516, 0), (0x517, 0x517, 1), (0x518, 0x518, 0), (0x519, 0x519, 1),
+    (0x51A, 0x51A, 0), (0x51B, 0x51B, 1), (0x51C, 0x51C, 0), (0x51D, 0x51D, 1), (0x51E, 0x51E, 0),
+    (0x51F, 0x51F, 1), (0x520, 0x520, 0), (0x521, 0x521, 1), (0x522, 0x522, 0), (0x523, 0x523, 1),
+    (0x524, 0x524, 0), (0x525, 0x525, 1), (0x526, 0x526, 0), (0x527, 0x527, 1), (0x528, 0x528, 0),
+    (0x529, 0x529, 1), (0x52A, 0x52A, 0), (0x52B, 0x52B, 1), (0x52C, 0x52C, 0), (0x52D, 0x52D, 1),
+    (0x52E, 0x52E, 0), (0x52F, 0x52F, 1), (0x531, 0x556, 0), (0x559, 0x559, 3), (0x55A, 0x55F, 17),
+    (0x560, 0x588, 1), (0x589, 0x589, 17), (0x58A, 0x58A, 12), (0x58D, 0x58E, 21),
+    (0x58F, 0x58F, 19), (0x591, 0x5BD, 5), (0x5BE, 0x5BE, 12), (0x5BF, 0x5BF, 5),
+    (0x5C0, 0x5C0, 17), (0x5C1, 0x5C2, 5), (0x5C3, 0x5C3, 17), (0x5C4, 0x5C5, 5),
+    (0x5C6, 0x5C6, 17), (0x5C7, 0x5C7, 5), (0x5D0, 0x5EA, 4), (0x5EF, 0x5F2, 4), (0x5F3, 0x5F4, 17),
+    (0x600, 0x605, 26), (0x606, 0x608, 18), (0x609, 0x60A, 17), (0x60B, 0x60B, 19),
+    (0x60C, 0x60D, 17), (0x60E, 0x60F, 21), (0x610, 0x61A, 5), (0x61B, 0x61B, 17),
+    (0x61C, 0x61C, 26), (0x61D, 0x61F, 17), (0x620, 0x63F, 4), (0x640, 0x640, 3), (0x641, 0x64A, 4),
+    (0x64B, 0x65F, 5), (0x660, 0x669, 8), (0x66A, 0x66D, 17), (0x66E, 0x66F, 4), (0x670, 0x670, 5),
+    (0x671, 0x6D3, 4), (0x6D4, 0x6D4, 17), (0x6D5, 0x6D5, 4), (0x6D6, 0x6DC, 5), (0x6DD, 0x6DD, 26),
+    (0x6DE, 0x6DE, 21), (0x6DF, 0x6E4, 5), (0x6E5, 0x6E6, 3), (0x6E7, 0x6E8, 5), (0x6E9, 0x6E9, 21),
+    (0x6EA, 0x6ED, 5), (0x6EE, 0x6EF, 4), (0x6F0, 0x6F9, 8), (0x6FA, 0x6FC, 4), (0x6FD, 0x6FE, 21),
+    (0x6FF, 0x6FF, 4), (0x700, 0x70D, 17), (0x70F, 0x70F, 26), (0x710, 0x710, 4), (0x711, 0x711, 5),
+    (0x712, 0x72F, 4), (0x730, 0x74A, 5), (0x74D, 0x7A5, 4), (0x7A6, 0x7B0, 5), (0x7B1, 0x7B1, 4),
+    (0x7C0, 0x7C9, 8), (0x7CA, 0x7EA, 4), (0x7EB, 0x7F3, 5), (0x7F4, 0x7F5, 3), (0x7F6, 0x7F6, 21),
+    (0x7F7, 0x7F9, 17), (0x7FA, 0x7FA, 3), (0x7FD, 0x7FD, 5), (0x7FE, 0x7FF, 19), (0x800, 0x815, 4),
+    (0x816, 0x819, 5), (0x81A, 0x81A, 3), (0x81B, 0x823, 5), (0x824, 0x824, 3), (0x825, 0x827, 5),
+    (0x828, 0x828, 3), (0x829, 0x82D, 5), (0x830, 0x83E, 17), (0x840, 0x858, 4), (0x859, 0x85B, 5),
+    (0x85E, 0x85E, 17), (0x860, 0x86A, 4), (0x870, 0x887, 4), (0x888, 0x888, 20), (0x889, 0x88F, 4),
+    (0x890, 0x891, 26), (0x897, 0x89F, 5), (0x8A0, 0x8C8, 4), (0x8C9, 0x8C9, 3), (0x8CA, 0x8E1, 5),
+    (0x8E2, 0x8E2, 26), (0x8E3, 0x902, 5), (0x903, 0x903, 6), (0x904, 0x939, 4), (0x93A, 0x93A, 5),
+    (0x93B, 0x93B, 6), (0x93C, 0x93C, 5), (0x93D, 0x93D, 4), (0x93E, 0x940, 6), (0x941, 0x948, 5),
+    (0x949, 0x94C, 6), (0x94D, 0x94D, 5), (0x94E, 0x94F, 6), (0x950, 0x950, 4), (0x951, 0x957, 5),
+    (0x958, 0x961, 4), (0x962, 0x963, 5), (0x964, 0x965, 17), (0x966, 0x96F, 8), (0x970, 0x970, 17),
+    (0x971, 0x971, 3), (0x972, 0x980, 4), (0x981, 0x981, 5), (0x982, 0x983, 6), (0x985, 0x98C, 4),
+    (0x98F, 0x990, 4), (0x993, 0x9A8, 4), (0x9AA, 0x9B0, 4), (0x9B2, 0x9B2, 4), (0x9B6, 0x9B9, 4),
+    (0x9BC, 0x9BC, 5), (0x9BD, 0x9BD, 4), (0x9BE, 0x9C0, 6), (0x9C1, 0x9C4, 5), (0x9C7, 0x9C8, 6),
+    (0x9CB, 0x9CC, 6), (0x9CD, 0x9CD, 5), (0x9CE, 0x9CE, 4), (0x9D7, 0x9D7, 6), (0x9DC, 0x9DD, 4),
+    (0x9DF, 0x9E1, 4), (0x9E2, 0x9E3, 5), (0x9E6, 0x9EF, 8), (0x9F0, 0x9F1, 4), (0x9F2, 0x9F3, 19),
+    (0x9F4, 0x9F9, 10), (0x9FA, 0x9FA, 21), (0x9FB, 0x9FB, 19), (0x9FC, 0x9FC, 4),
+    (0x9FD, 0x9FD, 17), (0x9FE, 0x9FE, 5), (0xA01, 0xA02, 5), (0xA03, 0xA03, 6), (0xA05, 0xA0A, 4),
+    (0xA0F, 0xA10, 4), (0xA13, 0xA28, 4), (0xA2A, 0xA30, 4), (0xA32, 0xA33, 4), (0xA35, 0xA36, 4),
+    (0xA38, 0xA39, 4), (0xA3C, 0xA3C, 5), (0xA3E, 0xA40, 6), (0xA41, 0xA42, 5), (0xA47, 0xA48, 5),
+    (0xA4B, 0xA4D, 5), (0xA51, 0xA51, 5), (0xA59, 0xA5C, 4), (0xA5E, 0xA5E, 4), (0xA66, 0xA6F, 8),
+    (0xA70, 0xA71, 5), (0xA72, 0xA74, 4), (0xA75, 0xA75, 5), (0xA76, 0xA76, 17), (0xA81, 0xA82, 5),
+    (0xA83, 0xA83, 6), (0xA85, 0xA8D, 4), (0xA8F, 0xA91, 4), (0xA93, 0xAA8, 4), (0xAAA, 0xAB0, 4),
+    (0xAB2, 0xAB3, 4), (0xAB5, 0xAB9, 4), (0xABC, 0xABC, 5), (0xABD, 0xABD, 4), (0xABE, 0xAC0, 6),
+    (0xAC1, 0xAC5, 5), (0xAC7, 0xAC8, 5), (0xAC9, 0xAC9, 6), (0xACB, 0xACC, 6), (0xACD, 0xACD, 5),
+    (0xAD0, 0xAD0, 4), (0xAE0, 0xAE1, 4), (0xAE2, 0xAE3, 5), (0xAE6, 0xAEF, 8), (0xAF0, 0xAF0, 17),
+    (0xAF1, 0xAF1, 19), (0xAF9, 0xAF9, 4), (0xAFA, 0xAFF, 5), (0xB01, 0xB01, 5), (0xB02, 0xB03, 6),
+    (0xB05, 0xB0C, 4), (0xB0F, 0xB10, 4), (0xB13, 0xB28, 4), (0xB2A, 0xB30, 4), (0xB32, 0xB33, 4),
+    (0xB35, 0xB39, 4), (0xB3C, 0xB3C, 5), (0xB3D, 0xB3D, 4), (0xB3E, 0xB3E, 6), (0xB3F, 0xB3F, 5),
+    (0xB40, 0xB40, 6), (0xB41, 0xB44, 5), (0xB47, 0xB48, 6), (0xB4B, 0xB4C, 6), (0xB4D, 0xB4D, 5),
+    (0xB55, 0xB56, 5), (0xB57, 0xB57, 6), (0xB5C, 0xB5D, 4), (0xB5F, 0xB61, 4), (0xB62, 0xB63, 5),
+    (0xB66, 0xB6F, 8), (0xB70, 0xB70, 21), (0xB71, 0xB71, 4), (0xB72, 0xB77, 10), (0xB82, 0xB82, 5),
+    (0xB83, 0xB83, 4), (0xB85, 0xB8A, 4), (0xB8E, 0xB90, 4), (0xB92, 0xB95, 4), (0xB99, 0xB9A, 4),
+    (0xB9C, 0xB9C, 4), (0xB9E, 0xB9F, 4), (0xBA3, 0xBA4, 4), (0xBA8, 0xBAA, 4), (0xBAE, 0xBB9, 4),
+    (0xBBE, 0xBBF, 6), (0xBC0, 0xBC0, 5), (0xBC1, 0xBC2, 6), (0xBC6, 0xBC8, 6), (0xBCA, 0xBCC, 6),
+    (0xBCD, 0xBCD, 5), (0xBD0, 0xBD0, 4), (0xBD7, 0xBD7, 6), (0xBE6, 0xBEF, 8), (0xBF0, 0xBF2, 10),
+    (0xBF3, 0xBF8, 21), (0xBF9, 0xBF9, 19), (0xBFA, 0xBFA, 21), (0xC00, 0xC00, 5),
+    (0xC01, 0xC03, 6), (0xC04, 0xC04, 5), (0xC05, 0xC0C, 4), (0xC0E, 0xC10, 4), (0xC12, 0xC28, 4),
+    (0xC2A, 0xC39, 4), (0xC3C, 0xC3C, 5), (0xC3D, 0xC3D, 4), (0xC3E, 0xC40, 5), (0xC41, 0xC44, 6),
+    (0xC46, 0xC48, 5), (0xC4A, 0xC4D, 5), (0xC55, 0xC56, 5), (0xC58, 0xC5A, 4), (0xC5C, 0xC5D, 4),
+    (0xC60, 0xC61, 4), (0xC62, 0xC63, 5), (0xC66, 0xC6F, 8), (0xC77, 0xC77, 17), (0xC78, 0xC7E, 10),
+    (0xC7F, 0xC7F, 21), (0xC80, 0xC80, 4), (0xC81, 0xC81, 5), (0xC82, 0xC83, 6), (0xC84, 0xC84, 17),
+    (0xC85, 0xC8C, 4), (0xC8E, 0xC90, 4), (0xC92, 0xCA8, 4), (0xCAA, 0xCB3, 4), (0xCB5, 0xCB9, 4),
+    (0xCBC, 0xCBC, 5), (0xCBD, 0xCBD, 4), (0xCBE, 0xCBE, 6), (0xCBF, 0xCBF, 5), (0xCC0, 0xCC4, 6),
+    (0xCC6, 0xCC6, 5), (0xCC7, 0xCC8, 6), (0xCCA, 0xCCB, 6), (0xCCC, 0xCCD, 5), (0xCD5, 0xCD6, 6),
+    (0xCDC, 0xCDE, 4), (0xCE0, 0xCE1, 4), (0xCE2, 0xCE3, 5), (0xCE6, 0xCEF, 8), (0xCF1, 0xCF2, 4),
+    (0xCF3, 0xCF3, 6), (0xD00, 0xD01, 5), (0xD02, 0xD03, 6), (0xD04, 0xD0C, 4), (0xD0E, 0xD10, 4),
+    (0xD12, 0xD3A, 4), (0xD3B, 0xD3C, 5), (0xD3D, 0xD3D, 4), (0xD3E, 0xD40, 6), (0xD41, 0xD44, 5),
+    (0xD46, 0xD48, 6), (0xD4A, 0xD4C, 6), (0xD4D, 0xD4D, 5), (0xD4E, 0xD4E, 4), (0xD4F, 0xD4F, 21),
+    (0xD54, 0xD56, 4), (0xD57, 0xD57, 6), (0xD58, 0xD5E, 10), (0xD5F, 0xD61, 4), (0xD62, 0xD63, 5),
+    (0xD66, 0xD6F, 8), (0xD70, 0xD78, 10), (0xD79, 0xD79, 21), (0xD7A, 0xD7F, 4), (0xD81, 0xD81, 5),
+    (0xD82, 0xD83, 6), (0xD85, 0xD96, 4), (0xD9A, 0xDB1, 4), (0xDB3, 0xDBB, 4), (0xDBD, 0xDBD, 4),
+    (0xDC0, 0xDC6, 4), (0xDCA, 0xDCA, 5), (0xDCF, 0xDD1, 6), (0xDD2, 0xDD4, 5), (0xDD6, 0xDD6, 5),
+    (0xDD8, 0xDDF, 6), (0xDE6, 0xDEF, 8), (0xDF2, 0xDF3, 6), (0xDF4, 0xDF4, 17), (0xE01, 0xE30, 4),
+    (0xE31, 0xE31, 5), (0xE32, 0xE33, 4), (0xE34, 0xE3A, 5), (0xE3F, 0xE3F, 19), (0xE40, 0xE45, 4),
+    (0xE46, 0xE46, 3), (0xE47, 0xE4E, 5), (0xE4F, 0xE4F, 17), (0xE50, 0xE59, 8), (0xE5A, 0xE5B, 17),
+    (0xE81, 0xE82, 4), (0xE84, 0xE84, 4), (0xE86, 0xE8A, 4), (0xE8C, 0xEA3, 4), (0xEA5, 0xEA5, 4),
+    (0xEA7, 0xEB0, 4), (0xEB1, 0xEB1, 5), (0xEB2, 0xEB3, 4), (0xEB4, 0xEBC, 5), (0xEBD, 0xEBD, 4),
+    (0xEC0, 0xEC4, 4), (0xEC6, 0xEC6, 3), (0xEC8, 0xECE, 5), (0xED0, 0xED9, 8), (0xEDC, 0xEDF, 4),
+    (0xF00, 0xF00, 4), (0xF01, 0xF03, 21), (0xF04, 0xF12, 17), (0xF13, 0xF13, 21),
+    (0xF14, 0xF14, 17), (0xF15, 0xF17, 21), (0xF18, 0xF19, 5), (0xF1A, 0xF1F, 21),
+    (0xF20, 0xF29, 8), (0xF2A, 0xF33, 10), (0xF34, 0xF34, 21), (0xF35, 0xF35, 5),
+    (0xF36, 0xF36, 21), (0xF37, 0xF37, 5), (0xF38, 0xF38, 21), (0xF39, 0xF39, 5),
+    (0xF3A, 0xF3A, 13), (0xF3B, 0xF3B, 14), (0xF3C, 0xF3C, 13), (0xF3D, 0xF3D, 14),
+    (0xF3E, 0xF3F, 6), (0xF40, 0xF47, 4), (0xF49, 0xF6C, 4), (0xF71, 0xF7E, 5), (0xF7F, 0xF7F, 6),
+    (0xF80, 0xF84, 5), (0xF85, 0xF85, 17), (0xF86, 0xF87, 5), (0xF88, 0xF8C, 4), (0xF8D, 0xF97, 5),
+    (0xF99, 0xFBC, 5), (0xFBE, 0xFC5, 21), (0xFC6, 0xFC6, 5), (0xFC7, 0xFCC, 21),
+    (0xFCE, 0xFCF, 21), (0xFD0, 0xFD4, 17), (0xFD5, 0xFD8, 21), (0xFD9, 0xFDA, 17),
+    (0x1000, 0x102A, 4), (0x102B, 0x102C, 6), (0x102D, 0x1030, 5), (0x1031, 0x1031, 6),
+    (0x1032, 0x1037, 5), (0x1038, 0x1038, 6), (0x1039, 0x103A, 5), (0x103B, 0x103C, 6),
+    (0x103D, 0x103E, 5), (0x103F, 0x103F, 4), (0x1040, 0x1049, 8), (0x104A, 0x104F, 17),
+    (0x1050, 0x1055, 4), (0x1056, 0x1057, 6), (0x1058, 0x1059, 5), (0x105A, 0x105D, 4),
+    (0x105E, 0x1060, 5), (0x1061, 0x1061, 4), (0x1062, 0x1064, 6), (0x1065, 0x1066, 4),
+    (0x1067, 0x106D, 6), (0x106E, 0x1070, 4), (0x1071, 0x1074, 5), (0x1075, 0x1081, 4),
+    (0x1082, 0x1082, 5), (0x1083, 0x1084, 6), (0x1085, 0x1086, 5), (0x1087, 0x108C, 6),
+    (0x108D, 0x108D, 5), (0x108E, 0x108E, 4), (0x108F, 0x108F, 6), (0x1090, 0x1099, 8),
+    (0x109A, 0x109C, 6), (0x109D, 0x109D, 5), (0x109E, 0x109F, 21), (0x10A0, 0x10C5, 0),
+    (0x10C7, 0x10C7, 0), (0x10CD, 0x10CD, 0), (0x10D0, 0x10FA, 1), (0x10FB, 0x10FB, 17),
+    (0x10FC, 0x10FC, 3), (0x10FD, 0x10FF, 1), (0x1100, 0x1248, 4), (0x124A, 0x124D, 4),
+    (0x1250, 0x1256, 4), (0x1258, 0x1258, 4), (0x125A, 0x125D, 4), (0x1260, 0x1288, 4),
+    (0x128A, 0x128D, 4), (0x1290, 0x12B0, 4), (0x12B2, 0x12B5, 4), (0x12B8, 0x12BE, 4),
+    (0x12C0, 0x12C0, 4), (0x12C2, 0x12C5, 4), (0x12C8, 0x12D6, 4), (0x12D8, 0x1310, 4),
+    (0x1312, 0x1315, 4), (0x1318, 0x135A, 4), (0x135D, 0x135F, 5), (0x1360, 0x1368, 17),
+    (0x1369, 0x137C, 10), (0x1380, 0x138F, 4), (0x1390, 0x1399, 21), (0x13A0, 0x13F5, 0),
+    (0x13F8, 0x13FD, 1), (0x1400, 0x1400, 12), (0x1401, 0x166C, 4), (0x166D, 0x166D, 21),
+    (0x166E, 0x166E, 17), (0x166F, 0x167F, 4), (0x1680, 0x1680, 22), (0x1681, 0x169A, 4),
+    (0x169B, 0x169B, 13), (0x169C, 0x169C, 14), (0x16A0, 0x16EA, 4), (0x16EB, 0x16ED, 17),
+    (0x16EE, 0x16F0, 9), (0x16F1, 0x16F8, 4), (0x1700, 0x1711, 4), (0x1712, 0x1714, 5),
+    (0x1715, 0x1715, 6), (0x171F, 0x1731, 4), (0x1732, 0x1733, 5), (0x1734, 0x1734, 6),
+    (0x1735, 0x1736, 17), (0x1740, 0x1751, 4), (0x1752, 0x1753, 5), (0x1760, 0x176C, 4),
+    (0x176E, 0x1770, 4), (0x1772, 0x1773, 5), (0x1780, 0x17B3, 4), (0x17B4, 0x17B5, 5),
+    (0x17B6, 0x17B6, 6), (0x17B7, 0x17BD, 5), (0x17BE, 0x17C5, 6), (0x17C6, 0x17C6, 5),
+    (0x17C7, 0x17C8, 6), (0x17C9, 0x17D3, 5), (0x17D4, 0x17D6, 17), (0x17D7, 0x17D7, 3),
+    (0x17D8, 0x17DA, 17), (0x17DB, 0x17DB, 19), (0x17DC, 0x17DC, 4), (0x17DD, 0x17DD, 5),
+    (0x17E0, 0x17E9, 8), (0x17F0, 0x17F9, 10), (0x1800, 0x1805, 17), (0x1806, 0x1806, 12),
+    (0x1807, 0x180A, 17), (0x180B, 0x180D, 5), (0x180E, 0x180E, 26), (0x180F, 0x180F, 5),
+    (0x1810, 0x1819, 8), (0x1820, 0x1842, 4), (0x1843, 0x1843, 3), (0x1844, 0x1878, 4),
+    (0x1880, 0x1884, 4), (0x1885, 0x1886, 5), (0x1887, 0x18A8, 4), (0x18A9, 0x18A9, 5),
+    (0x18AA, 0x18AA, 4), (0x18B0, 0x18F5, 4), (0x1900, 0x191E, 4), (0x1920, 0x1922, 5),
+    (0x1923, 0x1926, 6), (0x1927, 0x1928, 5), (0x1929, 0x192B, 6), (0x1930, 0x1931, 6),
+    (0x1932, 0x1932, 5), (0x1933, 0x1938, 6), (0x1939, 0x193B, 5), (0x1940, 0x1940, 21),
+    (0x1944, 0x1945, 17), (0x1946, 0x194F, 8), (0x1950, 0x196D, 4), (0x1970, 0x1974, 4),
+    (0x1980, 0x19AB, 4), (0x19B0, 0x19C9, 4), (0x19D0, 0x19D9, 8), (0x19DA, 0x19DA, 10),
+    (0x19DE, 0x19FF, 21), (0x1A00, 0x1A16, 4), (0x1A17, 0x1A18, 5), (0x1A19, 0x1A1A, 6),
+    (0x1A1B, 0x1A1B, 5), (0x1A1E, 0x1A1F, 17), (0x1A20, 0x1A54, 4), (0x1A55, 0x1A55, 6),
+    (0x1A56, 0x1A56, 5), (0x1A57, 0x1A57, 6), (0x1A58, 0x1A5E, 5), (0x1A60, 0x1A60, 5),
+    (0x1A61, 0x1A61, 6), (0x1A62, 0x1A62, 5), (0x1A63, 0x1A64, 6), (0x1A65, 0x1A6C, 5),
+    (0x1A6D, 0x1A72, 6), (0x1A73, 0x1A7C, 5), (0x1A7F, 0x1A7F, 5), (0x1A80, 0x1A89, 8),
+    (0x1A90, 0x1A99, 8), (0x1AA0, 0x1AA6, 17), (0x1AA7, 0x1AA7, 3), (0x1AA8, 0x1AAD, 17),
+    (0x1AB0, 0x1ABD, 5), (0x1ABE, 0x1ABE, 7), (0x1ABF, 0x1ADD, 5), (0x1AE0, 0x1AEB, 5),
+    (0x1B00, 0x1B03, 5), (0x1B04, 0x1B04, 6), (0x1B05, 0x1B33, 4), (0x1B34, 0x1B34, 5),
+    (0x1B35, 0x1B35, 6), (0x1B36, 0x1B3A, 5), (0x1B3B, 0x1B3B, 6), (0x1B3C, 0x1B3C, 5),
+    (0x1B3D, 0x1B41, 6), (0x1B42, 0x1B42, 5), (0x1B43, 0x1B44, 6), (0x1B45, 0x1B4C, 4),
+    (0x1B4E, 0x1B4F, 17), (0x1B50, 0x1B59, 8), (0x1B5A, 0x1B60, 17), (0x1B61, 0x1B6A, 21),
+    (0x1B6B, 0x1B73, 5), (0x1B74, 0x1B7C, 21), (0x1B7D, 0x1B7F, 17), (0x1B80, 0x1B81, 5),
+    (0x1B82, 0x1B82, 6), (0x1B83, 0x1BA0, 4), (0x1BA1, 0x1BA1, 6), (0x1BA2, 0x1BA5, 5),
+    (0x1BA6, 0x1BA7, 6), (0x1BA8, 0x1BA9, 5), (0x1BAA, 0x1BAA, 6), (0x1BAB, 0x1BAD, 5),
+    (0x1BAE, 0x1BAF, 4), (0x1BB0, 0x1BB9, 8), (0x1BBA, 0x1BE5, 4), (0x1BE6, 0x1BE6, 5),
+    (0x1BE7, 0x1BE7, 6), (0x1BE8, 0x1BE9, 5), (0x1BEA, 0x1BEC, 6), (0x1BED, 0x1BED, 5),
+    (0x1BEE, 0x1BEE, 6), (0x1BEF, 0x1BF1, 5), (0x1BF2, 0x1BF3, 6), (0x1BFC, 0x1BFF, 17),
+    (0x1C00, 0x1C23, 4), (0x1C24, 0x1C2B, 6), (0x1C2C, 0x1C33, 5), (0x1C34, 0x1C35, 6),
+    (0x1C36, 0x1C37, 5), (0x1C3B, 0x1C3F, 17), (0x1C40, 0x1C49, 8), (0x1C4D, 0x1C4F, 4),
+    (0x1C50, 0x1C59, 8), (0x1C5A, 0x1C77, 4), (0x1C78, 0x1C7D, 3), (0x1C7E, 0x1C7F, 17),
+    (0x1C80, 0x1C88, 1), (0x1C89, 0x1C89, 0), (0x1C8A, 0x1C8A, 1), (0x1C90, 0x1CBA, 0),
+    (0x1CBD, 0x1CBF, 0), (0x1CC0, 0x1CC7, 17), (0x1CD0, 0x1CD2, 5), (0x1CD3, 0x1CD3, 17),
+    (0x1CD4, 0x1CE0, 5), (0x1CE1, 0x1CE1, 6), (0x1CE2, 0x1CE8, 5), (0x1CE9, 0x1CEC, 4),
+    (0x1CED, 0x1CED, 5), (0x1CEE, 0x1CF3, 4), (0x1CF4, 0x1CF4, 5), (0x1CF5, 0x1CF6, 4),
+    (0x1CF7, 0x1CF7, 6), (0x1CF8, 0x1CF9, 5), (0x1CFA, 0x1CFA, 4), (0x1D00, 0x1D2B, 1),
+    (0x1D2C, 0x1D6A, 3), (0x1D6B, 0x1D77, 1), (0x1D78, 0x1D78, 3), (0x1D79, 0x1D9A, 1),
+    (0x1D9B, 0x1DBF, 3), (0x1DC0, 0x1DFF, 5), (0x1E00, 0x1E00, 0), (0x1E01, 0x1E01, 1),
+    (0x1E02, 0x1E02, 0), (0x1E03, 0x1E03, 1), (0x1E04, 0x1E04, 0), (0x1E05, 0x1E05, 1),
+    (0x1E06, 0x1E06, 0), (0x1E07, 0x1E07, 1), (0x1E08, 0x1E08, 0), (0x1E09, 0x1E09, 1),
+    (0x1E0A, 0x1E0A, 0), (0x1E0B, 0x1E0B, 1), (0x1E0C, 0x1E0C, 0), (0x1E0D, 0x1E0D, 1),
+    (0x1E0E, 0x1E0E, 0), (0x1E0F, 0x1E0F, 1), (0x1E10, 0x1E10, 0), (0x1E11, 0x1E11, 1),
+    (0x1E12, 0x1E12, 0), (0x1E13, 0x1E13, 1), (0x1E14, 0x1E14, 0), (0x1E15, 0x1E15, 1),
+    (0x1E16, 0x1E16, 0), (0x1E17, 0x1E17, 1), (0x1E18, 0x1E18, 0), (0x1E19, 0x1E19, 1),
+    (0x1E1A, 0x1E1A, 0), (0x1E1B, 0x1E1B, 1), (0x1E1C, 0x1E1C, 0), (0x1E1D, 0x1E1D, 1),
+    (0x1E1E, 0x1E1E, 0), (0x1E1F, 0x1E1F, 1), (0x1E20, 0x1E20, 0), (0x1E21, 0x1E21, 1),
+    (0x1E22, 0x1E22, 0), (0x1E23, 0x1E23, 1), (0x1E24, 0x1E24, 0), (0x1E25, 0x1E25, 1),
+    (0x1E26, 0x1E26, 0), (0x1E27, 0x1E27, 1), (0x1E28, 0x1E28, 0), (0x1E29, 0x1E29, 1),
+    (0x1E2A, 0x1E2A, 0), (0x1E2B, 0x1E2B, 1), (0x1E2C, 0x1E2C, 0), (0x1E2D, 0x1E2D, 1),
+    (0x1E2E, 0x1E2E, 0), (0x1E2F, 0x1E2F, 1), (0x1E30, 0x1E30, 0), (0x1E31, 0x1E31, 1),
+    (0x1E32, 0x1E32, 0), (0x1E33, 0x1E33, 1), (0x1E34, 0x1E34, 0), (0x1E35, 0x1E35, 1),
+    (0x1E36, 0x1E36, 0), (0x1E37, 0x1E37, 1), (0x1E38, 0x1E38, 0), (0x1E39, 0x1E39, 1),
+    (0x1E3A, 0x1E3A, 0), (0x1E3B, 0x1E3B, 1), (0x1E3C, 0x1E3C, 0), (0x1E3D, 0x1E3D, 1),
+    (0x1E3E, 0x1E3E, 0), (0x1E3F, 0x1E3F, 1), (0x1E40, 0x1E40, 0), (0x1E41, 0x1E41, 1),
+    (0x1E42, 0x1E42, 0), (0x1E43, 0x1E43, 1), (0x1E44, 0x1E44, 0), (0x1E45, 0x1E45, 1),
+    (0x1E46, 0x1E46, 0), (0x1E47, 0x1E47, 1), (0x1E48, 0x1E48, 0), (0x1E49, 0x1E49, 1),
+    (0x1E4A, 0x1E4A, 0), (0x1E4B, 0x1E4B, 1), (0x1E4C, 0x1E4C, 0), (0x1E4D, 0x1E4D, 1),
+    (0x1E4E, 0x1E4E, 0), (0x1E4F, 0x1E4F, 1), (0x1E50, 0x1E50, 0), (0x1E51, 0x1E51, 1),
+    (0x1E52, 0x1E52, 0), (0x1E53, 0x1E53, 1), (0x1E54, 0x1E54, 0), (0x1E55, 0x1E55, 1),
+    (0x1E56, 0x1E56, 0), (0x1E57, 0x1E57, 1), (0x1E58, 0x1E58, 0), (0x1E59, 0x1E59, 1),
+    (0x1E5A, 0x1E5A, 0), (0x1E5B, 0x1E5B, 1), (0x1E5C, 0x1E5C, 0), (0x1E5D, 0x1E5D, 1),
+    (0x1E5E, 0x1E5E, 0), (0x1E5F, 0x1E5F, 1), (0x1E60, 0x1E60, 0), (0x1E61, 0x1E61, 1),
+    (0x1E62, 0x1E62, 0), (0x1E63, 0x1E63, 1), (0x1E64, 0x1E64, 0), (0x1E65, 0x1E65, 1),
+    (0x1E66, 0x1E66, 0), (0x1E67, 0x1E67, 1), (0x1E68, 0x1E68, 0), (0x1E69, 0x1E69, 1),
+    (0x1E6A, 0x1E6A, 0), (0x1E6B, 0x1E6B, 1), (0x1E6C, 0x1E6C, 0), (0x1E6D, 0x1E6D, 1),
+    (0x1E6E, 0x1E6E, 0), (0x1E6F, 0x1E6F, 1), (0x1E70, 0x1E70, 0), (0x1E71, 0x1E71, 1),
+    (0x1E72, 0x1E72, 0), (0x1E73, 0x1E73, 1), (0x1E74, 0x1E74, 0), (0x1E75, 0x1E75, 1),
+    (0x1E76, 0x1E76, 0), (0x1E77, 0x1E77, 1), (0x1E78, 0x1E78, 0), (0x1E79, 0x1E79, 1),
+    (0x1E7A, 0x1E7A, 0), (0x1E7B, 0x1E7B, 1), (0x1E7C, 0x1E7C, 0), (0x1E7D, 0x1E7D, 1),
+    (0x1E7E, 0x1E7E, 0), (0x1E7F, 0x1E7F, 1), (0x1E80, 0x1E80, 0), (0x1E81, 0x1E81, 1),
+    (0x1E82, 0x1E82, 0), (0x1E83, 0x1E83, 1), (0x1E84, 0x1E84, 0), (0x1E85, 0x1E85, 1),
+    (0x1E86, 0x1E86, 0), (0x1E87, 0x1E87, 1), (0x1E88, 0x1E88, 0), (0x1E89, 0x1E89, 1),
+    (0x1E8A, 0x1E8A, 0), (0x1E8B, 0x1E8B, 1), (0x1E8C, 0x1E8C, 0), (0x1E8D, 0x1E8D, 1),
+    (0x1E8E, 0x1E8E, 0), (0x1E8F, 0x1E8F, 1), (0x1E90, 0x1E90, 0), (0x1E91, 0x1E91, 1),
+    (0x1E92, 0x1E92, 0), (0x1E93, 0x1E93, 1), (0x1E94, 0x1E94, 0), (0x1E95, 0x1E9D, 1),
+    (0x1E9E, 0x1E9E, 0), (0x1E9F, 0x1E9F, 1), (0x1EA0, 0x1EA0, 0), (0x1EA1, 0x1EA1, 1),
+    (0x1EA2, 0x1EA2, 0), (0x1EA3, 0x1EA3, 1), (0x1EA4, 0x1EA4, 0), (0x1EA5, 0x1EA5, 1),
+    (0x1EA6, 0x1EA6, 0), (0x1EA7, 0x1EA7, 1), (0x1EA8, 0x1EA8, 0), (0x1EA9, 0x1EA9, 1),
+    (0x1EAA, 0x1EAA, 0), (0x1EAB, 0x1EAB, 1), (0x1EAC, 0x1EAC, 0), (0x1EAD, 0x1EAD, 1),
+    (0x1EAE, 0x1EAE, 0), (0x1EAF, 0x1EAF, 1), (0x1EB0, 0x1EB0, 0), (0x1EB1, 0x1EB1, 1),
+    (0x1EB2, 0x1EB2, 0), (0x1EB3, 0x1EB3, 1), (0x1EB4, 0x1EB4, 0), (0x1EB5, 0x1EB5, 1),
+    (0x1EB6, 0x1EB6, 0), (0x1EB7, 0x1EB7, 1), (0x1EB8, 0x1EB8, 0), (0x1EB9, 0x1EB9, 1),
+    (0x1EBA, 0x1EBA, 0), (0x1EBB, 0x1EBB, 1), (0x1EBC, 0x1EBC, 0), (0x1EBD, 0x1EBD, 1),
+    (0x1EBE, 0x1EBE, 0), (0x1EBF, 0x1EBF, 1), (0x1EC0, 0x1EC0, 0), (0x1EC1, 0x1EC1, 1),
+    (0x1EC2, 0x1EC2, 0), (0x1EC3, 0x1EC3, 1), (0x1EC4, 0x1EC4, 0), (0x1EC5, 0x1EC5, 1),
+    (0x1EC6, 0x1EC6, 0), (0x1EC7, 0x1EC7, 1), (0x1EC8, 0x1EC8, 0), (0x1EC9, 0x1EC9, 1),
+    (0x1ECA, 0x1ECA, 0), (0x1ECB, 0x1ECB, 1), (0x1ECC, 0x1ECC, 0), (0x1ECD, 0x1ECD, 1),
+    (0x1ECE, 0x1ECE, 0), (0x1ECF, 0x1ECF, 1), (0x1ED0, 0x1ED0, 0), (0x1ED1, 0x1ED1, 1),
+    (0x1ED2, 0x1ED2, 0), (0x1ED3, 0x1ED3, 1), (0x1ED4, 0x1ED4, 0), (0x1ED5, 0x1ED5, 1),
+    (0x1ED6, 0x1ED6, 0), (0x1ED7, 0x1ED7, 1), (0x1ED8, 0x1ED8, 0), (0x1ED9, 0x1ED9, 1),
+    (0x1EDA, 0x1EDA, 0), (0x1EDB, 0x1EDB, 1), (0x1EDC, 0x1EDC, 0), (0x1EDD, 0x1EDD, 1),
+    (0x1EDE, 0x1EDE, 0), (0x1EDF, 0x1EDF, 1), (0x1EE0, 0x1EE0, 0), (0x1EE1, 0x1EE1, 1),
+    (0x1EE2, 0x1EE2, 0), (0x1EE3, 0x1EE3, 1), (0x1EE4, 0x1EE4, 0), (0x1EE5, 0x1EE5, 1),
+    (0x1EE6, 0x1EE6, 0), (0x1EE7, 0x1EE7, 1), (0x1EE8, 0x1EE8, 0), (0x1EE9, 0x1EE9, 1),
+    (0x1EEA, 0x1EEA, 0), (0x1EEB, 0x1EEB, 1), (0x1EEC, 0x1EEC, 0), (0x1EED, 0x1EED, 1),
+    (0x1EEE, 0x1EEE, 0), (0x1EEF, 0x1EEF, 1), (0x1EF0, 0x1EF0, 0), (0x1EF1, 0x1EF1, 1),
+    (0x1EF2, 0x1EF2, 0), (0x1EF3, 0x1EF3, 1), (0x1EF4, 0x1EF4, 0), (0x1EF5, 0x1EF5, 1),
+    (0x1EF6, 0x1EF6, 0), (0x1EF7, 0x1EF7, 1), (0x1EF8, 0x1EF8, 0), (0x1EF9, 0x1EF9, 1),
+    (0x1EFA, 0x1EFA, 0), (0x1EFB, 0x1EFB, 1), (0x1EFC, 0x1EFC, 0), (0x1EFD, 0x1EFD, 1),
+    (0x1EFE, 0x1EFE, 0), (0x1EFF, 0x1F07, 1), (0x1F08, 0x1F0F, 0), (0x1F10, 0x1F15, 1),
+    (0x1F18, 0x1F1D, 0), (0x1F20, 0x1F27, 1), (0x1F28, 0x1F2F, 0), (0x1F30, 0x1F37, 1),
+    (0x1F38, 0x1F3F, 0), (0x1F40, 0x1F45, 1), (0x1F48, 0x1F4D, 0), (0x1F50, 0x1F57, 1),
+    (0x1F59, 0x1F59, 0), (0x1F5B, 0x1F5B, 0), (0x1F5D, 0x1F5D, 0), (0x1F5F, 0x1F5F, 0),
+    (0x1F60, 0x1F67, 1), (0x1F68, 0x1F6F, 0), (0x1F70, 0x1F7D, 1), (0x1F80, 0x1F87, 1),
+    (0x1F88, 0x1F8F, 2), (0x1F90, 0x1F97, 1), (0x1F98, 0x1F9F, 2), (0x1FA0, 0x1FA7, 1),
+    (0x1FA8, 0x1FAF, 2), (0x1FB0, 0x1FB4, 1), (0x1FB6, 0x1FB7, 1), (0x1FB8, 0x1FBB, 0),
+    (0x1FBC, 0x1FBC, 2), (0x1FBD, 0x1FBD, 20), (0x1FBE, 0x1FBE, 1), (0x1FBF, 0x1FC1, 20),
+    (0x1FC2, 0x1FC4, 1), (0x1FC6, 0x1FC7, 1), (0x1FC8, 0x1FCB, 0), (0x1FCC, 0x1FCC, 2),
+    (0x1FCD, 0x1FCF, 20), (0x1FD0, 0x1FD3, 1), (0x1FD6, 0x1FD7, 1), (0x1FD8, 0x1FDB, 0),
+    (0x1FDD, 0x1FDF, 20), (0x1FE0, 0x1FE7, 1), (0x1FE8, 0x1FEC, 0), (0x1FED, 0x1FEF, 20),
+    (0x1FF2, 0x1FF4, 1), (0x1FF6, 0x1FF7, 1), (0x1FF8, 0x1FFB, 0), (0x1FFC, 0x1FFC, 2),
+    (0x1FFD, 0x1FFE, 20), (0x2000, 0x200A, 22), (0x200B, 0x200F, 26), (0x2010, 0x2015, 12),
+    (0x2016, 0x2017, 17), (0x2018, 0x2018, 15), (0x2019, 0x2019, 16), (0x201A, 0x201A, 13),
+    (0x201B, 0x201C, 15), (0x201D, 0x201D, 16), (0x201E, 0x201E, 13), (0x201F, 0x201F, 15),
+    (0x2020, 0x2027, 17), (0x2028, 0x2028, 23), (0x2029, 0x2029, 24), (0x202A, 0x202E, 26),
+    (0x202F, 0x202F, 22), (0x2030, 0x2038, 17), (0x2039, 0x2039, 15), (0x203A, 0x203A, 16),
+    (0x203B, 0x203E, 17), (0x203F, 0x2040, 11), (0x2041, 0x2043, 17), (0x2044, 0x2044, 18),
+    (0x2045, 0x2045, 13), (0x2046, 0x2046, 14), (0x2047, 0x2051, 17), (0x2052, 0x2052, 18),
+    (0x2053, 0x2053, 17), (0x2054, 0x2054, 11), (0x2055, 0x205E, 17), (0x205F, 0x205F, 22),
+    (0x2060, 0x2064, 26), (0x2066, 0x206F, 26), (0x2070, 0x2070, 10), (0x2071, 0x2071, 3),
+    (0x2074, 0x2079, 10), (0x207A, 0x207C, 18), (0x207D, 0x207D, 13), (0x207E, 0x207E, 14),
+    (0x207F, 0x207F, 3), (0x2080, 0x2089, 10), (0x208A, 0x208C, 18), (0x208D, 0x208D, 13),
+    (0x208E, 0x208E, 14), (0x2090, 0x209C, 3), (0x20A0, 0x20C1, 19), (0x20D0, 0x20DC, 5),
+    (0x20DD, 0x20E0, 7), (0x20E1, 0x20E1, 5), (0x20E2, 0x20E4, 7), (0x20E5, 0x20F0, 5),
+    (0x2100, 0x2101, 21), (0x2102, 0x2102, 0), (0x2103, 0x2106, 21), (0x2107, 0x2107, 0),
+    (0x2108, 0x2109, 21), (0x210A, 0x210A, 1), (0x210B, 0x210D, 0), (0x210E, 0x210F, 1),
+    (0x2110, 0x2112, 0), (0x2113, 0x2113, 1), (0x2114, 0x2114, 21), (0x2115, 0x2115, 0),
+    (0x2116, 0x2117, 21), (0x2118, 0x2118, 18), (0x2119, 0x211D, 0), (0x211E, 0x2123, 21),
+    (0x2124, 0x2124, 0), (0x2125, 0x2125, 21), (0x2126, 0x2126, 0), (0x2127, 0x2127, 21),
+    (0x2128, 0x2128, 0), (0x2129, 0x2129, 21), (0x212A, 0x212D, 0), (0x212E, 0x212E, 21),
+    (0x212F, 0x212F, 1), (0x2130, 0x2133, 0), (0x2134, 0x2134, 1), (0x2135, 0x2138, 4),
+    (0x2139, 0x2139, 1), (0x213A, 0x213B, 21), (0x213C, 0x213D, 1), (0x213E, 0x213F, 0),
+    (0x2140, 0x2144, 18), (0x2145, 0x2145, 0), (0x2146, 0x2149, 1), (0x214A, 0x214A, 21),
+    (0x214B, 0x214B, 18), (0x214C, 0x214D, 21), (0x214E, 0x214E, 1), (0x214F, 0x214F, 21),
+    (0x2150, 0x215F, 10), (0x2160, 0x2182, 9), (0x2183, 0x2183, 0), (0x2184, 0x2184, 1),
+    (0x2185, 0x2188, 9), (0x2189, 0x2189, 10), (0x218A, 0x218B, 21), (0x2190, 0x2194, 18),
+    (0x2195, 0x2199, 21), (0x219A, 0x219B, 18), (0x219C, 0x219F, 21), (0x21A0, 0x21A0, 18),
+    (0x21A1, 0x21A2, 21), (0x21A3, 0x21A3, 18), (0x21A4, 0x21A5, 21), (0x21A6, 0x21A6, 18),
+    (0x21A7, 0x21AD, 21), (0x21AE, 0x21AE, 18), (0x21AF, 0x21CD, 21), (0x21CE, 0x21CF, 18),
+    (0x21D0, 0x21D1, 21), (0x21D2, 0x21D2, 18), (0x21D3, 0x21D3, 21), (0x21D4, 0x21D4, 18),
+    (0x21D5, 0x21F3, 21), (0x21F4, 0x22FF, 18), (0x2300, 0x2307, 21), (0x2308, 0x2308, 13),
+    (0x2309, 0x2309, 14), (0x230A, 0x230A, 13), (0x230B, 0x230B, 14), (0x230C, 0x231F, 21),
+    (0x2320, 0x2321, 18), (0x2322, 0x2328, 21), (0x2329, 0x2329, 13), (0x232A, 0x232A, 14),
+    (0x232B, 0x237B, 21), (0x237C, 0x237C, 18), (0x237D, 0x239A, 21), (0x239B, 0x23B3, 18),
+    (0x23B4, 0x23DB, 21), (0x23DC, 0x23E1, 18), (0x23E2, 0x2429, 21), (0x2440, 0x244A, 21),
+    (0x2460, 0x249B, 10), (0x249C, 0x24E9, 21), (0x24EA, 0x24FF, 10), (0x2500, 0x25B6, 21),
+    (0x25B7, 0x25B7, 18), (0x25B8, 0x25C0, 21), (0x25C1, 0x25C1, 18), (0x25C2, 0x25F7, 21),
+    (0x25F8, 0x25FF, 18), (0x2600, 0x266E, 21), (0x266F, 0x266F, 18), (0x2670, 0x2767, 21),
+    (0x2768, 0x2768, 13), (0x2769, 0x2769, 14), (0x276A, 0x276A, 13), (0x276B, 0x276B, 14),
+    (0x276C, 0x276C, 13), (0x276D, 0x276D, 14), (0x276E, 0x276E, 13), (0x276F, 0x276F, 14),
+    (0x2770, 0x2770, 13), (0x2771, 0x2771, 14), (0x2772, 0x2772, 13), (0x2773, 0x2773, 14),
+    (0x2774, 0x2774, 13), (0x2775, 0x2775, 14), (0x2776, 0x2793, 10), (0x2794, 0x27BF, 21),
+    (0x27C0, 0x27C4, 18), (0x27C5, 0x27C5, 13), (0x27C6, 0x27C6, 14), (0x27C7, 0x27E5, 18),
+    (0x27E6, 0x27E6, 13), (0x27E7, 0x27E7, 14), (0x27E8, 0x27E8, 13), (0x27E9, 0x27E9, 14),
+    (0x27EA, 0x27EA, 13), (0x27EB, 0x27EB, 14), (0x27EC, 0x27EC, 13), (0x27ED, 0x27ED, 14),
+    (0x27EE, 0x27EE, 13), (0x27EF, 0x27EF, 14), (0x27F0, 0x27FF, 18), (0x2800, 0x28FF, 21),
+    (0x2900, 0x2982, 18), (0x2983, 0x2983, 13), (0x2984, 0x2984, 14), (0x2985, 0x2985, 13),
+    (0x2986, 0x2986, 14), (0x2987, 0x2987, 13), (0x2988, 0x2988, 14), (0x2989, 0x2989, 13),
+    (0x298A, 0x298A, 14), (0x298B, 0x298B, 13), (0x298C, 0x298C, 14), (0x298D, 0x298D, 13),
+    (0x298E, 0x298E, 14), (0x298F, 0x298F, 13), (0x2990, 0x2990, 14), (0x2991, 0x2991, 13),
+    (0x2992, 0x2992, 14), (0x2993, 0x2993, 13), (0x2994, 0x2994, 14), (0x2995, 0x2995, 13),
+    (0x2996, 0x2996, 14), (0x2997, 0x2997, 13), (0x2998, 0x2998, 14), (0x2999, 0x29D7, 18),
+    (0x29D8, 0x29D8, 13), (0x29D9, 0x29D9, 14), (0x29DA, 0x29DA, 13), (0x29DB, 0x29DB, 14),
+    (0x29DC, 0x29FB, 18), (0x29FC, 0x29FC, 13), (0x29FD, 0x29FD, 14), (0x29FE, 0x2AFF, 18),
+    (0x2B00, 0x2B2F, 21), (0x2B30, 0x2B44, 18), (0x2B45, 0x2B46, 21), (0x2B47, 0x2B4C, 18),
+    (0x2B4D, 0x2B73, 21), (0x2B76, 0x2BFF, 21), (0x2C00, 0x2C2F, 0), (0x2C30, 0x2C5F, 1),
+    (0x2C60, 0x2C60, 0), (0x2C61, 0x2C61, 1), (0x2C62, 0x2C64, 0), (0x2C65, 0x2C66, 1),
+    (0x2C67, 0x2C67, 0), (0x2C68, 0x2C68, 1), (0x2C69, 0x2C69, 0), (0x2C6A, 0x2C6A, 1),
+    (0x2C6B, 0x2C6B, 0), (0x2C6C, 0x2C6C, 1), (0x2C6D, 0x2C70, 0), (0x2C71, 0x2C71, 1),
+    (0x2C72, 0x2C72, 0), (0x2C73, 0x2C74, 1), (0x2C75, 0x2C75, 0), (0x2C76, 0x2C7B, 1),
+    (0x2C7C, 0x2C7D, 3), (0x2C7E, 0x2C80, 0), (0x2C81, 0x2C81, 1), (0x2C82, 0x2C82, 0),
+    (0x2C83, 0x2C83, 1), (0x2C84, 0x2C84, 0), (0x2C85, 0x2C85, 1), (0x2C86, 0x2C86, 0),
+    (0x2C87, 0x2C87, 1), (0x2C88, 0x2C88, 0), (0x2C89, 0x2C89, 1), (0x2C8A, 0x2C8A, 0),
+    (0x2C8B, 0x2C8B, 1), (0x2C8C, 0x2C8C, 0), (0x2C8D, 0x2C8D, 1), (0x2C8E, 0x2C8E, 0),
+    (0x2C8F, 0x2C8F, 1), (0x2C90, 0x2C90, 0), (0x2C91, 0x2C91, 1), (0x2C92, 0x2C92, 0),
+    (0x2C93, 0x2C93, 1), (0x2C94, 0x2C94, 0), (0x2C95, 0x2C95, 1), (0x2C96, 0x2C96, 0),
+    (0x2C97, 0x2C97, 1), (0x2C98, 0x2C98, 0), (0x2C99, 0x2C99, 1), (0x2C9A, 0x2C9A, 0),
+    (0x2C9B, 0x2C9B, 1), (0x2C9C, 0x2C9C, 0), (0x2C9D, 0x2C9D, 1), (0x2C9E, 0x2C9E, 0),
+    (0x2C9F, 0x2C9F, 1), (0x2CA0, 0x2CA0, 0), (0x2CA1, 0x2CA1, 1), (0x2CA2, 0x2CA2, 0),
+    (0x2CA3, 0x2CA3, 1), (0x2CA4, 0x2CA4, 0), (0x2CA5, 0x2CA5, 1), (0x2CA6, 0x2CA6, 0),
+    (0x2CA7, 0x2CA7, 1), (0x2CA8, 0x2CA8, 0), (0x2CA9, 0x2CA9, 1), (0x2CAA, 0x2CAA, 0),
+    (0x2CAB, 0x2CAB, 1), (0x2CAC, 0x2CAC, 0), (0x2CAD, 0x2CAD, 1), (0x2CAE, 0x2CAE, 0),
+    (0x2CAF, 0x2CAF, 1), (0x2CB0, 0x2CB0, 0), (0x2CB1, 0x2CB1, 1), (0x2CB2, 0x2CB2, 0),
+    (0x2CB3, 0x2CB3, 1), (0x2CB4, 0x2CB4, 0), (0x2CB5, 0x2CB5, 1), (0x2CB6, 0x2CB6, 0),
+    (0x2CB7, 0x2CB7, 1), (0x2CB8, 0x2CB8, 0), (0x2CB9, 0x2CB9, 1), (0x2CBA, 0x2CBA, 0),
+    (0x2CBB, 0x2CBB, 1), (0x2CBC, 0x2CBC, 0), (0x2CBD, 0x2CBD, 1), (0x2CBE, 0x2CBE, 0),
+    (0x2CBF, 0x2CBF, 1), (0x2CC0, 0x2CC0, 0), (0x2CC1, 0x2CC1, 1), (0x2CC2, 0x2CC2, 0),
+    (0x2CC3, 0x2CC3, 1), (0x2CC4, 0x2CC4, 0), (0x2CC5, 0x2CC5, 1), (0x2CC6, 0x2CC6, 0),
+    (0x2CC7, 0x2CC7, 1), (0x2CC8, 0x2CC8, 0), (0x2CC9, 0x2CC9, 1), (0x2CCA, 0x2CCA, 0),
+    (0x2CCB, 0x2CCB, 1), (0x2CCC, 0x2CCC, 0), (0x2CCD, 0x2CCD, 1), (0x2CCE, 0x2CCE, 0),
+    (0x2CCF, 0x2CCF, 1), (0x2CD0, 0x2CD0, 0), (0x2CD1, 0x2CD1, 1), (0x2CD2, 0x2CD2, 0),
+    (0x2CD3, 0x2CD3, 1), (0x2CD4, 0x2CD4, 0), (0x2CD5, 0x2CD5, 1), (0x2CD6, 0x2CD6, 0),
+    (0x2CD7, 0x2CD7, 1), (0x2CD8, 0x2CD8, 0), (0x2CD9, 0x2CD9, 1), (0x2CDA, 0x2CDA, 0),
+    (0x2CDB, 0x2CDB, 1), (0x2CDC, 0x2CDC, 0), (0x2CDD, 0x2CDD, 1), (0x2CDE, 0x2CDE, 0),
+    (0x2CDF, 0x2CDF, 1), (0x2CE0, 0x2CE0, 0), (0x2CE1, 0x2CE1, 1), (0x2CE2, 0x2CE2, 0),
+    (0x2CE3, 0x2CE4, 1), (0x2CE5, 0x2CEA, 21), (0x2CEB, 0x2CEB, 0), (0x2CEC, 0x2CEC, 1),
+    (0x2CED, 0x2CED, 0), (0x2CEE, 0x2CEE, 1), (0x2CEF, 0x2CF1, 5), (0x2CF2, 0x2CF2, 0),
+    (0x2CF3, 0x2CF3, 1), (0x2CF9, 0x2CFC, 17), (0x2CFD, 0x2CFD, 10), (0x2CFE, 0x2CFF, 17),
+    (0x2D00, 0x2D25, 1), (0x2D27, 0x2D27, 1), (0x2D2D, 0x2D2D, 1), (0x2D30, 0x2D67, 4),
+    (0x2D6F, 0x2D6F, 3), (0x2D70, 0x2D70, 17), (0x2D7F, 0x2D7F, 5), (0x2D80, 0x2D96, 4),
+    (0x2DA0, 0x2DA6, 4), (0x2DA8, 0x2DAE, 4), (0x2DB0, 0x2DB6, 4), (0x2DB8, 0x2DBE, 4),
+    (0x2DC0, 0x2DC6, 4), (0x2DC8, 0x2DCE, 4), (0x2DD0, 0x2DD6, 4), (0x2DD8, 0x2DDE, 4),
+    (0x2DE0, 0x2DFF, 5), (0x2E00, 0x2E01, 17), (0x2E02, 0x2E02, 15), (0x2E03, 0x2E03, 16),
+    (0x2E04, 0x2E04, 15), (0x2E05, 0x2E05, 16), (0x2E06, 0x2E08, 17), (0x2E09, 0x2E09, 15),
+    (0x2E0A, 0x2E0A, 16), (0x2E0B, 0x2E0B, 17), (0x2E0C, 0x2E0C, 15), (0x2E0D, 0x2E0D, 16),
+    (0x2E0E, 0x2E16, 17), (0x2E17, 0x2E17, 12), (0x2E18, 0x2E19, 17), (0x2E1A, 0x2E1A, 12),
+    (0x2E1B, 0x2E1B, 17), (0x2E1C, 0x2E1C, 15), (0x2E1D, 0x2E1D, 16), (0x2E1E, 0x2E1F, 17),
+    (0x2E20, 0x2E20, 15), (0x2E21, 0x2E21, 16), (0x2E22, 0x2E22, 13), (0x2E23, 0x2E23, 14),
+    (0x2E24, 0x2E24, 13), (0x2E25, 0x2E25, 14), (0x2E26, 0x2E26, 13), (0x2E27, 0x2E27, 14),
+    (0x2E28, 0x2E28, 13), (0x2E29, 0x2E29, 14), (0x2E2A, 0x2E2E, 17), (0x2E2F, 0x2E2F, 3),
+    (0x2E30, 0x2E39, 17), (0x2E3A, 0x2E3B, 12), (0x2E3C, 0x2E3F, 17), (0x2E40, 0x2E40, 12),
+    (0x2E41, 0x2E41, 17), (0x2E42, 0x2E42, 13), (0x2E43, 0x2E4F, 17), (0x2E50, 0x2E51, 21),
+    (0x2E52, 0x2E54, 17), (0x2E55, 0x2E55, 13), (0x2E56, 0x2E56, 14), (0x2E57, 0x2E57, 13),
+    (0x2E58, 0x2E58, 14), (0x2E59, 0x2E59, 13), (0x2E5A, 0x2E5A, 14), (0x2E5B, 0x2E5B, 13),
+    (0x2E5C, 0x2E5C, 14), (0x2E5D, 0x2E5D, 12), (0x2E80, 0x2E99, 21), (0x2E9B, 0x2EF3, 21),
+    (0x2F00, 0x2FD5, 21), (0x2FF0, 0x2FFF, 21), (0x3000, 0x3000, 22), (0x3001, 0x3003, 17),
+    (0x3004, 0x3004, 21), (0x3005, 0x3005, 3), (0x3006, 0x3006, 4), (0x3007, 0x3007, 9),
+    (0x3008, 0x3008, 13), (0x3009, 0x3009, 14), (0x300A, 0x300A, 13), (0x300B, 0x300B, 14),
+    (0x300C, 0x300C, 13), (0x300D, 0x300D, 14), (0x300E, 0x300E, 13), (0x300F, 0x300F, 14),
+    (0x3010, 0x3010, 13), (0x3011, 0x3011, 14), (0x3012, 0x3013, 21), (0x3014, 0x3014, 13),
+    (0x3015, 0x3015, 14), (0x3016, 0x3016, 13), (0x3017, 0x3017, 14), (0x3018, 0x3018, 13),
+    (0x3019, 0x3019, 14), (0x301A, 0x301A, 13), (0x301B, 0x301B, 14), (0x301C, 0x301C, 12),
+    (0x301D, 0x301D, 13), (0x301E, 0x301F, 14), (0x3020, 0x3020, 21), (0x3021, 0x3029, 9),
+    (0x302A, 0x302D, 5), (0x302E, 0x302F, 6), (0x3030, 0x3030, 12), (0x3031, 0x3035, 3),
+    (0x3036, 0x3037, 21), (0x3038, 0x303A, 9), (0x303B, 0x303B, 3), (0x303C, 0x303C, 4),
+    (0x303D, 0x303D, 17), (0x303E, 0x303F, 21), (0x3041, 0x3096, 4), (0x3099, 0x309A, 5),
+    (0x309B, 0x309C, 20), (0x309D, 0x309E, 3), (0x309F, 0x309F, 4), (0x30A0, 0x30A0, 12),
+    (0x30A1, 0x30FA, 4), (0x30FB, 0x30FB, 17), (0x30FC, 0x30FE, 3), (0x30FF, 0x30FF, 4),
+    (0x3105, 0x312F, 4), (0x3131, 0x318E, 4), (0x3190, 0x3191, 21), (0x3192, 0x3195, 10),
+    (0x3196, 0x319F, 21), (0x31A0, 0x31BF, 4), (0x31C0, 0x31E5, 21), (0x31EF, 0x31EF, 21),
+    (0x31F0, 0x31FF, 4), (0x3200, 0x321E, 21), (0x3220, 0x3229, 10), (0x322A, 0x3247, 21),
+    (0x3248, 0x324F, 10), (0x3250, 0x3250, 21), (0x3251, 0x325F, 10), (0x3260, 0x327F, 21),
+    (0x3280, 0x3289, 10), (0x328A, 0x32B0, 21), (0x32B1, 0x32BF, 10), (0x32C0, 0x33FF, 21),
+    (0x3400, 0x4DBF, 4), (0x4DC0, 0x4DFF, 21), (0x4E00, 0xA014, 4), (0xA015, 0xA015, 3),
+    (0xA016, 0xA48C, 4), (0xA490, 0xA4C6, 21), (0xA4D0, 0xA4F7, 4), (0xA4F8, 0xA4FD, 3),
+    (0xA4FE, 0xA4FF, 17), (0xA500, 0xA60B, 4), (0xA60C, 0xA60C, 3), (0xA60D, 0xA60F, 17),
+    (0xA610, 0xA61F, 4), (0xA620, 0xA629, 8), (0xA62A, 0xA62B, 4), (0xA640, 0xA640, 0),
+    (0xA641, 0xA641, 1), (0xA642, 0xA642, 0), (0xA643, 0xA643, 1), (0xA644, 0xA644, 0),
+    (0xA645, 0xA645, 1), (0xA646, 0xA646, 0), (0xA647, 0xA647, 1), (0xA648, 0xA648, 0),
+    (0xA649, 0xA649, 1), (0xA64A, 0xA64A, 0), (0xA64B, 0xA64B, 1), (0xA64C, 0xA64C, 0),
+    (0xA64D, 0xA64D, 1), (0xA64E, 0xA64E, 0), (0xA64F, 0xA64F, 1), (0xA650, 0xA650, 0),
+    (0xA651, 0xA651, 1), (0xA652, 0xA652, 0), (0xA653, 0xA653, 1), (0xA654, 0xA654, 0),
+    (0xA655, 0xA655, 1), (0xA656, 0xA656, 0), (0xA657, 0xA657, 1), (0xA658, 0xA658, 0),
+    (0xA659, 0xA659, 1), (0xA65A, 0xA65A, 0), (0xA65B, 0xA65B, 1), (0xA65C, 0xA65C, 0),
+    (0xA65D, 0xA65D, 1), (0xA65E, 0xA65E, 0), (0xA65F, 0xA65F, 1), (0xA660, 0xA660, 0),
+    (0xA661, 0xA661, 1), (0xA662, 0xA662, 0), (0xA663, 0xA663, 1), (0xA664, 0xA664, 0),
+    (0xA665, 0xA665, 1), (0xA666, 0xA666, 0), (0xA667, 0xA667, 1), (0xA668, 0xA668, 0),
+    (0xA669, 0xA669, 1), (0xA66A, 0xA66A, 0), (0xA66B, 0xA66B, 1), (0xA66C, 0xA66C, 0),
+    (0xA66D, 0xA66D, 1), (0xA66E, 0xA66E, 4), (0xA66F, 0xA66F, 5), (0xA670, 0xA672, 7),
+    (0xA673, 0xA673, 17), (0xA674, 0xA67D, 5), (0xA67E, 0xA67E, 17), (0xA67F, 0xA67F, 3),
+    (0xA680, 0xA680, 0), (0xA681, 0xA681, 1), (0xA682, 0xA682, 0), (0xA683, 0xA683, 1),
+    (0xA684, 0xA684, 0), (0xA685, 0xA685, 1), (0xA686, 0xA686, 0), (0xA687, 0xA687, 1),
+    (0xA688, 0xA688, 0), (0xA689, 0xA689, 1), (0xA68A, 0xA68A, 0), (0xA68B, 0xA68B, 1),
+    (0xA68C, 0xA68C, 0), (0xA68D, 0xA68D, 1), (0xA68E, 0xA68E, 0), (0xA68F, 0xA68F, 1),
+    (0xA690, 0xA690, 0), (0xA691, 0xA691, 1), (0xA692, 0xA692, 0), (0xA693, 0xA693, 1),
+    (0xA694, 0xA694, 0), (0xA695, 0xA695, 1), (0xA696, 0xA696, 0), (0xA697, 0xA697, 1),
+    (0xA698, 0xA698, 0), (0xA699, 0xA699, 1), (0xA69A, 0xA69A, 0), (0xA69B, 0xA69B, 1),
+    (0xA69C, 0xA69D, 3), (0xA69E, 0xA69F, 5), (0xA6A0, 0xA6E5, 4), (0xA6E6, 0xA6EF, 9),
+    (0xA6F0, 0xA6F1, 5), (0xA6F2, 0xA6F7, 17), (0xA700, 0xA716, 20), (0xA717, 0xA71F, 3),
+    (0xA720, 0xA721, 20), (0xA722, 0xA722, 0), (0xA723, 0xA723, 1), (0xA724, 0xA724, 0),
+    (0xA725, 0xA725, 1), (0xA726, 0xA726, 0), (0xA727, 0xA727, 1), (0xA728, 0xA728, 0),
+    (0xA729, 0xA729, 1), (0xA72A, 0xA72A, 0), (0xA72B, 0xA72B, 1), (0xA72C, 0xA72C, 0),
+    (0xA72D, 0xA72D, 1), (0xA72E, 0xA72E, 0), (0xA72F, 0xA731, 1), (0xA732, 0xA732, 0),
+    (0xA733, 0xA733, 1), (0xA734, 0xA734, 0), (0xA735, 0xA735, 1), (0xA736, 0xA736, 0),
+    (0xA737, 0xA737, 1), (0xA738, 0xA738, 0), (0xA739, 0xA739, 1), (0xA73A, 0xA73A, 0),
+    (0xA73B, 0xA73B, 1), (0xA73C, 0xA73C, 0), (0xA73D, 0xA73D, 1), (0xA73E, 0xA73E, 0),
+    (0xA73F, 0xA73F, 1), (0xA740, 0xA740, 0), (0xA741, 0xA741, 1), (0xA742, 0xA742, 0),
+    (0xA743, 0xA743, 1), (0xA744, 0xA744, 0), (0xA745, 0xA745, 1), (0xA746, 0xA746, 0),
+    (0xA747, 0xA747, 1), (0xA748, 0xA748, 0), (0xA749, 0xA749, 1), (0xA74A, 0xA74A, 0),
+    (0xA74B, 0xA74B, 1), (0xA74C, 0xA74C, 0), (0xA74D, 0xA74D, 1), (0xA74E, 0xA74E, 0),
+    (0xA74F, 0xA74F, 1), (0xA750, 0xA750, 0), (0xA751, 0xA751, 1), (0xA752, 0xA752, 0),
+    (0xA753, 0xA753, 1), (0xA754, 0xA754, 0), (0xA755, 0xA755, 1), (0xA756, 0xA756, 0),
+    (0xA757, 0xA757, 1), (0xA758, 0xA758, 0), (0xA759, 0xA759, 1), (0xA75A, 0xA75A, 0),
+    (0xA75B, 0xA75B, 1), (0xA75C, 0xA75C, 0), (0xA75D, 0xA75D, 1), (0xA75E, 0xA75E, 0),
+    (0xA75F, 0xA75F, 1), (0xA760, 0xA760, 0), (0xA761, 0xA761, 1), (0xA762, 0xA762, 0),
+    (0xA763, 0xA763, 1), (0xA764, 0xA764, 0), (0xA765, 0xA765, 1), (0xA766, 0xA766, 0),
+    (0xA767, 0xA767, 1), (0xA768, 0xA768, 0), (0xA769, 0xA769, 1), (0xA76A, 0xA76A, 0),
+    (0xA76B, 0xA76B, 1), (0xA76C, 0xA76C, 0), (0xA76D, 0xA76D, 1), (0xA76E, 0xA76E, 0),
+    (0xA76F, 0xA76F, 1), (0xA770, 0xA770, 3), (0xA771, 0xA778, 1), (0xA779, 0xA779, 0),
+    (0xA77A, 0xA77A, 1), (0xA77B, 0xA77B, 0), (0xA77C, 0xA77C, 1), (0xA77D, 0xA77E, 0),
+    (0xA77F, 0xA77F, 1), (0xA780, 0xA780, 0), (0xA781, 0xA781, 1), (0xA782, 0xA782, 0),
+    (0xA783, 0xA783, 1), (0xA784, 0xA784, 0), (0xA785, 0xA785, 1), (0xA786, 0xA786, 0),
+    (0xA787, 0xA787, 1), (0xA788, 0xA788, 3), (0xA789, 0xA78A, 20), (0xA78B, 0xA78B, 0),
+    (0xA78C, 0xA78C, 1), (0xA78D, 0xA78D, 0), (0xA78E, 0xA78E, 1), (0xA78F, 0xA78F, 4),
+    (0xA790, 0xA790, 0), (0xA791, 0xA791, 1), (0xA792, 0xA792, 0), (0xA793, 0xA795, 1),
+    (0xA796, 0xA796, 0), (0xA797, 0xA797, 1), (0xA798, 0xA798, 0), (0xA799, 0xA799, 1),
+    (0xA79A, 0xA79A, 0), (0xA79B, 0xA79B, 1), (0xA79C, 0xA79C, 0), (0xA79D, 0xA79D, 1),
+    (0xA79E, 0xA79E, 0), (0xA79F, 0xA79F, 1), (0xA7A0, 0xA7A0, 0), (0xA7A1, 0xA7A1, 1),
+    (0xA7A2, 0xA7A2, 0), (0xA7A3, 0xA7A3, 1), (0xA7A4, 0xA7A4, 0), (0xA7A5, 0xA7A5, 1),
+    (0xA7A6, 0xA7A6, 0), (0xA7A7, 0xA7A7, 1), (0xA7A8, 0xA7A8, 0), (0xA7A9, 0xA7A9, 1),
+    (0xA7AA, 0xA7AE, 0), (0xA7AF, 0xA7AF, 1), (0xA7B0, 0xA7B4, 0), (0xA7B5, 0xA7B5, 1),
+    (0xA7B6, 0xA7B6, 0), (0xA7B7, 0xA7B7, 1), (0xA7B8, 0xA7B8, 0), (0xA7B9, 0xA7B9, 1),
+    (0xA7BA, 0xA7BA, 0), (0xA7BB, 0xA7BB, 1), (0xA7BC, 0xA7BC, 0), (0xA7BD, 0xA7BD, 1),
+    (0xA7BE, 0xA7BE, 0), (0xA7BF, 0xA7BF, 1), (0xA7C0, 0xA7C0, 0), (0xA7C1, 0xA7C1, 1),
+    (0xA7C2, 0xA7C2, 0), (0xA7C3, 0xA7C3, 1), (0xA7C4, 0xA7C7, 0), (0xA7C8, 0xA7C8, 1),
+    (0xA7C9, 0xA7C9, 0), (0xA7CA, 0xA7CA, 1), (0xA7CB, 0xA7CC, 0), (0xA7CD, 0xA7CD, 1),
+    (0xA7CE, 0xA7CE, 0), (0xA7CF, 0xA7CF, 1), (0xA7D0, 0xA7D0, 0), (0xA7D1, 0xA7D1, 1),
+    (0xA7D2, 0xA7D2, 0), (0xA7D3, 0xA7D3, 1), (0xA7D4, 0xA7D4, 0), (0xA7D5, 0xA7D5, 1),
+    (0xA7D6, 0xA7D6, 0), (0xA7D7, 0xA7D7, 1), (0xA7D8, 0xA7D8, 0), (0xA7D9, 0xA7D9, 1),
+    (0xA7DA, 0xA7DA, 0), (0xA7DB, 0xA7DB, 1), (0xA7DC, 0xA7DC, 0), (0xA7F1, 0xA7F4, 3),
+    (0xA7F5, 0xA7F5, 0), (0xA7F6, 0xA7F6, 1), (0xA7F7, 0xA7F7, 4), (0xA7F8, 0xA7F9, 3),
+    (0xA7FA, 0xA7FA, 1), (0xA7FB, 0xA801, 4), (0xA802, 0xA802, 5), (0xA803, 0xA805, 4),
+    (0xA806, 0xA806, 5), (0xA807, 0xA80A, 4), (0xA80B, 0xA80B, 5), (0xA80C, 0xA822, 4),
+    (0xA823, 0xA824, 6), (0xA825, 0xA826, 5), (0xA827, 0xA827, 6), (0xA828, 0xA82B, 21),
+    (0xA82C, 0xA82C, 5), (0xA830, 0xA835, 10), (0xA836, 0xA837, 21), (0xA838, 0xA838, 19),
+    (0xA839, 0xA839, 21), (0xA840, 0xA873, 4), (0xA874, 0xA877, 17), (0xA880, 0xA881, 6),
+    (0xA882, 0xA8B3, 4), (0xA8B4, 0xA8C3, 6), (0xA8C4, 0xA8C5, 5), (0xA8CE, 0xA8CF, 17),
+    (0xA8D0, 0xA8D9, 8), (0xA8E0, 0xA8F1, 5), (0xA8F2, 0xA8F7, 4), (0xA8F8, 0xA8FA, 17),
+    (0xA8FB, 0xA8FB, 4), (0xA8FC, 0xA8FC, 17), (0xA8FD, 0xA8FE, 4), (0xA8FF, 0xA8FF, 5),
+    (0xA900, 0xA909, 8), (0xA90A, 0xA925, 4), (0xA926, 0xA92D, 5), (0xA92E, 0xA92F, 17),
+    (0xA930, 0xA946, 4), (0xA947, 0xA951, 5), (0xA952, 0xA953, 6), (0xA95F, 0xA95F, 17),
+    (0xA960, 0xA97C, 4), (0xA980, 0xA982, 5), (0xA983, 0xA983, 6), (0xA984, 0xA9B2, 4),
+    (0xA9B3, 0xA9B3, 5), (0xA9B4, 0xA9B5, 6), (0xA9B6, 0xA9B9, 5), (0xA9BA, 0xA9BB, 6),
+    (0xA9BC, 0xA9BD, 5), (0xA9BE, 0xA9C0, 6), (0xA9C1, 0xA9CD, 17), (0xA9CF, 0xA9CF, 3),
+    (0xA9D0, 0xA9D9, 8), (0xA9DE, 0xA9DF, 17), (0xA9E0, 0xA9E4, 4), (0xA9E5, 0xA9E5, 5),
+    (0xA9E6, 0xA9E6, 3), (0xA9E7, 0xA9EF, 4), (0xA9F0, 0xA9F9, 8), (0xA9FA, 0xA9FE, 4),
+    (0xAA00, 0xAA28, 4), (0xAA29, 0xAA2E, 5), (0xAA2F, 0xAA30, 6), (0xAA31, 0xAA32, 5),
+    (0xAA33, 0xAA34, 6), (0xAA35, 0xAA36, 5), (0xAA40, 0xAA42, 4), (0xAA43, 0xAA43, 5),
+    (0xAA44, 0xAA4B, 4), (0xAA4C, 0xAA4C, 5), (0xAA4D, 0xAA4D, 6), (0xAA50, 0xAA59, 8),
+    (0xAA5C, 0xAA5F, 17), (0xAA60, 0xAA6F, 4), (0xAA70, 0xAA70, 3), (0xAA71, 0xAA76, 4),
+    (0xAA77, 0xAA79, 21), (0xAA7A, 0xAA7A, 4), (0xAA7B, 0xAA7B, 6), (0xAA7C, 0xAA7C, 5),
+    (0xAA7D, 0xAA7D, 6), (0xAA7E, 0xAAAF, 4), (0xAAB0, 0xAAB0, 5), (0xAAB1, 0xAAB1, 4),
+    (0xAAB2, 0xAAB4, 5), (0xAAB5, 0xAAB6, 4), (0xAAB7, 0xAAB8, 5), (0xAAB9, 0xAABD, 4),
+    (0xAABE, 0xAABF, 5), (0xAAC0, 0xAAC0, 4), (0xAAC1, 0xAAC1, 5), (0xAAC2, 0xAAC2, 4),
+    (0xAADB, 0xAADC, 4), (0xAADD, 0xAADD, 3), (0xAADE, 0xAADF, 17), (0xAAE0, 0xAAEA, 4),
+    (0xAAEB, 0xAAEB, 6), (0xAAEC, 0xAAED, 5), (0xAAEE, 0xAAEF, 6), (0xAAF0, 0xAAF1, 17),
+    (0xAAF2, 0xAAF2, 4), (0xAAF3, 0xAAF4, 3), (0xAAF5, 0xAAF5, 6), (0xAAF6, 0xAAF6, 5),
+    (0xAB01, 0xAB06, 4), (0xAB09, 0xAB0E, 4), (0xAB11, 0xAB16, 4), (0xAB20, 0xAB26, 4),
+    (0xAB28, 0xAB2E, 4), (0xAB30, 0xAB5A, 1), (0xAB5B, 0xAB5B, 20), (0xAB5C, 0xAB5F, 3),
+    (0xAB60, 0xAB68, 1), (0xAB69, 0xAB69, 3), (0xAB6A, 0xAB6B, 20), (0xAB70, 0xABBF, 1),
+    (0xABC0, 0xABE2, 4), (0xABE3, 0xABE4, 6), (0xABE5, 0xABE5, 5), (0xABE6, 0xABE7, 6),
+    (0xABE8, 0xABE8, 5), (0xABE9, 0xABEA, 6), (0xABEB, 0xABEB, 17), (0xABEC, 0xABEC, 6),
+    (0xABED, 0xABED, 5), (0xABF0, 0xABF9, 8), (0xAC00, 0xD7A3, 4), (0xD7B0, 0xD7C6, 4),
+    (0xD7CB, 0xD7FB, 4), (0xD800, 0xDFFF, 27), (0xE000, 0xF8FF, 28), (0xF900, 0xFA6D, 4),
+    (0xFA70, 0xFAD9, 4), (0xFB00, 0xFB06, 1), (0xFB13, 0xFB17, 1), (0xFB1D, 0xFB1D, 4),
+    (0xFB1E, 0xFB1E, 5), (0xFB1F, 0xFB28, 4), (0xFB29, 0xFB29, 18), (0xFB2A, 0xFB36, 4),
+    (0xFB38, 0xFB3C, 4), (0xFB3E, 0xFB3E, 4), (0xFB40, 0xFB41, 4), (0xFB43, 0xFB44, 4),
+    (0xFB46, 0xFBB1, 4), (0xFBB2, 0xFBC2, 20), (0xFBC3, 0xFBD2, 21), (0xFBD3, 0xFD3D, 4),
+    (0xFD3E, 0xFD3E, 14), (0xFD3F, 0xFD3F, 13), (0xFD40, 0xFD4F, 21), (0xFD50, 0xFD8F, 4),
+    (0xFD90, 0xFD91, 21), (0xFD92, 0xFDC7, 4), (0xFDC8, 0xFDCF, 21), (0xFDF0, 0xFDFB, 4),
+    (0xFDFC, 0xFDFC, 19), (0xFDFD, 0xFDFF, 21), (0xFE00, 0xFE0F, 5), (0xFE10, 0xFE16, 17),
+    (0xFE17, 0xFE17, 13), (0xFE18, 0xFE18, 14), (0xFE19, 0xFE19, 17), (0xFE20, 0xFE2F, 5),
+    (0xFE30, 0xFE30, 17), (0xFE31, 0xFE32, 12), (0xFE33, 0xFE34, 11), (0xFE35, 0xFE35, 13),
+    (0xFE36, 0xFE36, 14), (0xFE37, 0xFE37, 13), (0xFE38, 0xFE38, 14), (0xFE39, 0xFE39, 13),
+    (0xFE3A, 0xFE3A, 14), (0xFE3B, 0xFE3B, 13), (0xFE3C, 0xFE3C, 14), (0xFE3D, 0xFE3D, 13),
+    (0xFE3E, 0xFE3E, 14), (0xFE3F, 0xFE3F, 13), (0xFE40, 0xFE40, 14), (0xFE41, 0xFE41, 13),
+    (0xFE42, 0xFE42, 14), (0xFE43, 0xFE43, 13), (0xFE44, 0xFE44, 14), (0xFE45, 0xFE46, 17),
+    (0xFE47, 0xFE47, 13), (0xFE48, 0xFE48, 14), (0xFE49, 0xFE4C, 17), (0xFE4D, 0xFE4F, 11),
+    (0xFE50, 0xFE52, 17), (0xFE54, 0xFE57, 17), (0xFE58, 0xFE58, 12), (0xFE59, 0xFE59, 13),
+    (0xFE5A, 0xFE5A, 14), (0xFE5B, 0xFE5B, 13), (0xFE5C, 0xFE5C, 14), (0xFE5D, 0xFE5D, 13),
+    (0xFE5E, 0xFE5E, 14), (0xFE5F, 0xFE61, 17), (0xFE62, 0xFE62, 18), (0xFE63, 0xFE63, 12),
+    (0xFE64, 0xFE66, 18), (0xFE68, 0xFE68, 17), (0xFE69, 0xFE69, 19), (0xFE6A, 0xFE6B, 17),
+    (0xFE70, 0xFE74, 4), (0xFE76, 0xFEFC, 4), (0xFEFF, 0xFEFF, 26), (0xFF01, 0xFF03, 17),
+    (0xFF04, 0xFF04, 19), (0xFF05, 0xFF07, 17), (0xFF08, 0xFF08, 13), (0xFF09, 0xFF09, 14),
+    (0xFF0A, 0xFF0A, 17), (0xFF0B, 0xFF0B, 18), (0xFF0C, 0xFF0C, 17), (0xFF0D, 0xFF0D, 12),
+    (0xFF0E, 0xFF0F, 17), (0xFF10, 0xFF19, 8), (0xFF1A, 0xFF1B, 17), (0xFF1C, 0xFF1E, 18),
+    (0xFF1F, 0xFF20, 17), (0xFF21, 0xFF3A, 0), (0xFF3B, 0xFF3B, 13), (0xFF3C, 0xFF3C, 17),
+    (0xFF3D, 0xFF3D, 14), (0xFF3E, 0xFF3E, 20), (0xFF3F, 0xFF3F, 11), (0xFF40, 0xFF40, 20),
+    (0xFF41, 0xFF5A, 1), (0xFF5B, 0xFF5B, 13), (0xFF5C, 0xFF5C, 18), (0xFF5D, 0xFF5D, 14),
+    (0xFF5E, 0xFF5E, 18), (0xFF5F, 0xFF5F, 13), (0xFF60, 0xFF60, 14), (0xFF61, 0xFF61, 17),
+    (0xFF62, 0xFF62, 13), (0xFF63, 0xFF63, 14), (0xFF64, 0xFF65, 17), (0xFF66, 0xFF6F, 4),
+    (0xFF70, 0xFF70, 3), (0xFF71, 0xFF9D, 4), (0xFF9E, 0xFF9F, 3), (0xFFA0, 0xFFBE, 4),
+    (0xFFC2, 0xFFC7, 4), (0xFFCA, 0xFFCF, 4), (0xFFD2, 0xFFD7, 4), (0xFFDA, 0xFFDC, 4),
+    (0xFFE0, 0xFFE1, 19), (0xFFE2, 0xFFE2, 18), (0xFFE3, 0xFFE3, 20), (0xFFE4, 0xFFE4, 21),
+    (0xFFE5, 0xFFE6, 19), (0xFFE8, 0xFFE8, 21), (0xFFE9, 0xFFEC, 18), (0xFFED, 0xFFEE, 21),
+    (0xFFF9, 0xFFFB, 26), (0xFFFC, 0xFFFD, 21), (0x10000, 0x1000B, 4), (0x1000D, 0x10026, 4),
+    (0x10028, 0x1003A, 4), (0x1003C, 0x1003D, 4), (0x1003F, 0x1004D, 4), (0x10050, 0x1005D, 4),
+    (0x10080, 0x100FA, 4), (0x10100, 0x10102, 17), (0x10107, 0x10133, 10), (0x10137, 0x1013F, 21),
+    (0x10140, 0x10174, 9), (0x10175, 0x10178, 10), (0x10179, 0x10189, 21), (0x1018A, 0x1018B, 10),
+    (0x1018C, 0x1018E, 21), (0x10190, 0x1019C, 21), (0x101A0, 0x101A0, 21), (0x101D0, 0x101FC, 21),
+    (0x101FD, 0x101FD, 5), (0x10280, 0x1029C, 4), (0x102A0, 0x102D0, 4), (0x102E0, 0x102E0, 5),
+    (0x102E1, 0x102FB, 10), (0x10300, 0x1031F, 4), (0x10320, 0x10323, 10), (0x1032D, 0x10340, 4),
+    (0x10341, 0x10341, 9), (0x10342, 0x10349, 4), (0x1034A, 0x1034A, 9), (0x10350, 0x10375, 4),
+    (0x10376, 0x1037A, 5), (0x10380, 0x1039D, 4), (0x1039F, 0x1039F, 17), (0x103A0, 0x103C3, 4),
+    (0x103C8, 0x103CF, 4), (0x103D0, 0x103D0, 17), (0x103D1, 0x103D5, 9), (0x10400, 0x10427, 0),
+    (0x10428, 0x1044F, 1), (0x10450, 0x1049D, 4), (0x104A0, 0x104A9, 8), (0x104B0, 0x104D3, 0),
+    (0x104D8, 0x104FB, 1), (0x10500, 0x10527, 4), (0x10530, 0x10563, 4), (0x1056F, 0x1056F, 17),
+    (0x10570, 0x1057A, 0), (0x1057C, 0x1058A, 0), (0x1058C, 0x10592, 0), (0x10594, 0x10595, 0),
+    (0x10597, 0x105A1, 1), (0x105A3, 0x105B1, 1), (0x105B3, 0x105B9, 1), (0x105BB, 0x105BC, 1),
+    (0x105C0, 0x105F3, 4), (0x10600, 0x10736, 4), (0x10740, 0x10755, 4), (0x10760, 0x10767, 4),
+    (0x10780, 0x10785, 3), (0x10787, 0x107B0, 3), (0x107B2, 0x107BA, 3), (0x10800, 0x10805, 4),
+    (0x10808, 0x10808, 4), (0x1080A, 0x10835, 4), (0x10837, 0x10838, 4), (0x1083C, 0x1083C, 4),
+    (0x1083F, 0x10855, 4), (0x10857, 0x10857, 17), (0x10858, 0x1085F, 10), (0x10860, 0x10876, 4),
+    (0x10877, 0x10878, 21), (0x10879, 0x1087F, 10), (0x10880, 0x1089E, 4), (0x108A7, 0x108AF, 10),
+    (0x108E0, 0x108F2, 4), (0x108F4, 0x108F5, 4), (0x108FB, 0x108FF, 10), (0x10900, 0x10915, 4),
+    (0x10916, 0x1091B, 10), (0x1091F, 0x1091F, 17), (0x10920, 0x10939, 4), (0x1093F, 0x1093F, 17),
+    (0x10940, 0x10959, 4), (0x10980, 0x109B7, 4), (0x109BC, 0x109BD, 10), (0x109BE, 0x109BF, 4),
+    (0x109C0, 0x109CF, 10), (0x109D2, 0x109FF, 10), (0x10A00, 0x10A00, 4), (0x10A01, 0x10A03, 5),
+    (0x10A05, 0x10A06, 5), (0x10A0C, 0x10A0F, 5), (0x10A10, 0x10A13, 4), (0x10A15, 0x10A17, 4),
+    (0x10A19, 0x10A35, 4), (0x10A38, 0x10A3A, 5), (0x10A3F, 0x10A3F, 5), (0x10A40, 0x10A48, 10),
+    (0x10A50, 0x10A58, 17), (0x10A60, 0x10A7C, 4), (0x10A7D, 0x10A7E, 10), (0x10A7F, 0x10A7F, 17),
+    (0x10A80, 0x10A9C, 4), (0x10A9D, 0x10A9F, 10), (0x10AC0, 0x10AC7, 4), (0x10AC8, 0x10AC8, 21),
+    (0x10AC9, 0x10AE4, 4), (0x10AE5, 0x10AE6, 5), (0x10AEB, 0x10AEF, 10), (0x10AF0, 0x10AF6, 17),
+    (0x10B00, 0x10B35, 4), (0x10B39, 0x10B3F, 17), (0x10B40, 0x10B55, 4), (0x10B58, 0x10B5F, 10),
+    (0x10B60, 0x10B72, 4), (0x10B78, 0x10B7F, 10), (0x10B80, 0x10B91, 4), (0x10B99, 0x10B9C, 17),
+    (0x10BA9, 0x10BAF, 10), (0x10C00, 0x10C48, 4), (0x10C80, 0x10CB2, 0), (0x10CC0, 0x10CF2, 1),
+    (0x10CFA, 0x10CFF, 10), (0x10D00, 0x10D23, 4), (0x10D24, 0x10D27, 5), (0x10D30, 0x10D39, 8),
+    (0x10D40, 0x10D49, 8), (0x10D4A, 0x10D4D, 4), (0x10D4E, 0x10D4E, 3), (0x10D4F, 0x10D4F, 4),
+    (0x10D50, 0x10D65, 0), (0x10D69, 0x10D6D, 5), (0x10D6E, 0x10D6E, 12), (0x10D6F, 0x10D6F, 3),
+    (0x10D70, 0x10D85, 1), (0x10D8E, 0x10D8F, 18), (0x10E60, 0x10E7E, 10), (0x10E80, 0x10EA9, 4),
+    (0x10EAB, 0x10EAC, 5), (0x10EAD, 0x10EAD, 12), (0x10EB0, 0x10EB1, 4), (0x10EC2, 0x10EC4, 4),
+    (0x10EC5, 0x10EC5, 3), (0x10EC6, 0x10EC7, 4), (0x10ED0, 0x10ED0, 17), (0x10ED1, 0x10ED8, 21),
+    (0x10EFA, 0x10EFF, 5), (0x10F00, 0x10F1C, 4), (0x10F1D, 0x10F26, 10), (0x10F27, 0x10F27, 4),
+    (0x10F30, 0x10F45, 4), (0x10F46, 0x10F50, 5), (0x10F51, 0x10F54, 10), (0x10F55, 0x10F59, 17),
+    (0x10F70, 0x10F81, 4), (0x10F82, 0x10F85, 5), (0x10F86, 0x10F89, 17), (0x10FB0, 0x10FC4, 4),
+    (0x10FC5, 0x10FCB, 10), (0x10FE0, 0x10FF6, 4), (0x11000, 0x11000, 6), (0x11001, 0x11001, 5),
+    (0x11002, 0x11002, 6), (0x11003, 0x11037, 4), (0x11038, 0x11046, 5), (0x11047, 0x1104D, 17),
+    (0x11052, 0x11065, 10), (0x11066, 0x1106F, 8), (0x11070, 0x11070, 5), (0x11071, 0x11072, 4),
+    (0x11073, 0x11074, 5), (0x11075, 0x11075, 4), (0x1107F, 0x11081, 5), (0x11082, 0x11082, 6),
+    (0x11083, 0x110AF, 4), (0x110B0, 0x110B2, 6), (0x110B3, 0x110B6, 5), (0x110B7, 0x110B8, 6),
+    (0x110B9, 0x110BA, 5), (0x110BB, 0x110BC, 17), (0x110BD, 0x110BD, 26), (0x110BE, 0x110C1, 17),
+    (0x110C2, 0x110C2, 5), (0x110CD, 0x110CD, 26), (0x110D0, 0x110E8, 4), (0x110F0, 0x110F9, 8),
+    (0x11100, 0x11102, 5), (0x11103, 0x11126, 4), (0x11127, 0x1112B, 5), (0x1112C, 0x1112C, 6),
+    (0x1112D, 0x11134, 5), (0x11136, 0x1113F, 8), (0x11140, 0x11143, 17), (0x11144, 0x11144, 4),
+    (0x11145, 0x11146, 6), (0x11147, 0x11147, 4), (0x11150, 0x11172, 4), (0x11173, 0x11173, 5),
+    (0x11174, 0x11175, 17), (0x11176, 0x11176, 4), (0x11180, 0x11181, 5), (0x11182, 0x11182, 6),
+    (0x11183, 0x111B2, 4), (0x111B3, 0x111B5, 6), (0x111B6, 0x111BE, 5), (0x111BF, 0x111C0, 6),
+    (0x111C1, 0x111C4, 4), (0x111C5, 0x111C8, 17), (0x111C9, 0x111CC, 5), (0x111CD, 0x111CD, 17),
+    (0x111CE, 0x111CE, 6), (0x111CF, 0x111CF, 5), (0x111D0, 0x111D9, 8), (0x111DA, 0x111DA, 4),
+    (0x111DB, 0x111DB, 17), (0x111DC, 0x111DC, 4), (0x111DD, 0x111DF, 17), (0x111E1, 0x111F4, 10),
+    (0x11200, 0x11211, 4), (0x11213, 0x1122B, 4), (0x1122C, 0x1122E, 6), (0x1122F, 0x11231, 5),
+    (0x11232, 0x11233, 6), (0x11234, 0x11234, 5), (0x11235, 0x11235, 6), (0x11236, 0x11237, 5),
+    (0x11238, 0x1123D, 17), (0x1123E, 0x1123E, 5), (0x1123F, 0x11240, 4), (0x11241, 0x11241, 5),
+    (0x11280, 0x11286, 4), (0x11288, 0x11288, 4), (0x1128A, 0x1128D, 4), (0x1128F, 0x1129D, 4),
+    (0x1129F, 0x112A8, 4), (0x112A9, 0x112A9, 17), (0x112B0, 0x112DE, 4), (0x112DF, 0x112DF, 5),
+    (0x112E0, 0x112E2, 6), (0x112E3, 0x112EA, 5), (0x112F0, 0x112F9, 8), (0x11300, 0x11301, 5),
+    (0x11302, 0x11303, 6), (0x11305, 0x1130C, 4), (0x1130F, 0x11310, 4), (0x11313, 0x11328, 4),
+    (0x1132A, 0x11330, 4), (0x11332, 0x11333, 4), (0x11335, 0x11339, 4), (0x1133B, 0x1133C, 5),
+    (0x1133D, 0x1133D, 4), (0x1133E, 0x1133F, 6), (0x11340, 0x11340, 5), (0x11341, 0x11344, 6),
+    (0x11347, 0x11348, 6), (0x1134B, 0x1134D, 6), (0x11350, 0x11350, 4), (0x11357, 0x11357, 6),
+    (0x1135D, 0x11361, 4), (0x11362, 0x11363, 6), (0x11366, 0x1136C, 5), (0x11370, 0x11374, 5),
+    (0x11380, 0x11389, 4), (0x1138B, 0x1138B, 4), (0x1138E, 0x1138E, 4), (0x11390, 0x113B5, 4),
+    (0x113B7, 0x113B7, 4), (0x113B8, 0x113BA, 6), (0x113BB, 0x113C0, 5), (0x113C2, 0x113C2, 6),
+    (0x113C5, 0x113C5, 6), (0x113C7, 0x113CA, 6), (0x113CC, 0x113CD, 6), (0x113CE, 0x113CE, 5),
+    (0x113CF, 0x113CF, 6), (0x113D0, 0x113D0, 5), (0x113D1, 0x113D1, 4), (0x113D2, 0x113D2, 5),
+    (0x113D3, 0x113D3, 4), (0x113D4, 0x113D5, 17), (0x113D7, 0x113D8, 17), (0x113E1, 0x113E2, 5),
+    (0x11400, 0x11434, 4), (0x11435, 0x11437, 6), (0x11438, 0x1143F, 5), (0x11440, 0x11441, 6),
+    (0x11442, 0x11444, 5), (0x11445, 0x11445, 6), (0x11446, 0x11446, 5), (0x11447, 0x1144A, 4),
+    (0x1144B, 0x1144F, 17), (0x11450, 0x11459, 8), (0x1145A, 0x1145B, 17), (0x1145D, 0x1145D, 17),
+    (0x1145E, 0x1145E, 5), (0x1145F, 0x11461, 4), (0x11480, 0x114AF, 4), (0x114B0, 0x114B2, 6),
+    (0x114B3, 0x114B8, 5), (0x114B9, 0x114B9, 6), (0x114BA, 0x114BA, 5), (0x114BB, 0x114BE, 6),
+    (0x114BF, 0x114C0, 5), (0x114C1, 0x114C1, 6), (0x114C2, 0x114C3, 5), (0x114C4, 0x114C5, 4),
+    (0x114C6, 0x114C6, 17), (0x114C7, 0x114C7, 4), (0x114D0, 0x114D9, 8), (0x11580, 0x115AE, 4),
+    (0x115AF, 0x115B1, 6), (0x115B2, 0x115B5, 5), (0x115B8, 0x115BB, 6), (0x115BC, 0x115BD, 5),
+    (0x115BE, 0x115BE, 6), (0x115BF, 0x115C0, 5), (0x115C1, 0x115D7, 17), (0x115D8, 0x115DB, 4),
+    (0x115DC, 0x115DD, 5), (0x11600, 0x1162F, 4), (0x11630, 0x11632, 6), (0x11633, 0x1163A, 5),
+    (0x1163B, 0x1163C, 6), (0x1163D, 0x1163D, 5), (0x1163E, 0x1163E, 6), (0x1163F, 0x11640, 5),
+    (0x11641, 0x11643, 17), (0x11644, 0x11644, 4), (0x11650, 0x11659, 8), (0x11660, 0x1166C, 17),
+    (0x11680, 0x116AA, 4), (0x116AB, 0x116AB, 5), (0x116AC, 0x116AC, 6), (0x116AD, 0x116AD, 5),
+    (0x116AE, 0x116AF, 6), (0x116B0, 0x116B5, 5), (0x116B6, 0x116B6, 6), (0x116B7, 0x116B7, 5),
+    (0x116B8, 0x116B8, 4), (0x116B9, 0x116B9, 17), (0x116C0, 0x116C9, 8), (0x116D0, 0x116E3, 8),
+    (0x11700, 0x1171A, 4), (0x1171D, 0x1171D, 5), (0x1171E, 0x1171E, 6), (0x1171F, 0x1171F, 5),
+    (0x11720, 0x11721, 6), (0x11722, 0x11725, 5), (0x11726, 0x11726, 6), (0x11727, 0x1172B, 5),
+    (0x11730, 0x11739, 8), (0x1173A, 0x1173B, 10), (0x1173C, 0x1173E, 17), (0x1173F, 0x1173F, 21),
+    (0x11740, 0x11746, 4), (0x11800, 0x1182B, 4), (0x1182C, 0x1182E, 6), (0x1182F, 0x11837, 5),
+    (0x11838, 0x11838, 6), (0x11839, 0x1183A, 5), (0x1183B, 0x1183B, 17), (0x118A0, 0x118BF, 0),
+    (0x118C0, 0x118DF, 1), (0x118E0, 0x118E9, 8), (0x118EA, 0x118F2, 10), (0x118FF, 0x11906, 4),
+    (0x11909, 0x11909, 4), (0x1190C, 0x11913, 4), (0x11915, 0x11916, 4), (0x11918, 0x1192F, 4),
+    (0x11930, 0x11935, 6), (0x11937, 0x11938, 6), (0x1193B, 0x1193C, 5), (0x1193D, 0x1193D, 6),
+    (0x1193E, 0x1193E, 5), (0x1193F, 0x1193F, 4), (0x11940, 0x11940, 6), (0x11941, 0x11941, 4),
+    (0x11942, 0x11942, 6), (0x11943, 0x11943, 5), (0x11944, 0x11946, 17), (0x11950, 0x11959, 8),
+    (0x119A0, 0x119A7, 4), (0x119AA, 0x119D0, 4), (0x119D1, 0x119D3, 6), (0x119D4, 0x119D7, 5),
+    (0x119DA, 0x119DB, 5), (0x119DC, 0x119DF, 6), (0x119E0, 0x119E0, 5), (0x119E1, 0x119E1, 4),
+    (0x119E2, 0x119E2, 17), (0x119E3, 0x119E3, 4), (0x119E4, 0x119E4, 6), (0x11A00, 0x11A00, 4),
+    (0x11A01, 0x11A0A, 5), (0x11A0B, 0x11A32, 4), (0x11A33, 0x11A38, 5), (0x11A39, 0x11A39, 6),
+    (0x11A3A, 0x11A3A, 4), (0x11A3B, 0x11A3E, 5), (0x11A3F, 0x11A46, 17), (0x11A47, 0x11A47, 5),
+    (0x11A50, 0x11A50, 4), (0x11A51, 0x11A56, 5), (0x11A57, 0x11A58, 6), (0x11A59, 0x11A5B, 5),
+    (0x11A5C, 0x11A89, 4), (0x11A8A, 0x11A96, 5), (0x11A97, 0x11A97, 6), (0x11A98, 0x11A99, 5),
+    (0x11A9A, 0x11A9C, 17), (0x11A9D, 0x11A9D, 4), (0x11A9E, 0x11AA2, 17), (0x11AB0, 0x11AF8, 4),
+    (0x11B00, 0x11B09, 17), (0x11B60, 0x11B60, 5), (0x11B61, 0x11B61, 6), (0x11B62, 0x11B64, 5),
+    (0x11B65, 0x11B65, 6), (0x11B66, 0x11B66, 5), (0x11B67, 0x11B67, 6), (0x11BC0, 0x11BE0, 4),
+    (0x11BE1, 0x11BE1, 17), (0x11BF0, 0x11BF9, 8), (0x11C00, 0x11C08, 4), (0x11C0A, 0x11C2E, 4),
+    (0x11C2F, 0x11C2F, 6), (0x11C30, 0x11C36, 5), (0x11C38, 0x11C3D, 5), (0x11C3E, 0x11C3E, 6),
+    (0x11C3F, 0x11C3F, 5), (0x11C40, 0x11C40, 4), (0x11C41, 0x11C45, 17), (0x11C50, 0x11C59, 8),
+    (0x11C5A, 0x11C6C, 10), (0x11C70, 0x11C71, 17), (0x11C72, 0x11C8F, 4), (0x11C92, 0x11CA7, 5),
+    (0x11CA9, 0x11CA9, 6), (0x11CAA, 0x11CB0, 5), (0x11CB1, 0x11CB1, 6), (0x11CB2, 0x11CB3, 5),
+    (0x11CB4, 0x11CB4, 6), (0x11CB5, 0x11CB6, 5), (0x11D00, 0x11D06, 4), (0x11D08, 0x11D09, 4),
+    (0x11D0B, 0x11D30, 4), (0x11D31, 0x11D36, 5), (0x11D3A, 0x11D3A, 5), (0x11D3C, 0x11D3D, 5),
+    (0x11D3F, 0x11D45, 5), (0x11D46, 0x11D46, 4), (0x11D47, 0x11D47, 5), (0x11D50, 0x11D59, 8),
+    (0x11D60, 0x11D65, 4), (0x11D67, 0x11D68, 4), (0x11D6A, 0x11D89, 4), (0x11D8A, 0x11D8E, 6),
+    (0x11D90, 0x11D91, 5), (0x11D93, 0x11D94, 6), (0x11D95, 0x11D95, 5), (0x11D96, 0x11D96, 6),
+    (0x11D97, 0x11D97, 5), (0x11D98, 0x11D98, 4), (0x11DA0, 0x11DA9, 8), (0x11DB0, 0x11DD8, 4),
+    (0x11DD9, 0x11DD9, 3), (0x11DDA, 0x11DDB, 4), (0x11DE0, 0x11DE9, 8), (0x11EE0, 0x11EF2, 4),
+    (0x11EF3, 0x11EF4, 5), (0x11EF5, 0x11EF6, 6), (0x11EF7, 0x11EF8, 17), (0x11F00, 0x11F01, 5),
+    (0x11F02, 0x11F02, 4), (0x11F03, 0x11F03, 6), (0x11F04, 0x11F10, 4), (0x11F12, 0x11F33, 4),
+    (0x11F34, 0x11F35, 6), (0x11F36, 0x11F3A, 5), (0x11F3E, 0x11F3F, 6), (0x11F40, 0x11F40, 5),
+    (0x11F41, 0x11F41, 6), (0x11F42, 0x11F42, 5), (0x11F43, 0x11F4F, 17), (0x11F50, 0x11F59, 8),
+    (0x11F5A, 0x11F5A, 5), (0x11FB0, 0x11FB0, 4), (0x11FC0, 0x11FD4, 10), (0x11FD5, 0x11FDC, 21),
+    (0x11FDD, 0x11FE0, 19), (0x11FE1, 0x11FF1, 21), (0x11FFF, 0x11FFF, 17), (0x12000, 0x12399, 4),
+    (0x12400, 0x1246E, 9), (0x12470, 0x12474, 17), (0x12480, 0x12543, 4), (0x12F90, 0x12FF0, 4),
+    (0x12FF1, 0x12FF2, 17), (0x13000, 0x1342F, 4), (0x13430, 0x1343F, 26), (0x13440, 0x13440, 5),
+    (0x13441, 0x13446, 4), (0x13447, 0x13455, 5), (0x13460, 0x143FA, 4), (0x14400, 0x14646, 4),
+    (0x16100, 0x1611D, 4), (0x1611E, 0x16129, 5), (0x1612A, 0x1612C, 6), (0x1612D, 0x1612F, 5),
+    (0x16130, 0x16139, 8), (0x16800, 0x16A38, 4), (0x16A40, 0x16A5E, 4), (0x16A60, 0x16A69, 8),
+    (0x16A6E, 0x16A6F, 17), (0x16A70, 0x16ABE, 4), (0x16AC0, 0x16AC9, 8), (0x16AD0, 0x16AED, 4),
+    (0x16AF0, 0x16AF4, 5), (0x16AF5, 0x16AF5, 17), (0x16B00, 0x16B2F, 4), (0x16B30, 0x16B36, 5),
+    (0x16B37, 0x16B3B, 17), (0x16B3C, 0x16B3F, 21), (0x16B40, 0x16B43, 3), (0x16B44, 0x16B44, 17),
+    (0x16B45, 0x16B45, 21), (0x16B50, 0x16B59, 8), (0x16B5B, 0x16B61, 10), (0x16B63, 0x16B77, 4),
+    (0x16B7D, 0x16B8F, 4), (0x16D40, 0x16D42, 3), (0x16D43, 0x16D6A, 4), (0x16D6B, 0x16D6C, 3),
+    (0x16D6D, 0x16D6F, 17), (0x16D70, 0x16D79, 8), (0x16E40, 0x16E5F, 0), (0x16E60, 0x16E7F, 1),
+    (0x16E80, 0x16E96, 10), (0x16E97, 0x16E9A, 17), (0x16EA0, 0x16EB8, 0), (0x16EBB, 0x16ED3, 1),
+    (0x16F00, 0x16F4A, 4), (0x16F4F, 0x16F4F, 5), (0x16F50, 0x16F50, 4), (0x16F51, 0x16F87, 6),
+    (0x16F8F, 0x16F92, 5), (0x16F93, 0x16F9F, 3), (0x16FE0, 0x16FE1, 3), (0x16FE2, 0x16FE2, 17),
+    (0x16FE3, 0x16FE3, 3), (0x16FE4, 0x16FE4, 5), (0x16FF0, 0x16FF1, 6), (0x16FF2, 0x16FF3, 3),
+    (0x16FF4, 0x16FF6, 9), (0x17000, 0x18CD5, 4), (0x18CFF, 0x18D1E, 4), (0x18D80, 0x18DF2, 4),
+    (0x1AFF0, 0x1AFF3, 3), (0x1AFF5, 0x1AFFB, 3), (0x1AFFD, 0x1AFFE, 3), (0x1B000, 0x1B122, 4),
+    (0x1B132, 0x1B132, 4), (0x1B150, 0x1B152, 4), (0x1B155, 0x1B155, 4), (0x1B164, 0x1B167, 4),
+    (0x1B170, 0x1B2FB, 4), (0x1BC00, 0x1BC6A, 4), (0x1BC70, 0x1BC7C, 4), (0x1BC80, 0x1BC88, 4),
+    (0x1BC90, 0x1BC99, 4), (0x1BC9C, 0x1BC9C, 21), (0x1BC9D, 0x1BC9E, 5), (0x1BC9F, 0x1BC9F, 17),
+    (0x1BCA0, 0x1BCA3, 26), (0x1CC00, 0x1CCEF, 21), (0x1CCF0, 0x1CCF9, 8), (0x1CCFA, 0x1CCFC, 21),
+    (0x1CD00, 0x1CEB3, 21), (0x1CEBA, 0x1CED0, 21), (0x1CEE0, 0x1CEEF, 21), (0x1CEF0, 0x1CEF0, 18),
+    (0x1CF00, 0x1CF2D, 5), (0x1CF30, 0x1CF46, 5), (0x1CF50, 0x1CFC3, 21), (0x1D000, 0x1D0F5, 21),
+    (0x1D100, 0x1D126, 21), (0x1D129, 0x1D164, 21), (0x1D165, 0x1D166, 6), (0x1D167, 0x1D169, 5),
+    (0x1D16A, 0x1D16C, 21), (0x1D16D, 0x1D172, 6), (0x1D173, 0x1D17A, 26), (0x1D17B, 0x1D182, 5),
+    (0x1D183, 0x1D184, 21), (0x1D185, 0x1D18B, 5), (0x1D18C, 0x1D1A9, 21), (0x1D1AA, 0x1D1AD, 5),
+    (0x1D1AE, 0x1D1EA, 21), (0x1D200, 0x1D241, 21), (0x1D242, 0x1D244, 5), (0x1D245, 0x1D245, 21),
+    (0x1D2C0, 0x1D2D3, 10), (0x1D2E0, 0x1D2F3, 10), (0x1D300, 0x1D356, 21), (0x1D360, 0x1D378, 10),
+    (0x1D400, 0x1D419, 0), (0x1D41A, 0x1D433, 1), (0x1D434, 0x1D44D, 0), (0x1D44E, 0x1D454, 1),
+    (0x1D456, 0x1D467, 1), (0x1D468, 0x1D481, 0), (0x1D482, 0x1D49B, 1), (0x1D49C, 0x1D49C, 0),
+    (0x1D49E, 0x1D49F, 0), (0x1D4A2, 0x1D4A2, 0), (0x1D4A5, 0x1D4A6, 0), (0x1D4A9, 0x1D4AC, 0),
+    (0x1D4AE, 0x1D4B5, 0), (0x1D4B6, 0x1D4B9, 1), (0x1D4BB, 0x1D4BB, 1), (0x1D4BD, 0x1D4C3, 1),
+    (0x1D4C5, 0x1D4CF, 1), (0x1D4D0, 0x1D4E9, 0), (0x1D4EA, 0x1D503, 1), (0x1D504, 0x1D505, 0),
+    (0x1D507, 0x1D50A, 0), (0x1D50D, 0x1D514, 0), (0x1D516, 0x1D51C, 0), (0x1D51E, 0x1D537, 1),
+    (0x1D538, 0x1D539, 0), (0x1D53B, 0x1D53E, 0), (0x1D540, 0x1D544, 0), (0x1D546, 0x1D546, 0),
+    (0x1D54A, 0x1D550, 0), (0x1D552, 0x1D56B, 1), (0x1D56C, 0x1D585, 0), (0x1D586, 0x1D59F, 1),
+    (0x1D5A0, 0x1D5B9, 0), (0x1D5BA, 0x1D5D3, 1), (0x1D5D4, 0x1D5ED, 0), (0x1D5EE, 0x1D607, 1),
+    (0x1D608, 0x1D621, 0), (0x1D622, 0x1D63B, 1), (0x1D63C, 0x1D655, 0), (0x1D656, 0x1D66F, 1),
+    (0x1D670, 0x1D689, 0), (0x1D68A, 0x1D6A5, 1), (0x1D6A8, 0x1D6C0, 0), (0x1D6C1, 0x1D6C1, 18),
+    (0x1D6C2, 0x1D6DA, 1), (0x1D6DB, 0x1D6DB, 18), (0x1D6DC, 0x1D6E1, 1), (0x1D6E2, 0x1D6FA, 0),
+    (0x1D6FB, 0x1D6FB, 18), (0x1D6FC, 0x1D714, 1), (0x1D715, 0x1D715, 18), (0x1D716, 0x1D71B, 1),
+    (0x1D71C, 0x1D734, 0), (0x1D735, 0x1D735, 18), (0x1D736, 0x1D74E, 1), (0x1D74F, 0x1D74F, 18),
+    (0x1D750, 0x1D755, 1), (0x1D756, 0x1D76E, 0), (0x1D76F, 0x1D76F, 18), (0x1D770, 0x1D788, 1),
+    (0x1D789, 0x1D789, 18), (0x1D78A, 0x1D78F, 1), (0x1D790, 0x1D7A8, 0), (0x1D7A9, 0x1D7A9, 18),
+    (0x1D7AA, 0x1D7C2, 1), (0x1D7C3, 0x1D7C3, 18), (0x1D7C4, 0x1D7C9, 1), (0x1D7CA, 0x1D7CA, 0),
+    (0x1D7CB, 0x1D7CB, 1), (0x1D7CE, 0x1D7FF, 8), (0x1D800, 0x1D9FF, 21), (0x1DA00, 0x1DA36, 5),
+    (0x1DA37, 0x1DA3A, 21), (0x1DA3B, 0x1DA6C, 5), (0x1DA6D, 0x1DA74, 21), (0x1DA75, 0x1DA75, 5),
+    (0x1DA76, 0x1DA83, 21), (0x1DA84, 0x1DA84, 5), (0x1DA85, 0x1DA86, 21), (0x1DA87, 0x1DA8B, 17),
+    (0x1DA9B, 0x1DA9F, 5), (0x1DAA1, 0x1DAAF, 5), (0x1DF00, 0x1DF09, 1), (0x1DF0A, 0x1DF0A, 4),
+    (0x1DF0B, 0x1DF1E, 1), (0x1DF25, 0x1DF2A, 1), (0x1E000, 0x1E006, 5), (0x1E008, 0x1E018, 5),
+    (0x1E01B, 0x1E021, 5), (0x1E023, 0x1E024, 5), (0x1E026, 0x1E02A, 5), (0x1E030, 0x1E06D, 3),
+    (0x1E08F, 0x1E08F, 5), (0x1E100, 0x1E12C, 4), (0x1E130, 0x1E136, 5), (0x1E137, 0x1E13D, 3),
+    (0x1E140, 0x1E149, 8), (0x1E14E, 0x1E14E, 4), (0x1E14F, 0x1E14F, 21), (0x1E290, 0x1E2AD, 4),
+    (0x1E2AE, 0x1E2AE, 5), (0x1E2C0, 0x1E2EB, 4), (0x1E2EC, 0x1E2EF, 5), (0x1E2F0, 0x1E2F9, 8),
+    (0x1E2FF, 0x1E2FF, 19), (0x1E4D0, 0x1E4EA, 4), (0x1E4EB, 0x1E4EB, 3), (0x1E4EC, 0x1E4EF, 5),
+    (0x1E4F0, 0x1E4F9, 8), (0x1E5D0, 0x1E5ED, 4), (0x1E5EE, 0x1E5EF, 5), (0x1E5F0, 0x1E5F0, 4),
+    (0x1E5F1, 0x1E5FA, 8), (0x1E5FF, 0x1E5FF, 17), (0x1E6C0, 0x1E6DE, 4), (0x1E6E0, 0x1E6E2, 4),
+    (0x1E6E3, 0x1E6E3, 5), (0x1E6E4, 0x1E6E5, 4), (0x1E6E6, 0x1E6E6, 5), (0x1E6E7, 0x1E6ED, 4),
+    (0x1E6EE, 0x1E6EF, 5), (0x1E6F0, 0x1E6F4, 4), (0x1E6F5, 0x1E6F5, 5), (0x1E6FE, 0x1E6FE, 4),
+    (0x1E6FF, 0x1E6FF, 3), (0x1E7E0, 0x1E7E6, 4), (0x1E7E8, 0x1E7EB, 4), (0x1E7ED, 0x1E7EE, 4),
+    (0x1E7F0, 0x1E7FE, 4), (0x1E800, 0x1E8C4, 4), (0x1E8C7, 0x1E8CF, 10), (0x1E8D0, 0x1E8D6, 5),
+    (0x1E900, 0x1E921, 0), (0x1E922, 0x1E943, 1), (0x1E944, 0x1E94A, 5), (0x1E94B, 0x1E94B, 3),
+    (0x1E950, 0x1E959, 8), (0x1E95E, 0x1E95F, 17), (0x1EC71, 0x1ECAB, 10), (0x1ECAC, 0x1ECAC, 21),
+    (0x1ECAD, 0x1ECAF, 10), (0x1ECB0, 0x1ECB0, 19), (0x1ECB1, 0x1ECB4, 10), (0x1ED01, 0x1ED2D, 10),
+    (0x1ED2E, 0x1ED2E, 21), (0x1ED2F, 0x1ED3D, 10), (0x1EE00, 0x1EE03, 4), (0x1EE05, 0x1EE1F, 4),
+    (0x1EE21, 0x1EE22, 4), (0x1EE24, 0x1EE24, 4), (0x1EE27, 0x1EE27, 4), (0x1EE29, 0x1EE32, 4),
+    (0x1EE34, 0x1EE37, 4), (0x1EE39, 0x1EE39, 4), (0x1EE3B, 0x1EE3B, 4), (0x1EE42, 0x1EE42, 4),
+    (0x1EE47, 0x1EE47, 4), (0x1EE49, 0x1EE49, 4), (0x1EE4B, 0x1EE4B, 4), (0x1EE4D, 0x1EE4F, 4),
+    (0x1EE51, 0x1EE52, 4), (0x1EE54, 0x1EE54, 4), (0x1EE57, 0x1EE57, 4), (0x1EE59, 0x1EE59, 4),
+    (0x1EE5B, 0x1EE5B, 4), (0x1EE5D, 0x1EE5D, 4), (0x1EE5F, 0x1EE5F, 4), (0x1EE61, 0x1EE62, 4),
+    (0x1EE64, 0x1EE64, 4), (0x1EE67, 0x1EE6A, 4), (0x1EE6C, 0x1EE72, 4), (0x1EE74, 0x1EE77, 4),
+    (0x1EE79, 0x1EE7C, 4), (0x1EE7E, 0x1EE7E, 4), (0x1EE80, 0x1EE89, 4), (0x1EE8B, 0x1EE9B, 4),
+    (0x1EEA1, 0x1EEA3, 4), (0x1EEA5, 0x1EEA9, 4), (0x1EEAB, 0x1EEBB, 4), (0x1EEF0, 0x1EEF1, 18),
+    (0x1F000, 0x1F02B, 21), (0x1F030, 0x1F093, 21), (0x1F0A0, 0x1F0AE, 21), (0x1F0B1, 0x1F0BF, 21),
+    (0x1F0C1, 0x1F0CF, 21), (0x1F0D1, 0x1F0F5, 21), (0x1F100, 0x1F10C, 10), (0x1F10D, 0x1F1AD, 21),
+    (0x1F1E6, 0x1F202, 21), (0x1F210, 0x1F23B, 21), (0x1F240, 0x1F248, 21), (0x1F250, 0x1F251, 21),
+    (0x1F260, 0x1F265, 21), (0x1F300, 0x1F3FA, 21), (0x1F3FB, 0x1F3FF, 20), (0x1F400, 0x1F6D8, 21),
+    (0x1F6DC, 0x1F6EC, 21), (0x1F6F0, 0x1F6FC, 21), (0x1F700, 0x1F7D9, 21), (0x1F7E0, 0x1F7EB, 21),
+    (0x1F7F0, 0x1F7F0, 21), (0x1F800, 0x1F80B, 21), (0x1F810, 0x1F847, 21), (0x1F850, 0x1F859, 21),
+    (0x1F860, 0x1F887, 21), (0x1F890, 0x1F8AD, 21), (0x1F8B0, 0x1F8BB, 21), (0x1F8C0, 0x1F8C1, 21),
+    (0x1F8D0, 0x1F8D8, 18), (0x1F900, 0x1FA57, 21), (0x1FA60, 0x1FA6D, 21), (0x1FA70, 0x1FA7C, 21),
+    (0x1FA80, 0x1FA8A, 21), (0x1FA8E, 0x1FAC6, 21), (0x1FAC8, 0x1FAC8, 21), (0x1FACD, 0x1FADC, 21),
+    (0x1FADF, 0x1FAEA, 21), (0x1FAEF, 0x1FAF8, 21), (0x1FB00, 0x1FB92, 21), (0x1FB94, 0x1FBEF, 21),
+    (0x1FBF0, 0x1FBF9, 8), (0x1FBFA, 0x1FBFA, 21), (0x20000, 0x2A6DF, 4), (0x2A700, 0x2B81D, 4),
+    (0x2B820, 0x2CEAD, 4), (0x2CEB0, 0x2EBE0, 4), (0x2EBF0, 0x2EE5D, 4), (0x2F800, 0x2FA1D, 4),
+    (0x30000, 0x3134A, 4), (0x31350, 0x33479, 4), (0xE0001, 0xE0001, 26), (0xE0020, 0xE007F, 26),
+    (0xE0100, 0xE01EF, 5), (0xF0000, 0xFFFFD, 28), (0x100000, 0x10FFFD, 28),
+];
+
+#[rustfmt::skip]
+pub(super) static XID_START: &[(u32, u32)] = &[
+    (0x41, 0x5A), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xBA, 0xBA), (0xC0, 0xD6),
+    (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1), (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE),
+    (0x370, 0x374), (0x376, 0x377), (0x37B, 0x37D), (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A),
+    (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481), (0x48A, 0x52F), (0x531, 0x556),
+    (0x559, 0x559), (0x560, 0x588), (0x5D0, 0x5EA), (0x5EF, 0x5F2), (0x620, 0x64A), (0x66E, 0x66F),
+    (0x671, 0x6D3), (0x6D5, 0x6D5), (0x6E5, 0x6E6), (0x6EE, 0x6EF), (0x6FA, 0x6FC), (0x6FF, 0x6FF),
+    (0x710, 0x710), (0x712, 0x72F), (0x74D, 0x7A5), (0x7B1, 0x7B1), (0x7CA, 0x7EA), (0x7F4, 0x7F5),
+    (0x7FA, 0x7FA), (0x800, 0x815), (0x81A, 0x81A), (0x824, 0x824), (0x828, 0x828), (0x840, 0x858),
+    (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F), (0x8A0, 0x8C9), (0x904, 0x939), (0x93D, 0x93D),
+    (0x950, 0x950), (0x958, 0x961), (0x971, 0x980), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8),
+    (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BD, 0x9BD), (0x9CE, 0x9CE), (0x9DC, 0x9DD),
+    (0x9DF, 0x9E1), (0x9F0, 0x9F1), (0x9FC, 0x9FC), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28),
+    (0xA2A, 0xA30), (0xA32, 0xA33), (0xA35, 0xA36), (0xA38, 0xA39), (0xA59, 0xA5C), (0xA5E, 0xA5E),
+    (0xA72, 0xA74), (0xA85, 0xA8D), (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3),
+    (0xAB5, 0xAB9), (0xABD, 0xABD), (0xAD0, 0xAD0), (0xAE0, 0xAE1), (0xAF9, 0xAF9), (0xB05, 0xB0C),
+    (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39), (0xB3D, 0xB3D),
+    (0xB5C, 0xB5D), (0xB5F, 0xB61), (0xB71, 0xB71), (0xB83, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90),
+    (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA),
+    (0xBAE, 0xBB9), (0xBD0, 0xBD0), (0xC05, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39),
+    (0xC3D, 0xC3D), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC61), (0xC80, 0xC80), (0xC85, 0xC8C),
+    (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBD, 0xCBD), (0xCDC, 0xCDE),
+    (0xCE0, 0xCE1), (0xCF1, 0xCF2), (0xD04, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD3A), (0xD3D, 0xD3D),
+    (0xD4E, 0xD4E), (0xD54, 0xD56), (0xD5F, 0xD61), (0xD7A, 0xD7F), (0xD85, 0xD96), (0xD9A, 0xDB1),
+    (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xE01, 0xE30), (0xE32, 0xE32), (0xE40, 0xE46),
+    (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEB0),
+    (0xEB2, 0xEB2), (0xEBD, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEDC, 0xEDF), (0xF00, 0xF00),
+    (0xF40, 0xF47), (0xF49, 0xF6C), (0xF88, 0xF8C), (0x1000, 0x102A), (0x103F, 0x103F),
+    (0x1050, 0x1055), (0x105A, 0x105D), (0x1061, 0x1061), (0x1065, 0x1066), (0x106E, 0x1070),
+    (0x1075, 0x1081), (0x108E, 0x108E), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD),
+    (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258),
+    (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5),
+    (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310),
+    (0x1312, 0x1315), (0x1318, 0x135A), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD),
+    (0x1401, 0x166C), (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8),
+    (0x1700, 0x1711), (0x171F, 0x1731), (0x1740, 0x1751), (0x1760, 0x176C), (0x176E, 0x1770),
+    (0x1780, 0x17B3), (0x17D7, 0x17D7), (0x17DC, 0x17DC), (0x1820, 0x1878), (0x1880, 0x18A8),
+    (0x18AA, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1950, 0x196D), (0x1970, 0x1974),
+    (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x1A00, 0x1A16), (0x1A20, 0x1A54), (0x1AA7, 0x1AA7),
+    (0x1B05, 0x1B33), (0x1B45, 0x1B4C), (0x1B83, 0x1BA0), (0x1BAE, 0x1BAF), (0x1BBA, 0x1BE5),
+    (0x1C00, 0x1C23), (0x1C4D, 0x1C4F), (0x1C5A, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF), (0x1CE9, 0x1CEC), (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF6), (0x1CFA, 0x1CFA),
+    (0x1D00, 0x1DBF), (0x1E00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D),
+    (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D),
+    (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC),
+    (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC),
+    (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x2102, 0x2102), (0x2107, 0x2107),
+    (0x210A, 0x2113), (0x2115, 0x2115), (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126),
+    (0x2128, 0x2128), (0x212A, 0x2139), (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E),
+    (0x2160, 0x2188), (0x2C00, 0x2CE4), (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3), (0x2D00, 0x2D25),
+    (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D80, 0x2D96),
+    (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6),
+    (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x3005, 0x3007), (0x3021, 0x3029),
+    (0x3031, 0x3035), (0x3038, 0x303C), (0x3041, 0x3096), (0x309D, 0x309F), (0x30A1, 0x30FA),
+    (0x30FC, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF),
+    (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD), (0xA500, 0xA60C), (0xA610, 0xA61F),
+    (0xA62A, 0xA62B), (0xA640, 0xA66E), (0xA67F, 0xA69D), (0xA6A0, 0xA6EF), (0xA717, 0xA71F),
+    (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA801), (0xA803, 0xA805), (0xA807, 0xA80A),
+    (0xA80C, 0xA822), (0xA840, 0xA873), (0xA882, 0xA8B3), (0xA8F2, 0xA8F7), (0xA8FB, 0xA8FB),
+    (0xA8FD, 0xA8FE), (0xA90A, 0xA925), (0xA930, 0xA946), (0xA960, 0xA97C), (0xA984, 0xA9B2),
+    (0xA9CF, 0xA9CF), (0xA9E0, 0xA9E4), (0xA9E6, 0xA9EF), (0xA9FA, 0xA9FE), (0xAA00, 0xAA28),
+    (0xAA40, 0xAA42), (0xAA44, 0xAA4B), (0xAA60, 0xAA76), (0xAA7A, 0xAA7A), (0xAA7E, 0xAAAF),
+    (0xAAB1, 0xAAB1), (0xAAB5, 0xAAB6), (0xAAB9, 0xAABD), (0xAAC0, 0xAAC0), (0xAAC2, 0xAAC2),
+    (0xAADB, 0xAADD), (0xAAE0, 0xAAEA), (0xAAF2, 0xAAF4), (0xAB01, 0xAB06), (0xAB09, 0xAB0E),
+    (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69),
+    (0xAB70, 0xABE2), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D),
+    (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB1D), (0xFB1F, 0xFB28),
+    (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44),
+    (0xFB46, 0xFBB1), (0xFBD3, 0xFC5D), (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7),
+    (0xFDF0, 0xFDF9), (0xFE71, 0xFE71), (0xFE73, 0xFE73), (0xFE77, 0xFE77), (0xFE79, 0xFE79),
+    (0xFE7B, 0xFE7B), (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC), (0xFF21, 0xFF3A), (0xFF41, 0xFF5A),
+    (0xFF66, 0xFF9D), (0xFFA0, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7),
+    (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A),
+    (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA),
+    (0x10140, 0x10174), (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x10300, 0x1031F),
+    (0x1032D, 0x1034A), (0x10350, 0x10375), (0x10380, 0x1039D), (0x103A0, 0x103C3),
+    (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104B0, 0x104D3),
+    (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A),
+    (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1),
+    (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3),
+    (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785),
+    (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808),
+    (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855),
+    (0x10860, 0x10876), (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5),
+    (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959), (0x10980, 0x109B7),
+    (0x109BE, 0x109BF), (0x10A00, 0x10A00), (0x10A10, 0x10A13), (0x10A15, 0x10A17),
+    (0x10A19, 0x10A35), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7),
+    (0x10AC9, 0x10AE4), (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72),
+    (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2),
+    (0x10D00, 0x10D23), (0x10D4A, 0x10D65), (0x10D6F, 0x10D85), (0x10E80, 0x10EA9),
+    (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10F00, 0x10F1C), (0x10F27, 0x10F27),
+    (0x10F30, 0x10F45), (0x10F70, 0x10F81), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6),
+    (0x11003, 0x11037), (0x11071, 0x11072), (0x11075, 0x11075), (0x11083, 0x110AF),
+    (0x110D0, 0x110E8), (0x11103, 0x11126), (0x11144, 0x11144), (0x11147, 0x11147),
+    (0x11150, 0x11172), (0x11176, 0x11176), (0x11183, 0x111B2), (0x111C1, 0x111C4),
+    (0x111DA, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x1122B),
+    (0x1123F, 0x11240), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D),
+    (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112DE), (0x11305, 0x1130C),
+    (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333),
+    (0x11335, 0x11339), (0x1133D, 0x1133D), (0x11350, 0x11350), (0x1135D, 0x11361),
+    (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5),
+    (0x113B7, 0x113B7), (0x113D1, 0x113D1), (0x113D3, 0x113D3), (0x11400, 0x11434),
+    (0x11447, 0x1144A), (0x1145F, 0x11461), (0x11480, 0x114AF), (0x114C4, 0x114C5),
+    (0x114C7, 0x114C7), (0x11580, 0x115AE), (0x115D8, 0x115DB), (0x11600, 0x1162F),
+    (0x11644, 0x11644), (0x11680, 0x116AA), (0x116B8, 0x116B8), (0x11700, 0x1171A),
+    (0x11740, 0x11746), (0x11800, 0x1182B), (0x118A0, 0x118DF), (0x118FF, 0x11906),
+    (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x1192F),
+    (0x1193F, 0x1193F), (0x11941, 0x11941), (0x119A0, 0x119A7), (0x119AA, 0x119D0),
+    (0x119E1, 0x119E1), (0x119E3, 0x119E3), (0x11A00, 0x11A00), (0x11A0B, 0x11A32),
+    (0x11A3A, 0x11A3A), (0x11A50, 0x11A50), (0x11A5C, 0x11A89), (0x11A9D, 0x11A9D),
+    (0x11AB0, 0x11AF8), (0x11BC0, 0x11BE0), (0x11C00, 0x11C08), (0x11C0A, 0x11C2E),
+    (0x11C40, 0x11C40), (0x11C72, 0x11C8F), (0x11D00, 0x11D06), (0x11D08, 0x11D09),
+    (0x11D0B, 0x11D30), (0x11D46, 0x11D46), (0x11D60, 0x11D65), (0x11D67, 0x11D68),
+    (0x11D6A, 0x11D89), (0x11D98, 0x11D98), (0x11DB0, 0x11DDB), (0x11EE0, 0x11EF2),
+    (0x11F02, 0x11F02), (0x11F04, 0x11F10), (0x11F12, 0x11F33), (0x11FB0, 0x11FB0),
+    (0x12000, 0x12399), (0x12400, 0x1246E), (0x12480, 0x12543), (0x12F90, 0x12FF0),
+    (0x13000, 0x1342F), (0x13441, 0x13446), (0x13460, 0x143FA), (0x14400, 0x14646),
+    (0x16100, 0x1611D), (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A70, 0x16ABE),
+    (0x16AD0, 0x16AED), (0x16B00, 0x16B2F), (0x16B40, 0x16B43), (0x16B63, 0x16B77),
+    (0x16B7D, 0x16B8F), (0x16D40, 0x16D6C), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8),
+    (0x16EBB, 0x16ED3), (0x16F00, 0x16F4A), (0x16F50, 0x16F50), (0x16F93, 0x16F9F),
+    (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3), (0x16FF2, 0x16FF6), (0x17000, 0x18CD5),
+    (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB),
+    (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122), (0x1B132, 0x1B132), (0x1B150, 0x1B152),
+    (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A),
+    (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1D400, 0x1D454),
+    (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6),
+    (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3),
+    (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C),
+    (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546),
+    (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA),
+    (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E),
+    (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2),
+    (0x1D7C4, 0x1D7CB), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E030, 0x1E06D),
+    (0x1E100, 0x1E12C), (0x1E137, 0x1E13D), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AD),
+    (0x1E2C0, 0x1E2EB), (0x1E4D0, 0x1E4EB), (0x1E5D0, 0x1E5ED), (0x1E5F0, 0x1E5F0),
+    (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6E2), (0x1E6E4, 0x1E6E5), (0x1E6E7, 0x1E6ED),
+    (0x1E6F0, 0x1E6F4), (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB),
+    (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E900, 0x1E943),
+    (0x1E94B, 0x1E94B), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37),
+    (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B),
+    (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3),
+    (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D),
+    (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D),
+    (0x30000, 0x3134A), (0x31350, 0x33479),
+];
+
+#[rustfmt::skip]
+pub(super) static XID_CONTINUE: &[(u32, u32)] = &[
+    (0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5),
+    (0xB7, 0xB7), (0xBA, 0xBA), (0xC0, 0xD6), (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1),
+    (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE), (0x300, 0x374), (0x376, 0x377), (0x37B, 0x37D),
+    (0x37F, 0x37F), (0x386, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481),
+    (0x483, 0x487), (0x48A, 0x52F), (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x591, 0x5BD),
+    (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5), (0x5C7, 0x5C7), (0x5D0, 0x5EA), (0x5EF, 0x5F2),
+    (0x610, 0x61A), (0x620, 0x669), (0x66E, 0x6D3), (0x6D5, 0x6DC), (0x6DF, 0x6E8), (0x6EA, 0x6FC),
+    (0x6FF, 0x6FF), (0x710, 0x74A), (0x74D, 0x7B1), (0x7C0, 0x7F5), (0x7FA, 0x7FA), (0x7FD, 0x7FD),
+    (0x800, 0x82D), (0x840, 0x85B), (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F), (0x897, 0x8E1),
+    (0x8E3, 0x963), (0x966, 0x96F), (0x971, 0x983), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8),
+    (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BC, 0x9C4), (0x9C7, 0x9C8), (0x9CB, 0x9CE),
+    (0x9D7, 0x9D7), (0x9DC, 0x9DD), (0x9DF, 0x9E3), (0x9E6, 0x9F1), (0x9FC, 0x9FC), (0x9FE, 0x9FE),
+    (0xA01, 0xA03), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33),
+    (0xA35, 0xA36), (0xA38, 0xA39), (0xA3C, 0xA3C), (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D),
+    (0xA51, 0xA51), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA66, 0xA75), (0xA81, 0xA83), (0xA85, 0xA8D),
+    (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABC, 0xAC5),
+    (0xAC7, 0xAC9), (0xACB, 0xACD), (0xAD0, 0xAD0), (0xAE0, 0xAE3), (0xAE6, 0xAEF), (0xAF9, 0xAFF),
+    (0xB01, 0xB03), (0xB05, 0xB0C), (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33),
+    (0xB35, 0xB39), (0xB3C, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4D), (0xB55, 0xB57), (0xB5C, 0xB5D),
+    (0xB5F, 0xB63), (0xB66, 0xB6F), (0xB71, 0xB71), (0xB82, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90),
+    (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA),
+    (0xBAE, 0xBB9), (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCD), (0xBD0, 0xBD0), (0xBD7, 0xBD7),
+    (0xBE6, 0xBEF), (0xC00, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3C, 0xC44),
+    (0xC46, 0xC48), (0xC4A, 0xC4D), (0xC55, 0xC56), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC63),
+    (0xC66, 0xC6F), (0xC80, 0xC83), (0xC85, 0xC8C), (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3),
+    (0xCB5, 0xCB9), (0xCBC, 0xCC4), (0xCC6, 0xCC8), (0xCCA, 0xCCD), (0xCD5, 0xCD6), (0xCDC, 0xCDE),
+    (0xCE0, 0xCE3), (0xCE6, 0xCEF), (0xCF1, 0xCF3), (0xD00, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD44),
+    (0xD46, 0xD48), (0xD4A, 0xD4E), (0xD54, 0xD57), (0xD5F, 0xD63), (0xD66, 0xD6F), (0xD7A, 0xD7F),
+    (0xD81, 0xD83), (0xD85, 0xD96), (0xD9A, 0xDB1), (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6),
+    (0xDCA, 0xDCA), (0xDCF, 0xDD4), (0xDD6, 0xDD6), (0xDD8, 0xDDF), (0xDE6, 0xDEF), (0xDF2, 0xDF3),
+    (0xE01, 0xE3A), (0xE40, 0xE4E), (0xE50, 0xE59), (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A),
+    (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEC8, 0xECE),
+    (0xED0, 0xED9), (0xEDC, 0xEDF), (0xF00, 0xF00), (0xF18, 0xF19), (0xF20, 0xF29), (0xF35, 0xF35),
+    (0xF37, 0xF37), (0xF39, 0xF39), (0xF3E, 0xF47), (0xF49, 0xF6C), (0xF71, 0xF84), (0xF86, 0xF97),
+    (0xF99, 0xFBC), (0xFC6, 0xFC6), (0x1000, 0x1049), (0x1050, 0x109D), (0x10A0, 0x10C5),
+    (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D),
+    (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D),
+    (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5),
+    (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A), (0x135D, 0x135F),
+    (0x1369, 0x1371), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+    (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1715),
+    (0x171F, 0x1734), (0x1740, 0x1753), (0x1760, 0x176C), (0x176E, 0x1770), (0x1772, 0x1773),
+    (0x1780, 0x17D3), (0x17D7, 0x17D7), (0x17DC, 0x17DD), (0x17E0, 0x17E9), (0x180B, 0x180D),
+    (0x180F, 0x1819), (0x1820, 0x1878), (0x1880, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E),
+    (0x1920, 0x192B), (0x1930, 0x193B), (0x1946, 0x196D), (0x1970, 0x1974), (0x1980, 0x19AB),
+    (0x19B0, 0x19C9), (0x19D0, 0x19DA), (0x1A00, 0x1A1B), (0x1A20, 0x1A5E), (0x1A60, 0x1A7C),
+    (0x1A7F, 0x1A89), (0x1A90, 0x1A99), (0x1AA7, 0x1AA7), (0x1AB0, 0x1ABD), (0x1ABF, 0x1ADD),
+    (0x1AE0, 0x1AEB), (0x1B00, 0x1B4C), (0x1B50, 0x1B59), (0x1B6B, 0x1B73), (0x1B80, 0x1BF3),
+    (0x1C00, 0x1C37), (0x1C40, 0x1C49), (0x1C4D, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF), (0x1CD0, 0x1CD2), (0x1CD4, 0x1CFA), (0x1D00, 0x1F15), (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE),
+    (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC),
+    (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x200C, 0x200D), (0x203F, 0x2040), (0x2054, 0x2054),
+    (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x20D0, 0x20DC), (0x20E1, 0x20E1),
+    (0x20E5, 0x20F0), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115),
+    (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x2139),
+    (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4),
+    (0x2CEB, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67),
+    (0x2D6F, 0x2D6F), (0x2D7F, 0x2D96), (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6),
+    (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE),
+    (0x2DE0, 0x2DFF), (0x3005, 0x3007), (0x3021, 0x302F), (0x3031, 0x3035), (0x3038, 0x303C),
+    (0x3041, 0x3096), (0x3099, 0x309A), (0x309D, 0x309F), (0x30A1, 0x30FF), (0x3105, 0x312F),
+    (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C),
+    (0xA4D0, 0xA4FD), (0xA500, 0xA60C), (0xA610, 0xA62B), (0xA640, 0xA66F), (0xA674, 0xA67D),
+    (0xA67F, 0xA6F1), (0xA717, 0xA71F), (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA827),
+    (0xA82C, 0xA82C), (0xA840, 0xA873), (0xA880, 0xA8C5), (0xA8D0, 0xA8D9), (0xA8E0, 0xA8F7),
+    (0xA8FB, 0xA8FB), (0xA8FD, 0xA92D), (0xA930, 0xA953), (0xA960, 0xA97C), (0xA980, 0xA9C0),
+    (0xA9CF, 0xA9D9), (0xA9E0, 0xA9FE), (0xAA00, 0xAA36), (0xAA40, 0xAA4D), (0xAA50, 0xAA59),
+    (0xAA60, 0xAA76), (0xAA7A, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEF), (0xAAF2, 0xAAF6),
+    (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E),
+    (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABEA), (0xABEC, 0xABED), (0xABF0, 0xABF9),
+    (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9),
+    (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFC5D),
+    (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDF9), (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F), (0xFE33, 0xFE34), (0xFE4D, 0xFE4F), (0xFE71, 0xFE71), (0xFE73, 0xFE73),
+    (0xFE77, 0xFE77), (0xFE79, 0xFE79), (0xFE7B, 0xFE7B), (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC),
+    (0xFF10, 0xFF19), (0xFF21, 0xFF3A), (0xFF3F, 0xFF3F), (0xFF41, 0xFF5A), (0xFF65, 0xFFBE),
+    (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0x10000, 0x1000B),
+    (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D),
+    (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x101FD, 0x101FD),
+    (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x102E0, 0x102E0), (0x10300, 0x1031F),
+    (0x1032D, 0x1034A), (0x10350, 0x1037A), (0x10380, 0x1039D), (0x103A0, 0x103C3),
+    (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104A0, 0x104A9),
+    (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563),
+    (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595),
+    (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC),
+    (0x105C0, 0x105F3), (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767),
+    (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805),
+    (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C),
+    (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E), (0x108E0, 0x108F2),
+    (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959),
+    (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A03), (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35), (0x10A38, 0x10A3A),
+    (0x10A3F, 0x10A3F), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7),
+    (0x10AC9, 0x10AE6), (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72),
+    (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2),
+    (0x10D00, 0x10D27), (0x10D30, 0x10D39), (0x10D40, 0x10D65), (0x10D69, 0x10D6D),
+    (0x10D6F, 0x10D85), (0x10E80, 0x10EA9), (0x10EAB, 0x10EAC), (0x10EB0, 0x10EB1),
+    (0x10EC2, 0x10EC7), (0x10EFA, 0x10F1C), (0x10F27, 0x10F27), (0x10F30, 0x10F50),
+    (0x10F70, 0x10F85), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11000, 0x11046),
+    (0x11066, 0x11075), (0x1107F, 0x110BA), (0x110C2, 0x110C2), (0x110D0, 0x110E8),
+    (0x110F0, 0x110F9), (0x11100, 0x11134), (0x11136, 0x1113F), (0x11144, 0x11147),
+    (0x11150, 0x11173), (0x11176, 0x11176), (0x11180, 0x111C4), (0x111C9, 0x111CC),
+    (0x111CE, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x11237),
+    (0x1123E, 0x11241), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D),
+    (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112EA), (0x112F0, 0x112F9),
+    (0x11300, 0x11303), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328),
+    (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133B, 0x11344),
+    (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11350, 0x11350), (0x11357, 0x11357),
+    (0x1135D, 0x11363), (0x11366, 0x1136C), (0x11370, 0x11374), (0x11380, 0x11389),
+    (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5), (0x113B7, 0x113C0),
+    (0x113C2, 0x113C2), (0x113C5, 0x113C5), (0x113C7, 0x113CA), (0x113CC, 0x113D3),
+    (0x113E1, 0x113E2), (0x11400, 0x1144A), (0x11450, 0x11459), (0x1145E, 0x11461),
+    (0x11480, 0x114C5), (0x114C7, 0x114C7), (0x114D0, 0x114D9), (0x11580, 0x115B5),
+    (0x115B8, 0x115C0), (0x115D8, 0x115DD), (0x11600, 0x11640), (0x11644, 0x11644),
+    (0x11650, 0x11659), (0x11680, 0x116B8), (0x116C0, 0x116C9), (0x116D0, 0x116E3),
+    (0x11700, 0x1171A), (0x1171D, 0x1172B), (0x11730, 0x11739), (0x11740, 0x11746),
+    (0x11800, 0x1183A), (0x118A0, 0x118E9), (0x118FF, 0x11906), (0x11909, 0x11909),
+    (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x11935), (0x11937, 0x11938),
+    (0x1193B, 0x11943), (0x11950, 0x11959), (0x119A0, 0x119A7), (0x119AA, 0x119D7),
+    (0x119DA, 0x119E1), (0x119E3, 0x119E4), (0x11A00, 0x11A3E), (0x11A47, 0x11A47),
+    (0x11A50, 0x11A99), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11B60, 0x11B67),
+    (0x11BC0, 0x11BE0), (0x11BF0, 0x11BF9), (0x11C00, 0x11C08), (0x11C0A, 0x11C36),
+    (0x11C38, 0x11C40), (0x11C50, 0x11C59), (0x11C72, 0x11C8F), (0x11C92, 0x11CA7),
+    (0x11CA9, 0x11CB6), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D36),
+    (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D47), (0x11D50, 0x11D59),
+    (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D8E), (0x11D90, 0x11D91),
+    (0x11D93, 0x11D98), (0x11DA0, 0x11DA9), (0x11DB0, 0x11DDB), (0x11DE0, 0x11DE9),
+    (0x11EE0, 0x11EF6), (0x11F00, 0x11F10), (0x11F12, 0x11F3A), (0x11F3E, 0x11F42),
+    (0x11F50, 0x11F5A), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E),
+    (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342F), (0x13440, 0x13455),
+    (0x13460, 0x143FA), (0x14400, 0x14646), (0x16100, 0x16139), (0x16800, 0x16A38),
+    (0x16A40, 0x16A5E), (0x16A60, 0x16A69), (0x16A70, 0x16ABE), (0x16AC0, 0x16AC9),
+    (0x16AD0, 0x16AED), (0x16AF0, 0x16AF4), (0x16B00, 0x16B36), (0x16B40, 0x16B43),
+    (0x16B50, 0x16B59), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16D40, 0x16D6C),
+    (0x16D70, 0x16D79), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3),
+    (0x16F00, 0x16F4A), (0x16F4F, 0x16F87), (0x16F8F, 0x16F9F), (0x16FE0, 0x16FE1),
+    (0x16FE3, 0x16FE4), (0x16FF0, 0x16FF6), (0x17000, 0x18CD5), (0x18CFF, 0x18D1E),
+    (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE),
+    (0x1B000, 0x1B122), (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155),
+    (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C),
+    (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1BC9D, 0x1BC9E), (0x1CCF0, 0x1CCF9),
+    (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D165, 0x1D169), (0x1D16D, 0x1D172),
+    (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244),
+    (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514),
+    (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0),
+    (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734),
+    (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8),
+    (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1D7CE, 0x1D7FF), (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F),
+    (0x1DAA1, 0x1DAAF), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E000, 0x1E006),
+    (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A),
+    (0x1E030, 0x1E06D), (0x1E08F, 0x1E08F), (0x1E100, 0x1E12C), (0x1E130, 0x1E13D),
+    (0x1E140, 0x1E149), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AE), (0x1E2C0, 0x1E2F9),
+    (0x1E4D0, 0x1E4F9), (0x1E5D0, 0x1E5FA), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6F5),
+    (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE),
+    (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E8D0, 0x1E8D6), (0x1E900, 0x1E94B),
+    (0x1E950, 0x1E959), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37),
+    (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B),
+    (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3),
+    (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x1FBF0, 0x1FBF9), (0x20000, 0x2A6DF),
+    (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D),
+    (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479), (0xE0100, 0xE01EF),
+];
+
+/// Full case folding (status C and F of CaseFolding.txt) of the characters
+/// that change
+#[rustfmt::skip]
+pub(super) static CASE_FOLDING: &[(u32, &str)] = &[
+    (0x41, "a"), (0x42, "b"), (0x43, "c"), (0x44, "d"), (0x45, "e"), (0x46, "f"), (0x47, "g"),
+    (0x48, "h"), (0x49, "i"), (0x4A, "j"), (0x4B, "k"), (0x4C, "l"), (0x4D, "m"), (0x4E, "n"),
+    (0x4F, "o"), (0x50, "p"), (0x51, "q"), (0x52, "r"), (0x53, "s"), (0x54, "t"), (0x55, "u"),
+    (0x56, "v"), (0x57, "w"), (0x58, "x"), (0x59, "y"), (0x5A, "z"), (0xB5, "\u{3BC}"),
+    (0xC0, "\u{E0}"), (0xC1, "\u{E1}"), (0xC2, "\u{E2}"), (0xC3, "\u{E3}"), (0xC4, "\u{E4}"),
+    (0xC5, "\u{E5}"), (0xC6, "\u{E6}"), (0xC7, "\u{E7}"), (0xC8, "\u{E8}"), (0xC9, "\u{E9}"),
+    (0xCA, "\u{EA}"), (0xCB, "\u{EB}"), (0xCC, "\u{EC}"), (0xCD, "\u{ED}"), (0xCE, "\u{EE}"),
+    (0xCF, "\u{EF}"), (0xD0, "\u{F0}"), (0xD1, "\u{F1}"), (0xD2, "\u{F2}"), (0xD3, "\u{F3}"),
+    (0xD4, "\u{F4}"), (0xD5, "\u{F5}"), (0xD6, "\u{F6}"), (0xD8, "\u{F8}"), (0xD9, "\u{F9}"),
+    (0xDA, "\u{FA}"), (0xDB, "\u{FB}"), (0xDC, "\u{FC}"), (0xDD, "\u{FD}"), (0xDE, "\u{FE}"),
+    (0xDF, "ss"), (0x100, "\u{101}"), (0x102, "\u{103}"), (0x104, "\u{105}"), (0x106, "\u{107}"),
+    (0x108, "\u{109}"), (0x10A, "\u{10B}"), (0x10C, "\u{10D}"), (0x10E, "\u{10F}"),
+    (0x110, "\u{111}"), (0x112, "\u{113}"), (0x114, "\u{115}"), (0x116, "\u{117}"),
+    (0x118, "\u{119}"), (0x11A, "\u{11B}"), (0x11C, "\u{11D}"), (0x11E, "\u{11F}"),
+    (0x120, "\u{121}"), (0x122, "\u{123}"), (0x124, "\u{125}"), (0x126, "\u{127}"),
+    (0x128, "\u{129}"), (0x12A, "\u{12B}"), (0x12C, "\u{12D}"), (0x12E, "\u{12F}"),
+    (0x130, "i\u{307}"), (0x132, "\u{133}"), (0x134, "\u{135}"), (0x136, "\u{137}"),
+    (0x139, "\u{13A}"), (0x13B, "\u{13C}"), (0x13D, "\u{13E}"), (0x13F, "\u{140}"),
+    (0x141, "\u{142}"), (0x143, "\u{144}"), (0x145, "\u{146}"), (0x147, "\u{148}"),
+    (0x149, "\u{2BC}n"), (0x14A, "\u{14B}"), (0x14C, "\u{14D}"), (0x14E, "\u{14F}"),
+    (0x150, "\u{151}"), (0x152, "\u{153}"), (0x154, "\u{155}"), (0x156, "\u{157}"),
+    (0x158, "\u{159}"), (0x15A, "\u{15B}"), (0x15C, "\u{15D}"), (0x15E, "\u{15F}"),
+    (0x160, "\u{161}"), (0x162, "\u{163}"), (0x164, "\u{165}"), (0x166, "\u{167}"),
+    (0x168, "\u{169}"), (0x16A, "\u{16B}"), (0x16C, "\u{16D}"), (0x16E, "\u{16F}"),
+    (0x170, "\u{171}"), (0x172, "\u{173}"), (0x174, "\u{175}"), (0x176, "\u{177}"),
+    (0x178, "\u{FF}"), (0x179, "\u{17A}"), (0x17B, "\u{17C}"), (0x17D, "\u{17E}"), (0x17F, "s"),
+    (0x181, "\u{253}"), (0x182, "\u{183}"), (0x184, "\u{185}"), (0x186, "\u{254}"),
+    (0x187, "\u{188}"), (0x189, "\u{256}"), (0x18A, "\u{257}"), (0x18B, "\u{18C}"),
+    (0x18E, "\u{1DD}"), (0x18F, "\u{259}"), (0x190, "\u{25B}"), (0x191, "\u{192}"),
+    (0x193, "\u{260}"), (0x194, "\u{263}"), (0x196, "\u{269}"), (0x197, "\u{268}"),
+    (0x198, "\u{199}"), (0x19C, "\u{26F}"), (0x19D, "\u{272}"), (0x19F, "\u{275}"),
+    (0x1A0, "\u{1A1}"), (0x1A2, "\u{1A3}"), (0x1A4, "\u{1A5}"), (0x1A6, "\u{280}"),
+    (0x1A7, "\u{1A8}"), (0x1A9, "\u{283}"), (0x1AC, "\u{1AD}"), (0x1AE, "\u{288}"),
+    (0x1AF, "\u{1B0}"), (0x1B1, "\u{28A}"), (0x1B2, "\u{28B}"), (0x1B3, "\u{1B4}"),
+    (0x1B5, "\u{1B6}"), (0x1B7, "\u{292}"), (0x1B8, "\u{1B9}"), (0x1BC, "\u{1BD}"),
+    (0x1C4, "\u{1C6}"), (0x1C5, "\u{1C6}"), (0x1C7, "\u{1C9}"), (0x1C8, "\u{1C9}"),
+    (0x1CA, "\u{1CC}"), (0x1CB, "\u{1CC}"), (0x1CD, "\u{1CE}"), (0x1CF, "\u{1D0}"),
+    (0x1D1, "\u{1D2}"), (0x1D3, "\u{1D4}"), (0x1D5, "\u{1D6}"), (0x1D7, "\u{1D8}"),
+    (0x1D9, "\u{1DA}"), (0x1DB, "\u{1DC}"), (0x1DE, "\u{1DF}"), (0x1E0, "\u{1E1}"),
+    (0x1E2, "\u{1E3}"), (0x1E4, "\u{1E5}"), (0x1E6, "\u{1E7}"), (0x1E8, "\u{1E9}"),
+    (0x1EA, "\u{1EB}"), (0x1EC, "\u{1ED}"), (0x1EE, "\u{1EF}"), (0x1F0, "j\u{30C}"),
+    (0x1F1, "\u{1F3}"), (0x1F2, "\u{1F3}"), (0x1F4, "\u{1F5}"), (0x1F6, "\u{195}"),
+    (0x1F7, "\u{1BF}"), (0x1F8, "\u{1F9}"), (0x1FA, "\u{1FB}"), (0x1FC, "\u{1FD}"),
+    (0x1FE, "\u{1FF}"), (0x200, "\u{201}"), (0x202, "\u{203}"), (0x204, "\u{205}"),
+    (0x206, "\u{207}"), (0x208, "\u{209}"), (0x20A, "\u{20B}"), (0x20C, "\u{20D}"),
+    (0x20E, "\u{20F}"), (0x210, "\u{211}"), (0x212, "\u{213}"), (0x214, "\u{215}"),
+    (0x216, "\u{217}"), (0x218, "\u{219}"), (0x21A, "\u{21B}"), (0x21C, "\u{21D}"),
+    (0x21E, "\u{21F}"), (0x220, "\u{19E}"), (0x222, "\u{223}"), (0x224, "\u{225}"),
+    (0x226, "\u{227}"), (0x228, "\u{229}"), (0x22A, "\u{22B}"), (0x22C, "\u{22D}"),
+    (0x22E, "\u{22F}"), (0x230, "\u{231}"), (0x232, "\u{233}"), (0x23A, "\u{2C65}"),
+    (0x23B, "\u{23C}"), (0x23D, "\u{19A}"), (0x23E, "\u{2C66}"), (0x241, "\u{242}"),
+    (0x243, "\u{180}"), (0x244, "\u{289}"), (0x245, "\u{28C}"), (0x246, "\u{247}"),
+    (0x248, "\u{249}"), (0x24A, "\u{24B}"), (0x24C, "\u{24D}"), (0x24E, "\u{24F}"),
+    (0x345, "\u{3B9}"), (0x370, "\u{371}"), (0x372, "\u{373}"), (0x376, "\u{377}"),
+    (0x37F, "\u{3F3}"), (0x386, "\u{3AC}"), (0x388, "\u{3AD}"), (0x389, "\u{3AE}"),
+    (0x38A, "\u{3AF}"), (0x38C, "\u{3CC}"), (0x38E, "\u{3CD}"), (0x38F, "\u{3CE}"),
+    (0x390, "\u{3B9}\u{308}\u{301}"), (0x391, "\u{3B1}"), (0x392, "\u{3B2}"), (0x393, "\u{3B3}"),
+    (0x394, "\u{3B4}"), (0x395, "\u{3B5}"), (0x396, "\u{3B6}"), (0x397, "\u{3B7}"),
+    (0x398, "\u{3B8}"), (0x399, "\u{3B9}"), (0x39A, "\u{3BA}"), (0x39B, "\u{3BB}"),
+    (0x39C, "\u{3BC}"), (0x39D, "\u{3BD}"), (0x39E, "\u{3BE}"), (0x39F, "\u{3BF}"),
+    (0x3A0, "\u{3C0}"), (0x3A1, "\u{3C1}"), (0x3A3, "\u{3C3}"), (0x3A4, "\u{3C4}"),
+    (0x3A5, "\u{3C5}"), (0x3A6, "\u{3C6}"), (0x3A7, "\u{3C7}"), (0x3A8, "\u{3C8}"),
+    (0x3A9, "\u{3C9}"), (0x3AA, "\u{3CA}"), (0x3AB, "\u{3CB}"), (0x3B0, "\u{3C5}\u{308}\u{301}"),
+    (0x3C2, "\u{3C3}"), (0x3CF, "\u{3D7}"), (0x3D0, "\u{3B2}"), (0x3D1, "\u{3B8}"),
+    (0x3D5, "\u{3C6}"), (0x3D6, "\u{3C0}"), (0x3D8, "\u{3D9}"), (0x3DA, "\u{3DB}"),
+    (0x3DC, "\u{3DD}"), (0x3DE, "\u{3DF}"), (0x3E0, "\u{3E1}"), (0x3E2, "\u{3E3}"),
+    (0x3E4, "\u{3E5}"), (0x3E6, "\u{3E7}"), (0x3E8, "\u{3E9}"), (0x3EA, "\u{3EB}"),
+    (0x3EC, "\u{3ED}"), (0x3EE, "\u{3EF}"), (0x3F0, "\u{3BA}"), (0x3F1, "\u{3C1}"),
+    (0x3F4, "\u{3B8}"), (0x3F5, "\u{3B5}"), (0x3F7, "\u{3F8}"), (0x3F9, "\u{3F2}"),
+    (0x3FA, "\u{3FB}"), (0x3FD, "\u{37B}"), (0x3FE, "\u{37C}"), (0x3FF, "\u{37D}"),
+    (0x400, "\u{450}"), (0x401, "\u{451}"), (0x402, "\u{452}"), (0x403, "\u{453}"),
+    (0x404, "\u{454}"), (0x405, "\u{455}"), (0x406, "\u{456}"), (0x407, "\u{457}"),
+    (0x408, "\u{458}"), (0x409, "\u{459}"), (0x40A, "\u{45A}"), (0x40B, "\u{45B}"),
+    (0x40C, "\u{45C}"), (0x40D, "\u{45D}"), (0x40E, "\u{45E}"), (0x40F, "\u{45F}"),
+    (0x410, "\u{430}"), (0x411, "\u{431}"), (0x412, "\u{432}"), (0x413, "\u{433}"),
+    (0x414, "\u{434}"), (0x415, "\u{435}"), (0x416, "\u{436}"), (0x417, "\u{437}"),
+    (0x418, "\u{438}"), (0x419, "\u{439}"), (0x41A, "\u{43A}"), (0x41B, "\u{43B}"),
+    (0x41C, "\u{43C}"), (0x41D, "\u{43D}"), (0x41E, "\u{43E}"), (0x41F, "\u{43F}"),
+    (0x420, "\u{440}"), (0x421, "\u{441}"), (0x422, "\u{442}"), (0x423, "\u{443}"),
+    (0x424, "\u{444}"), (0x425, "\u{445}"), (0x426, "\u{446}"), (0x427, "\u{447}"),
+    (0x428, "\u{448}"), (0x429, "\u{449}"), (0x42A, "\u{44A}"), (0x42B, "\u{44B}"),
+    (0x42C, "\u{44C}"), (0x42D, "\u{44D}"), (0x42E, "\u{44E}"), (0x42F, "\u{44F}"),
+    (0x460, "\u{461}"), (0x462, "\u{463}"), (0x464, "\u{465}"), (0x466, "\u{467}"),
+    (0x468, "\u{469}"), (0x46A, "\u{46B}"), (0x46C, "\u{46D}"), (0x46E, "\u{46F}"),
+    (0x470, "\u{471}"), (0x472, "\u{473}"), (0x474, "\u{475}"), (0x476, "\u{477}"),
+    (0x478, "\u{479}"), (0x47A, "\u{47B}"), (0x47C, "\u{47D}"), (0x47E, "\u{47F}"),
+    (0x480, "\u{481}"), (0x48A, "\u{48B}"), (0x48C, "\u{48D}"), (0x48E, "\u{48F}"),
+    (0x490, "\u{491}"), (0x492, "\u{493}"), (0x494, "\u{495}"), (0x496, "\u{497}"),
+    (0x498, "\u{499}"), (0x49A, "\u{49B}"), (0x49C, "\u{49D}"), (0x49E, "\u{49F}"),
+    (0x4A0, "\u{4A1}"), (0x4A2, "\u{4A3}"), (0x4A4, "\u{4A5}"), (0x4A6, "\u{4A7}"),
+    (0x4A8, "\u{4A9}"), (0x4AA, "\u{4AB}"), (0x4AC, "\u{4AD}"), (0x4AE, "\u{4AF}"),
+    (0x4B0, "\u{4B1}"), (0x4B2, "\u{4B3}"), (0x4B4, "\u{4B5}"), (0x4B6, "\u{4B7}"),
+    (0x4B8, "\u{4B9}"), (0x4BA, "\u{4BB}"), (0x4BC, "\u{4BD}"), (0x4BE, "\u{4BF}"),
+    (0x4C0, "\u{4CF}"), (0x4C1, "\u{4C2}"), (0x4C3, "\u{4C4}"), (0x4C5, "\u{4C6}"),
+    (0x4C7, "\u{4C8}"), (0x4C9, "\u{4CA}"), (0x4CB, "\u{4CC}"), (0x4CD, "\u{4CE}"),
+    (0x4D0, "\u{4D1}"), (0x4D2, "\u{4D3}"), (0x4D4, "\u{4D5}"), (0x4D6, "\u{4D7}"),
+    (0x4D8, "\u{4D9}"), (0x4DA, "\u{4DB}"), (0x4DC, "\u{4DD}"), (0x4DE, "\u{4DF}"),
+    (0x4E0, "\u{4E1}"), (0x4E2, "\u{4E3}"), (0x4E4, "\u{4E5}"), (0x4E6, "\u{4E7}"),
+    (0x4E8, "\u{4E9}"), (0x4EA, "\u{4EB}"), (0x4EC, "\u{4ED}"), (0x4EE, "\u{4EF}"),
+    (0x4F0, "\u{4F1}"), (0x4F2, "\u{4F3}"), (0x4F4, "\u{4F5}"), (0x4F6, "\u{4F7}"),
+    (0x4F8, "\u{4F9}"), (0x4FA, "\u{4FB}"), (0x4FC, "\u{4FD}"), (0x4FE, "\u{4FF}"),
+    (0x500, "\u{501}"), (0x502, "\u{503}"), (0x504, "\u{505}"), (0x506, "\u{507}"),
+    (0x508, "\u{509}"), (0x50A, "\u{50B}"), (0x50C, "\u{50D}"), (0x50E, "\u{50F}"),
+    (0x510, "\u{511}"), (0x512, "\u{513}"), (0x514, "\u{515}"), (0x516, "\u{517}"),
+    (0x518, "\u{519}"), (0x51A, "\u{51B}"), (0x51C, "\u{51D}"), (0x51E, "\u{51F}"),
+    (0x520, "\u{521}"), (0x522, "\u{523}"), (0x524, "\u{525}"), (0x526, "\u{527}"),
+    (0x528, "\u{529}"), (0x52A, "\u{52B}"), (0x52C, "\u{52D}"), (0x52E, "\u{52F}"),
+    (0x531, "\u{561}"), (0x532, "\u{562}"), (0x533, "\u{563}"), (0x534, "\u{564}"),
+    (0x535, "\u{565}"), (0x536, "\u{566}"), (0x537, "\u{567}"), (0x538, "\u{568}"),
+    (0x539, "\u{569}"), (0x53A, "\u{56A}"), (0x53B, "\u{56B}"), (0x53C, "\u{56C}"),
+    (0x53D, "\u{56D}"), (0x53E, "\u{56E}"), (0x53F, "\u{56F}"), (0x540, "\u{570}"),
+    (0x541, "\u{571}"), (0x542, "\u{572}"), (0x543, "\u{573}"), (0x544, "\u{574}"),
+    (0x545, "\u{575}"), (0x546, "\u{576}"), (0x547, "\u{577}"), (0x548, "\u{578}"),
+    (0x549, "\u{579}"), (0x54A, "\u{57A}"), (0x54B, "\u{57B}"), (0x54C, "\u{57C}"),
+    (0x54D, "\u{57D}"), (0x54E, "\u{57E}"), (0x54F, "\u{57F}"), (0x550, "\u{580}"),
+    (0x551, "\u{581}"), (0x552, "\u{582}"), (0x553, "\u{583}"), (0x554, "\u{584}"),
+    (0x555, "\u{585}"), (0x556, "\u{586}"), (0x587, "\u{565}\u{582}"), (0x10A0, "\u{2D00}"),
+    (0x10A1, "\u{2D01}"), (0x10A2, "\u{2D02}"), (0x10A3, "\u{2D03}"), (0x10A4, "\u{2D04}"),
+    (0x10A5, "\u{2D05}"), (0x10A6, "\u{2D06}"), (0x10A7, "\u{2D07}"), (0x10A8, "\u{2D08}"),
+    (0x10A9, "\u{2D09}"), (0x10AA, "\u{2D0A}"), (0x10AB, "\u{2D0B}"), (0x10AC, "\u{2D0C}"),
+    (0x10AD, "\u{2D0D}"), (0x10AE, "\u{2D0E}"), (0x10AF, "\u{2D0F}"), (0x10B0, "\u{2D10}"),
+    (0x10B1, "\u{2D11}"), (0x10B2, "\u{2D12}"), (0x10B3, "\u{2D13}"), (0x10B4, "\u{2D14}"),
+    (0x10B5, "\u{2D15}"), (0x10B6, "\u{2D16}"), (0x10B7, "\u{2D17}"), (0x10B8, "\u{2D18}"),
+    (0x10B9, "\u{2D19}"), (0x10BA, "\u{2D1A}"), (0x10BB, "\u{2D1B}"), (0x10BC, "\u{2D1C}"),
+    (0x10BD, "\u{2D1D}"), (0x10BE, "\u{2D1E}"), (0x10BF, "\u{2D1F}"), (0x10C0, "\u{2D20}"),
+    (0x10C1, "\u{2D21}"), (0x10C2, "\u{2D22}"), (0x10C3, "\u{2D23}"), (0x10C4, "\u{2D24}"),
+    (0x10C5, "\u{2D25}"), (0x10C7, "\u{2D27}"), (0x10CD, "\u{2D2D}"), (0x13F8, "\u{13F0}"),
+    (0x13F9, "\u{13F1}"), (0x13FA, "\u{13F2}"), (0x13FB, "\u{13F3}"), (0x13FC, "\u{13F4}"),
+    (0x13FD, "\u{13F5}"), (0x1C80, "\u{432}"), (0x1C81, "\u{434}"), (0x1C82, "\u{43E}"),
+    (0x1C83, "\u{441}"), (0x1C84, "\u{442}"), (0x1C85, "\u{442}"), (0x1C86, "\u{44A}"),
+    (0x1C87, "\u{463}"), (0x1C88, "\u{A64B}"), (0x1C89, "\u{1C8A}"), (0x1C90, "\u{10D0}"),
+    (0x1C91, "\u{10D1}"), (0x1C92, "\u{10D2}"), (0x1C93, "\u{10D3}"), (0x1C94, "\u{10D4}"),
+    (0x1C95, "\u{10D5}"), (0x1C96, "\u{10D6}"), (0x1C97, "\u{10D7}"), (0x1C98, "\u{10D8}"),
+    (0x1C99, "\u{10D9}"), (0x1C9A, "\u{10DA}"), (0x1C9B, "\u{10DB}"), (0x1C9C, "\u{10DC}"),
+    (0x1C9D, "\u{10DD}"), (0x1C9E, "\u{10DE}"), (0x1C9F, "\u{10DF}"), (0x1CA0, "\u{10E0}"),
+    (0x1CA1, "\u{10E1}"), (0x1CA2, "\u{10E2}"), (0x1CA3, "\u{10E3}"), (0x1CA4, "\u{10E4}"),
+    (0x1CA5, "\u{10E5}"), (0x1CA6, "\u{10E6}"), (0x1CA7, "\u{10E7}"), (0x1CA8, "\u{10E8}"),
+    (0x1CA9, "\u{10E9}"), (0x1CAA, "\u{10EA}"), (0x1CAB, "\u{10EB}"), (0x1CAC, "\u{10EC}"),
+    (0x1CAD, "\u{10ED}"), (0x1CAE, "\u{10EE}"), (0x1CAF, "\u{10EF}"), (0x1CB0, "\u{10F0}"),
+    (0x1CB1, "\u{10F1}"), (0x1CB2, "\u{10F2}"), (0x1CB3, "\u{10F3}"), (0x1CB4, "\u{10F4}"),
+    (0x1CB5, "\u{10F5}"), (0x1CB6, "\u{10F6}"), (0x1CB7, "\u{10F7}"), (0x1CB8, "\u{10F8}"),
+    (0x1CB9, "\u{10F9}"), (0x1CBA, "\u{10FA}"), (0x1CBD, "\u{10FD}"), (0x1CBE, "\u{10FE}"),
+    (0x1CBF, "\u{10FF}"), (0x1E00, "\u{1E01}"), (0x1E02, "\u{1E03}"), (0x1E04, "\u{1E05}"),
+    (0x1E06, "\u{1E07}"), (0x1E08, "\u{1E09}"), (0x1E0A, "\u{1E0B}"), (0x1E0C, "\u{1E0D}"),
+    (0x1E0E, "\u{1E0F}"), (0x1E10, "\u{1E11}"), (0x1E12, "\u{1E13}"), (0x1E14, "\u{1E15}"),
+    (0x1E16, "\u{1E17}"), (0x1E18, "\u{1E19}"), (0x1E1A, "\u{1E1B}"), (0x1E1C, "\u{1E1D}"),
+    (0x1E1E, "\u{1E1F}"), (0x1E20, "\u{1E21}"), (0x1E22, "\u{1E23}"), (0x1E24, "\u{1E25}"),
+    (0x1E26, "\u{1E27}"), (0x1E28, "\u{1E29}"), (0x1E2A, "\u{1E2B}"), (0x1E2C, "\u{1E2D}"),
+    (0x1E2E, "\u{1E2F}"), (0x1E30, "\u{1E31}"), (0x1E32, "\u{1E33}"), (0x1E34, "\u{1E35}"),
+    (0x1E36, "\u{1E37}"), (0x1E38, "\u{1E39}"), (0x1E3A, "\u{1E3B}"), (0x1E3C, "\u{1E3D}"),
+    (0x1E3E, "\u{1E3F}"), (0x1E40, "\u{1E41}"), (0x1E42, "\u{1E43}"), (0x1E44, "\u{1E45}"),
+    (0x1E46, "\u{1E47}"), (0x1E48, "\u{1E49}"), (0x1E4A, "\u{1E4B}"), (0x1E4C, "\u{1E4D}"),
+    (0x1E4E, "\u{1E4F}"), (0x1E50, "\u{1E51}"), (0x1E52, "\u{1E53}"), (0x1E54, "\u{1E55}"),
+    (0x1E56, "\u{1E57}"), (0x1E58, "\u{1E59}"), (0x1E5A, "\u{1E5B}"), (0x1E5C, "\u{1E5D}"),
+    (0x1E5E, "\u{1E5F}"), (0x1E60, "\u{1E61}"), (0x1E62, "\u{1E63}"), (0x1E64, "\u{1E65}"),
+    (0x1E66, "\u{1E67}"), (0x1E68, "\u{1E69}"), (0x1E6A, "\u{1E6B}"), (0x1E6C, "\u{1E6D}"),
+    (0x1E6E, "\u{1E6F}"), (0x1E70, "\u{1E71}"), (0x1E72, "\u{1E73}"), (0x1E74, "\u{1E75}"),
+    (0x1E76, "\u{1E77}"), (0x1E78, "\u{1E79}"), (0x1E7A, "\u{1E7B}"), (0x1E7C, "\u{1E7D}"),
+    (0x1E7E, "\u{1E7F}"), (0x1E80, "\u{1E81}"), (0x1E82, "\u{1E83}"), (0x1E84, "\u{1E85}"),
+    (0x1E86, "\u{1E87}"), (0x1E88, "\u{1E89}"), (0x1E8A, "\u{1E8B}"), (0x1E8C, "\u{1E8D}"),
+    (0x1E8E, "\u{1E8F}"), (0x1E90, "\u{1E91}"), (0x1E92, "\u{1E93}"), (0x1E94, "\u{1E95}"),
+    (0x1E96, "h\u{331}"), (0x1E97, "t\u{308}"), (0x1E98, "w\u{30A}"), (0x1E99, "y\u{30A}"),
+    (0x1E9A, "a\u{2BE}"), (0x1E9B, "\u{1E61}"), (0x1E9E, "ss"), (0x1EA0, "\u{1EA1}"),
+    (0x1EA2, "\u{1EA3}"), (0x1EA4, "\u{1EA5}"), (0x1EA6, "\u{1EA7}"), (0x1EA8, "\u{1EA9}"),
+    (0x1EAA, "\u{1EAB}"), (0x1EAC, "\u{1EAD}"), (0x1EAE, "\u{1EAF}"), (0x1EB0, "\u{1EB1}"),
+    (0x1EB2, "\u{1EB3}"), (0x1EB4, "\u{1EB5}"), (0x1EB6, "\u{1EB7}"), (0x1EB8, "\u{1EB9}"),
+    (0x1EBA, "\u{1EBB}"), (0x1EBC, "\u{1EBD}"), (0x1EBE, "\u{1EBF}"), (0x1EC0, "\u{1EC1}"),
+    (0x1EC2, "\u{1EC3}"), (0x1EC4, "\u{1EC5}"), (0x1EC6, "\u{1EC7}"), (0x1EC8, "\u{1EC9}"),
+    (0x1ECA, "\u{1ECB}"), (0x1ECC, "\u{1ECD}"), (0x1ECE, "\u{1ECF}"), (0x1ED0, "\u{1ED1}"),
+    (0x1ED2, "\u{1ED3}"), (0x1ED4, "\u{1ED5}"), (0x1ED6, "\u{1ED7}"), (0x1ED8, "\u{1ED9}"),
+    (0x1EDA, "\u{1EDB}"), (0x1EDC, "\u{1EDD}"), (0x1EDE, "\u{1EDF}"), (0x1EE0, "\u{1EE1}"),
+    (0x1EE2, "\u{1EE3}"), (0x1EE4, "\u{1EE5}"), (0x1EE6, "\u{1EE7}"), (0x1EE8, "\u{1EE9}"),
+    (0x1EEA, "\u{1EEB}"), (0x1EEC, "\u{1EED}"), (0x1EEE, "\u{1EEF}"), (0x1EF0, "\u{1EF1}"),
+    (0x1EF2, "\u{1EF3}"), (0x1EF4, "\u{1EF5}"), (0x1EF6, "\u{1EF7}"), (0x1EF8, "\u{1EF9}"),
+    (0x1EFA, "\u{1EFB}"), (0x1EFC, "\u{1EFD}"), (0x1EFE, "\u{1EFF}"), (0x1F08, "\u{1F00}"),
+    (0x1F09, "\u{1F01}"), (0x1F0A, "\u{1F02}"), (0x1F0B, "\u{1F03}"), (0x1F0C, "\u{1F04}"),
+    (0x1F0D, "\u{1F05}"), (0x1F0E, "\u{1F06}"), (0x1F0F, "\u{1F07}"), (0x1F18, "\u{1F10}"),
+    (0x1F19, "\u{1F11}"), (0x1F1A, "\u{1F12}"), (0x1F1B, "\u{1F13}"), (0x1F1C, "\u{1F14}"),
+    (0x1F1D, "\u{1F15}"), (0x1F28, "\u{1F20}"), (0x1F29, "\u{1F21}"), (0x1F2A, "\u{1F22}"),
+    (0x1F2B, "\u{1F23}"), (0x1F2C, "\u{1F24}"), (0x1F2D, "\u{1F25}"), (0x1F2E, "\u{1F26}"),
+    (0x1F2F, "\u{1F27}"), (0x1F38, "\u{1F30}"), (0x1F39, "\u{1F31}"), (0x1F3A, "\u{1F32}"),
+    (0x1F3B, "\u{1F33}"), (0x1F3C, "\u{1F34}"), (0x1F3D, "\u{1F35}"), (0x1F3E, "\u{1F36}"),
+    (0x1F3F, "\u{1F37}"), (0x1F48, "\u{1F40}"), (0x1F49, "\u{1F41}"), (0x1F4A, "\u{1F42}"),
+    (0x1F4B, "\u{1F43}"), (0x1F4C, "\u{1F44}"), (0x1F4D, "\u{1F45}"), (0x1F50, "\u{3C5}\u{313}"),
+    (0x1F52, "\u{3C5}\u{313}\u{300}"), (0x1F54, "\u{3C5}\u{313}\u{301}"),
+    (0x1F56, "\u{3C5}\u{313}\u{342}"), (0x1F59, "\u{1F51}"), (0x1F5B, "\u{1F53}"),
+    (0x1F5D, "\u{1F55}"), (0x1F5F, "\u{1F57}"), (0x1F68, "\u{1F60}"), (0x1F69, "\u{1F61}"),
+    (0x1F6A, "\u{1F62}"), (0x1F6B, "\u{1F63}"), (0x1F6C, "\u{1F64}"), (0x1F6D, "\u{1F65}"),
+    (0x1F6E, "\u{1F66}"), (0x1F6F, "\u{1F67}"), (0x1F80, "\u{1F00}\u{3B9}"),
+    (0x1F81, "\u{1F01}\u{3B9}"), (0x1F82, "\u{1F02}\u{3B9}"), (0x1F83, "\u{1F03}\u{3B9}"),
+    (0x1F84, "\u{1F04}\u{3B9}"), (0x1F85, "\u{1F05}\u{3B9}"), (0x1F86, "\u{1F06}\u{3B9}"),
+    (0x1F87, "\u{1F07}\u{3B9}"), (0x1F88, "\u{1F00}\u{3B9}"), (0x1F89, "\u{1F01}\u{3B9}"),
+    (0x1F8A, "\u{1F02}\u{3B9}"), (0x1F8B, "\u{1F03}\u{3B9}"), (0x1F8C, "\u{1F04}\u{3B9}"),
+    (0x1F8D, "\u{1F05}\u{3B9}"), (0x1F8E, "\u{1F06}\u{3B9}"), (0x1F8F, "\u{1F07}\u{3B9}"),
+    (0x1F90, "\u{1F20}\u{3B9}"), (0x1F91, "\u{1F21}\u{3B9}"), (0x1F92, "\u{1F22}\u{3B9}"),
+    (0x1F93, "\u{1F23}\u{3B9}"), (0x1F94, "\u{1F24}\u{3B9}"), (0x1F95, "\u{1F25}\u{3B9}"),
+    (0x1F96, "\u{1F26}\u{3B9}"), (0x1F97, "\u{1F27}\u{3B9}"), (0x1F98, "\u{1F20}\u{3B9}"),
+    (0x1F99, "\u{1F21}\u{3B9}"), (0x1F9A, "\u{1F22}\u{3B9}"), (0x1F9B, "\u{1F23}\u{3B9}"),
+    (0x1F9C, "\u{1F24}\u{3B9}"), (0x1F9D, "\u{1F25}\u{3B9}"), (0x1F9E, "\u{1F26}\u{3B9}"),
+    (0x1F9F, "\u{1F27}\u{3B9}"), (0x1FA0, "\u{1F60}\u{3B9}"), (0x1FA1, "\u{1F61}\u{3B9}"),
+    (0x1FA2, "\u{1F62}\u{3B9}"), (0x1FA3, "\u{1F63}\u{3B9}"), (0x1FA4, "\u{1F64}\u{3B9}"),
+    (0x1FA5, "\u{1F65}\u{3B9}"), (0x1FA6, "\u{1F66}\u{3B9}"), (0x1FA7, "\u{1F67}\u{3B9}"),
+    (0x1FA8, "\u{1F60}\u{3B9}"), (0x1FA9, "\u{1F61}\u{3B9}"), (0x1FAA, "\u{1F62}\u{3B9}"),
+    (0x1FAB, "\u{1F63}\u{3B9}"), (0x1FAC, "\u{1F64}\u{3B9}"), (0x1FAD, "\u{1F65}\u{3B9}"),
+    (0x1FAE, "\u{1F66}\u{3B9}"), (0x1FAF, "\u{1F67}\u{3B9}"), (0x1FB2, "\u{1F70}\u{3B9}"),
+    (0x1FB3, "\u{3B1}\u{3B9}"), (0x1FB4, "\u{3AC}\u{3B9}"), (0x1FB6, "\u{3B1}\u{342}"),
+    (0x1FB7, "\u{3B1}\u{342}\u{3B9}"), (0x1FB8, "\u{1FB0}"), (0x1FB9, "\u{1FB1}"),
+    (0x1FBA, "\u{1F70}"), (0x1FBB, "\u{1F71}"), (0x1FBC, "\u{3B1}\u{3B9}"), (0x1FBE, "\u{3B9}"),
+    (0x1FC2, "\u{1F74}\u{3B9}"), (0x1FC3, "\u{3B7}\u{3B9}"), (0x1FC4, "\u{3AE}\u{3B9}"),
+    (0x1FC6, "\u{3B7}\u{342}"), (0x1FC7, "\u{3B7}\u{342}\u{3B9}"), (0x1FC8, "\u{1F72}"),
+    (0x1FC9, "\u{1F73}"), (0x1FCA, "\u{1F74}"), (0x1FCB, "\u{1F75}"), (0x1FCC, "\u{3B7}\u{3B9}"),
+    (0x1FD2, "\u{3B9}\u{308}\u{300}"), (0x1FD3, "\u{3B9}\u{308}\u{301}"),
+    (0x1FD6, "\u{3B9}\u{342}"), (0x1FD7, "\u{3B9}\u{308}\u{342}"), (0x1FD8, "\u{1FD0}"),
+    (0x1FD9, "\u{1FD1}"), (0x1FDA, "\u{1F76}"), (0x1FDB, "\u{1F77}"),
+    (0x1FE2, "\u{3C5}\u{308}\u{300}"), (0x1FE3, "\u{3C5}\u{308}\u{301}"),
+    (0x1FE4, "\u{3C1}\u{313}"), (0x1FE6, "\u{3C5}\u{342}"), (0x1FE7, "\u{3C5}\u{308}\u{342}"),
+    (0x1FE8, "\u{1FE0}"), (0x1FE9, "\u{1FE1}"), (0x1FEA, "\u{1F7A}"), (0x1FEB, "\u{1F7B}"),
+    (0x1FEC, "\u{1FE5}"), (0x1FF2, "\u{1F7C}\u{3B9}"), (0x1FF3, "\u{3C9}\u{3B9}"),
+    (0x1FF4, "\u{3CE}\u{3B9}"), (0x1FF6, "\u{3C9}\u{342}"), (0x1FF7, "\u{3C9}\u{342}\u{3B9}"),
+    (0x1FF8, "\u{1F78}"), (0x1FF9, "\u{1F79}"), (0x1FFA, "\u{1F7C}"), (0x1FFB, "\u{1F7D}"),
+    (0x1FFC, "\u{3C9}\u{3B9}"), (0x2126, "\u{3C9}"), (0x212A, "k"), (0x212B, "\u{E5}"),
+    (0x2132, "\u{214E}"), (0x2160, "\u{2170}"), (0x2161, "\u{2171}"), (0x2162, "\u{2172}"),
+    (0x2163, "\u{2173}"), (0x2164, "\u{2174}"), (0x2165, "\u{2175}"), (0x2166, "\u{2176}"),
+    (0x2167, "\u{2177}"), (0x2168, "\u{2178}"), (0x2169, "\u{2179}"), (0x216A, "\u{217A}"),
+    (0x216B, "\u{217B}"), (0x216C, "\u{217C}"), (0x216D, "\u{217D}"), (0x216E, "\u{217E}"),
+    (0x216F, "\u{217F}"), (0x2183, "\u{2184}"), (0x24B6, "\u{24D0}"), (0x24B7, "\u{24D1}"),
+    (0x24B8, "\u{24D2}"), (0x24B9, "\u{24D3}"), (0x24BA, "\u{24D4}"), (0x24BB, "\u{24D5}"),
+    (0x24BC, "\u{24D6}"), (0x24BD, "\u{24D7}"), (0x24BE, "\u{24D8}"), (0x24BF, "\u{24D9}"),
+    (0x24C0, "\u{24DA}"), (0x24C1, "\u{24DB}"), (0x24C2, "\u{24DC}"), (0x24C3, "\u{24DD}"),
+    (0x24C4, "\u{24DE}"), (0x24C5, "\u{24DF}"), (0x24C6, "\u{24E0}"), (0x24C7, "\u{24E1}"),
+    (0x24C8, "\u{24E2}"), (0x24C9, "\u{24E3}"), (0x24CA, "\u{24E4}"), (0x24CB, "\u{24E5}"),
+    (0x24CC, "\u{24E6}"), (0x24CD, "\u{24E7}"), (0x24CE, "\u{24E8}"), (0x24CF, "\u{24E9}"),
+    (0x2C00, "\u{2C30}"), (0x2C01, "\u{2C31}"), (0x2C02, "\u{2C32}"), (0x2C03, "\u{2C33}"),
+    (0x2C04, "\u{2C34}"), (0x2C05, "\u{2C35}"), (0x2C06, "\u{2C36}"), (0x2C07, "\u{2C37}"),
+    (0x2C08, "\u{2C38}"), (0x2C09, "\u{2C39}"), (0x2C0A, "\u{2C3A}"), (0x2C0B, "\u{2C3B}"),
+    (0x2C0C, "\u{2C3C}"), (0x2C0D, "\u{2C3D}"), (0x2C0E, "\u{2C3E}"), (0x2C0F, "\u{2C3F}"),
+    (0x2C10, "\u{2C40}"), (0x2C11, "\u{2C41}"), (0x2C12, "\u{2C42}"), (0x2C13, "\u{2C43}"),
+    (0x2C14, "\u{2C44}"), (0x2C15, "\u{2C45}"), (0x2C16, "\u{2C46}"), (0x2C17, "\u{2C47}"),
+    (0x2C18, "\u{2C48}"), (0x2C19, "\u{2C49}"), (0x2C1A, "\u{2C4A}"), (0x2C1B, "\u{2C4B}"),
+    (0x2C1C, "\u{2C4C}"), (0x2C1D, "\u{2C4D}"), (0x2C1E, "\u{2C4E}"), (0x2C1F, "\u{2C4F}"),
+    (0x2C20, "\u{2C50}"), (0x2C21, "\u{2C51}"), (0x2C22, "\u{2C52}"), (0x2C23, "\u{2C53}"),
+    (0x2C24, "\u{2C54}"), (0x2C25, "\u{2C55}"), (0x2C26, "\u{2C56}"), (0x2C27, "\u{2C57}"),
+    (0x2C28, "\u{2C58}"), (0x2C29, "\u{2C59}"), (0x2C2A, "\u{2C5A}"), (0x2C2B, "\u{2C5B}"),
+    (0x2C2C, "\u{2C5C}"), (0x2C2D, "\u{2C5D}"), (0x2C2E, "\u{2C5E}"), (0x2C2F, "\u{2C5F}"),
+    (0x2C60, "\u{2C61}"), (0x2C62, "\u{26B}"), (0x2C63, "\u{1D7D}"), (0x2C64, "\u{27D}"),
+    (0x2C67, "\u{2C68}"), (0x2C69, "\u{2C6A}"), (0x2C6B, "\u{2C6C}"), (0x2C6D, "\u{251}"),
+    (0x2C6E, "\u{271}"), (0x2C6F, "\u{250}"), (0x2C70, "\u{252}"), (0x2C72, "\u{2C73}"),
+    (0x2C75, "\u{2C76}"), (0x2C7E, "\u{23F}"), (0x2C7F, "\u{240}"), (0x2C80, "\u{2C81}"),
+    (0x2C82, "\u{2C83}"), (0x2C84, "\u{2C85}"), (0x2C86, "\u{2C87}"), (0x2C88, "\u{2C89}"),
+    (0x2C8A, "\u{2C8B}"), (0x2C8C, "\u{2C8D}"), (0x2C8E, "\u{2C8F}"), (0x2C90, "\u{2C91}"),
+    (0x2C92, "\u{2C93}"), (0x2C94, "\u{2C95}"), (0x2C96, "\u{2C97}"), (0x2C98, "\u{2C99}"),
+    (0x2C9A, "\u{2C9B}"), (0x2C9C, "\u{2C9D}"), (0x2C9E, "\u{2C9F}"), (0x2CA0, "\u{2CA1}"),
+    (0x2CA2, "\u{2CA3}"), (0x2CA4, "\u{2CA5}"), (0x2CA6, "\u{2CA7}"), (0x2CA8, "\u{2CA9}"),
+    (0x2CAA, "\u{2CAB}"), (0x2CAC, "\u{2CAD}"), (0x2CAE, "\u{2CAF}"), (0x2CB0, "\u{2CB1}"),
+    (0x2CB2, "\u{2CB3}"), (0x2CB4, "\u{2CB5}"), (0x2CB6, "\u{2CB7}"), (0x2CB8, "\u{2CB9}"),
+    (0x2CBA, "\u{2CBB}"), (0x2CBC, "\u{2CBD}"), (0x2CBE, "\u{2CBF}"), (0x2CC0, "\u{2CC1}"),
+    (0x2CC2, "\u{2CC3}"), (0x2CC4, "\u{2CC5}"), (0x2CC6, "\u{2CC7}"), (0x2CC8, "\u{2CC9}"),
+    (0x2CCA, "\u{2CCB}"), (0x2CCC, "\u{2CCD}"), (0x2CCE, "\u{2CCF}"), (0x2CD0, "\u{2CD1}"),
+    (0x2CD2, "\u{2CD3}"), (0x2CD4, "\u{2CD5}"), (0x2CD6, "\u{2CD7}"), (0x2CD8, "\u{2CD9}"),
+    (0x2CDA, "\u{2CDB}"), (0x2CDC, "\u{2CDD}"), (0x2CDE, "\u{2CDF}"), (0x2CE0, "\u{2CE1}"),
+    (0x2CE2, "\u{2CE3}"), (0x2CEB, "\u{2CEC}"), (0x2CED, "\u{2CEE}"), (0x2CF2, "\u{2CF3}"),
+    (0xA640, "\u{A641}"), (0xA642, "\u{A643}"), (0xA644, "\u{A645}"), (0xA646, "\u{A647}"),
+    (0xA648, "\u{A649}"), (0xA64A, "\u{A64B}"), (0xA64C, "\u{A64D}"), (0xA64E, "\u{A64F}"),
+    (0xA650, "\u{A651}"), (0xA652, "\u{A653}"), (0xA654, "\u{A655}"), (0xA656, "\u{A657}"),
+    (0xA658, "\u{A659}"), (0xA65A, "\u{A65B}"), (0xA65C, "\u{A65D}"), (0xA65E, "\u{A65F}"),
+    (0xA660, "\u{A661}"), (0xA662, "\u{A663}"), (0xA664, "\u{A665}"), (0xA666, "\u{A667}"),
+    (0xA668, "\u{A669}"), (0xA66A, "\u{A66B}"), (0xA66C, "\u{A66D}"), (0xA680, "\u{A681}"),
+    (0xA682, "\u{A683}"), (0xA684, "\u{A685}"), (0xA686, "\u{A687}"), (0xA688, "\u{A689}"),
+    (0xA68A, "\u{A68B}"), (0xA68C, "\u{A68D}"), (0xA68E, "\u{A68F}"), (0xA690, "\u{A691}"),
+    (0xA692, "\u{A693}"), (0xA694, "\u{A695}"), (0xA696, "\u{A697}"), (0xA698, "\u{A699}"),
+    (0xA69A, "\u{A69B}"), (0xA722, "\u{A723}"), (0xA724, "\u{A725}"), (0xA726, "\u{A727}"),
+    (0xA728, "\u{A729}"), (0xA72A, "\u{A72B}"), (0xA72C, "\u{A72D}"), (0xA72E, "\u{A72F}"),
+    (0xA732, "\u{A733}"), (0xA734, "\u{A735}"), (0xA736, "\u{A737}"), (0xA738, "\u{A739}"),
+    (0xA73A, "\u{A73B}"), (0xA73C, "\u{A73D}"), (0xA73E, "\u{A73F}"), (0xA740, "\u{A741}"),
+    (0xA742, "\u{A743}"), (0xA744, "\u{A745}"), (0xA746, "\u{A747}"), (0xA748, "\u{A749}"),
+    (0xA74A, "\u{A74B}"), (0xA74C, "\u{A74D}"), (0xA74E, "\u{A74F}"), (0xA750, "\u{A751}"),
+    (0xA752, "\u{A753}"), (0xA754, "\u{A755}"), (0xA756, "\u{A757}"), (0xA758, "\u{A759}"),
+    (0xA75A, "\u{A75B}"), (0xA75C, "\u{A75D}"), (0xA75E, "\u{A75F}"), (0xA760, "\u{A761}"),
+    (0xA762, "\u{A763}"), (0xA764, "\u{A765}"), (0xA766, "\u{A767}"), (0xA768, "\u{A769}"),
+    (0xA76A, "\u{A76B}"), (0xA76C, "\u{A76D}"), (0xA76E, "\u{A76F}"), (0xA779, "\u{A77A}"),
+    (0xA77B, "\u{A77C}"), (0xA77D, "\u{1D79}"), (0xA77E, "\u{A77F}"), (0xA780, "\u{A781}"),
+    (0xA782, "\u{A783}"), (0xA784, "\u{A785}"), (0xA786, "\u{A787}"), (0xA78B, "\u{A78C}"),
+    (0xA78D, "\u{265}"), (0xA790, "\u{A791}"), (0xA792, "\u{A793}"), (0xA796, "\u{A797}"),
+    (0xA798, "\u{A799}"), (0xA79A, "\u{A79B}"), (0xA79C, "\u{A79D}"), (0xA79E, "\u{A79F}"),
+    (0xA7A0, "\u{A7A1}"), (0xA7A2, "\u{A7A3}"), (0xA7A4, "\u{A7A5}"), (0xA7A6, "\u{A7A7}"),
+    (0xA7A8, "\u{A7A9}"), (0xA7AA, "\u{266}"), (0xA7AB, "\u{25C}"), (0xA7AC, "\u{261}"),
+    (0xA7AD, "\u{26C}"), (0xA7AE, "\u{26A}"), (0xA7B0, "\u{29E}"), (0xA7B1, "\u{287}"),
+    (0xA7B2, "\u{29D}"), (0xA7B3, "\u{AB53}"), (0xA7B4, "\u{A7B5}"), (0xA7B6, "\u{A7B7}"),
+    (0xA7B8, "\u{A7B9}"), (0xA7BA, "\u{A7BB}"), (0xA7BC, "\u{A7BD}"), (0xA7BE, "\u{A7BF}"),
+    (0xA7C0, "\u{A7C1}"), (0xA7C2, "\u{A7C3}"), (0xA7C4, "\u{A794}"), (0xA7C5, "\u{282}"),
+    (0xA7C6, "\u{1D8E}"), (0xA7C7, "\u{A7C8}"), (0xA7C9, "\u{A7CA}"), (0xA7CB, "\u{264}"),
+    (0xA7CC, "\u{A7CD}"), (0xA7CE, "\u{A7CF}"), (0xA7D0, "\u{A7D1}"), (0xA7D2, "\u{A7D3}"),
+    (0xA7D4, "\u{A7D5}"), (0xA7D6, "\u{A7D7}"), (0xA7D8, "\u{A7D9}"), (0xA7DA, "\u{A7DB}"),
+    (0xA7DC, "\u{19B}"), (0xA7F5, "\u{A7F6}"), (0xAB70, "\u{13A0}"), (0xAB71, "\u{13A1}"),
+    (0xAB72, "\u{13A2}"), (0xAB73, "\u{13A3}"), (0xAB74, "\u{13A4}"), (0xAB75, "\u{13A5}"),
+    (0xAB76, "\u{13A6}"), (0xAB77, "\u{13A7}"), (0xAB78, "\u{13A8}"), (0xAB79, "\u{13A9}"),
+    (0xAB7A, "\u{13AA}"), (0xAB7B, "\u{13AB}"), (0xAB7C, "\u{13AC}"), (0xAB7D, "\u{13AD}"),
+    (0xAB7E, "\u{13AE}"), (0xAB7F, "\u{13AF}"), (0xAB80, "\u{13B0}"), (0xAB81, "\u{13B1}"),
+    (0xAB82, "\u{13B2}"), (0xAB83, "\u{13B3}"), (0xAB84, "\u{13B4}"), (0xAB85, "\u{13B5}"),
+    (0xAB86, "\u{13B6}"), (0xAB87, "\u{13B7}"), (0xAB88, "\u{13B8}"), (0xAB89, "\u{13B9}"),
+    (0xAB8A, "\u{13BA}"), (0xAB8B, "\u{13BB}"), (0xAB8C, "\u{13BC}"), (0xAB8D, "\u{13BD}"),
+    (0xAB8E, "\u{13BE}"), (0xAB8F, "\u{13BF}"), (0xAB90, "\u{13C0}"), (0xAB91, "\u{13C1}"),
+    (0xAB92, "\u{13C2}"), (0xAB93, "\u{13C3}"), (0xAB94, "\u{13C4}"), (0xAB95, "\u{13C5}"),
+    (0xAB96, "\u{13C6}"), (0xAB97, "\u{13C7}"), (0xAB98, "\u{13C8}"), (0xAB99, "\u{13C9}"),
+    (0xAB9A, "\u{13CA}"), (0xAB9B, "\u{13CB}"), (0xAB9C, "\u{13CC}"), (0xAB9D, "\u{13CD}"),
+    (0xAB9E, "\u{13CE}"), (0xAB9F, "\u{13CF}"), (0xABA0, "\u{13D0}"), (0xABA1, "\u{13D1}"),
+    (0xABA2, "\u{13D2}"), (0xABA3, "\u{13D3}"), (0xABA4, "\u{13D4}"), (0xABA5, "\u{13D5}"),
+    (0xABA6, "\u{13D6}"), (0xABA7, "\u{13D7}"), (0xABA8, "\u{13D8}"), (0xABA9, "\u{13D9}"),
+    (0xABAA, "\u{13DA}"), (0xABAB, "\u{13DB}"), (0xABAC, "\u{13DC}"), (0xABAD, "\u{13DD}"),
+    (0xABAE, "\u{13DE}"), (0xABAF, "\u{13DF}"), (0xABB0, "\u{13E0}"), (0xABB1, "\u{13E1}"),
+    (0xABB2, "\u{13E2}"), (0xABB3, "\u{13E3}"), (0xABB4, "\u{13E4}"), (0xABB5, "\u{13E5}"),
+    (0xABB6, "\u{13E6}"), (0xABB7, "\u{13E7}"), (0xABB8, "\u{13E8}"), (0xABB9, "\u{13E9}"),
+    (0xABBA, "\u{13EA}"), (0xABBB, "\u{13EB}"), (0xABBC, "\u{13EC}"), (0xABBD, "\u{13ED}"),
+    (0xABBE, "\u{13EE}"), (0xABBF, "\u{13EF}"), (0xFB00, "ff"), (0xFB01, "fi"), (0xFB02, "fl"),
+    (0xFB03, "ffi"), (0xFB04, "ffl"), (0xFB05, "st"), (0xFB06, "st"), (0xFB13, "\u{574}\u{576}"),
+    (0xFB14, "\u{574}\u{565}"), (0xFB15, "\u{574}\u{56B}"), (0xFB16, "\u{57E}\u{576}"),
+    (0xFB17, "\u{574}\u{56D}"), (0xFF21, "\u{FF41}"), (0xFF22, "\u{FF42}"), (0xFF23, "\u{FF43}"),
+    (0xFF24, "\u{FF44}"), (0xFF25, "\u{FF45}"), (0xFF26, "\u{FF46}"), (0xFF27, "\u{FF47}"),
+    (0xFF28, "\u{FF48}"), (0xFF29, "\u{FF49}"), (0xFF2A, "\u{FF4A}"), (0xFF2B, "\u{FF4B}"),
+    (0xFF2C, "\u{FF4C}"), (0xFF2D, "\u{FF4D}"), (0xFF2E, "\u{FF4E}"), (0xFF2F, "\u{FF4F}"),
+    (0xFF30, "\u{FF50}"), (0xFF31, "\u{FF51}"), (0xFF32, "\u{FF52}"), (0xFF33, "\u{FF53}"),
+    (0xFF34, "\u{FF54}"), (0xFF35, "\u{FF55}"), (0xFF36, "\u{FF56}"), (0xFF37, "\u{FF57}"),
+    (0xFF38, "\u{FF58}"), (0xFF39, "\u{FF59}"), (0xFF3A, "\u{FF5A}"), (0x10400, "\u{10428}"),
+    (0x10401, "\u{10429}"), (0x10402, "\u{1042A}"), (0x10403, "\u{1042B}"), (0x10404, "\u{1042C}"),
+    (0x10405, "\u{1042D}"), (0x10406, "\u{1042E}"), (0x10407, "\u{1042F}"), (0x10408, "\u{10430}"),
+    (0x10409, "\u{10431}"), (0x1040A, "\u{10432}"), (0x1040B, "\u{10433}"), (0x1040C, "\u{10434}"),
+    (0x1040D, "\u{10435}"), (0x1040E, "\u{10436}"), (0x1040F, "\u{10437}"), (0x10410, "\u{10438}"),
+    (0x10411, "\u{10439}"), (0x10412, "\u{1043A}"), (0x10413, "\u{1043B}"), (0x10414, "\u{1043C}"),
+    (0x10415, "\u{1043D}"), (0x10416, "\u{1043E}"), (0x10417, "\u{1043F}"), (0x10418, "\u{10440}"),
+    (0x10419, "\u{10441}"), (0x1041A, "\u{10442}"), (0x1041B, "\u{10443}"), (0x1041C, "\u{10444}"),
+    (0x1041D, "\u{10445}"), (0x1041E, "\u{10446}"), (0x1041F, "\u{10447}"), (0x10420, "\u{10448}"),
+    (0x10421, "\u{10449}"), (0x10422, "\u{1044A}"), (0x10423, "\u{1044B}"), (0x10424, "\u{1044C}"),
+    (0x10425, "\u{1044D}"), (0x10426, "\u{1044E}"), (0x10427, "\u{1044F}"), (0x104B0, "\u{104D8}"),
+    (0x104B1, "\u{104D9}"), (0x104B2, "\u{104DA}"), (0x104B3, "\u{104DB}"), (0x104B4, "\u{104DC}"),
+    (0x104B5, "\u{104DD}"), (0x104B6, "\u{104DE}"), (0x104B7, "\u{104DF}"), (0x104B8, "\u{104E0}"),
+    (0x104B9, "\u{104E1}"), (0x104BA, "\u{104E2}"), (0x104BB, "\u{104E3}"), (0x104BC, "\u{104E4}"),
+    (0x104BD, "\u{104E5}"), (0x104BE, "\u{104E6}"), (0x104BF, "\u{104E7}"), (0x104C0, "\u{104E8}"),
+    (0x104C1, "\u{104E9}"), (0x104C2, "\u{104EA}"), (0x104C3, "\u{104EB}"), (0x104C4, "\u{104EC}"),
+    (0x104C5, "\u{104ED}"), (0x104C6, "\u{104EE}"), (0x104C7, "\u{104EF}"), (0x104C8, "\u{104F0}"),
+    (0x104C9, "\u{104F1}"), (0x104CA, "\u{104F2}"), (0x104CB, "\u{104F3}"), (0x104CC, "\u{104F4}"),
+    (0x104CD, "\u{104F5}"), (0x104CE, "\u{104F6}"), (0x104CF, "\u{104F7}"), (0x104D0, "\u{104F8}"),
+    (0x104D1, "\u{104F9}"), (0x104D2, "\u{104FA}"), (0x104D3, "\u{104FB}"), (0x10570, "\u{10597}"),
+    (0x10571, "\u{10598}"), (0x10572, "\u{10599}"), (0x10573, "\u{1059A}"), (0x10574, "\u{1059B}"),
+    (0x10575, "\u{1059C}"), (0x10576, "\u{1059D}"), (0x10577, "\u{1059E}"), (0x10578, "\u{1059F}"),
+    (0x10579, "\u{105A0}"), (0x1057A, "\u{105A1}"), (0x1057C, "\u{105A3}"), (0x1057D, "\u{105A4}"),
+    (0x1057E, "\u{105A5}"), (0x1057F, "\u{105A6}"), (0x10580, "\u{105A7}"), (0x10581, "\u{105A8}"),
+    (0x10582, "\u{105A9}"), (0x10583, "\u{105AA}"), (0x10584, "\u{105AB}"), (0x10585, "\u{105AC}"),
+    (0x10586, "\u{105AD}"), (0x10587, "\u{105AE}"), (0x10588, "\u{105AF}"), (0x10589, "\u{105B0}"),
+    (0x1058A, "\u{105B1}"), (0x1058C, "\u{105B3}"), (0x1058D, "\u{105B4}"), (0x1058E, "\u{105B5}"),
+    (0x1058F, "\u{105B6}"), (0x10590, "\u{105B7}"), (0x10591, "\u{105B8}"), (0x10592, "\u{105B9}"),
+    (0x10594, "\u{105BB}"), (0x10595, "\u{105BC}"), (0x10C80, "\u{10CC0}"), (0x10C81, "\u{10CC1}"),
+    (0x10C82, "\u{10CC2}"), (0x10C83, "\u{10CC3}"), (0x10C84, "\u{10CC4}"), (0x10C85, "\u{10CC5}"),
+    (0x10C86, "\u{10CC6}"), (0x10C87, "\u{10CC7}"), (0x10C88, "\u{10CC8}"), (0x10C89, "\u{10CC9}"),
+    (0x10C8A, "\u{10CCA}"), (0x10C8B, "\u{10CCB}"), (0x10C8C, "\u{10CCC}"), (0x10C8D, "\u{10CCD}"),
+    (0x10C8E, "\u{10CCE}"), (0x10C8F, "\u{10CCF}"), (0x10C90, "\u{10CD0}"), (0x10C91, "\u{10CD1}"),
+    (0x10C92, "\u{10CD2}"), (0x10C93, "\u{10CD3}"), (0x10C94, "\u{10CD4}"), (0x10C95, "\u{10CD5}"),
+    (0x10C96, "\u{10CD6}"), (0x10C97, "\u{10CD7}"), (0x10C98, "\u{10CD8}"), (0x10C99, "\u{10CD9}"),
+    (0x10C9A, "\u{10CDA}"), (0x10C9B, "\u{10CDB}"), (0x10C9C, "\u{10CDC}"), (0x10C9D, "\u{10CDD}"),
+    (0x10C9E, "\u{10CDE}"), (0x10C9F, "\u{10CDF}"), (0x10CA0, "\u{10CE0}"), (0x10CA1, "\u{10CE1}"),
+    (0x10CA2, "\u{10CE2}"), (0x10CA3, "\u{10CE3}"), (0x10CA4, "\u{10CE4}"), (0x10CA5, "\u{10CE5}"),
+    (0x10CA6, "\u{10CE6}"), (0x10CA7, "\u{10CE7}"), (0x10CA8, "\u{10CE8}"), (0x10CA9, "\u{10CE9}"),
+    (0x10CAA, "\u{10CEA}"), (0x10CAB, "\u{10CEB}"), (0x10CAC, "\u{10CEC}"), (0x10CAD, "\u{10CED}"),
+    (0x10CAE, "\u{10CEE}"), (0x10CAF, "\u{10CEF}"), (0x10CB0, "\u{10CF0}"), (0x10CB1, "\u{10CF1}"),
+    (0x10CB2, "\u{10CF2}"), (0x10D50, "\u{10D70}"), (0x10D51, "\u{10D71}"), (0x10D52, "\u{10D72}"),
+    (0x10D53, "\u{10D73}"), (0x10D54, "\u{10D74}"), (0x10D55, "\u{10D75}"), (0x10D56, "\u{10D76}"),
+    (0x10D57, "\u{10D77}"), (0x10D58, "\u{10D78}"), (0x10D59, "\u{10D79}"), (0x10D5A, "\u{10D7A}"),
+    (0x10D5B, "\u{10D7B}"), (0x10D5C, "\u{10D7C}"), (0x10D5D, "\u{10D7D}"), (0x10D5E, "\u{10D7E}"),
+    (0x10D5F, "\u{10D7F}"), (0x10D60, "\u{10D80}"), (0x10D61, "\u{10D81}"), (0x10D62, "\u{10D82}"),
+    (0x10D63, "\u{10D83}"), (0x10D64, "\u{10D84}"), (0x10D65, "\u{10D85}"), (0x118A0, "\u{118C0}"),
+    (0x118A1, "\u{118C1}"), (0x118A2, "\u{118C2}"), (0x118A3, "\u{118C3}"), (0x118A4, "\u{118C4}"),
+    (0x118A5, "\u{118C5}"), (0x118A6, "\u{118C6}"), (0x118A7, "\u{118C7}"), (0x118A8, "\u{118C8}"),
+    (0x118A9, "\u{118C9}"), (0x118AA, "\u{118CA}"), (0x118AB, "\u{118CB}"), (0x118AC, "\u{118CC}"),
+    (0x118AD, "\u{118CD}"), (0x118AE, "\u{118CE}"), (0x118AF, "\u{118CF}"), (0x118B0, "\u{118D0}"),
+    (0x118B1, "\u{118D1}"), (0x118B2, "\u{118D2}"), (0x118B3, "\u{118D3}"), (0x118B4, "\u{118D4}"),
+    (0x118B5, "\u{118D5}"), (0x118B6, "\u{118D6}"), (0x118B7, "\u{118D7}"), (0x118B8, "\u{118D8}"),
+    (0x118B9, "\u{118D9}"), (0x118BA, "\u{118DA}"), (0x118BB, "\u{118DB}"), (0x118BC, "\u{118DC}"),
+    (0x118BD, "\u{118DD}"), (0x118BE, "\u{118DE}"), (0x118BF, "\u{118DF}"), (0x16E40, "\u{16E60}"),
+    (0x16E41, "\u{16E61}"), (0x16E42, "\u{16E62}"), (0x16E43, "\u{16E63}"), (0x16E44, "\u{16E64}"),
+    (0x16E45, "\u{16E65}"), (0x16E46, "\u{16E66}"), (0x16E47, "\u{16E67}"), (0x16E48, "\u{16E68}"),
+    (0x16E49, "\u{16E69}"), (0x16E4A, "\u{16E6A}"), (0x16E4B, "\u{16E6B}"), (0x16E4C, "\u{16E6C}"),
+    (0x16E4D, "\u{16E6D}"), (0x16E4E, "\u{16E6E}"), (0x16E4F, "\u{16E6F}"), (0x16E50, "\u{16E70}"),
+    (0x16E51, "\u{16E71}"), (0x16E52, "\u{16E72}"), (0x16E53, "\u{16E73}"), (0x16E54, "\u{16E74}"),
+    (0x16E55, "\u{16E75}"), (0x16E56, "\u{16E76}"), (0x16E57, "\u{16E77}"), (0x16E58, "\u{16E78}"),
+    (0x16E59, "\u{16E79}"), (0x16E5A, "\u{16E7A}"), (0x16E5B, "\u{16E7B}"), (0x16E5C, "\u{16E7C}"),
+    (0x16E5D, "\u{16E7D}"), (0x16E5E, "\u{16E7E}"), (0x16E5F, "\u{16E7F}"), (0x16EA0, "\u{16EBB}"),
+    (0x16EA1, "\u{16EBC}"), (0x16EA2, "\u{16EBD}"), (0x16EA3, "\u{16EBE}"), (0x16EA4, "\u{16EBF}"),
+    (0x16EA5, "\u{16EC0}"), (0x16EA6, "\u{16EC1}"), (0x16EA7, "\u{16EC2}"), (0x16EA8, "\u{16EC3}"),
+    (0x16EA9, "\u{16EC4}"), (0x16EAA, "\u{16EC5}"), (0x16EAB, "\u{16EC6}"), (0x16EAC, "\u{16EC7}"),
+    (0x16EAD, "\u{16EC8}"), (0x16EAE, "\u{16EC9}"), (0x16EAF, "\u{16ECA}"), (0x16EB0, "\u{16ECB}"),
+    (0x16EB1, "\u{16ECC}"), (0x16EB2, "\u{16ECD}"), (0x16EB3, "\u{16ECE}"), (0x16EB4, "\u{16ECF}"),
+    (0x16EB5, "\u{16ED0}"), (0x16EB6, "\u{16ED1}"), (0x16EB7, "\u{16ED2}"), (0x16EB8, "\u{16ED3}"),
+    (0x1E900, "\u{1E922}"), (0x1E901, "\u{1E923}"), (0x1E902, "\u{1E924}"), (0x1E903, "\u{1E925}"),
+    (0x1E904, "\u{1E926}"), (0x1E905, "\u{1E927}"), (0x1E906, "\u{1E928}"), (0x1E907, "\u{1E929}"),
+    (0x1E908, "\u{1E92A}"), (0x1E909, "\u{1E92B}"), (0x1E90A, "\u{1E92C}"), (0x1E90B, "\u{1E92D}"),
+    (0x1E90C, "\u{1E92E}"), (0x1E90D, "\u{1E92F}"), (0x1E90E, "\u{1E930}"), (0x1E90F, "\u{1E931}"),
+    (0x1E910, "\u{1E932}"), (0x1E911, "\u{1E933}"), (0x1E912, "\u{1E934}"), (0x1E913, "\u{1E935}"),
+    (0x1E914, "\u{1E936}"), (0x1E915, "\u{1E937}"), (0x1E916, "\u{1E938}"), (0x1E917, "\u{1E939}"),
+    (0x1E918, "\u{1E93A}"), (0x1E919, "\u{1E93B}"), (0x1E91A, "\u{1E93C}"), (0x1E91B, "\u{1E93D}"),
+    (0x1E91C, "\u{1E93E}"), (0x1E91D, "\u{1E93F}"), (0x1E91E, "\u{1E940}"), (0x1E91F, "\u{1E941}"),
+    (0x1E920, "\u{1E942}"), (0x1E921, "\u{1E943}"),
+];
+
+/// Full canonical decomposition of the characters that change, except for
+/// Hangul syllables, which are decomposed algorithmically
+#[rustfmt::skip]
+pub(super) static DECOMPOSITIONS: &[(u32, &str)] = &[
+    (0xC0, "A\u{300}"), (0xC1, "A\u{301}"), (0xC2, "A\u{302}"), (0xC3, "A\u{303}"),
+    (0xC4, "A\u{308}"), (0xC5, "A\u{30A}"), (0xC7, "C\u{327}"), (0xC8, "E\u{300}"),
+    (0xC9, "E\u{301}"), (0xCA, "E\u{302}"), (0xCB, "E\u{308}"), (0xCC, "I\u{300}"),
+    (0xCD, "I\u{301}"), (0xCE, "I\u{302}"), (0xCF, "I\u{308}"), (0xD1, "N\u{303}"),
+    (0xD2, "O\u{300}"), (0xD3, "O\u{301}"), (0xD4, "O\u{302}"), (0xD5, "O\u{303}"),
+    (0xD6, "O\u{308}"), (0xD9, "U\u{300}"), (0xDA, "U\u{301}"), (0xDB, "U\u{302}"),
+    (0xDC, "U\u{308}"), (0xDD, "Y\u{301}"), (0xE0, "a\u{300}"), (0xE1, "a\u{301}"),
+    (0xE2, "a\u{302}"), (0xE3, "a\u{303}"), (0xE4, "a\u{308}"), (0xE5, "a\u{30A}"),
+    (0xE7, "c\u{327}"), (0xE8, "e\u{300}"), (0xE9, "e\u{301}"), (0xEA, "e\u{302}"),
+    (0xEB, "e\u{308}"), (0xEC, "i\u{300}"), (0xED, "i\u{301}"), (0xEE, "i\u{302}"),
+    (0xEF, "i\u{308}"), (0xF1, "n\u{303}"), (0xF2, "o\u{300}"), (0xF3, "o\u{301}"),
+    (0xF4, "o\u{302}"), (0xF5, "o\u{303}"), (0xF6, "o\u{308}"), (0xF9, "u\u{300}"),
+    (0xFA, "u\u{301}"), (0xFB, "u\u{302}"), (0xFC, "u\u{308}"), (0xFD, "y\u{301}"),
+    (0xFF, "y\u{308}"), (0x100, "A\u{304}"), (0x101, "a\u{304}"), (0x102, "A\u{306}"),
+    (0x103, "a\u{306}"), (0x104, "A\u{328}"), (0x105, "a\u{328}"), (0x106, "C\u{301}"),
+    (0x107, "c\u{301}"), (0x108, "C\u{302}"), (0x109, "c\u{302}"), (0x10A, "C\u{307}"),
+    (0x10B, "c\u{307}"), (0x10C, "C\u{30C}"), (0x10D, "c\u{30C}"), (0x10E, "D\u{30C}"),
+    (0x10F, "d\u{30C}"), (0x112, "E\u{304}"), (0x113, "e\u{304}"), (0x114, "E\u{306}"),
+    (0x115, "e\u{306}"), (0x116, "E\u{307}"), (0x117, "e\u{307}"), (0x118, "E\u{328}"),
+    (0x119, "e\u{328}"), (0x11A, "E\u{30C}"), (0x11B, "e\u{30C}"), (0x11C, "G\u{302}"),
+    (0x11D, "g\u{302}"), (0x11E, "G\u{306}"), (0x11F, "g\u{306}"), (0x120, "G\u{307}"),
+    (0x121, "g\u{307}"), (0x122, "G\u{327}"), (0x123, "g\u{327}"), (0x124, "H\u{302}"),
+    (0x125, "h\u{302}"), (0x128, "I\u{303}"), (0x129, "i\u{303}"), (0x12A, "I\u{304}"),
+    (0x12B, "i\u{304}"), (0x12C, "I\u{306}"), (0x12D, "i\u{306}"), (0x12E, "I\u{328}"),
+    (0x12F, "i\u{328}"), (0x130, "I\u{307}"), (0x134, "J\u{302}"), (0x135, "j\u{302}"),
+    (0x136, "K\u{327}"), (0x137, "k\u{327}"), (0x139, "L\u{301}"), (0x13A, "l\u{301}"),
+    (0x13B, "L\u{327}"), (0x13C, "l\u{327}"), (0x13D, "L\u{30C}"), (0x13E, "l\u{30C}"),
+    (0x143, "N\u{301}"), (0x144, "n\u{301}"), (0x145, "N\u{327}"), (0x146, "n\u{327}"),
+    (0x147, "N\u{30C}"), (0x148, "n\u{30C}"), (0x14C, "O\u{304}"), (0x14D, "o\u{304}"),
+    (0x14E, "O\u{306}"), (0x14F, "o\u{306}"), (0x150, "O\u{30B}"), (0x151, "o\u{30B}"),
+    (0x154, "R\u{301}"), (0x155, "r\u{301}"), (0x156, "R\u{327}"), (0x157, "r\u{327}"),
+    (0x158, "R\u{30C}"), (0x159, "r\u{30C}"), (0x15A, "S\u{301}"), (0x15B, "s\u{301}"),
+    (0x15C, "S\u{302}"), (0x15D, "s\u{302}"), (0x15E, "S\u{327}"), (0x15F, "s\u{327}"),
+    (0x160, "S\u{30C}"), (0x161, "s\u{30C}"), (0x162, "T\u{327}"), (0x163, "t\u{327}"),
+    (0x164, "T\u{30C}"), (0x165, "t\u{30C}"), (0x168, "U\u{303}"), (0x169, "u\u{303}"),
+    (0x16A, "U\u{304}"), (0x16B, "u\u{304}"), (0x16C, "U\u{306}"), (0x16D, "u\u{306}"),
+    (0x16E, "U\u{30A}"), (0x16F, "u\u{30A}"), (0x170, "U\u{30B}"), (0x171, "u\u{30B}"),
+    (0x172, "U\u{328}"), (0x173, "u\u{328}"), (0x174, "W\u{302}"), (0x175, "w\u{302}"),
+    (0x176, "Y\u{302}"), (0x177, "y\u{302}"), (0x178, "Y\u{308}"), (0x179, "Z\u{301}"),
+    (0x17A, "z\u{301}"), (0x17B, "Z\u{307}"), (0x17C, "z\u{307}"), (0x17D, "Z\u{30C}"),
+    (0x17E, "z\u{30C}"), (0x1A0, "O\u{31B}"), (0x1A1, "o\u{31B}"), (0x1AF, "U\u{31B}"),
+    (0x1B0, "u\u{31B}"), (0x1CD, "A\u{30C}"), (0x1CE, "a\u{30C}"), (0x1CF, "I\u{30C}"),
+    (0x1D0, "i\u{30C}"), (0x1D1, "O\u{30C}"), (0x1D2, "o\u{30C}"), (0x1D3, "U\u{30C}"),
+    (0x1D4, "u\u{30C}"), (0x1D5, "U\u{308}\u{304}"), (0x1D6, "u\u{308}\u{304}"),
+    (0x1D7, "U\u{308}\u{301}"), (0x1D8, "u\u{308}\u{301}"), (0x1D9, "U\u{308}\u{30C}"),
+    (0x1DA, "u\u{308}\u{30C}"), (0x1DB, "U\u{308}\u{300}"), (0x1DC, "u\u{308}\u{300}"),
+    (0x1DE, "A\u{308}\u{304}"), (0x1DF, "a\u{308}\u{304}"), (0x1E0, "A\u{307}\u{304}"),
+    (0x1E1, "a\u{307}\u{304}"), (0x1E2, "\u{C6}\u{304}"), (0x1E3, "\u{E6}\u{304}"),
+    (0x1E6, "G\u{30C}"), (0x1E7, "g\u{30C}"), (0x1E8, "K\u{30C}"), (0x1E9, "k\u{30C}"),
+    (0x1EA, "O\u{328}"), (0x1EB, "o\u{328}"), (0x1EC, "O\u{328}\u{304}"),
+    (0x1ED, "o\u{328}\u{304}"), (0x1EE, "\u{1B7}\u{30C}"), (0x1EF, "\u{292}\u{30C}"),
+    (0x1F0, "j\u{30C}"), (0x1F4, "G\u{301}"), (0x1F5, "g\u{301}"), (0x1F8, "N\u{300}"),
+    (0x1F9, "n\u{300}"), (0x1FA, "A\u{30A}\u{301}"), (0x1FB, "a\u{30A}\u{301}"),
+    (0x1FC, "\u{C6}\u{301}"), (0x1FD, "\u{E6}\u{301}"), (0x1FE, "\u{D8}\u{301}"),
+    (0x1FF, "\u{F8}\u{301}"), (0x200, "A\u{30F}"), (0x201, "a\u{30F}"), (0x202, "A\u{311}"),
+    (0x203, "a\u{311}"), (0x204, "E\u{30F}"), (0x205, "e\u{30F}"), (0x206, "E\u{311}"),
+    (0x207, "e\u{311}"), (0x208, "I\u{30F}"), (0x209, "i\u{30F}"), (0x20A, "I\u{311}"),
+    (0x20B, "i\u{311}"), (0x20C, "O\u{30F}"), (0x20D, "o\u{30F}"), (0x20E, "O\u{311}"),
+    (0x20F, "o\u{311}"), (0x210, "R\u{30F}"), (0x211, "r\u{30F}"), (0x212, "R\u{311}"),
+    (0x213, "r\u{311}"), (0x214, "U\u{30F}"), (0x215, "u\u{30F}"), (0x216, "U\u{311}"),
+    (0x217, "u\u{311}"), (0x218, "S\u{326}"), (0x219, "s\u{326}"), (0x21A, "T\u{326}"),
+    (0x21B, "t\u{326}"), (0x21E, "H\u{30C}"), (0x21F, "h\u{30C}"), (0x226, "A\u{307}"),
+    (0x227, "a\u{307}"), (0x228, "E\u{327}"), (0x229, "e\u{327}"), (0x22A, "O\u{308}\u{304}"),
+    (0x22B, "o\u{308}\u{304}"), (0x22C, "O\u{303}\u{304}"), (0x22D, "o\u{303}\u{304}"),
+    (0x22E, "O\u{307}"), (0x22F, "o\u{307}"), (0x230, "O\u{307}\u{304}"),
+    (0x231, "o\u{307}\u{304}"), (0x232, "Y\u{304}"), (0x233, "y\u{304}"), (0x340, "\u{300}"),
+    (0x341, "\u{301}"), (0x343, "\u{313}"), (0x344, "\u{308}\u{301}"), (0x374, "\u{2B9}"),
+    (0x37E, ";"), (0x385, "\u{A8}\u{301}"), (0x386, "\u{391}\u{301}"), (0x387, "\u{B7}"),
+    (0x388, "\u{395}\u{301}"), (0x389, "\u{397}\u{301}"), (0x38A, "\u{399}\u{301}"),
+    (0x38C, "\u{39F}\u{301}"), (0x38E, "\u{3A5}\u{301}"), (0x38F, "\u{3A9}\u{301}"),
+    (0x390, "\u{3B9}\u{308}\u{301}"), (0x3AA, "\u{399}\u{308}"), (0x3AB, "\u{3A5}\u{308}"),
+    (0x3AC, "\u{3B1}\u{301}"), (0x3AD, "\u{3B5}\u{301}"), (0x3AE, "\u{3B7}\u{301}"),
+    (0x3AF, "\u{3B9}\u{301}"), (0x3B0, "\u{3C5}\u{308}\u{301}"), (0x3CA, "\u{3B9}\u{308}"),
+    (0x3CB, "\u{3C5}\u{308}"), (0x3CC, "\u{3BF}\u{301}"), (0x3CD, "\u{3C5}\u{301}"),
+    (0x3CE, "\u{3C9}\u{301}"), (0x3D3, "\u{3D2}\u{301}"), (0x3D4, "\u{3D2}\u{308}"),
+    (0x400, "\u{415}\u{300}"), (0x401, "\u{415}\u{308}"), (0x403, "\u{413}\u{301}"),
+    (0x407, "\u{406}\u{308}"), (0x40C, "\u{41A}\u{301}"), (0x40D, "\u{418}\u{300}"),
+    (0x40E, "\u{423}\u{306}"), (0x419, "\u{418}\u{306}"), (0x439, "\u{438}\u{306}"),
+    (0x450, "\u{435}\u{300}"), (0x451, "\u{435}\u{308}"), (0x453, "\u{433}\u{301}"),
+    (0x457, "\u{456}\u{308}"), (0x45C, "\u{43A}\u{301}"), (0x45D, "\u{438}\u{300}"),
+    (0x45E, "\u{443}\u{306}"), (0x476, "\u{474}\u{30F}"), (0x477, "\u{475}\u{30F}"),
+    (0x4C1, "\u{416}\u{306}"), (0x4C2, "\u{436}\u{306}"), (0x4D0, "\u{410}\u{306}"),
+    (0x4D1, "\u{430}\u{306}"), (0x4D2, "\u{410}\u{308}"), (0x4D3, "\u{430}\u{308}"),
+    (0x4D6, "\u{415}\u{306}"), (0x4D7, "\u{435}\u{306}"), (0x4DA, "\u{4D8}\u{308}"),
+    (0x4DB, "\u{4D9}\u{308}"), (0x4DC, "\u{416}\u{308}"), (0x4DD, "\u{436}\u{308}"),
+    (0x4DE, "\u{417}\u{308}"), (0x4DF, "\u{437}\u{308}"), (0x4E2, "\u{418}\u{304}"),
+    (0x4E3, "\u{438}\u{304}"), (0x4E4, "\u{418}\u{308}"), (0x4E5, "\u{438}\u{308}"),
+    (0x4E6, "\u{41E}\u{308}"), (0x4E7, "\u{43E}\u{308}"), (0x4EA, "\u{4E8}\u{308}"),
+    (0x4EB, "\u{4E9}\u{308}"), (0x4EC, "\u{42D}\u{308}"), (0x4ED, "\u{44D}\u{308}"),
+    (0x4EE, "\u{423}\u{304}"), (0x4EF, "\u{443}\u{304}"), (0x4F0, "\u{423}\u{308}"),
+    (0x4F1, "\u{443}\u{308}"), (0x4F2, "\u{423}\u{30B}"), (0x4F3, "\u{443}\u{30B}"),
+    (0x4F4, "\u{427}\u{308}"), (0x4F5, "\u{447}\u{308}"), (0x4F8, "\u{42B}\u{308}"),
+    (0x4F9, "\u{44B}\u{308}"), (0x622, "\u{627}\u{653}"), (0x623, "\u{627}\u{654}"),
+    (0x624, "\u{648}\u{654}"), (0x625, "\u{627}\u{655}"), (0x626, "\u{64A}\u{654}"),
+    (0x6C0, "\u{6D5}\u{654}"), (0x6C2, "\u{6C1}\u{654}"), (0x6D3, "\u{6D2}\u{654}"),
+    (0x929, "\u{928}\u{93C}"), (0x931, "\u{930}\u{93C}"), (0x934, "\u{933}\u{93C}"),
+    (0x958, "\u{915}\u{93C}"), (0x959, "\u{916}\u{93C}"), (0x95A, "\u{917}\u{93C}"),
+    (0x95B, "\u{91C}\u{93C}"), (0x95C, "\u{921}\u{93C}"), (0x95D, "\u{922}\u{93C}"),
+    (0x95E, "\u{92B}\u{93C}"), (0x95F, "\u{92F}\u{93C}"), (0x9CB, "\u{9C7}\u{9BE}"),
+    (0x9CC, "\u{9C7}\u{9D7}"), (0x9DC, "\u{9A1}\u{9BC}"), (0x9DD, "\u{9A2}\u{9BC}"),
+    (0x9DF, "\u{9AF}\u{9BC}"), (0xA33, "\u{A32}\u{A3C}"), (0xA36, "\u{A38}\u{A3C}"),
+    (0xA59, "\u{A16}\u{A3C}"), (0xA5A, "\u{A17}\u{A3C}"), (0xA5B, "\u{A1C}\u{A3C}"),
+    (0xA5E, "\u{A2B}\u{A3C}"), (0xB48, "\u{B47}\u{B56}"), (0xB4B, "\u{B47}\u{B3E}"),
+    (0xB4C, "\u{B47}\u{B57}"), (0xB5C, "\u{B21}\u{B3C}"), (0xB5D, "\u{B22}\u{B3C}"),
+    (0xB94, "\u{B92}\u{BD7}"), (0xBCA, "\u{BC6}\u{BBE}"), (0xBCB, "\u{BC7}\u{BBE}"),
+    (0xBCC, "\u{BC6}\u{BD7}"), (0xC48, "\u{C46}\u{C56}"), (0xCC0, "\u{CBF}\u{CD5}"),
+    (0xCC7, "\u{CC6}\u{CD5}"), (0xCC8, "\u{CC6}\u{CD6}"), (0xCCA, "\u{CC6}\u{CC2}"),
+    (0xCCB, "\u{CC6}\u{CC2}\u{CD5}"), (0xD4A, "\u{D46}\u{D3E}"), (0xD4B, "\u{D47}\u{D3E}"),
+    (0xD4C, "\u{D46}\u{D57}"), (0xDDA, "\u{DD9}\u{DCA}"), (0xDDC, "\u{DD9}\u{DCF}"),
+    (0xDDD, "\u{DD9}\u{DCF}\u{DCA}"), (0xDDE, "\u{DD9}\u{DDF}"), (0xF43, "\u{F42}\u{FB7}"),
+    (0xF4D, "\u{F4C}\u{FB7}"), (0xF52, "\u{F51}\u{FB7}"), (0xF57, "\u{F56}\u{FB7}"),
+    (0xF5C, "\u{F5B}\u{FB7}"), (0xF69, "\u{F40}\u{FB5}"), (0xF73, "\u{F71}\u{F72}"),
+    (0xF75, "\u{F71}\u{F74}"), (0xF76, "\u{FB2}\u{F80}"), (0xF78, "\u{FB3}\u{F80}"),
+    (0xF81, "\u{F71}\u{F80}"), (0xF93, "\u{F92}\u{FB7}"), (0xF9D, "\u{F9C}\u{FB7}"),
+    (0xFA2, "\u{FA1}\u{FB7}"), (0xFA7, "\u{FA6}\u{FB7}"), (0xFAC, "\u{FAB}\u{FB7}"),
+    (0xFB9, "\u{F90}\u{FB5}"), (0x1026, "\u{1025}\u{102E}"), (0x1B06, "\u{1B05}\u{1B35}"),
+    (0x1B08, "\u{1B07}\u{1B35}"), (0x1B0A, "\u{1B09}\u{1B35}"), (0x1B0C, "\u{1B0B}\u{1B35}"),
+    (0x1B0E, "\u{1B0D}\u{1B35}"), (0x1B12, "\u{1B11}\u{1B35}"), (0x1B3B, "\u{1B3A}\u{1B35}"),
+    (0x1B3D, "\u{1B3C}\u{1B35}"), (0x1B40, "\u{1B3E}\u{1B35}"), (0x1B41, "\u{1B3F}\u{1B35}"),
+    (0x1B43, "\u{1B42}\u{1B35}"), (0x1E00, "A\u{325}"), (0x1E01, "a\u{325}"), (0x1E02, "B\u{307}"),
+    (0x1E03, "b\u{307}"), (0x1E04, "B\u{323}"), (0x1E05, "b\u{323}"), (0x1E06, "B\u{331}"),
+    (0x1E07, "b\u{331}"), (0x1E08, "C\u{327}\u{301}"), (0x1E09, "c\u{327}\u{301}"),
+    (0x1E0A, "D\u{307}"), (0x1E0B, "d\u{307}"), (0x1E0C, "D\u{323}"), (0x1E0D, "d\u{323}"),
+    (0x1E0E, "D\u{331}"), (0x1E0F, "d\u{331}"), (0x1E10, "D\u{327}"), (0x1E11, "d\u{327}"),
+    (0x1E12, "D\u{32D}"), (0x1E13, "d\u{32D}"), (0x1E14, "E\u{304}\u{300}"),
+    (0x1E15, "e\u{304}\u{300}"), (0x1E16, "E\u{304}\u{301}"), (0x1E17, "e\u{304}\u{301}"),
+    (0x1E18, "E\u{32D}"), (0x1E19, "e\u{32D}"), (0x1E1A, "E\u{330}"), (0x1E1B, "e\u{330}"),
+    (0x1E1C, "E\u{327}\u{306}"), (0x1E1D, "e\u{327}\u{306}"), (0x1E1E, "F\u{307}"),
+    (0x1E1F, "f\u{307}"), (0x1E20, "G\u{304}"), (0x1E21, "g\u{304}"), (0x1E22, "H\u{307}"),
+    (0x1E23, "h\u{307}"), (0x1E24, "H\u{323}"), (0x1E25, "h\u{323}"), (0x1E26, "H\u{308}"),
+    (0x1E27, "h\u{308}"), (0x1E28, "H\u{327}"), (0x1E29, "h\u{327}"), (0x1E2A, "H\u{32E}"),
+    (0x1E2B, "h\u{32E}"), (0x1E2C, "I\u{330}"), (0x1E2D, "i\u{330}"), (0x1E2E, "I\u{308}\u{301}"),
+    (0x1E2F, "i\u{308}\u{301}"), (0x1E30, "K\u{301}"), (0x1E31, "k\u{301}"), (0x1E32, "K\u{323}"),
+    (0x1E33, "k\u{323}"), (0x1E34, "K\u{331}"), (0x1E35, "k\u{331}"), (0x1E36, "L\u{323}"),
+    (0x1E37, "l\u{323}"), (0x1E38, "L\u{323}\u{304}"), (0x1E39, "l\u{323}\u{304}"),
+    (0x1E3A, "L\u{331}"), (0x1E3B, "l\u{331}"), (0x1E3C, "L\u{32D}"), (0x1E3D, "l\u{32D}"),
+    (0x1E3E, "M\u{301}"), (0x1E3F, "m\u{301}"), (0x1E40, "M\u{307}"), (0x1E41, "m\u{307}"),
+    (0x1E42, "M\u{323}"), (0x1E43, "m\u{323}"), (0x1E44, "N\u{307}"), (0x1E45, "n\u{307}"),
+    (0x1E46, "N\u{323}"), (0x1E47, "n\u{323}"), (0x1E48, "N\u{331}"), (0x1E49, "n\u{331}"),
+    (0x1E4A, "N\u{32D}"), (0x1E4B, "n\u{32D}"), (0x1E4C, "O\u{303}\u{301}"),
+    (0x1E4D, "o\u{303}\u{301}"), (0x1E4E, "O\u{303}\u{308}"), (0x1E4F, "o\u{303}\u{308}"),
+    (0x1E50, "O\u{304}\u{300}"), (0x1E51, "o\u{304}\u{300}"), (0x1E52, "O\u{304}\u{301}"),
+    (0x1E53, "o\u{304}\u{301}"), (0x1E54, "P\u{301}"), (0x1E55, "p\u{301}"), (0x1E56, "P\u{307}"),
+    (0x1E57, "p\u{307}"), (0x1E58, "R\u{307}"), (0x1E59, "r\u{307}"), (0x1E5A, "R\u{323}"),
+    (0x1E5B, "r\u{323}"), (0x1E5C, "R\u{323}\u{304}"), (0x1E5D, "r\u{323}\u{304}"),
+    (0x1E5E, "R\u{331}"), (0x1E5F, "r\u{331}"), (0x1E60, "S\u{307}"), (0x1E61, "s\u{307}"),
+    (0x1E62, "S\u{323}"), (0x1E63, "s\u{323}"), (0x1E64, "S\u{301}\u{307}"),
+    (0x1E65, "s\u{301}\u{307}"), (0x1E66, "S\u{30C}\u{307}"), (0x1E67, "s\u{30C}\u{307}"),
+    (0x1E68, "S\u{323}\u{307}"), (0x1E69, "s\u{323}\u{307}"), (0x1E6A, "T\u{307}"),
+    (0x1E6B, "t\u{307}"), (0x1E6C, "T\u{323}"), (0x1E6D, "t\u{323}"), (0x1E6E, "T\u{331}"),
+    (0x1E6F, "t\u{331}"), (0x1E70, "T\u{32D}"), (0x1E71, "t\u{32D}"), (0x1E72, "U\u{324}"),
+    (0x1E73, "u\u{324}"), (0x1E74, "U\u{330}"), (0x1E75, "u\u{330}"), (0x1E76, "U\u{32D}"),
+    (0x1E77, "u\u{32D}"), (0x1E78, "U\u{303}\u{301}"), (0x1E79, "u\u{303}\u{301}"),
+    (0x1E7A, "U\u{304}\u{308}"), (0x1E7B, "u\u{304}\u{308}"), (0x1E7C, "V\u{303}"),
+    (0x1E7D, "v\u{303}"), (0x1E7E, "V\u{323}"), (0x1E7F, "v\u{323}"), (0x1E80, "W\u{300}"),
+    (0x1E81, "w\u{300}"), (0x1E82, "W\u{301}"), (0x1E83, "w\u{301}"), (0x1E84, "W\u{308}"),
+    (0x1E85, "w\u{308}"), (0x1E86, "W\u{307}"), (0x1E87, "w\u{307}"), (0x1E88, "W\u{323}"),
+    (0x1E89, "w\u{323}"), (0x1E8A, "X\u{307}"), (0x1E8B, "x\u{307}"), (0x1E8C, "X\u{308}"),
+    (0x1E8D, "x\u{308}"), (0x1E8E, "Y\u{307}"), (0x1E8F, "y\u{307}"), (0x1E90, "Z\u{302}"),
+    (0x1E91, "z\u{302}"), (0x1E92, "Z\u{323}"), (0x1E93, "z\u{323}"), (0x1E94, "Z\u{331}"),
+    (0x1E95, "z\u{331}"), (0x1E96, "h\u{331}"), (0x1E97, "t\u{308}"), (0x1E98, "w\u{30A}"),
+    (0x1E99, "y\u{30A}"), (0x1E9B, "\u{17F}\u{307}"), (0x1EA0, "A\u{323}"), (0x1EA1, "a\u{323}"),
+    (0x1EA2, "A\u{309}"), (0x1EA3, "a\u{309}"), (0x1EA4, "A\u{302}\u{301}"),
+    (0x1EA5, "a\u{302}\u{301}"), (0x1EA6, "A\u{302}\u{300}"), (0x1EA7, "a\u{302}\u{300}"),
+    (0x1EA8, "A\u{302}\u{309}"), (0x1EA9, "a\u{302}\u{309}"), (0x1EAA, "A\u{302}\u{303}"),
+    (0x1EAB, "a\u{302}\u{303}"), (0x1EAC, "A\u{323}\u{302}"), (0x1EAD, "a\u{323}\u{302}"),
+    (0x1EAE, "A\u{306}\u{301}"), (0x1EAF, "a\u{306}\u{301}"), (0x1EB0, "A\u{306}\u{300}"),
+    (0x1EB1, "a\u{306}\u{300}"), (0x1EB2, "A\u{306}\u{309}"), (0x1EB3, "a\u{306}\u{309}"),
+    (0x1EB4, "A\u{306}\u{303}"), (0x1EB5, "a\u{306}\u{303}"), (0x1EB6, "A\u{323}\u{306}"),
+    (0x1EB7, "a\u{323}\u{306}"), (0x1EB8, "E\u{323}"), (0x1EB9, "e\u{323}"), (0x1EBA, "E\u{309}"),
+    (0x1EBB, "e\u{309}"), (0x1EBC, "E\u{303}"), (0x1EBD, "e\u{303}"), (0x1EBE, "E\u{302}\u{301}"),
+    (0x1EBF, "e\u{302}\u{301}"), (0x1EC0, "E\u{302}\u{300}"), (0x1EC1, "e\u{302}\u{300}"),
+    (0x1EC2, "E\u{302}\u{309}"), (0x1EC3, "e\u{302}\u{309}"), (0x1EC4, "E\u{302}\u{303}"),
+    (0x1EC5, "e\u{302}\u{303}"), (0x1EC6, "E\u{323}\u{302}"), (0x1EC7, "e\u{323}\u{302}"),
+    (0x1EC8, "I\u{309}"), (0x1EC9, "i\u{309}"), (0x1ECA, "I\u{323}"), (0x1ECB, "i\u{323}"),
+    (0x1ECC, "O\u{323}"), (0x1ECD, "o\u{323}"), (0x1ECE, "O\u{309}"), (0x1ECF, "o\u{309}"),
+    (0x1ED0, "O\u{302}\u{301}"), (0x1ED1, "o\u{302}\u{301}"), (0x1ED2, "O\u{302}\u{300}"),
+    (0x1ED3, "o\u{302}\u{300}"), (0x1ED4, "O\u{302}\u{309}"), (0x1ED5, "o\u{302}\u{309}"),
+    (0x1ED6, "O\u{302}\u{303}"), (0x1ED7, "o\u{302}\u{303}"), (0x1ED8, "O\u{323}\u{302}"),
+    (0x1ED9, "o\u{323}\u{302}"), (0x1EDA, "O\u{31B}\u{301}"), (0x1EDB, "o\u{31B}\u{301}"),
+    (0x1EDC, "O\u{31B}\u{300}"), (0x1EDD, "o\u{31B}\u{300}"), (0x1EDE, "O\u{31B}\u{309}"),
+    (0x1EDF, "o\u{31B}\u{309}"), (0x1EE0, "O\u{31B}\u{303}"), (0x1EE1, "o\u{31B}\u{303}"),
+    (0x1EE2, "O\u{31B}\u{323}"), (0x1EE3, "o\u{31B}\u{323}"), (0x1EE4, "U\u{323}"),
+    (0x1EE5, "u\u{323}"), (0x1EE6, "U\u{309}"), (0x1EE7, "u\u{309}"), (0x1EE8, "U\u{31B}\u{301}"),
+    (0x1EE9, "u\u{31B}\u{301}"), (0x1EEA, "U\u{31B}\u{300}"), (0x1EEB, "u\u{31B}\u{300}"),
+    (0x1EEC, "U\u{31B}\u{309}"), (0x1EED, "u\u{31B}\u{309}"), (0x1EEE, "U\u{31B}\u{303}"),
+    (0x1EEF, "u\u{31B}\u{303}"), (0x1EF0, "U\u{31B}\u{323}"), (0x1EF1, "u\u{31B}\u{323}"),
+    (0x1EF2, "Y\u{300}"), (0x1EF3, "y\u{300}"), (0x1EF4, "Y\u{323}"), (0x1EF5, "y\u{323}"),
+    (0x1EF6, "Y\u{309}"), (0x1EF7, "y\u{309}"), (0x1EF8, "Y\u{303}"), (0x1EF9, "y\u{303}"),
+    (0x1F00, "\u{3B1}\u{313}"), (0x1F01, "\u{3B1}\u{314}"), (0x1F02, "\u{3B1}\u{313}\u{300}"),
+    (0x1F03, "\u{3B1}\u{314}\u{300}"), (0x1F04, "\u{3B1}\u{313}\u{301}"),
+    (0x1F05, "\u{3B1}\u{314}\u{301}"), (0x1F06, "\u{3B1}\u{313}\u{342}"),
+    (0x1F07, "\u{3B1}\u{314}\u{342}"), (0x1F08, "\u{391}\u{313}"), (0x1F09, "\u{391}\u{314}"),
+    (0x1F0A, "\u{391}\u{313}\u{300}"), (0x1F0B, "\u{391}\u{314}\u{300}"),
+    (0x1F0C, "\u{391}\u{313}\u{301}"), (0x1F0D, "\u{391}\u{314}\u{301}"),
+    (0x1F0E, "\u{391}\u{313}\u{342}"), (0x1F0F, "\u{391}\u{314}\u{342}"),
+    (0x1F10, "\u{3B5}\u{313}"), (0x1F11, "\u{3B5}\u{314}"), (0x1F12, "\u{3B5}\u{313}\u{300}"),
+    (0x1F13, "\u{3B5}\u{314}\u{300}"), (0x1F14, "\u{3B5}\u{313}\u{301}"),
+    (0x1F15, "\u{3B5}\u{314}\u{301}"), (0x1F18, "\u{395}\u{313}"), (0x1F19, "\u{395}\u{314}"),
+    (0x1F1A, "\u{395}\u{313}\u{300}"), (0x1F1B, "\u{395}\u{314}\u{300}"),
+    (0x1F1C, "\u{395}\u{313}\u{301}"), (0x1F1D, "\u{395}\u{314}\u{301}"),
+    (0x1F20, "\u{3B7}\u{313}"), (0x1F21, "\u{3B7}\u{314}"), (0x1F22, "\u{3B7}\u{313}\u{300}"),
+    (0x1F23, "\u{3B7}\u{314}\u{300}"), (0x1F24, "\u{3B7}\u{313}\u{301}"),
+    (0x1F25, "\u{3B7}\u{314}\u{301}"), (0x1F26, "\u{3B7}\u{313}\u{342}"),
+    (0x1F27, "\u{3B7}\u{314}\u{342}"), (0x1F28, "\u{397}\u{313}"), (0x1F29, "\u{397}\u{314}"),
+    (0x1F2A, "\u{397}\u{313}\u{300}"), (0x1F2B, "\u{397}\u{314}\u{300}"),
+    (0x1F2C, "\u{397}\u{313}\u{301}"), (0x1F2D, "\u{397}\u{314}\u{301}"),
+    (0x1F2E, "\u{397}\u{313}\u{342}"), (0x1F2F, "\u{397}\u{314}\u{342}"),
+    (0x1F30, "\u{3B9}\u{313}"), (0x1F31, "\u{3B9}\u{314}"), (0x1F32, "\u{3B9}\u{313}\u{300}"),
+    (0x1F33, "\u{3B9}\u{314}\u{300}"), (0x1F34, "\u{3B9}\u{313}\u{301}"),
+    (0x1F35, "\u{3B9}\u{314}\u{301}"), (0x1F36, "\u{3B9}\u{313}\u{342}"),
+    (0x1F37, "\u{3B9}\u{314}\u{342}"), (0x1F38, "\u{399}\u{313}"), (0x1F39, "\u{399}\u{314}"),
+    (0x1F3A, "\u{399}\u{313}\u{300}"), (0x1F3B, "\u{399}\u{314}\u{300}"),
+    (0x1F3C, "\u{399}\u{313}\u{301}"), (0x1F3D, "\u{399}\u{314}\u{301}"),
+    (0x1F3E, "\u{399}\u{313}\u{342}"), (0x1F3F, "\u{399}\u{314}\u{342}"),
+    (0x1F40, "\u{3BF}\u{313}"), (0x1F41, "\u{3BF}\u{314}"), (0x1F42, "\u{3BF}\u{313}\u{300}"),
+    (0x1F43, "\u{3BF}\u{314}\u{300}"), (0x1F44, "\u{3BF}\u{313}\u{301}"),
+    (0x1F45, "\u{3BF}\u{314}\u{301}"), (0x1F48, "\u{39F}\u{313}"), (0x1F49, "\u{39F}\u{314}"),
+    (0x1F4A, "\u{39F}\u{313}\u{300}"), (0x1F4B, "\u{39F}\u{314}\u{300}"),
+    (0x1F4C, "\u{39F}\u{313}\u{301}"), (0x1F4D, "\u{39F}\u{314}\u{301}"),
+    (0x1F50, "\u{3C5}\u{313}"), (0x1F51, "\u{3C5}\u{314}"), (0x1F52, "\u{3C5}\u{313}\u{300}"),
+    (0x1F53, "\u{3C5}\u{314}\u{300}"), (0x1F54, "\u{3C5}\u{313}\u{301}"),
+    (0x1F55, "\u{3C5}\u{314}\u{301}"), (0x1F56, "\u{3C5}\u{313}\u{342}"),
+    (0x1F57, "\u{3C5}\u{314}\u{342}"), (0x1F59, "\u{3A5}\u{314}"),
+    (0x1F5B, "\u{3A5}\u{314}\u{300}"), (0x1F5D, "\u{3A5}\u{314}\u{301}"),
+    (0x1F5F, "\u{3A5}\u{314}\u{342}"), (0x1F60, "\u{3C9}\u{313}"), (0x1F61, "\u{3C9}\u{314}"),
+    (0x1F62, "\u{3C9}\u{313}\u{300}"), (0x1F63, "\u{3C9}\u{314}\u{300}"),
+    (0x1F64, "\u{3C9}\u{313}\u{301}"), (0x1F65, "\u{3C9}\u{314}\u{301}"),
+    (0x1F66, "\u{3C9}\u{313}\u{342}"), (0x1F67, "\u{3C9}\u{314}\u{342}"),
+    (0x1F68, "\u{3A9}\u{313}"), (0x1F69, "\u{3A9}\u{314}"), (0x1F6A, "\u{3A9}\u{313}\u{300}"),
+    (0x1F6B, "\u{3A9}\u{314}\u{300}"), (0x1F6C, "\u{3A9}\u{313}\u{301}"),
+    (0x1F6D, "\u{3A9}\u{314}\u{301}"), (0x1F6E, "\u{3A9}\u{313}\u{342}"),
+    (0x1F6F, "\u{3A9}\u{314}\u{342}"), (0x1F70, "\u{3B1}\u{300}"), (0x1F71, "\u{3B1}\u{301}"),
+    (0x1F72, "\u{3B5}\u{300}"), (0x1F73, "\u{3B5}\u{301}"), (0x1F74, "\u{3B7}\u{300}"),
+    (0x1F75, "\u{3B7}\u{301}"), (0x1F76, "\u{3B9}\u{300}"), (0x1F77, "\u{3B9}\u{301}"),
+    (0x1F78, "\u{3BF}\u{300}"), (0x1F79, "\u{3BF}\u{301}"), (0x1F7A, "\u{3C5}\u{300}"),
+    (0x1F7B, "\u{3C5}\u{301}"), (0x1F7C, "\u{3C9}\u{300}"), (0x1F7D, "\u{3C9}\u{301}"),
+    (0x1F80, "\u{3B1}\u{313}\u{345}"), (0x1F81, "\u{3B1}\u{314}\u{345}"),
+    (0x1F82, "\u{3B1}\u{313}\u{300}\u{345}"), (0x1F83, "\u{3B1}\u{314}\u{300}\u{345}"),
+    (0x1F84, "\u{3B1}\u{313}\u{301}\u{345}"), (0x1F85, "\u{3B1}\u{314}\u{301}\u{345}"),
+    (0x1F86, "\u{3B1}\u{313}\u{342}\u{345}"), (0x1F87, "\u{3B1}\u{314}\u{342}\u{345}"),
+    (0x1F88, "\u{391}\u{313}\u{345}"), (0x1F89, "\u{391}\u{314}\u{345}"),
+    (0x1F8A, "\u{391}\u{313}\u{300}\u{345}"), (0x1F8B, "\u{391}\u{314}\u{300}\u{345}"),
+    (0x1F8C, "\u{391}\u{313}\u{301}\u{345}"), (0x1F8D, "\u{391}\u{314}\u{301}\u{345}"),
+    (0x1F8E, "\u{391}\u{313}\u{342}\u{345}"), (0x1F8F, "\u{391}\u{314}\u{342}\u{345}"),
+    (0x1F90, "\u{3B7}\u{313}\u{345}"), (0x1F91, "\u{3B7}\u{314}\u{345}"),
+    (0x1F92, "\u{3B7}\u{313}\u{300}\u{345}"), (0x1F93, "\u{3B7}\u{314}\u{300}\u{345}"),
+    (0x1F94, "\u{3B7}\u{313}\u{301}\u{345}"), (0x1F95, "\u{3B7}\u{314}\u{301}\u{345}"),
+    (0x1F96, "\u{3B7}\u{313}\u{342}\u{345}"), (0x1F97, "\u{3B7}\u{314}\u{342}\u{345}"),
+    (0x1F98, "\u{397}\u{313}\u{345}"), (0x1F99, "\u{397}\u{314}\u{345}"),
+    (0x1F9A, "\u{397}\u{313}\u{300}\u{345}"), (0x1F9B, "\u{397}\u{314}\u{300}\u{345}"),
+    (0x1F9C, "\u{397}\u{313}\u{301}\u{345}"), (0x1F9D, "\u{397}\u{314}\u{301}\u{345}"),
+    (0x1F9E, "\u{397}\u{313}\u{342}\u{345}"), (0x1F9F, "\u{397}\u{314}\u{342}\u{345}"),
+    (0x1FA0, "\u{3C9}\u{313}\u{345}"), (0x1FA1, "\u{3C9}\u{314}\u{345}"),
+    (0x1FA2, "\u{3C9}\u{313}\u{300}\u{345}"), (0x1FA3, "\u{3C9}\u{314}\u{300}\u{345}"),
+    (0x1FA4, "\u{3C9}\u{313}\u{301}\u{345}"), (0x1FA5, "\u{3C9}\u{314}\u{301}\u{345}"),
+    (0x1FA6, "\u{3C9}\u{313}\u{342}\u{345}"), (0x1FA7, "\u{3C9}\u{314}\u{342}\u{345}"),
+    (0x1FA8, "\u{3A9}\u{313}\u{345}"), (0x1FA9, "\u{3A9}\u{314}\u{345}"),
+    (0x1FAA, "\u{3A9}\u{313}\u{300}\u{345}"), (0x1FAB, "\u{3A9}\u{314}\u{300}\u{345}"),
+    (0x1FAC, "\u{3A9}\u{313}\u{301}\u{345}"), (0x1FAD, "\u{3A9}\u{314}\u{301}\u{345}"),
+    (0x1FAE, "\u{3A9}\u{313}\u{342}\u{345}"), (0x1FAF, "\u{3A9}\u{314}\u{342}\u{345}"),
+    (0x1FB0, "\u{3B1}\u{306}"), (0x1FB1, "\u{3B1}\u{304}"), (0x1FB2, "\u{3B1}\u{300}\u{345}"),
+    (0x1FB3, "\u{3B1}\u{345}"), (0x1FB4, "\u{3B1}\u{301}\u{345}"), (0x1FB6, "\u{3B1}\u{342}"),
+    (0x1FB7, "\u{3B1}\u{342}\u{345}"), (0x1FB8, "\u{391}\u{306}"), (0x1FB9, "\u{391}\u{304}"),
+    (0x1FBA, "\u{391}\u{300}"), (0x1FBB, "\u{391}\u{301}"), (0x1FBC, "\u{391}\u{345}"),
+    (0x1FBE, "\u{3B9}"), (0x1FC1, "\u{A8}\u{342}"), (0x1FC2, "\u{3B7}\u{300}\u{345}"),
+    (0x1FC3, "\u{3B7}\u{345}"), (0x1FC4, "\u{3B7}\u{301}\u{345}"), (0x1FC6, "\u{3B7}\u{342}"),
+    (0x1FC7, "\u{3B7}\u{342}\u{345}"), (0x1FC8, "\u{395}\u{300}"), (0x1FC9, "\u{395}\u{301}"),
+    (0x1FCA, "\u{397}\u{300}"), (0x1FCB, "\u{397}\u{301}"), (0x1FCC, "\u{397}\u{345}"),
+    (0x1FCD, "\u{1FBF}\u{300}"), (0x1FCE, "\u{1FBF}\u{301}"), (0x1FCF, "\u{1FBF}\u{342}"),
+    (0x1FD0, "\u{3B9}\u{306}"), (0x1FD1, "\u{3B9}\u{304}"), (0x1FD2, "\u{3B9}\u{308}\u{300}"),
+    (0x1FD3, "\u{3B9}\u{308}\u{301}"), (0x1FD6, "\u{3B9}\u{342}"),
+    (0x1FD7, "\u{3B9}\u{308}\u{342}"), (0x1FD8, "\u{399}\u{306}"), (0x1FD9, "\u{399}\u{304}"),
+    (0x1FDA, "\u{399}\u{300}"), (0x1FDB, "\u{399}\u{301}"), (0x1FDD, "\u{1FFE}\u{300}"),
+    (0x1FDE, "\u{1FFE}\u{301}"), (0x1FDF, "\u{1FFE}\u{342}"), (0x1FE0, "\u{3C5}\u{306}"),
+    (0x1FE1, "\u{3C5}\u{304}"), (0x1FE2, "\u{3C5}\u{308}\u{300}"),
+    (0x1FE3, "\u{3C5}\u{308}\u{301}"), (0x1FE4, "\u{3C1}\u{313}"), (0x1FE5, "\u{3C1}\u{314}"),
+    (0x1FE6, "\u{3C5}\u{342}"), (0x1FE7, "\u{3C5}\u{308}\u{342}"), (0x1FE8, "\u{3A5}\u{306}"),
+    (0x1FE9, "\u{3A5}\u{304}"), (0x1FEA, "\u{3A5}\u{300}"), (0x1FEB, "\u{3A5}\u{301}"),
+    (0x1FEC, "\u{3A1}\u{314}"), (0x1FED, "\u{A8}\u{300}"), (0x1FEE, "\u{A8}\u{301}"), (0x1FEF, "`"),
+    (0x1FF2, "\u{3C9}\u{300}\u{345}"), (0x1FF3, "\u{3C9}\u{345}"),
+    (0x1FF4, "\u{3C9}\u{301}\u{345}"), (0x1FF6, "\u{3C9}\u{342}"),
+    (0x1FF7, "\u{3C9}\u{342}\u{345}"), (0x1FF8, "\u{39F}\u{300}"), (0x1FF9, "\u{39F}\u{301}"),
+    (0x1FFA, "\u{3A9}\u{300}"), (0x1FFB, "\u{3A9}\u{301}"), (0x1FFC, "\u{3A9}\u{345}"),
+    (0x1FFD, "\u{B4}"), (0x2000, "\u{2002}"), (0x2001, "\u{2003}"), (0x2126, "\u{3A9}"),
+    (0x212A, "K"), (0x212B, "A\u{30A}"), (0x219A, "\u{2190}\u{338}"), (0x219B, "\u{2192}\u{338}"),
+    (0x21AE, "\u{2194}\u{338}"), (0x21CD, "\u{21D0}\u{338}"), (0x21CE, "\u{21D4}\u{338}"),
+    (0x21CF, "\u{21D2}\u{338}"), (0x2204, "\u{2203}\u{338}"), (0x2209, "\u{2208}\u{338}"),
+    (0x220C, "\u{220B}\u{338}"), (0x2224, "\u{2223}\u{338}"), (0x2226, "\u{2225}\u{338}"),
+    (0x2241, "\u{223C}\u{338}"), (0x2244, "\u{2243}\u{338}"), (0x2247, "\u{2245}\u{338}"),
+    (0x2249, "\u{2248}\u{338}"), (0x2260, "=\u{338}"), (0x2262, "\u{2261}\u{338}"),
+    (0x226D, "\u{224D}\u{338}"), (0x226E, "<\u{338}"), (0x226F, ">\u{338}"),
+    (0x2270, "\u{2264}\u{338}"), (0x2271, "\u{2265}\u{338}"), (0x2274, "\u{2272}\u{338}"),
+    (0x2275, "\u{2273}\u{338}"), (0x2278, "\u{2276}\u{338}"), (0x2279, "\u{2277}\u{338}"),
+    (0x2280, "\u{227A}\u{338}"), (0x2281, "\u{227B}\u{338}"), (0x2284, "\u{2282}\u{338}"),
+    (0x2285, "\u{2283}\u{338}"), (0x2288, "\u{2286}\u{338}"), (0x2289, "\u{2287}\u{338}"),
+    (0x22AC, "\u{22A2}\u{338}"), (0x22AD, "\u{22A8}\u{338}"), (0x22AE, "\u{22A9}\u{338}"),
+    (0x22AF, "\u{22AB}\u{338}"), (0x22E0, "\u{227C}\u{338}"), (0x22E1, "\u{227D}\u{338}"),
+    (0x22E2, "\u{2291}\u{338}"), (0x22E3, "\u{2292}\u{338}"), (0x22EA, "\u{22B2}\u{338}"),
+    (0x22EB, "\u{22B3}\u{338}"), (0x22EC, "\u{22B4}\u{338}"), (0x22ED, "\u{22B5}\u{338}"),
+    (0x2329, "\u{3008}"), (0x232A, "\u{3009}"), (0x2ADC, "\u{2ADD}\u{338}"),
+    (0x304C, "\u{304B}\u{3099}"), (0x304E, "\u{304D}\u{3099}"), (0x3050, "\u{304F}\u{3099}"),
+    (0x3052, "\u{3051}\u{3099}"), (0x3054, "\u{3053}\u{3099}"), (0x3056, "\u{3055}\u{3099}"),
+    (0x3058, "\u{3057}\u{3099}"), (0x305A, "\u{3059}\u{3099}"), (0x305C, "\u{305B}\u{3099}"),
+    (0x305E, "\u{305D}\u{3099}"), (0x3060, "\u{305F}\u{3099}"), (0x3062, "\u{3061}\u{3099}"),
+    (0x3065, "\u{3064}\u{3099}"), (0x3067, "\u{3066}\u{3099}"), (0x3069, "\u{3068}\u{3099}"),
+    (0x3070, "\u{306F}\u{3099}"), (0x3071, "\u{306F}\u{309A}"), (0x3073, "\u{3072}\u{3099}"),
+    (0x3074, "\u{3072}\u{309A}"), (0x3076, "\u{3075}\u{3099}"), (0x3077, "\u{3075}\u{309A}"),
+    (0x3079, "\u{3078}\u{3099}"), (0x307A, "\u{3078}\u{309A}"), (0x307C, "\u{307B}\u{3099}"),
+    (0x307D, "\u{307B}\u{309A}"), (0x3094, "\u{3046}\u{3099}"), (0x309E, "\u{309D}\u{3099}"),
+    (0x30AC, "\u{30AB}\u{3099}"), (0x30AE, "\u{30AD}\u{3099}"), (0x30B0, "\u{30AF}\u{3099}"),
+    (0x30B2, "\u{30B1}\u{3099}"), (0x30B4, "\u{30B3}\u{3099}"), (0x30B6, "\u{30B5}\u{3099}"),
+    (0x30B8, "\u{30B7}\u{3099}"), (0x30BA, "\u{30B9}\u{3099}"), (0x30BC, "\u{30BB}\u{3099}"),
+    (0x30BE, "\u{30BD}\u{3099}"), (0x30C0, "\u{30BF}\u{3099}"), (0x30C2, "\u{30C1}\u{3099}"),
+    (0x30C5, "\u{30C4}\u{3099}"), (0x30C7, "\u{30C6}\u{3099}"), (0x30C9, "\u{30C8}\u{3099}"),
+    (0x30D0, "\u{30CF}\u{3099}"), (0x30D1, "\u{30CF}\u{309A}"), (0x30D3, "\u{30D2}\u{3099}"),
+    (0x30D4, "\u{30D2}\u{309A}"), (0x30D6, "\u{30D5}\u{3099}"), (0x30D7, "\u{30D5}\u{309A}"),
+    (0x30D9, "\u{30D8}\u{3099}"), (0x30DA, "\u{30D8}\u{309A}"), (0x30DC, "\u{30DB}\u{3099}"),
+    (0x30DD, "\u{30DB}\u{309A}"), (0x30F4, "\u{30A6}\u{3099}"), (0x30F7, "\u{30EF}\u{3099}"),
+    (0x30F8, "\u{30F0}\u{3099}"), (0x30F9, "\u{30F1}\u{3099}"), (0x30FA, "\u{30F2}\u{3099}"),
+    (0x30FE, "\u{30FD}\u{3099}"), (0xF900, "\u{8C48}"), (0xF901, "\u{66F4}"), (0xF902, "\u{8ECA}"),
+    (0xF903, "\u{8CC8}"), (0xF904, "\u{6ED1}"), (0xF905, "\u{4E32}"), (0xF906, "\u{53E5}"),
+    (0xF907, "\u{9F9C}"), (0xF908, "\u{9F9C}"), (0xF909, "\u{5951}"), (0xF90A, "\u{91D1}"),
+    (0xF90B, "\u{5587}"), (0xF90C, "\u{5948}"), (0xF90D, "\u{61F6}"), (0xF90E, "\u{7669}"),
+    (0xF90F, "\u{7F85}"), (0xF910, "\u{863F}"), (0xF911, "\u{87BA}"), (0xF912, "\u{88F8}"),
+    (0xF913, "\u{908F}"), (0xF914, "\u{6A02}"), (0xF915, "\u{6D1B}"), (0xF916, "\u{70D9}"),
+    (0xF917, "\u{73DE}"), (0xF918, "\u{843D}"), (0xF919, "\u{916A}"), (0xF91A, "\u{99F1}"),
+    (0xF91B, "\u{4E82}"), (0xF91C, "\u{5375}"), (0xF91D, "\u{6B04}"), (0xF91E, "\u{721B}"),
+    (0xF91F, "\u{862D}"), (0xF920, "\u{9E1E}"), (0xF921, "\u{5D50}"), (0xF922, "\u{6FEB}"),
+    (0xF923, "\u{85CD}"), (0xF924, "\u{8964}"), (0xF925, "\u{62C9}"), (0xF926, "\u{81D8}"),
+    (0xF927, "\u{881F}"), (0xF928, "\u{5ECA}"), (0xF929, "\u{6717}"), (0xF92A, "\u{6D6A}"),
+    (0xF92B, "\u{72FC}"), (0xF92C, "\u{90CE}"), (0xF92D, "\u{4F86}"), (0xF92E, "\u{51B7}"),
+    (0xF92F, "\u{52DE}"), (0xF930, "\u{64C4}"), (0xF931, "\u{6AD3}"), (0xF932, "\u{7210}"),
+    (0xF933, "\u{76E7}"), (0xF934, "\u{8001}"), (0xF935, "\u{8606}"), (0xF936, "\u{865C}"),
+    (0xF937, "\u{8DEF}"), (0xF938, "\u{9732}"), (0xF939, "\u{9B6F}"), (0xF93A, "\u{9DFA}"),
+    (0xF93B, "\u{788C}"), (0xF93C, "\u{797F}"), (0xF93D, "\u{7DA0}"), (0xF93E, "\u{83C9}"),
+    (0xF93F, "\u{9304}"), (0xF940, "\u{9E7F}"), (0xF941, "\u{8AD6}"), (0xF942, "\u{58DF}"),
+    (0xF943, "\u{5F04}"), (0xF944, "\u{7C60}"), (0xF945, "\u{807E}"), (0xF946, "\u{7262}"),
+    (0xF947, "\u{78CA}"), (0xF948, "\u{8CC2}"), (0xF949, "\u{96F7}"), (0xF94A, "\u{58D8}"),
+    (0xF94B, "\u{5C62}"), (0xF94C, "\u{6A13}"), (0xF94D, "\u{6DDA}"), (0xF94E, "\u{6F0F}"),
+    (0xF94F, "\u{7D2F}"), (0xF950, "\u{7E37}"), (0xF951, "\u{964B}"), (0xF952, "\u{52D2}"),
+    (0xF953, "\u{808B}"), (0xF954, "\u{51DC}"), (0xF955, "\u{51CC}"), (0xF956, "\u{7A1C}"),
+    (0xF957, "\u{7DBE}"), (0xF958, "\u{83F1}"), (0xF959, "\u{9675}"), (0xF95A, "\u{8B80}"),
+    (0xF95B, "\u{62CF}"), (0xF95C, "\u{6A02}"), (0xF95D, "\u{8AFE}"), (0xF95E, "\u{4E39}"),
+    (0xF95F, "\u{5BE7}"), (0xF960, "\u{6012}"), (0xF961, "\u{7387}"), (0xF962, "\u{7570}"),
+    (0xF963, "\u{5317}"), (0xF964, "\u{78FB}"), (0xF965, "\u{4FBF}"), (0xF966, "\u{5FA9}"),
+    (0xF967, "\u{4E0D}"), (0xF968, "\u{6CCC}"), (0xF969, "\u{6578}"), (0xF96A, "\u{7D22}"),
+    (0xF96B, "\u{53C3}"), (0xF96C, "\u{585E}"), (0xF96D, "\u{7701}"), (0xF96E, "\u{8449}"),
+    (0xF96F, "\u{8AAA}"), (0xF970, "\u{6BBA}"), (0xF971, "\u{8FB0}"), (0xF972, "\u{6C88}"),
+    (0xF973, "\u{62FE}"), (0xF974, "\u{82E5}"), (0xF975, "\u{63A0}"), (0xF976, "\u{7565}"),
+    (0xF977, "\u{4EAE}"), (0xF978, "\u{5169}"), (0xF979, "\u{51C9}"), (0xF97A, "\u{6881}"),
+    (0xF97B, "\u{7CE7}"), (0xF97C, "\u{826F}"), (0xF97D, "\u{8AD2}"), (0xF97E, "\u{91CF}"),
+    (0xF97F, "\u{52F5}"), (0xF980, "\u{5442}"), (0xF981, "\u{5973}"), (0xF982, "\u{5EEC}"),
+    (0xF983, "\u{65C5}"), (0xF984, "\u{6FFE}"), (0xF985, "\u{792A}"), (0xF986, "\u{95AD}"),
+    (0xF987, "\u{9A6A}"), (0xF988, "\u{9E97}"), (0xF989, "\u{9ECE}"), (0xF98A, "\u{529B}"),
+    (0xF98B, "\u{66C6}"), (0xF98C, "\u{6B77}"), (0xF98D, "\u{8F62}"), (0xF98E, "\u{5E74}"),
+    (0xF98F, "\u{6190}"), (0xF990, "\u{6200}"), (0xF991, "\u{649A}"), (0xF992, "\u{6F23}"),
+    (0xF993, "\u{7149}"), (0xF994, "\u{7489}"), (0xF995, "\u{79CA}"), (0xF996, "\u{7DF4}"),
+    (0xF997, "\u{806F}"), (0xF998, "\u{8F26}"), (0xF999, "\u{84EE}"), (0xF99A, "\u{9023}"),
+    (0xF99B, "\u{934A}"), (0xF99C, "\u{5217}"), (0xF99D, "\u{52A3}"), (0xF99E, "\u{54BD}"),
+    (0xF99F, "\u{70C8}"), (0xF9A0, "\u{88C2}"), (0xF9A1, "\u{8AAA}"), (0xF9A2, "\u{5EC9}"),
+    (0xF9A3, "\u{5FF5}"), (0xF9A4, "\u{637B}"), (0xF9A5, "\u{6BAE}"), (0xF9A6, "\u{7C3E}"),
+    (0xF9A7, "\u{7375}"), (0xF9A8, "\u{4EE4}"), (0xF9A9, "\u{56F9}"), (0xF9AA, "\u{5BE7}"),
+    (0xF9AB, "\u{5DBA}"), (0xF9AC, "\u{601C}"), (0xF9AD, "\u{73B2}"), (0xF9AE, "\u{7469}"),
+    (0xF9AF, "\u{7F9A}"), (0xF9B0, "\u{8046}"), (0xF9B1, "\u{9234}"), (0xF9B2, "\u{96F6}"),
+    (0xF9B3, "\u{9748}"), (0xF9B4, "\u{9818}"), (0xF9B5, "\u{4F8B}"), (0xF9B6, "\u{79AE}"),
+    (0xF9B7, "\u{91B4}"), (0xF9B8, "\u{96B8}"), (0xF9B9, "\u{60E1}"), (0xF9BA, "\u{4E86}"),
+    (0xF9BB, "\u{50DA}"), (0xF9BC, "\u{5BEE}"), (0xF9BD, "\u{5C3F}"), (0xF9BE, "\u{6599}"),
+    (0xF9BF, "\u{6A02}"), (0xF9C0, "\u{71CE}"), (0xF9C1, "\u{7642}"), (0xF9C2, "\u{84FC}"),
+    (0xF9C3, "\u{907C}"), (0xF9C4, "\u{9F8D}"), (0xF9C5, "\u{6688}"), (0xF9C6, "\u{962E}"),
+    (0xF9C7, "\u{5289}"), (0xF9C8, "\u{677B}"), (0xF9C9, "\u{67F3}"), (0xF9CA, "\u{6D41}"),
+    (0xF9CB, "\u{6E9C}"), (0xF9CC, "\u{7409}"), (0xF9CD, "\u{7559}"), (0xF9CE, "\u{786B}"),
+    (0xF9CF, "\u{7D10}"), (0xF9D0, "\u{985E}"), (0xF9D1, "\u{516D}"), (0xF9D2, "\u{622E}"),
+    (0xF9D3, "\u{9678}"), (0xF9D4, "\u{502B}"), (0xF9D5, "\u{5D19}"), (0xF9D6, "\u{6DEA}"),
+    (0xF9D7, "\u{8F2A}"), (0xF9D8, "\u{5F8B}"), (0xF9D9, "\u{6144}"), (0xF9DA, "\u{6817}"),
+    (0xF9DB, "\u{7387}"), (0xF9DC, "\u{9686}"), (0xF9DD, "\u{5229}"), (0xF9DE, "\u{540F}"),
+    (0xF9DF, "\u{5C65}"), (0xF9E0, "\u{6613}"), (0xF9E1, "\u{674E}"), (0xF9E2, "\u{68A8}"),
+    (0xF9E3, "\u{6CE5}"), (0xF9E4, "\u{7406}"), (0xF9E5, "\u{75E2}"), (0xF9E6, "\u{7F79}"),
+    (0xF9E7, "\u{88CF}"), (0xF9E8, "\u{88E1}"), (0xF9E9, "\u{91CC}"), (0xF9EA, "\u{96E2}"),
+    (0xF9EB, "\u{533F}"), (0xF9EC, "\u{6EBA}"), (0xF9ED, "\u{541D}"), (0xF9EE, "\u{71D0}"),
+    (0xF9EF, "\u{7498}"), (0xF9F0, "\u{85FA}"), (0xF9F1, "\u{96A3}"), (0xF9F2, "\u{9C57}"),
+    (0xF9F3, "\u{9E9F}"), (0xF9F4, "\u{6797}"), (0xF9F5, "\u{6DCB}"), (0xF9F6, "\u{81E8}"),
+    (0xF9F7, "\u{7ACB}"), (0xF9F8, "\u{7B20}"), (0xF9F9, "\u{7C92}"), (0xF9FA, "\u{72C0}"),
+    (0xF9FB, "\u{7099}"), (0xF9FC, "\u{8B58}"), (0xF9FD, "\u{4EC0}"), (0xF9FE, "\u{8336}"),
+    (0xF9FF, "\u{523A}"), (0xFA00, "\u{5207}"), (0xFA01, "\u{5EA6}"), (0xFA02, "\u{62D3}"),
+    (0xFA03, "\u{7CD6}"), (0xFA04, "\u{5B85}"), (0xFA05, "\u{6D1E}"), (0xFA06, "\u{66B4}"),
+    (0xFA07, "\u{8F3B}"), (0xFA08, "\u{884C}"), (0xFA09, "\u{964D}"), (0xFA0A, "\u{898B}"),
+    (0xFA0B, "\u{5ED3}"), (0xFA0C, "\u{5140}"), (0xFA0D, "\u{55C0}"), (0xFA10, "\u{585A}"),
+    (0xFA12, "\u{6674}"), (0xFA15, "\u{51DE}"), (0xFA16, "\u{732A}"), (0xFA17, "\u{76CA}"),
+    (0xFA18, "\u{793C}"), (0xFA19, "\u{795E}"), (0xFA1A, "\u{7965}"), (0xFA1B, "\u{798F}"),
+    (0xFA1C, "\u{9756}"), (0xFA1D, "\u{7CBE}"), (0xFA1E, "\u{7FBD}"), (0xFA20, "\u{8612}"),
+    (0xFA22, "\u{8AF8}"), (0xFA25, "\u{9038}"), (0xFA26, "\u{90FD}"), (0xFA2A, "\u{98EF}"),
+    (0xFA2B, "\u{98FC}"), (0xFA2C, "\u{9928}"), (0xFA2D, "\u{9DB4}"), (0xFA2E, "\u{90DE}"),
+    (0xFA2F, "\u{96B7}"), (0xFA30, "\u{4FAE}"), (0xFA31, "\u{50E7}"), (0xFA32, "\u{514D}"),
+    (0xFA33, "\u{52C9}"), (0xFA34, "\u{52E4}"), (0xFA35, "\u{5351}"), (0xFA36, "\u{559D}"),
+    (0xFA37, "\u{5606}"), (0xFA38, "\u{5668}"), (0xFA39, "\u{5840}"), (0xFA3A, "\u{58A8}"),
+    (0xFA3B, "\u{5C64}"), (0xFA3C, "\u{5C6E}"), (0xFA3D, "\u{6094}"), (0xFA3E, "\u{6168}"),
+    (0xFA3F, "\u{618E}"), (0xFA40, "\u{61F2}"), (0xFA41, "\u{654F}"), (0xFA42, "\u{65E2}"),
+    (0xFA43, "\u{6691}"), (0xFA44, "\u{6885}"), (0xFA45, "\u{6D77}"), (0xFA46, "\u{6E1A}"),
+    (0xFA47, "\u{6F22}"), (0xFA48, "\u{716E}"), (0xFA49, "\u{722B}"), (0xFA4A, "\u{7422}"),
+    (0xFA4B, "\u{7891}"), (0xFA4C, "\u{793E}"), (0xFA4D, "\u{7949}"), (0xFA4E, "\u{7948}"),
+    (0xFA4F, "\u{7950}"), (0xFA50, "\u{7956}"), (0xFA51, "\u{795D}"), (0xFA52, "\u{798D}"),
+    (0xFA53, "\u{798E}"), (0xFA54, "\u{7A40}"), (0xFA55, "\u{7A81}"), (0xFA56, "\u{7BC0}"),
+    (0xFA57, "\u{7DF4}"), (0xFA58, "\u{7E09}"), (0xFA59, "\u{7E41}"), (0xFA5A, "\u{7F72}"),
+    (0xFA5B, "\u{8005}"), (0xFA5C, "\u{81ED}"), (0xFA5D, "\u{8279}"), (0xFA5E, "\u{8279}"),
+    (0xFA5F, "\u{8457}"), (0xFA60, "\u{8910}"), (0xFA61, "\u{8996}"), (0xFA62, "\u{8B01}"),
+    (0xFA63, "\u{8B39}"), (0xFA64, "\u{8CD3}"), (0xFA65, "\u{8D08}"), (0xFA66, "\u{8FB6}"),
+    (0xFA67, "\u{9038}"), (0xFA68, "\u{96E3}"), (0xFA69, "\u{97FF}"), (0xFA6A, "\u{983B}"),
+    (0xFA6B, "\u{6075}"), (0xFA6C, "\u{242EE}"), (0xFA6D, "\u{8218}"), (0xFA70, "\u{4E26}"),
+    (0xFA71, "\u{51B5}"), (0xFA72, "\u{5168}"), (0xFA73, "\u{4F80}"), (0xFA74, "\u{5145}"),
+    (0xFA75, "\u{5180}"), (0xFA76, "\u{52C7}"), (0xFA77, "\u{52FA}"), (0xFA78, "\u{559D}"),
+    (0xFA79, "\u{5555}"), (0xFA7A, "\u{5599}"), (0xFA7B, "\u{55E2}"), (0xFA7C, "\u{585A}"),
+    (0xFA7D, "\u{58B3}"), (0xFA7E, "\u{5944}"), (0xFA7F, "\u{5954}"), (0xFA80, "\u{5A62}"),
+    (0xFA81, "\u{5B28}"), (0xFA82, "\u{5ED2}"), (0xFA83, "\u{5ED9}"), (0xFA84, "\u{5F69}"),
+    (0xFA85, "\u{5FAD}"), (0xFA86, "\u{60D8}"), (0xFA87, "\u{614E}"), (0xFA88, "\u{6108}"),
+    (0xFA89, "\u{618E}"), (0xFA8A, "\u{6160}"), (0xFA8B, "\u{61F2}"), (0xFA8C, "\u{6234}"),
+    (0xFA8D, "\u{63C4}"), (0xFA8E, "\u{641C}"), (0xFA8F, "\u{6452}"), (0xFA90, "\u{6556}"),
+    (0xFA91, "\u{6674}"), (0xFA92, "\u{6717}"), (0xFA93, "\u{671B}"), (0xFA94, "\u{6756}"),
+    (0xFA95, "\u{6B79}"), (0xFA96, "\u{6BBA}"), (0xFA97, "\u{6D41}"), (0xFA98, "\u{6EDB}"),
+    (0xFA99, "\u{6ECB}"), (0xFA9A, "\u{6F22}"), (0xFA9B, "\u{701E}"), (0xFA9C, "\u{716E}"),
+    (0xFA9D, "\u{77A7}"), (0xFA9E, "\u{7235}"), (0xFA9F, "\u{72AF}"), (0xFAA0, "\u{732A}"),
+    (0xFAA1, "\u{7471}"), (0xFAA2, "\u{7506}"), (0xFAA3, "\u{753B}"), (0xFAA4, "\u{761D}"),
+    (0xFAA5, "\u{761F}"), (0xFAA6, "\u{76CA}"), (0xFAA7, "\u{76DB}"), (0xFAA8, "\u{76F4}"),
+    (0xFAA9, "\u{774A}"), (0xFAAA, "\u{7740}"), (0xFAAB, "\u{78CC}"), (0xFAAC, "\u{7AB1}"),
+    (0xFAAD, "\u{7BC0}"), (0xFAAE, "\u{7C7B}"), (0xFAAF, "\u{7D5B}"), (0xFAB0, "\u{7DF4}"),
+    (0xFAB1, "\u{7F3E}"), (0xFAB2, "\u{8005}"), (0xFAB3, "\u{8352}"), (0xFAB4, "\u{83EF}"),
+    (0xFAB5, "\u{8779}"), (0xFAB6, "\u{8941}"), (0xFAB7, "\u{8986}"), (0xFAB8, "\u{8996}"),
+    (0xFAB9, "\u{8ABF}"), (0xFABA, "\u{8AF8}"), (0xFABB, "\u{8ACB}"), (0xFABC, "\u{8B01}"),
+    (0xFABD, "\u{8AFE}"), (0xFABE, "\u{8AED}"), (0xFABF, "\u{8B39}"), (0xFAC0, "\u{8B8A}"),
+    (0xFAC1, "\u{8D08}"), (0xFAC2, "\u{8F38}"), (0xFAC3, "\u{9072}"), (0xFAC4, "\u{9199}"),
+    (0xFAC5, "\u{9276}"), (0xFAC6, "\u{967C}"), (0xFAC7, "\u{96E3}"), (0xFAC8, "\u{9756}"),
+    (0xFAC9, "\u{97DB}"), (0xFACA, "\u{97FF}"), (0xFACB, "\u{980B}"), (0xFACC, "\u{983B}"),
+    (0xFACD, "\u{9B12}"), (0xFACE, "\u{9F9C}"), (0xFACF, "\u{2284A}"), (0xFAD0, "\u{22844}"),
+    (0xFAD1, "\u{233D5}"), (0xFAD2, "\u{3B9D}"), (0xFAD3, "\u{4018}"), (0xFAD4, "\u{4039}"),
+    (0xFAD5, "\u{25249}"), (0xFAD6, "\u{25CD0}"), (0xFAD7, "\u{27ED3}"), (0xFAD8, "\u{9F43}"),
+    (0xFAD9, "\u{9F8E}"), (0xFB1D, "\u{5D9}\u{5B4}"), (0xFB1F, "\u{5F2}\u{5B7}"),
+    (0xFB2A, "\u{5E9}\u{5C1}"), (0xFB2B, "\u{5E9}\u{5C2}"), (0xFB2C, "\u{5E9}\u{5BC}\u{5C1}"),
+    (0xFB2D, "\u{5E9}\u{5BC}\u{5C2}"), (0xFB2E, "\u{5D0}\u{5B7}"), (0xFB2F, "\u{5D0}\u{5B8}"),
+    (0xFB30, "\u{5D0}\u{5BC}"), (0xFB31, "\u{5D1}\u{5BC}"), (0xFB32, "\u{5D2}\u{5BC}"),
+    (0xFB33, "\u{5D3}\u{5BC}"), (0xFB34, "\u{5D4}\u{5BC}"), (0xFB35, "\u{5D5}\u{5BC}"),
+    (0xFB36, "\u{5D6}\u{5BC}"), (0xFB38, "\u{5D8}\u{5BC}"), (0xFB39, "\u{5D9}\u{5BC}"),
+    (0xFB3A, "\u{5DA}\u{5BC}"), (0xFB3B, "\u{5DB}\u{5BC}"), (0xFB3C, "\u{5DC}\u{5BC}"),
+    (0xFB3E, "\u{5DE}\u{5BC}"), (0xFB40, "\u{5E0}\u{5BC}"), (0xFB41, "\u{5E1}\u{5BC}"),
+    (0xFB43, "\u{5E3}\u{5BC}"), (0xFB44, "\u{5E4}\u{5BC}"), (0xFB46, "\u{5E6}\u{5BC}"),
+    (0xFB47, "\u{5E7}\u{5BC}"), (0xFB48, "\u{5E8}\u{5BC}"), (0xFB49, "\u{5E9}\u{5BC}"),
+    (0xFB4A, "\u{5EA}\u{5BC}"), (0xFB4B, "\u{5D5}\u{5B9}"), (0xFB4C, "\u{5D1}\u{5BF}"),
+    (0xFB4D, "\u{5DB}\u{5BF}"), (0xFB4E, "\u{5E4}\u{5BF}"), (0x105C9, "\u{105D2}\u{307}"),
+    (0x105E4, "\u{105DA}\u{307}"), (0x1109A, "\u{11099}\u{110BA}"), (0x1109C, "\u{1109B}\u{110BA}"),
+    (0x110AB, "\u{110A5}\u{110BA}"), (0x1112E, "\u{11131}\u{11127}"),
+    (0x1112F, "\u{11132}\u{11127}"), (0x1134B, "\u{11347}\u{1133E}"),
+    (0x1134C, "\u{11347}\u{11357}"), (0x11383, "\u{11382}\u{113C9}"),
+    (0x11385, "\u{11384}\u{113BB}"), (0x1138E, "\u{1138B}\u{113C2}"),
+    (0x11391, "\u{11390}\u{113C9}"), (0x113C5, "\u{113C2}\u{113C2}"),
+    (0x113C7, "\u{113C2}\u{113B8}"), (0x113C8, "\u{113C2}\u{113C9}"),
+    (0x114BB, "\u{114B9}\u{114BA}"), (0x114BC, "\u{114B9}\u{114B0}"),
+    (0x114BE, "\u{114B9}\u{114BD}"), (0x115BA, "\u{115B8}\u{115AF}"),
+    (0x115BB, "\u{115B9}\u{115AF}"), (0x11938, "\u{11935}\u{11930}"),
+    (0x16121, "\u{1611E}\u{1611E}"), (0x16122, "\u{1611E}\u{16129}"),
+    (0x16123, "\u{1611E}\u{1611F}"), (0x16124, "\u{16129}\u{1611F}"),
+    (0x16125, "\u{1611E}\u{16120}"), (0x16126, "\u{1611E}\u{1611E}\u{1611F}"),
+    (0x16127, "\u{1611E}\u{16129}\u{1611F}"), (0x16128, "\u{1611E}\u{1611E}\u{16120}"),
+    (0x16D68, "\u{16D67}\u{16D67}"), (0x16D69, "\u{16D63}\u{16D67}"),
+    (0x16D6A, "\u{16D63}\u{16D67}\u{16D67}"), (0x1D15E, "\u{1D157}\u{1D165}"),
+    (0x1D15F, "\u{1D158}\u{1D165}"), (0x1D160, "\u{1D158}\u{1D165}\u{1D16E}"),
+    (0x1D161, "\u{1D158}\u{1D165}\u{1D16F}"), (0x1D162, "\u{1D158}\u{1D165}\u{1D170}"),
+    (0x1D163, "\u{1D158}\u{1D165}\u{1D171}"), (0x1D164, "\u{1D158}\u{1D165}\u{1D172}"),
+    (0x1D1BB, "\u{1D1B9}\u{1D165}"), (0x1D1BC, "\u{1D1BA}\u{1D165}"),
+    (0x1D1BD, "\u{1D1B9}\u{1D165}\u{1D16E}"), (0x1D1BE, "\u{1D1BA}\u{1D165}\u{1D16E}"),
+    (0x1D1BF, "\u{1D1B9}\u{1D165}\u{1D16F}"), (0x1D1C0, "\u{1D1BA}\u{1D165}\u{1D16F}"),
+    (0x2F800, "\u{4E3D}"), (0x2F801, "\u{4E38}"), (0x2F802, "\u{4E41}"), (0x2F803, "\u{20122}"),
+    (0x2F804, "\u{4F60}"), (0x2F805, "\u{4FAE}"), (0x2F806, "\u{4FBB}"), (0x2F807, "\u{5002}"),
+    (0x2F808, "\u{507A}"), (0x2F809, "\u{5099}"), (0x2F80A, "\u{50E7}"), (0x2F80B, "\u{50CF}"),
+    (0x2F80C, "\u{349E}"), (0x2F80D, "\u{2063A}"), (0x2F80E, "\u{514D}"), (0x2F80F, "\u{5154}"),
+    (0x2F810, "\u{5164}"), (0x2F811, "\u{5177}"), (0x2F812, "\u{2051C}"), (0x2F813, "\u{34B9}"),
+    (0x2F814, "\u{5167}"), (0x2F815, "\u{518D}"), (0x2F816, "\u{2054B}"), (0x2F817, "\u{5197}"),
+    (0x2F818, "\u{51A4}"), (0x2F819, "\u{4ECC}"), (0x2F81A, "\u{51AC}"), (0x2F81B, "\u{51B5}"),
+    (0x2F81C, "\u{291DF}"), (0x2F81D, "\u{51F5}"), (0x2F81E, "\u{5203}"), (0x2F81F, "\u{34DF}"),
+    (0x2F820, "\u{523B}"), (0x2F821, "\u{5246}"), (0x2F822, "\u{5272}"), (0x2F823, "\u{5277}"),
+    (0x2F824, "\u{3515}"), (0x2F825, "\u{52C7}"), (0x2F826, "\u{52C9}"), (0x2F827, "\u{52E4}"),
+    (0x2F828, "\u{52FA}"), (0x2F829, "\u{5305}"), (0x2F82A, "\u{5306}"), (0x2F82B, "\u{5317}"),
+    (0x2F82C, "\u{5349}"), (0x2F82D, "\u{5351}"), (0x2F82E, "\u{535A}"), (0x2F82F, "\u{5373}"),
+    (0x2F830, "\u{537D}"), (0x2F831, "\u{537F}"), (0x2F832, "\u{537F}"), (0x2F833, "\u{537F}"),
+    (0x2F834, "\u{20A2C}"), (0x2F835, "\u{7070}"), (0x2F836, "\u{53CA}"), (0x2F837, "\u{53DF}"),
+    (0x2F838, "\u{20B63}"), (0x2F839, "\u{53EB}"), (0x2F83A, "\u{53F1}"), (0x2F83B, "\u{5406}"),
+    (0x2F83C, "\u{549E}"), (0x2F83D, "\u{5438}"), (0x2F83E, "\u{5448}"), (0x2F83F, "\u{5468}"),
+    (0x2F840, "\u{54A2}"), (0x2F841, "\u{54F6}"), (0x2F842, "\u{5510}"), (0x2F843, "\u{5553}"),
+    (0x2F844, "\u{5563}"), (0x2F845, "\u{5584}"), (0x2F846, "\u{5584}"), (0x2F847, "\u{5599}"),
+    (0x2F848, "\u{55AB}"), (0x2F849, "\u{55B3}"), (0x2F84A, "\u{55C2}"), (0x2F84B, "\u{5716}"),
+    (0x2F84C, "\u{5606}"), (0x2F84D, "\u{5717}"), (0x2F84E, "\u{5651}"), (0x2F84F, "\u{5674}"),
+    (0x2F850, "\u{5207}"), (0x2F851, "\u{58EE}"), (0x2F852, "\u{57CE}"), (0x2F853, "\u{57F4}"),
+    (0x2F854, "\u{580D}"), (0x2F855, "\u{578B}"), (0x2F856, "\u{5832}"), (0x2F857, "\u{5831}"),
+    (0x2F858, "\u{58AC}"), (0x2F859, "\u{214E4}"), (0x2F85A, "\u{58F2}"), (0x2F85B, "\u{58F7}"),
+    (0x2F85C, "\u{5906}"), (0x2F85D, "\u{591A}"), (0x2F85E, "\u{5922}"), (0x2F85F, "\u{5962}"),
+    (0x2F860, "\u{216A8}"), (0x2F861, "\u{216EA}"), (0x2F862, "\u{59EC}"), (0x2F863, "\u{5A1B}"),
+    (0x2F864, "\u{5A27}"), (0x2F865, "\u{59D8}"), (0x2F866, "\u{5A66}"), (0x2F867, "\u{36EE}"),
+    (0x2F868, "\u{36FC}"), (0x2F869, "\u{5B08}"), (0x2F86A, "\u{5B3E}"), (0x2F86B, "\u{5B3E}"),
+    (0x2F86C, "\u{219C8}"), (0x2F86D, "\u{5BC3}"), (0x2F86E, "\u{5BD8}"), (0x2F86F, "\u{5BE7}"),
+    (0x2F870, "\u{5BF3}"), (0x2F871, "\u{21B18}"), (0x2F872, "\u{5BFF}"), (0x2F873, "\u{5C06}"),
+    (0x2F874, "\u{5F53}"), (0x2F875, "\u{5C22}"), (0x2F876, "\u{3781}"), (0x2F877, "\u{5C60}"),
+    (0x2F878, "\u{5C6E}"), (0x2F879, "\u{5CC0}"), (0x2F87A, "\u{5C8D}"), (0x2F87B, "\u{21DE4}"),
+    (0x2F87C, "\u{5D43}"), (0x2F87D, "\u{21DE6}"), (0x2F87E, "\u{5D6E}"), (0x2F87F, "\u{5D6B}"),
+    (0x2F880, "\u{5D7C}"), (0x2F881, "\u{5DE1}"), (0x2F882, "\u{5DE2}"), (0x2F883, "\u{382F}"),
+    (0x2F884, "\u{5DFD}"), (0x2F885, "\u{5E28}"), (0x2F886, "\u{5E3D}"), (0x2F887, "\u{5E69}"),
+    (0x2F888, "\u{3862}"), (0x2F889, "\u{22183}"), (0x2F88A, "\u{387C}"), (0x2F88B, "\u{5EB0}"),
+    (0x2F88C, "\u{5EB3}"), (0x2F88D, "\u{5EB6}"), (0x2F88E, "\u{5ECA}"), (0x2F88F, "\u{2A392}"),
+    (0x2F890, "\u{5EFE}"), (0x2F891, "\u{22331}"), (0x2F892, "\u{22331}"), (0x2F893, "\u{8201}"),
+    (0x2F894, "\u{5F22}"), (0x2F895, "\u{5F22}"), (0x2F896, "\u{38C7}"), (0x2F897, "\u{232B8}"),
+    (0x2F898, "\u{261DA}"), (0x2F899, "\u{5F62}"), (0x2F89A, "\u{5F6B}"), (0x2F89B, "\u{38E3}"),
+    (0x2F89C, "\u{5F9A}"), (0x2F89D, "\u{5FCD}"), (0x2F89E, "\u{5FD7}"), (0x2F89F, "\u{5FF9}"),
+    (0x2F8A0, "\u{6081}"), (0x2F8A1, "\u{393A}"), (0x2F8A2, "\u{391C}"), (0x2F8A3, "\u{6094}"),
+    (0x2F8A4, "\u{226D4}"), (0x2F8A5, "\u{60C7}"), (0x2F8A6, "\u{6148}"), (0x2F8A7, "\u{614C}"),
+    (0x2F8A8, "\u{614E}"), (0x2F8A9, "\u{614C}"), (0x2F8AA, "\u{617A}"), (0x2F8AB, "\u{618E}"),
+    (0x2F8AC, "\u{61B2}"), (0x2F8AD, "\u{61A4}"), (0x2F8AE, "\u{61AF}"), (0x2F8AF, "\u{61DE}"),
+    (0x2F8B0, "\u{61F2}"), (0x2F8B1, "\u{61F6}"), (0x2F8B2, "\u{6210}"), (0x2F8B3, "\u{621B}"),
+    (0x2F8B4, "\u{625D}"), (0x2F8B5, "\u{62B1}"), (0x2F8B6, "\u{62D4}"), (0x2F8B7, "\u{6350}"),
+    (0x2F8B8, "\u{22B0C}"), (0x2F8B9, "\u{633D}"), (0x2F8BA, "\u{62FC}"), (0x2F8BB, "\u{6368}"),
+    (0x2F8BC, "\u{6383}"), (0x2F8BD, "\u{63E4}"), (0x2F8BE, "\u{22BF1}"), (0x2F8BF, "\u{6422}"),
+    (0x2F8C0, "\u{63C5}"), (0x2F8C1, "\u{63A9}"), (0x2F8C2, "\u{3A2E}"), (0x2F8C3, "\u{6469}"),
+    (0x2F8C4, "\u{647E}"), (0x2F8C5, "\u{649D}"), (0x2F8C6, "\u{6477}"), (0x2F8C7, "\u{3A6C}"),
+    (0x2F8C8, "\u{654F}"), (0x2F8C9, "\u{656C}"), (0x2F8CA, "\u{2300A}"), (0x2F8CB, "\u{65E3}"),
+    (0x2F8CC, "\u{66F8}"), (0x2F8CD, "\u{6649}"), (0x2F8CE, "\u{3B19}"), (0x2F8CF, "\u{6691}"),
+    (0x2F8D0, "\u{3B08}"), (0x2F8D1, "\u{3AE4}"), (0x2F8D2, "\u{5192}"), (0x2F8D3, "\u{5195}"),
+    (0x2F8D4, "\u{6700}"), (0x2F8D5, "\u{669C}"), (0x2F8D6, "\u{80AD}"), (0x2F8D7, "\u{43D9}"),
+    (0x2F8D8, "\u{6717}"), (0x2F8D9, "\u{671B}"), (0x2F8DA, "\u{6721}"), (0x2F8DB, "\u{675E}"),
+    (0x2F8DC, "\u{6753}"), (0x2F8DD, "\u{233C3}"), (0x2F8DE, "\u{3B49}"), (0x2F8DF, "\u{67FA}"),
+    (0x2F8E0, "\u{6785}"), (0x2F8E1, "\u{6852}"), (0x2F8E2, "\u{6885}"), (0x2F8E3, "\u{2346D}"),
+    (0x2F8E4, "\u{688E}"), (0x2F8E5, "\u{681F}"), (0x2F8E6, "\u{6914}"), (0x2F8E7, "\u{3B9D}"),
+    (0x2F8E8, "\u{6942}"), (0x2F8E9, "\u{69A3}"), (0x2F8EA, "\u{69EA}"), (0x2F8EB, "\u{6AA8}"),
+    (0x2F8EC, "\u{236A3}"), (0x2F8ED, "\u{6ADB}"), (0x2F8EE, "\u{3C18}"), (0x2F8EF, "\u{6B21}"),
+    (0x2F8F0, "\u{238A7}"), (0x2F8F1, "\u{6B54}"), (0x2F8F2, "\u{3C4E}"), (0x2F8F3, "\u{6B72}"),
+    (0x2F8F4, "\u{6B9F}"), (0x2F8F5, "\u{6BBA}"), (0x2F8F6, "\u{6BBB}"), (0x2F8F7, "\u{23A8D}"),
+    (0x2F8F8, "\u{21D0B}"), (0x2F8F9, "\u{23AFA}"), (0x2F8FA, "\u{6C4E}"), (0x2F8FB, "\u{23CBC}"),
+    (0x2F8FC, "\u{6CBF}"), (0x2F8FD, "\u{6CCD}"), (0x2F8FE, "\u{6C67}"), (0x2F8FF, "\u{6D16}"),
+    (0x2F900, "\u{6D3E}"), (0x2F901, "\u{6D77}"), (0x2F902, "\u{6D41}"), (0x2F903, "\u{6D69}"),
+    (0x2F904, "\u{6D78}"), (0x2F905, "\u{6D85}"), (0x2F906, "\u{23D1E}"), (0x2F907, "\u{6D34}"),
+    (0x2F908, "\u{6E2F}"), (0x2F909, "\u{6E6E}"), (0x2F90A, "\u{3D33}"), (0x2F90B, "\u{6ECB}"),
+    (0x2F90C, "\u{6EC7}"), (0x2F90D, "\u{23ED1}"), (0x2F90E, "\u{6DF9}"), (0x2F90F, "\u{6F6E}"),
+    (0x2F910, "\u{23F5E}"), (0x2F911, "\u{23F8E}"), (0x2F912, "\u{6FC6}"), (0x2F913, "\u{7039}"),
+    (0x2F914, "\u{701E}"), (0x2F915, "\u{701B}"), (0x2F916, "\u{3D96}"), (0x2F917, "\u{704A}"),
+    (0x2F918, "\u{707D}"), (0x2F919, "\u{7077}"), (0x2F91A, "\u{70AD}"), (0x2F91B, "\u{20525}"),
+    (0x2F91C, "\u{7145}"), (0x2F91D, "\u{24263}"), (0x2F91E, "\u{719C}"), (0x2F91F, "\u{243AB}"),
+    (0x2F920, "\u{7228}"), (0x2F921, "\u{7235}"), (0x2F922, "\u{7250}"), (0x2F923, "\u{24608}"),
+    (0x2F924, "\u{7280}"), (0x2F925, "\u{7295}"), (0x2F926, "\u{24735}"), (0x2F927, "\u{24814}"),
+    (0x2F928, "\u{737A}"), (0x2F929, "\u{738B}"), (0x2F92A, "\u{3EAC}"), (0x2F92B, "\u{73A5}"),
+    (0x2F92C, "\u{3EB8}"), (0x2F92D, "\u{3EB8}"), (0x2F92E, "\u{7447}"), (0x2F92F, "\u{745C}"),
+    (0x2F930, "\u{7471}"), (0x2F931, "\u{7485}"), (0x2F932, "\u{74CA}"), (0x2F933, "\u{3F1B}"),
+    (0x2F934, "\u{7524}"), (0x2F935, "\u{24C36}"), (0x2F936, "\u{753E}"), (0x2F937, "\u{24C92}"),
+    (0x2F938, "\u{7570}"), (0x2F939, "\u{2219F}"), (0x2F93A, "\u{7610}"), (0x2F93B, "\u{24FA1}"),
+    (0x2F93C, "\u{24FB8}"), (0x2F93D, "\u{25044}"), (0x2F93E, "\u{3FFC}"), (0x2F93F, "\u{4008}"),
+    (0x2F940, "\u{76F4}"), (0x2F941, "\u{250F3}"), (0x2F942, "\u{250F2}"), (0x2F943, "\u{25119}"),
+    (0x2F944, "\u{25133}"), (0x2F945, "\u{771E}"), (0x2F946, "\u{771F}"), (0x2F947, "\u{771F}"),
+    (0x2F948, "\u{774A}"), (0x2F949, "\u{4039}"), (0x2F94A, "\u{778B}"), (0x2F94B, "\u{4046}"),
+    (0x2F94C, "\u{4096}"), (0x2F94D, "\u{2541D}"), (0x2F94E, "\u{784E}"), (0x2F94F, "\u{788C}"),
+    (0x2F950, "\u{78CC}"), (0x2F951, "\u{40E3}"), (0x2F952, "\u{25626}"), (0x2F953, "\u{7956}"),
+    (0x2F954, "\u{2569A}"), (0x2F955, "\u{256C5}"), (0x2F956, "\u{798F}"), (0x2F957, "\u{79EB}"),
+    (0x2F958, "\u{412F}"), (0x2F959, "\u{7A40}"), (0x2F95A, "\u{7A4A}"), (0x2F95B, "\u{7A4F}"),
+    (0x2F95C, "\u{2597C}"), (0x2F95D, "\u{25AA7}"), (0x2F95E, "\u{25AA7}"), (0x2F95F, "\u{7AEE}"),
+    (0x2F960, "\u{4202}"), (0x2F961, "\u{25BAB}"), (0x2F962, "\u{7BC6}"), (0x2F963, "\u{7BC9}"),
+    (0x2F964, "\u{4227}"), (0x2F965, "\u{25C80}"), (0x2F966, "\u{7CD2}"), (0x2F967, "\u{42A0}"),
+    (0x2F968, "\u{7CE8}"), (0x2F969, "\u{7CE3}"), (0x2F96A, "\u{7D00}"), (0x2F96B, "\u{25F86}"),
+    (0x2F96C, "\u{7D63}"), (0x2F96D, "\u{4301}"), (0x2F96E, "\u{7DC7}"), (0x2F96F, "\u{7E02}"),
+    (0x2F970, "\u{7E45}"), (0x2F971, "\u{4334}"), (0x2F972, "\u{26228}"), (0x2F973, "\u{26247}"),
+    (0x2F974, "\u{4359}"), (0x2F975, "\u{262D9}"), (0x2F976, "\u{7F7A}"), (0x2F977, "\u{2633E}"),
+    (0x2F978, "\u{7F95}"), (0x2F979, "\u{7FFA}"), (0x2F97A, "\u{8005}"), (0x2F97B, "\u{264DA}"),
+    (0x2F97C, "\u{26523}"), (0x2F97D, "\u{8060}"), (0x2F97E, "\u{265A8}"), (0x2F97F, "\u{8070}"),
+    (0x2F980, "\u{2335F}"), (0x2F981, "\u{43D5}"), (0x2F982, "\u{80B2}"), (0x2F983, "\u{8103}"),
+    (0x2F984, "\u{440B}"), (0x2F985, "\u{813E}"), (0x2F986, "\u{5AB5}"), (0x2F987, "\u{267A7}"),
+    (0x2F988, "\u{267B5}"), (0x2F989, "\u{23393}"), (0x2F98A, "\u{2339C}"), (0x2F98B, "\u{8201}"),
+    (0x2F98C, "\u{8204}"), (0x2F98D, "\u{8F9E}"), (0x2F98E, "\u{446B}"), (0x2F98F, "\u{8291}"),
+    (0x2F990, "\u{828B}"), (0x2F991, "\u{829D}"), (0x2F992, "\u{52B3}"), (0x2F993, "\u{82B1}"),
+    (0x2F994, "\u{82B3}"), (0x2F995, "\u{82BD}"), (0x2F996, "\u{82E6}"), (0x2F997, "\u{26B3C}"),
+    (0x2F998, "\u{82E5}"), (0x2F999, "\u{831D}"), (0x2F99A, "\u{8363}"), (0x2F99B, "\u{83AD}"),
+    (0x2F99C, "\u{8323}"), (0x2F99D, "\u{83BD}"), (0x2F99E, "\u{83E7}"), (0x2F99F, "\u{8457}"),
+    (0x2F9A0, "\u{8353}"), (0x2F9A1, "\u{83CA}"), (0x2F9A2, "\u{83CC}"), (0x2F9A3, "\u{83DC}"),
+    (0x2F9A4, "\u{26C36}"), (0x2F9A5, "\u{26D6B}"), (0x2F9A6, "\u{26CD5}"), (0x2F9A7, "\u{452B}"),
+    (0x2F9A8, "\u{84F1}"), (0x2F9A9, "\u{84F3}"), (0x2F9AA, "\u{8516}"), (0x2F9AB, "\u{273CA}"),
+    (0x2F9AC, "\u{8564}"), (0x2F9AD, "\u{26F2C}"), (0x2F9AE, "\u{455D}"), (0x2F9AF, "\u{4561}"),
+    (0x2F9B0, "\u{26FB1}"), (0x2F9B1, "\u{270D2}"), (0x2F9B2, "\u{456B}"), (0x2F9B3, "\u{8650}"),
+    (0x2F9B4, "\u{865C}"), (0x2F9B5, "\u{8667}"), (0x2F9B6, "\u{8669}"), (0x2F9B7, "\u{86A9}"),
+    (0x2F9B8, "\u{8688}"), (0x2F9B9, "\u{870E}"), (0x2F9BA, "\u{86E2}"), (0x2F9BB, "\u{8779}"),
+    (0x2F9BC, "\u{8728}"), (0x2F9BD, "\u{876B}"), (0x2F9BE, "\u{8786}"), (0x2F9BF, "\u{45D7}"),
+    (0x2F9C0, "\u{87E1}"), (0x2F9C1, "\u{8801}"), (0x2F9C2, "\u{45F9}"), (0x2F9C3, "\u{8860}"),
+    (0x2F9C4, "\u{8863}"), (0x2F9C5, "\u{27667}"), (0x2F9C6, "\u{88D7}"), (0x2F9C7, "\u{88DE}"),
+    (0x2F9C8, "\u{4635}"), (0x2F9C9, "\u{88FA}"), (0x2F9CA, "\u{34BB}"), (0x2F9CB, "\u{278AE}"),
+    (0x2F9CC, "\u{27966}"), (0x2F9CD, "\u{46BE}"), (0x2F9CE, "\u{46C7}"), (0x2F9CF, "\u{8AA0}"),
+    (0x2F9D0, "\u{8AED}"), (0x2F9D1, "\u{8B8A}"), (0x2F9D2, "\u{8C55}"), (0x2F9D3, "\u{27CA8}"),
+    (0x2F9D4, "\u{8CAB}"), (0x2F9D5, "\u{8CC1}"), (0x2F9D6, "\u{8D1B}"), (0x2F9D7, "\u{8D77}"),
+    (0x2F9D8, "\u{27F2F}"), (0x2F9D9, "\u{20804}"), (0x2F9DA, "\u{8DCB}"), (0x2F9DB, "\u{8DBC}"),
+    (0x2F9DC, "\u{8DF0}"), (0x2F9DD, "\u{208DE}"), (0x2F9DE, "\u{8ED4}"), (0x2F9DF, "\u{8F38}"),
+    (0x2F9E0, "\u{285D2}"), (0x2F9E1, "\u{285ED}"), (0x2F9E2, "\u{9094}"), (0x2F9E3, "\u{90F1}"),
+    (0x2F9E4, "\u{9111}"), (0x2F9E5, "\u{2872E}"), (0x2F9E6, "\u{911B}"), (0x2F9E7, "\u{9238}"),
+    (0x2F9E8, "\u{92D7}"), (0x2F9E9, "\u{92D8}"), (0x2F9EA, "\u{927C}"), (0x2F9EB, "\u{93F9}"),
+    (0x2F9EC, "\u{9415}"), (0x2F9ED, "\u{28BFA}"), (0x2F9EE, "\u{958B}"), (0x2F9EF, "\u{4995}"),
+    (0x2F9F0, "\u{95B7}"), (0x2F9F1, "\u{28D77}"), (0x2F9F2, "\u{49E6}"), (0x2F9F3, "\u{96C3}"),
+    (0x2F9F4, "\u{5DB2}"), (0x2F9F5, "\u{9723}"), (0x2F9F6, "\u{29145}"), (0x2F9F7, "\u{2921A}"),
+    (0x2F9F8, "\u{4A6E}"), (0x2F9F9, "\u{4A76}"), (0x2F9FA, "\u{97E0}"), (0x2F9FB, "\u{2940A}"),
+    (0x2F9FC, "\u{4AB2}"), (0x2F9FD, "\u{29496}"), (0x2F9FE, "\u{980B}"), (0x2F9FF, "\u{980B}"),
+    (0x2FA00, "\u{9829}"), (0x2FA01, "\u{295B6}"), (0x2FA02, "\u{98E2}"), (0x2FA03, "\u{4B33}"),
+    (0x2FA04, "\u{9929}"), (0x2FA05, "\u{99A7}"), (0x2FA06, "\u{99C2}"), (0x2FA07, "\u{99FE}"),
+    (0x2FA08, "\u{4BCE}"), (0x2FA09, "\u{29B30}"), (0x2FA0A, "\u{9B12}"), (0x2FA0B, "\u{9C40}"),
+    (0x2FA0C, "\u{9CFD}"), (0x2FA0D, "\u{4CCE}"), (0x2FA0E, "\u{4CED}"), (0x2FA0F, "\u{9D67}"),
+    (0x2FA10, "\u{2A0CE}"), (0x2FA11, "\u{4CF8}"), (0x2FA12, "\u{2A105}"), (0x2FA13, "\u{2A20E}"),
+    (0x2FA14, "\u{2A291}"), (0x2FA15, "\u{9EBB}"), (0x2FA16, "\u{4D56}"), (0x2FA17, "\u{9EF9}"),
+    (0x2FA18, "\u{9EFE}"), (0x2FA19, "\u{9F05}"), (0x2FA1A, "\u{9F0F}"), (0x2FA1B, "\u{9F16}"),
+    (0x2FA1C, "\u{9F3B}"), (0x2FA1D, "\u{2A600}"),
+];
+
+/// Canonical combining class of the non-starters: (first, last, class)
+#[rustfmt::skip]
+pub(super) static COMBINING_CLASSES: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31A, 0x31A, 232),
+    (0x31B, 0x31B, 216), (0x31C, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34A, 0x34C, 230), (0x34D, 0x34E, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35A, 220), (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233), (0x35D, 0x35E, 234), (0x35F, 0x35F, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36F, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220), (0x59C, 0x5A1, 230), (0x5A2, 0x5A7, 220), (0x5A8, 0x5A9, 230),
+    (0x5AA, 0x5AA, 220), (0x5AB, 0x5AC, 230), (0x5AD, 0x5AD, 222), (0x5AE, 0x5AE, 228),
+    (0x5AF, 0x5AF, 230), (0x5B0, 0x5B0, 10), (0x5B1, 0x5B1, 11), (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13), (0x5B4, 0x5B4, 14), (0x5B5, 0x5B5, 15), (0x5B6, 0x5B6, 16),
+    (0x5B7, 0x5B7, 17), (0x5B8, 0x5B8, 18), (0x5B9, 0x5BA, 19), (0x5BB, 0x5BB, 20),
+    (0x5BC, 0x5BC, 21), (0x5BD, 0x5BD, 22), (0x5BF, 0x5BF, 23), (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25), (0x5C4, 0x5C4, 230), (0x5C5, 0x5C5, 220), (0x5C7, 0x5C7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61A, 0x61A, 32),
+    (0x64B, 0x64B, 27), (0x64C, 0x64C, 28), (0x64D, 0x64D, 29), (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65B, 230), (0x65C, 0x65C, 220),
+    (0x65D, 0x65E, 230), (0x65F, 0x65F, 220), (0x670, 0x670, 35), (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230), (0x6E3, 0x6E3, 220), (0x6E4, 0x6E4, 230), (0x6E7, 0x6E8, 230),
+    (0x6EA, 0x6EA, 220), (0x6EB, 0x6EC, 230), (0x6ED, 0x6ED, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73A, 0x73A, 230), (0x73B, 0x73C, 220),
+    (0x73D, 0x73D, 230), (0x73E, 0x73E, 220), (0x73F, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74A, 230), (0x7EB, 0x7F1, 230),
+    (0x7F2, 0x7F2, 220), (0x7F3, 0x7F3, 230), (0x7FD, 0x7FD, 220), (0x816, 0x819, 230),
+    (0x81B, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82D, 230), (0x859, 0x85B, 220),
+    (0x897, 0x898, 230), (0x899, 0x89B, 220), (0x89C, 0x89F, 230), (0x8CA, 0x8CE, 230),
+    (0x8CF, 0x8D3, 220), (0x8D4, 0x8E1, 230), (0x8E3, 0x8E3, 220), (0x8E4, 0x8E5, 230),
+    (0x8E6, 0x8E6, 220), (0x8E7, 0x8E8, 230), (0x8E9, 0x8E9, 220), (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220), (0x8F0, 0x8F0, 27), (0x8F1, 0x8F1, 28), (0x8F2, 0x8F2, 29),
+    (0x8F3, 0x8F5, 230), (0x8F6, 0x8F6, 220), (0x8F7, 0x8F8, 230), (0x8F9, 0x8FA, 220),
+    (0x8FB, 0x8FF, 230), (0x93C, 0x93C, 7), (0x94D, 0x94D, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9BC, 0x9BC, 7), (0x9CD, 0x9CD, 9),
+    (0x9FE, 0x9FE, 230), (0xA3C, 0xA3C, 7), (0xA4D, 0xA4D, 9), (0xABC, 0xABC, 7), (0xACD, 0xACD, 9),
+    (0xB3C, 0xB3C, 7), (0xB4D, 0xB4D, 9), (0xBCD, 0xBCD, 9), (0xC3C, 0xC3C, 7), (0xC4D, 0xC4D, 9),
+    (0xC55, 0xC55, 84), (0xC56, 0xC56, 91), (0xCBC, 0xCBC, 7), (0xCCD, 0xCCD, 9), (0xD3B, 0xD3C, 9),
+    (0xD4D, 0xD4D, 9), (0xDCA, 0xDCA, 9), (0xE38, 0xE39, 103), (0xE3A, 0xE3A, 9),
+    (0xE48, 0xE4B, 107), (0xEB8, 0xEB9, 118), (0xEBA, 0xEBA, 9), (0xEC8, 0xECB, 122),
+    (0xF18, 0xF19, 220), (0xF35, 0xF35, 220), (0xF37, 0xF37, 220), (0xF39, 0xF39, 216),
+    (0xF71, 0xF71, 129), (0xF72, 0xF72, 130), (0xF74, 0xF74, 132), (0xF7A, 0xF7D, 130),
+    (0xF80, 0xF80, 130), (0xF82, 0xF83, 230), (0xF84, 0xF84, 9), (0xF86, 0xF87, 230),
+    (0xFC6, 0xFC6, 220), (0x1037, 0x1037, 7), (0x1039, 0x103A, 9), (0x108D, 0x108D, 220),
+    (0x135D, 0x135F, 230), (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9),
+    (0x17DD, 0x17DD, 230), (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230),
+    (0x193B, 0x193B, 220), (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9),
+    (0x1A75, 0x1A7C, 230), (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220),
+    (0x1ABB, 0x1ABC, 230), (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230),
+    (0x1AC3, 0x1AC4, 220), (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220), (0x1ACB, 0x1ADC, 230),
+    (0x1ADD, 0x1ADD, 220), (0x1AE0, 0x1AE5, 230), (0x1AE6, 0x1AE6, 220), (0x1AE7, 0x1AEA, 230),
+    (0x1AEB, 0x1AEB, 234), (0x1B34, 0x1B34, 7), (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230),
+    (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230), (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7),
+    (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7), (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1),
+    (0x1CD5, 0x1CD9, 220), (0x1CDA, 0x1CDB, 230), (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230),
+    (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220), (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230),
+    (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220), (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220),
+    (0x1DCB, 0x1DCC, 230), (0x1DCD, 0x1DCD, 234), (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220),
+    (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230), (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228),
+    (0x1DF9, 0x1DF9, 220), (0x1DFA, 0x1DFA, 218), (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233),
+    (0x1DFD, 0x1DFD, 220), (0x1DFE, 0x1DFE, 230), (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230),
+    (0x20D2, 0x20D3, 1), (0x20D4, 0x20D7, 230), (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230),
+    (0x20E1, 0x20E1, 230), (0x20E5, 0x20E6, 1), (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220),
+    (0x20E9, 0x20E9, 230), (0x20EA, 0x20EB, 1), (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230),
+    (0x2CEF, 0x2CF1, 230), (0x2D7F, 0x2D7F, 9), (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218),
+    (0x302B, 0x302B, 228), (0x302C, 0x302C, 232), (0x302D, 0x302D, 222), (0x302E, 0x302F, 224),
+    (0x3099, 0x309A, 8), (0xA66F, 0xA66F, 230), (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230),
+    (0xA6F0, 0xA6F1, 230), (0xA806, 0xA806, 9), (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9),
+    (0xA8E0, 0xA8F1, 230), (0xA92B, 0xA92D, 220), (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7),
+    (0xA9C0, 0xA9C0, 9), (0xAAB0, 0xAAB0, 230), (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220),
+    (0xAAB7, 0xAAB8, 230), (0xAABE, 0xAABF, 230), (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9),
+    (0xABED, 0xABED, 9), (0xFB1E, 0xFB1E, 26), (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220),
+    (0xFE2E, 0xFE2F, 230), (0x101FD, 0x101FD, 220), (0x102E0, 0x102E0, 220),
+    (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220), (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220), (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230),
+    (0x10D69, 0x10D6D, 230), (0x10EAB, 0x10EAC, 230), (0x10EFA, 0x10EFB, 220),
+    (0x10EFD, 0x10EFF, 220), (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230),
+    (0x10F4B, 0x10F4B, 220), (0x10F4C, 0x10F4C, 230), (0x10F4D, 0x10F50, 220),
+    (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x113CE, 0x113D0, 9),
+    (0x11442, 0x11442, 9), (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7), (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9), (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7), (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9), (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7), (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x11F41, 0x11F42, 9),
+    (0x1612F, 0x1612F, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230), (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1), (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230),
+    (0x1E000, 0x1E006, 230), (0x1E008, 0x1E018, 230), (0x1E01B, 0x1E021, 230),
+    (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230), (0x1E08F, 0x1E08F, 230),
+    (0x1E130, 0x1E136, 230), (0x1E2AE, 0x1E2AE, 230), (0x1E2EC, 0x1E2EF, 230),
+    (0x1E4EC, 0x1E4ED, 232), (0x1E4EE, 0x1E4EE, 220), (0x1E4EF, 0x1E4EF, 230),
+    (0x1E5EE, 0x1E5EE, 230), (0x1E5EF, 0x1E5EF, 220), (0x1E6E3, 0x1E6E3, 230),
+    (0x1E6E6, 0x1E6E6, 230), (0x1E6EE, 0x1E6EF, 230), (0x1E6F5, 0x1E6F5, 230),
+    (0x1E8D0, 0x1E8D6, 220), (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
@@ -1,43 +1,39 @@
 use std::{
     any::{type_name, Any, TypeId},
-    cell::{Cell, OnceCell, RefCell},
+    cell::{OnceCell, RefCell},
     collections::HashMap,
     rc::{Rc, Weak},
+    sync::{self, Arc, OnceLock},
 };
 
 use super::{Match, Matcher, Parse, Parser};
+use crate::grammar::{Grammar, GrammarNode, Rules};
+use crate::input::Input;
 use crate::result::{MatchResult, ParseResult, RawEzpcError};
+use crate::state::{self, Wrapped};
+use crate::trace;
 
 // Wrapping of Parsers. Further down, the wrapping of matchers is implemented.
-// It is not commented as it is basically the same, but the code is a bit simpler
-// because no dyn Any is needed: Matchers all have the same type, Parsers<Output = ?> have not.
+// It is not commented as it is basically the same. All of them are built by
+// build_wrapped() at the end of this file.
 
-pub enum ParserRef<O: 'static> {
-    Strong(Rc<OnceCell<Box<dyn Parse<Output = O>>>>),
-    Weak(Weak<OnceCell<Box<dyn Parse<Output = O>>>>),
+type ParserCell<O, I> = OnceCell<Box<dyn Parse<I, Output = O>>>;
+
+pub enum ParserRef<O: 'static, I: Input + ?Sized = str> {
+    Strong(Rc<ParserCell<O, I>>),
+    Weak(Weak<ParserCell<O, I>>),
 }
 
-pub struct WrappedParser<O: 'static> {
-    parser: ParserRef<O>,
+pub struct WrappedParser<O: 'static, I: Input + ?Sized = str> {
+    parser: ParserRef<O, I>,
     max_depth: usize,
     name: &'static str,
 }
 
-impl<O: 'static> Parse for WrappedParser<O> {
+impl<O: 'static + Clone, I: Input + ?Sized> Parse<I> for WrappedParser<O, I> {
     type Output = O;
 
-    fn apply<'a>(&self, input: &'a str) -> ParseResult<'a, Self::Output> {
-        thread_local! { static DEPTH: Cell<usize> = const { Cell::new(0) }; }
-
-        let depth = DEPTH.with(|d| d.get());
-        if depth > self.max_depth {
-            return Err(RawEzpcError::Recursion {
-                max_depth: self.max_depth,
-                parser_name: self.name,
-                pos: input.as_ptr(),
-            });
-        }
-
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
         let parser = match &self.parser {
             ParserRef::Strong(p) => p.clone(),
             // The top level of the recursion is a strong ref, so these inner weak
@@ -45,68 +41,113 @@ impl<O: 'static> Parse for WrappedParser<O> {
             ParserRef::Weak(weak) => weak.upgrade().unwrap(),
         };
 
-        DEPTH.with(|d| d.set(d.get() + 1));
-        let result = parser.get().unwrap().apply(input);
-        DEPTH.with(|d| d.set(d.get() - 1));
+        apply_wrapped(
+            parser.get().unwrap().as_ref(),
+            self.id().unwrap(),
+            input,
+            self.max_depth,
+            self.name,
+        )
+    }
 
-        result
+    /// The shared cell identifies the parser, no matter if referenced strong or weak
+    fn id(&self) -> Option<usize> {
+        Some(match &self.parser {
+            ParserRef::Strong(p) => Rc::as_ptr(p) as usize,
+            ParserRef::Weak(weak) => weak.as_ptr() as usize,
+        })
     }
 }
 
-pub trait WrapParser<O> {
-    fn wrap(self, max_depth: usize) -> Parser<WrappedParser<O>>;
+/// Wrapped parsers are named rules of the grammar, see Rules::rule
+impl<O: 'static, I: Input + ?Sized> Grammar for WrappedParser<O, I> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let parser = match &self.parser {
+            ParserRef::Strong(p) => p.clone(),
+            ParserRef::Weak(weak) => weak.upgrade().unwrap(),
+        };
+        rules.rule(self.name, |rules| parser.get().unwrap().describe(rules))
+    }
 }
 
-impl<O: 'static + Clone, P, F> WrapParser<O> for F
-where
-    P: Parse<Output = O> + 'static,
-    F: Fn() -> Parser<P> + 'static,
-{
-    fn wrap(self, max_depth: usize) -> Parser<WrappedParser<O>> {
-        thread_local! {
-            /// The parsers have different types based on their outputs, so they are wrapped in Any:
-            /// `Box<dyn Any> contains Rc<OnceCell<Box<dyn Parse<Output = ???>>>>`
-            static IN_PROGRESS: RefCell<HashMap<TypeId, Box<dyn Any>>> = Default::default();
-        }
+/// Applies a wrapped parser, shared by the Rc and the Arc based versions.
+/// It is traced under the type name of its builder function.
+fn apply_wrapped<'a, O: Clone + 'static, I: Input + ?Sized>(
+    parser: &dyn Parse<I, Output = O>,
+    id: usize,
+    input: &'a I,
+    max_depth: usize,
+    name: &'static str,
+) -> ParseResult<'a, O, I> {
+    trace::parse(name, input, || {
+        apply_growing(parser, id, input, max_depth, name)
+    })
+}
 
-        // Use the type of the recursive parser builder Fn as the identifier key
-        let type_id = self.type_id();
-
-        if let Some(parser) = IN_PROGRESS.with(|in_prog| {
-            in_prog
-                .borrow()
-                .get(&type_id)
-                .map(|parser| Rc::downgrade(parser.downcast_ref().unwrap()))
-        }) {
-            return Parser(WrappedParser {
-                parser: ParserRef::Weak(parser),
-                max_depth,
-                name: type_name::<F>(),
-            });
-        }
+/// Checks the recursion depth and resolves left recursion
+fn apply_growing<'a, O: Clone + 'static, I: Input + ?Sized>(
+    parser: &dyn Parse<I, Output = O>,
+    id: usize,
+    input: &'a I,
+    max_depth: usize,
+    name: &'static str,
+) -> ParseResult<'a, O, I> {
+    if let Some(seed) = state::lr_enter::<(O, usize)>(id, input.as_ptr()) {
+        // Left recursion: return the seed instead of recursing forever
+        return match seed {
+            Some((out, len)) => Ok((out, input.tail(len))),
+            None => Err(RawEzpcError::Mismatch {
+                pos: input.as_ptr(),
+            }),
+        };
+    }
 
-        // The parser does not exist in IN_PROGRESS, so we are at the root level
-        // of the recursion. First, build an empty cell that can be used by the recursion:
-        let parser_ref = Rc::new(OnceCell::new());
-        IN_PROGRESS.with(|in_prog| {
-            in_prog
-                .borrow_mut()
-                .insert(type_id, Box::new(parser_ref.clone()))
+    if !state::depth_enter(Wrapped::Parser, max_depth) {
+        state::lr_exit();
+        return Err(RawEzpcError::Recursion {
+            max_depth,
+            parser_name: name,
+            pos: input.as_ptr(),
         });
+    }
 
-        // Then build the true parser itself by executing the function. When it
-        // reaches building istelf, it will use the still empty OnceCell.
-        let parser: Box<dyn Parse<Output = O>> = Box::new(self().0);
+    let mut result = parser.apply(input);
+    if state::lr_recursed() {
+        // Grow the seed as long as the parser consumes more input
+        while let Ok((out, rest)) = result {
+            state::lr_set_seed((out.clone(), input.len() - rest.len()));
+            result = match parser.apply(input) {
+                Ok((grown, grown_rest)) if grown_rest.len() < rest.len() => Ok((grown, grown_rest)),
+                Ok(_) | Err(RawEzpcError::Mismatch { .. }) => {
+                    result = Ok((out, rest));
+                    break;
+                }
+                Err(err) => Err(err),
+            };
+        }
+    }
 
-        // Afterwards clean up the thread local to avoid memory leaks
-        IN_PROGRESS.with(|in_prog| in_prog.borrow_mut().remove(&type_id).unwrap());
+    state::depth_exit(Wrapped::Parser);
+    state::lr_exit();
+    result
+}
 
-        // Now we actually have the parser and can initialize the OnceCell that is
-        // already being referenced by the recursion points inside of the parser.
-        parser_ref.set(parser).unwrap_or_else(|_| unreachable!());
+pub trait WrapParser<O: Clone, I: Input + ?Sized = str> {
+    fn wrap(self, max_depth: usize) -> Parser<WrappedParser<O, I>>;
+}
 
+impl<O: 'static + Clone, I: Input + ?Sized, P, F> WrapParser<O, I> for F
+where
+    P: Parse<I, Output = O> + 'static,
+    F: Fn() -> Parser<P> + 'static,
+{
+    fn wrap(self, max_depth: usize) -> Parser<WrappedParser<O, I>> {
+        let parser = match build_wrapped::<F, Rc<ParserCell<O, I>>>(|| Box::new(self().0)) {
+            Built::Root(cell) => ParserRef::Strong(cell),
+            Built::Nested(cell) => ParserRef::Weak(cell),
+        };
         Parser(WrappedParser {
-            parser: ParserRef::Strong(parser_ref),
+            parser,
             max_depth,
             name: type_name::<F>(),
         })
@@ -114,85 +155,353 @@ where
 }
 
 // Same for matcher, see parser comments for more information.
-// We don't need dyn Any because all Matchers have the same type.
 
-pub enum MatcherRef {
-    Strong(Rc<OnceCell<Box<dyn Match>>>),
-    Weak(Weak<OnceCell<Box<dyn Match>>>),
+type MatcherCell<I> = OnceCell<Box<dyn Match<I>>>;
+
+pub enum MatcherRef<I: Input + ?Sized = str> {
+    Strong(Rc<MatcherCell<I>>),
+    Weak(Weak<MatcherCell<I>>),
 }
 
-pub struct WrappedMatcher {
-    matcher: MatcherRef,
+pub struct WrappedMatcher<I: Input + ?Sized = str> {
+    matcher: MatcherRef<I>,
     max_depth: usize,
     name: &'static str,
 }
 
-impl Match for WrappedMatcher {
-    fn apply<'a>(&self, input: &'a str) -> MatchResult<'a> {
-        thread_local! { static DEPTH: Cell<usize> = const { Cell::new(0) }; }
+impl<I: Input + ?Sized> Match<I> for WrappedMatcher<I> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        let matcher = match &self.matcher {
+            MatcherRef::Strong(p) => p.clone(),
+            MatcherRef::Weak(weak) => weak.upgrade().unwrap(),
+        };
 
-        let depth = DEPTH.with(|d| d.get());
-        if depth > self.max_depth {
-            return Err(RawEzpcError::Recursion {
-                max_depth: self.max_depth,
-                parser_name: self.name,
-                pos: input.as_ptr(),
-            });
-        }
+        match_wrapped(
+            matcher.get().unwrap().as_ref(),
+            self.id().unwrap(),
+            input,
+            self.max_depth,
+            self.name,
+        )
+    }
+
+    fn id(&self) -> Option<usize> {
+        Some(match &self.matcher {
+            MatcherRef::Strong(p) => Rc::as_ptr(p) as usize,
+            MatcherRef::Weak(weak) => weak.as_ptr() as usize,
+        })
+    }
+}
 
-        let parser = match &self.matcher {
+impl<I: Input + ?Sized> Grammar for WrappedMatcher<I> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let matcher = match &self.matcher {
             MatcherRef::Strong(p) => p.clone(),
             MatcherRef::Weak(weak) => weak.upgrade().unwrap(),
         };
+        rules.rule(self.name, |rules| matcher.get().unwrap().describe(rules))
+    }
+}
+
+fn match_wrapped<'a, I: Input + ?Sized>(
+    matcher: &dyn Match<I>,
+    id: usize,
+    input: &'a I,
+    max_depth: usize,
+    name: &'static str,
+) -> MatchResult<'a, I> {
+    if let Some(seed) = state::lr_enter::<usize>(id, input.as_ptr()) {
+        return match seed {
+            Some(len) => Ok(input.tail(len)),
+            None => Err(RawEzpcError::Mismatch {
+                pos: input.as_ptr(),
+            }),
+        };
+    }
 
-        DEPTH.with(|d| d.set(d.get() + 1));
-        let result = parser.get().unwrap().apply(input);
-        DEPTH.with(|d| d.set(d.get() - 1));
+    if !state::depth_enter(Wrapped::Matcher, max_depth) {
+        state::lr_exit();
+        return Err(RawEzpcError::Recursion {
+            max_depth,
+            parser_name: name,
+            pos: input.as_ptr(),
+        });
+    }
 
-        result
+    let mut result = matcher.apply(input);
+    if state::lr_recursed() {
+        while let Ok(rest) = result {
+            state::lr_set_seed(input.len() - rest.len());
+            result = match matcher.apply(input) {
+                Ok(grown_rest) if grown_rest.len() < rest.len() => Ok(grown_rest),
+                Ok(_) | Err(RawEzpcError::Mismatch { .. }) => {
+                    result = Ok(rest);
+                    break;
+                }
+                Err(err) => Err(err),
+            };
+        }
     }
+
+    state::depth_exit(Wrapped::Matcher);
+    state::lr_exit();
+    result
 }
 
-pub trait WrapMatcher {
-    fn wrap(self, max_depth: usize) -> Matcher<WrappedMatcher>;
+pub trait WrapMatcher<I: Input + ?Sized = str> {
+    fn wrap(self, max_depth: usize) -> Matcher<WrappedMatcher<I>>;
 }
 
-impl<M, F> WrapMatcher for F
+impl<I: Input + ?Sized, M, F> WrapMatcher<I> for F
 where
-    M: Match + 'static,
+    M: Match<I> + 'static,
     F: Fn() -> Matcher<M> + 'static,
 {
-    fn wrap(self, max_depth: usize) -> Matcher<WrappedMatcher> {
-        type MatchRef = Rc<OnceCell<Box<dyn Match>>>;
-        thread_local! {
-            static IN_PROGRESS: RefCell<HashMap<TypeId, MatchRef>> = Default::default();
-        }
-        let type_id = self.type_id();
-
-        if let Some(matcher) = IN_PROGRESS.with(|in_prog| {
-            in_prog
-                .borrow()
-                .get(&type_id)
-                .map(Rc::downgrade)
-        }) {
-            return Matcher(WrappedMatcher {
-                matcher: MatcherRef::Weak(matcher),
-                max_depth,
-                name: type_name::<F>(),
-            });
-        }
+    fn wrap(self, max_depth: usize) -> Matcher<WrappedMatcher<I>> {
+        let matcher = match build_wrapped::<F, Rc<MatcherCell<I>>>(|| Box::new(self().0)) {
+            Built::Root(cell) => MatcherRef::Strong(cell),
+            Built::Nested(cell) => MatcherRef::Weak(cell),
+        };
+        Matcher(WrappedMatcher {
+            matcher,
+            max_depth,
+            name: type_name::<F>(),
+        })
+    }
+}
 
-        let matcher_ref = Rc::new(OnceCell::new());
+// Thread safe versions of the wrapped parsers and matchers. They are identical
+// to the versions above, except that they use Arc and OnceLock and require the
+// wrapped parser to be Send + Sync. The recursion depth is tracked per parse
+// call, so a single parser can be used by many threads at the same time.
 
-        IN_PROGRESS.with(|in_prog| in_prog.borrow_mut().insert(type_id, matcher_ref.clone()));
-        let matcher: Box<dyn Match> = Box::new(self().0);
-        IN_PROGRESS.with(|in_prog| in_prog.borrow_mut().remove(&type_id).unwrap());
+type SyncParserCell<O, I> = OnceLock<Box<dyn Parse<I, Output = O> + Send + Sync>>;
 
-        matcher_ref.set(matcher).unwrap_or_else(|_| unreachable!());
-        Matcher(WrappedMatcher {
-            matcher: MatcherRef::Strong(matcher_ref),
+pub enum SyncParserRef<O: 'static, I: Input + ?Sized = str> {
+    Strong(Arc<SyncParserCell<O, I>>),
+    Weak(sync::Weak<SyncParserCell<O, I>>),
+}
+
+pub struct SyncWrappedParser<O: 'static, I: Input + ?Sized = str> {
+    parser: SyncParserRef<O, I>,
+    max_depth: usize,
+    name: &'static str,
+}
+
+impl<O: 'static + Clone, I: Input + ?Sized> Parse<I> for SyncWrappedParser<O, I> {
+    type Output = O;
+
+    fn apply<'a>(&self, input: &'a I) -> ParseResult<'a, Self::Output, I> {
+        let parser = match &self.parser {
+            SyncParserRef::Strong(p) => p.clone(),
+            SyncParserRef::Weak(weak) => weak.upgrade().unwrap(),
+        };
+
+        apply_wrapped(
+            parser.get().unwrap().as_ref(),
+            self.id().unwrap(),
+            input,
+            self.max_depth,
+            self.name,
+        )
+    }
+
+    fn id(&self) -> Option<usize> {
+        Some(match &self.parser {
+            SyncParserRef::Strong(p) => Arc::as_ptr(p) as usize,
+            SyncParserRef::Weak(weak) => weak.as_ptr() as usize,
+        })
+    }
+}
+
+impl<O: 'static, I: Input + ?Sized> Grammar for SyncWrappedParser<O, I> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let parser = match &self.parser {
+            SyncParserRef::Strong(p) => p.clone(),
+            SyncParserRef::Weak(weak) => weak.upgrade().unwrap(),
+        };
+        rules.rule(self.name, |rules| parser.get().unwrap().describe(rules))
+    }
+}
+
+pub trait WrapParserSync<O: Clone, I: Input + ?Sized = str> {
+    fn wrap_sync(self, max_depth: usize) -> Parser<SyncWrappedParser<O, I>>;
+}
+
+impl<O: 'static + Clone, I: Input + ?Sized, P, F> WrapParserSync<O, I> for F
+where
+    P: Parse<I, Output = O> + Send + Sync + 'static,
+    F: Fn() -> Parser<P> + 'static,
+{
+    fn wrap_sync(self, max_depth: usize) -> Parser<SyncWrappedParser<O, I>> {
+        let parser = match build_wrapped::<F, Arc<SyncParserCell<O, I>>>(|| Box::new(self().0)) {
+            Built::Root(cell) => SyncParserRef::Strong(cell),
+            Built::Nested(cell) => SyncParserRef::Weak(cell),
+        };
+        Parser(SyncWrappedParser {
+            parser,
+            max_depth,
+            name: type_name::<F>(),
+        })
+    }
+}
+
+type SyncMatcherCell<I> = OnceLock<Box<dyn Match<I> + Send + Sync>>;
+
+pub enum SyncMatcherRef<I: Input + ?Sized = str> {
+    Strong(Arc<SyncMatcherCell<I>>),
+    Weak(sync::Weak<SyncMatcherCell<I>>),
+}
+
+pub struct SyncWrappedMatcher<I: Input + ?Sized = str> {
+    matcher: SyncMatcherRef<I>,
+    max_depth: usize,
+    name: &'static str,
+}
+
+impl<I: Input + ?Sized> Match<I> for SyncWrappedMatcher<I> {
+    fn apply<'a>(&self, input: &'a I) -> MatchResult<'a, I> {
+        let matcher = match &self.matcher {
+            SyncMatcherRef::Strong(p) => p.clone(),
+            SyncMatcherRef::Weak(weak) => weak.upgrade().unwrap(),
+        };
+
+        match_wrapped(
+            matcher.get().unwrap().as_ref(),
+            self.id().unwrap(),
+            input,
+            self.max_depth,
+            self.name,
+        )
+    }
+
+    fn id(&self) -> Option<usize> {
+        Some(match &self.matcher {
+            SyncMatcherRef::Strong(p) => Arc::as_ptr(p) as usize,
+            SyncMatcherRef::Weak(weak) => weak.as_ptr() as usize,
+        })
+    }
+}
+
+impl<I: Input + ?Sized> Grammar for SyncWrappedMatcher<I> {
+    fn describe(&self, rules: &mut Rules) -> GrammarNode {
+        let matcher = match &self.matcher {
+            SyncMatcherRef::Strong(p) => p.clone(),
+            SyncMatcherRef::Weak(weak) => weak.upgrade().unwrap(),
+        };
+        rules.rule(self.name, |rules| matcher.get().unwrap().describe(rules))
+    }
+}
+
+pub trait WrapMatcherSync<I: Input + ?Sized = str> {
+    fn wrap_sync(self, max_depth: usize) -> Matcher<SyncWrappedMatcher<I>>;
+}
+
+impl<I: Input + ?Sized, M, F> WrapMatcherSync<I> for F
+where
+    M: Match<I> + Send + Sync + 'static,
+    F: Fn() -> Matcher<M> + 'static,
+{
+    fn wrap_sync(self, max_depth: usize) -> Matcher<SyncWrappedMatcher<I>> {
+        let matcher = match build_wrapped::<F, Arc<SyncMatcherCell<I>>>(|| Box::new(self().0)) {
+            Built::Root(cell) => SyncMatcherRef::Strong(cell),
+            Built::Nested(cell) => SyncMatcherRef::Weak(cell),
+        };
+        Matcher(SyncWrappedMatcher {
+            matcher,
             max_depth,
             name: type_name::<F>(),
         })
     }
 }
+
+// Building of the wrapped parsers and matchers, shared by the Rc and the Arc
+// based versions
+
+/// The Rc or Arc of the cell that holds a wrapped parser or matcher
+trait SharedCell: Clone + 'static {
+    type Weak;
+    type Value;
+    fn empty() -> Self;
+    fn downgrade(&self) -> Self::Weak;
+    fn set(&self, value: Self::Value);
+}
+
+impl<T: 'static> SharedCell for Rc<OnceCell<T>> {
+    type Weak = Weak<OnceCell<T>>;
+    type Value = T;
+
+    fn empty() -> Self {
+        Rc::new(OnceCell::new())
+    }
+
+    fn downgrade(&self) -> Self::Weak {
+        Rc::downgrade(self)
+    }
+
+    fn set(&self, value: T) {
+        OnceCell::set(self, value).unwrap_or_else(|_| unreachable!());
+    }
+}
+
+impl<T: 'static> SharedCell for Arc<OnceLock<T>> {
+    type Weak = sync::Weak<OnceLock<T>>;
+    type Value = T;
+
+    fn empty() -> Self {
+        Arc::new(OnceLock::new())
+    }
+
+    fn downgrade(&self) -> Self::Weak {
+        Arc::downgrade(self)
+    }
+
+    fn set(&self, value: T) {
+        OnceLock::set(self, value).unwrap_or_else(|_| unreachable!());
+    }
+}
+
+/// The root of the recursion owns the cell, the recursion points inside of
+/// the parser only reference it
+enum Built<C: SharedCell> {
+    Root(C),
+    Nested(C::Weak),
+}
+
+/// Builds the wrapped parser or matcher of the builder function F
+fn build_wrapped<F: 'static, C: SharedCell>(build: impl FnOnce() -> C::Value) -> Built<C> {
+    // Building happens on a single thread, so IN_PROGRESS can stay thread local.
+    // The cells have different types based on the outputs of the parsers,
+    // so they are wrapped in Any: `Box<dyn Any>` contains a C
+    thread_local! {
+        static IN_PROGRESS: RefCell<HashMap<(TypeId, TypeId), Box<dyn Any>>> = Default::default();
+    }
+    // Use the type of the recursive builder Fn as the identifier key, together
+    // with the type of the cell, as a Fn might be wrapped with and without sync
+    let key = (TypeId::of::<F>(), TypeId::of::<C>());
+
+    if let Some(cell) = IN_PROGRESS.with(|in_prog| {
+        let in_prog = in_prog.borrow();
+        let cell = in_prog.get(&key)?;
+        Some(cell.downcast_ref::<C>().unwrap().downgrade())
+    }) {
+        return Built::Nested(cell);
+    }
+
+    // The parser does not exist in IN_PROGRESS, so we are at the root level
+    // of the recursion. First, build an empty cell that can be used by the recursion:
+    let cell = C::empty();
+    IN_PROGRESS.with(|in_prog| in_prog.borrow_mut().insert(key, Box::new(cell.clone())));
+
+    // Then build the true parser itself by executing the function. When it
+    // reaches building itself, it will use the still empty cell.
+    let value = build();
+
+    // Afterwards clean up the thread local to avoid memory leaks
+    IN_PROGRESS.with(|in_prog| in_prog.borrow_mut().remove(&key).unwrap());
+
+    // Now we actually have the parser and can initialize the cell that is
+    // already being referenced by the recursion points inside of the parser.
+    cell.set(value);
+    Built::Root(cell)
+}
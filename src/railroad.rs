@@ -0,0 +1,276 @@
+use std::fmt::Write;
+
+use crate::grammar::{GrammarNode, Rules};
+
+// Railroad diagrams are laid out bottom up: every node becomes a diagram with
+// its track entering on the left and leaving on the right at y = 0. Nodes
+// extend up and down from the track, which is needed to stack alternatives.
+
+const CHAR_WIDTH: usize = 8;
+/// Half the height of a box
+const BOX: usize = 11;
+/// Length of the track between nodes and the space between alternatives
+const GAP: usize = 10;
+/// Horizontal space needed for the curves that branch off the track
+const BRANCH: usize = 20;
+
+struct Diagram {
+    width: usize,
+    up: usize,
+    down: usize,
+    svg: String,
+}
+
+impl Rules {
+    /// Draw every rule as railroad diagram into a single SVG image
+    pub fn to_svg(&self) -> String {
+        let mut body = String::new();
+        let mut width = 0;
+        let mut y = 0;
+        for (name, node) in self.iter() {
+            let diagram = layout(node);
+            y += 2 * GAP;
+            writeln!(
+                body,
+                r#"<text class="title" x="{GAP}" y="{y}">{}</text>"#,
+                escape(name)
+            )
+            .unwrap();
+            y += GAP + diagram.up;
+
+            // The track starts and ends with a short vertical bar
+            let end = 2 * GAP + diagram.width;
+            writeln!(
+                body,
+                concat!(
+                    r#"<g transform="translate({} {})">"#,
+                    r#"<path d="M 0 -{bar} V {bar} M 0 0 H {gap} M {end} 0 H {last} M {last} -{bar} V {bar}"/>"#,
+                    r#"<g transform="translate({gap} 0)">{}</g></g>"#
+                ),
+                GAP,
+                y,
+                diagram.svg,
+                bar = GAP / 2,
+                gap = GAP,
+                end = end - GAP,
+                last = end,
+            )
+            .unwrap();
+            y += diagram.down;
+            width = width.max(end + 2 * GAP);
+        }
+        let height = y + 2 * GAP;
+
+        format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+                "\n<style>",
+                "path {{ fill: none; stroke: #333; stroke-width: 1.5; }} ",
+                "rect {{ fill: #ffc; stroke: #333; stroke-width: 1.5; }} ",
+                "rect.rule {{ fill: #cdf; }} rect.special {{ fill: #eee; }} ",
+                "text {{ font: 13px monospace; text-anchor: middle; }} ",
+                "text.title {{ font-weight: bold; text-anchor: start; }}",
+                "</style>\n{body}</svg>\n"
+            ),
+            w = width,
+            h = height,
+            body = body
+        )
+    }
+}
+
+fn layout(node: &GrammarNode) -> Diagram {
+    match node {
+        GrammarNode::Terminal(_) => boxed(&node.to_string(), "terminal", BOX),
+        GrammarNode::Class(_) => boxed(&node.to_string(), "terminal", 0),
+        GrammarNode::Special(_) | GrammarNode::Not(_) | GrammarNode::Peek(_) => {
+            boxed(&node.to_string(), "special", 0)
+        }
+        GrammarNode::Rule(name) => boxed(name, "rule", 0),
+        GrammarNode::Sequence(nodes) => sequence(nodes.iter().map(layout).collect()),
+        GrammarNode::Choice(nodes) => choice(nodes.iter().map(layout).collect()),
+        GrammarNode::Optional(node) => optional(layout(node)),
+        GrammarNode::Repeat { node, min, max } => {
+            let label = match (min, max) {
+                (0 | 1, None) => None,
+                (0, Some(1)) => return optional(layout(node)),
+                (min, None) => Some(format!("{min}+")),
+                (min, Some(max)) if min == max => Some(format!("{min}×")),
+                (min, Some(max)) => Some(format!("{min}..{max}")),
+            };
+            let repeated = repeat(layout(node), label);
+            if *min == 0 {
+                optional(repeated)
+            } else {
+                repeated
+            }
+        }
+    }
+}
+
+fn boxed(text: &str, class: &str, radius: usize) -> Diagram {
+    let width = text.chars().count() * CHAR_WIDTH + 2 * GAP;
+    Diagram {
+        width,
+        up: BOX,
+        down: BOX,
+        svg: format!(
+            concat!(
+                r#"<rect class="{}" x="0" y="-{}" width="{}" height="{}" rx="{}"/>"#,
+                r#"<text x="{}" y="4">{}</text>"#
+            ),
+            class,
+            BOX,
+            width,
+            2 * BOX,
+            radius,
+            width / 2,
+            escape(text)
+        ),
+    }
+}
+
+fn sequence(diagrams: Vec<Diagram>) -> Diagram {
+    let mut svg = String::new();
+    let mut x = 0;
+    let (mut up, mut down) = (0, 0);
+    for (i, diagram) in diagrams.into_iter().enumerate() {
+        if i > 0 {
+            write!(svg, r#"<path d="M {x} 0 H {}"/>"#, x + GAP).unwrap();
+            x += GAP;
+        }
+        write!(
+            svg,
+            r#"<g transform="translate({x} 0)">{}</g>"#,
+            diagram.svg
+        )
+        .unwrap();
+        x += diagram.width;
+        up = up.max(diagram.up);
+        down = down.max(diagram.down);
+    }
+    Diagram {
+        width: x,
+        up,
+        down,
+        svg,
+    }
+}
+
+/// Alternatives are stacked below the first one
+fn choice(diagrams: Vec<Diagram>) -> Diagram {
+    let inner = diagrams.iter().map(|d| d.width).max().unwrap_or(0);
+    let width = inner + 2 * BRANCH;
+    let (left, right) = (BRANCH / 2, width - BRANCH / 2);
+    let mut svg = String::new();
+    let mut y = 0;
+    let up = diagrams.first().map_or(0, |d| d.up);
+    let mut down = 0;
+
+    for (i, diagram) in diagrams.iter().enumerate() {
+        if i > 0 {
+            y += down + GAP + diagram.up;
+            // Curve down from the track and back up to it
+            write!(
+                svg,
+                concat!(
+                    r#"<path d="M 0 0 Q {l} 0 {l} {r} V {a} Q {l} {y} {b} {y}"/>"#,
+                    r#"<path d="M {c} {y} Q {rr} {y} {rr} {a} V {r} Q {rr} 0 {w} 0"/>"#
+                ),
+                l = left,
+                r = BRANCH / 2,
+                a = y - BRANCH / 2,
+                y = y,
+                b = BRANCH,
+                c = width - BRANCH,
+                rr = right,
+                w = width,
+            )
+            .unwrap();
+        } else {
+            write!(
+                svg,
+                r#"<path d="M 0 0 H {BRANCH} M {} 0 H {width}"/>"#,
+                width - BRANCH
+            )
+            .unwrap();
+        }
+        // Shorter alternatives are extended to the common width
+        let end = BRANCH + diagram.width;
+        write!(
+            svg,
+            r#"<g transform="translate({BRANCH} {y})">{}</g><path d="M {end} {y} H {}"/>"#,
+            diagram.svg,
+            width - BRANCH
+        )
+        .unwrap();
+        down = diagram.down;
+    }
+
+    Diagram {
+        width,
+        up,
+        down: y + down,
+        svg,
+    }
+}
+
+/// An empty alternative on the track, the node below it
+fn optional(diagram: Diagram) -> Diagram {
+    let skip = Diagram {
+        width: 0,
+        up: 0,
+        down: 0,
+        svg: String::new(),
+    };
+    choice(vec![skip, diagram])
+}
+
+/// The node on the track with a loop back below it, labeled with the bounds
+fn repeat(diagram: Diagram, label: Option<String>) -> Diagram {
+    let width = diagram.width + 2 * BRANCH;
+    let (left, right) = (BRANCH / 2, width - BRANCH / 2);
+    let y = diagram.down + GAP;
+    let mut svg = format!(
+        concat!(
+            r#"<path d="M 0 0 H {b} M {c} 0 H {w}"/>"#,
+            r#"<g transform="translate({b} 0)">{svg}</g>"#,
+            r#"<path d="M {c} 0 Q {rr} 0 {rr} {r} V {a} Q {rr} {y} {c} {y} "#,
+            r#"H {b} Q {l} {y} {l} {a} V {r} Q {l} 0 {b} 0"/>"#
+        ),
+        b = BRANCH,
+        c = width - BRANCH,
+        w = width,
+        svg = diagram.svg,
+        rr = right,
+        l = left,
+        r = BRANCH / 2,
+        a = y - BRANCH / 2,
+        y = y,
+    );
+    let mut down = y;
+    if let Some(label) = label {
+        down += 2 * GAP;
+        write!(
+            svg,
+            r#"<text x="{}" y="{}">{}</text>"#,
+            width / 2,
+            y + 3 * GAP / 2,
+            escape(&label)
+        )
+        .unwrap();
+    }
+    Diagram {
+        width,
+        up: diagram.up,
+        down,
+        svg,
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,15 +1,21 @@
-use std::fmt::Display;
+use std::{borrow::Cow, error::Error, fmt::Display, sync::Arc};
 
-pub type ParseResult<'a, O> = Result<(O, &'a str), RawEzpcError>;
-pub type MatchResult<'a> = Result<&'a str, RawEzpcError>;
+use crate::input::Input;
+use crate::state::Base;
 
+pub type ParseResult<'a, O, I = str> = Result<(O, &'a I), RawEzpcError>;
+pub type MatchResult<'a, I = str> = Result<&'a I, RawEzpcError>;
+
+#[derive(Clone)]
 pub enum RawEzpcError {
     Mismatch {
         pos: *const u8,
     },
     Fatal {
-        message: &'static str,
+        message: Cow<'static, str>,
         pos: *const u8,
+        /// The error returned by a convert() function
+        source: Option<Arc<dyn Error + Send + Sync>>,
     },
     Recursion {
         max_depth: usize,
@@ -25,10 +31,13 @@ pub enum RawEzpcError {
 pub enum EzpcError {
     PartialParse {
         pos: Position,
+        expected: Vec<Expected>,
     },
     Fatal {
-        expected: &'static str,
+        expected: Cow<'static, str>,
         pos: Position,
+        /// Returned by Error::source(), e.g. the error of a convert() function
+        source: Option<Arc<dyn Error + Send + Sync>>,
     },
     Recursion {
         max_depth: usize,
@@ -37,39 +46,135 @@ pub enum EzpcError {
     },
 }
 
-impl std::error::Error for EzpcError {}
+impl Error for EzpcError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EzpcError::Fatal {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 impl std::fmt::Display for EzpcError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}", self.display_message(), self.position())
+    }
+}
+
+/// Kind of an EzpcError, without any of its data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    PartialParse,
+    Fatal,
+    Recursion,
+}
+
+impl EzpcError {
+    /// Kind of the error, to tell them apart without matching the variants
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            EzpcError::PartialParse { .. } => ErrorKind::PartialParse,
+            EzpcError::Fatal { .. } => ErrorKind::Fatal,
+            EzpcError::Recursion { .. } => ErrorKind::Recursion,
+        }
+    }
+
+    /// The error message without the position and without the colon that
+    /// leads to it in Display, e.g. "Parsing stopped before reaching end of
+    /// input, expected `]`"
+    pub fn message(&self) -> String {
+        let message = self.display_message();
+        match message.strip_suffix(':') {
+            Some(stripped) => stripped.to_owned(),
+            None => message,
+        }
+    }
+
+    /// What was expected at the error position, only known for partial parses
+    pub fn expected(&self) -> &[Expected] {
+        match self {
+            EzpcError::PartialParse { expected, .. } => expected,
+            _ => &[],
+        }
+    }
+
+    /// The message as printed above the position
+    fn display_message(&self) -> String {
         match self {
-            EzpcError::PartialParse { pos } => {
-                write!(f, "Parsing stopped before reaching end of input:\n{pos}")
+            EzpcError::PartialParse { expected, .. } => {
+                let mut message = "Parsing stopped before reaching end of input".to_owned();
+                match expected.as_slice() {
+                    [] => (),
+                    [single] => message += &format!(", expected {single}"),
+                    [first, rest @ ..] => {
+                        message += &format!(", expected one of {first}");
+                        for exp in rest {
+                            message += &format!(", {exp}");
+                        }
+                    }
+                }
+                message + ":"
             }
-            EzpcError::Fatal { expected, pos } => write!(f, "{expected}\n{pos}"),
+            EzpcError::Fatal { expected, .. } => expected.to_string(),
             EzpcError::Recursion {
                 max_depth,
                 parser_name,
-                pos,
-            } => write!(
-                f,
-                "Exceeded maximum recursion depth {max_depth} of parser {parser_name}:\n{pos}"
-            ),
+                ..
+            } => format!("Exceeded maximum recursion depth {max_depth} of parser {parser_name}:"),
         }
     }
-}
 
-impl EzpcError {
-    pub fn from_raw(raw: RawEzpcError, source: &str) -> Self {
+    pub fn position(&self) -> &Position {
+        match self {
+            EzpcError::PartialParse { pos, .. }
+            | EzpcError::Fatal { pos, .. }
+            | EzpcError::Recursion { pos, .. } => pos,
+        }
+    }
+
+    /// Used for streamed input, where the parsed source is a chunk of the input
+    pub(crate) fn shift(mut self, base: Base) -> Self {
+        match &mut self {
+            EzpcError::PartialParse { pos, .. }
+            | EzpcError::Fatal { pos, .. }
+            | EzpcError::Recursion { pos, .. } => {
+                // The source might start in the middle of its first line
+                if pos.line == 1 {
+                    pos.column += base.column;
+                    pos.utf16_column += base.utf16_column;
+                }
+                pos.line += base.lines;
+                pos.offset += base.offset;
+            }
+        }
+        self
+    }
+
+    pub fn from_raw<I: Input + ?Sized>(raw: RawEzpcError, source: &I) -> Self {
+        Self::from_raw_with(raw, |pos| source.position(pos))
+    }
+
+    /// Like from_raw, with the position of a pointer into the source built by position
+    pub(crate) fn from_raw_with(
+        raw: RawEzpcError,
+        position: impl Fn(*const u8) -> Position,
+    ) -> Self {
         match raw {
             RawEzpcError::Mismatch { pos } => EzpcError::PartialParse {
-                pos: Position::from_ptr(source, pos),
+                pos: position(pos),
+                expected: Vec::new(),
             },
             RawEzpcError::Fatal {
                 message: expected,
                 pos,
+                source: error,
             } => EzpcError::Fatal {
                 expected,
-                pos: Position::from_ptr(source, pos),
+                pos: position(pos),
+                source: error,
             },
             RawEzpcError::Recursion {
                 max_depth,
@@ -78,50 +183,414 @@ impl EzpcError {
             } => EzpcError::Recursion {
                 max_depth,
                 parser_name,
-                pos: Position::from_ptr(source, pos),
+                pos: position(pos),
             },
         }
     }
 }
 
+/// Something that a matcher expected at a position where parsing failed.
+/// The expectations at the furthest failure position are collected for every
+/// parse and reported with EzpcError::PartialParse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    Tag(&'static str),
+    /// The tag in any case, see tag_ci()
+    TagCi(&'static str),
+    OneOf(&'static str),
+    NoneOf(&'static str),
+    /// A character in the range, see char_in()
+    CharIn(char, char),
+    Eof,
+    /// Text matching the regex, see regex()
+    Regex(&'static str),
+    /// Binary input: a byte sequence, a byte in a range or a number of bytes
+    ByteTag(&'static [u8]),
+    ByteIn(u8, u8),
+    Bytes(usize),
+    /// Provided by the user with the label() modifier
+    Label(&'static str),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Tag(tag) => write!(f, "`{}`", tag.escape_debug()),
+            Expected::TagCi(tag) => write!(f, "`{}` in any case", tag.escape_debug()),
+            Expected::OneOf(bag) => write!(f, "a character of `{}`", bag.escape_debug()),
+            Expected::NoneOf(bag) => write!(f, "a character not in `{}`", bag.escape_debug()),
+            Expected::CharIn(start, end) => write!(
+                f,
+                "a character in `{}`..=`{}`",
+                start.escape_debug(),
+                end.escape_debug()
+            ),
+            Expected::Eof => write!(f, "end of input"),
+            Expected::Regex(pattern) => write!(f, "text matching `{}`", pattern.escape_debug()),
+            Expected::ByteTag(bytes) => write!(f, "bytes `{}`", HexBytes(bytes)),
+            Expected::ByteIn(start, end) => write!(f, "a byte in {start:#04x}..={end:#04x}"),
+            Expected::Bytes(1) => write!(f, "a byte"),
+            Expected::Bytes(count) => write!(f, "{count} bytes"),
+            Expected::Label(label) => write!(f, "{label}"),
+        }
+    }
+}
+
+/// Location of a parsed piece of the input, returned by the spanned() modifiers.
+/// start and end are byte offsets into the parsed source, line and column
+/// (both starting at 1) point to the start of the span like in Position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the part of the source that is covered by this span
+    pub fn slice<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.start..self.end]
+    }
+}
+
+/// Text consumed by a slice() parser. All substrings of a parse share one
+/// copy of its source, so they are built without allocating. The copy is kept
+/// alive as long as any of them is.
+#[derive(Clone)]
+pub struct Substr {
+    pub(crate) source: Arc<str>,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+impl Substr {
+    pub fn as_str(&self) -> &str {
+        &self.source[self.start..self.end]
+    }
+}
+
+impl std::ops::Deref for Substr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Substr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::borrow::Borrow<str> for Substr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Text that was not sliced from a source, e.g. with decoded escape sequences
+impl From<String> for Substr {
+    fn from(text: String) -> Self {
+        Substr {
+            end: text.len(),
+            source: text.into(),
+            start: 0,
+        }
+    }
+}
+
+impl From<&str> for Substr {
+    fn from(text: &str) -> Self {
+        Substr {
+            source: text.into(),
+            start: 0,
+            end: text.len(),
+        }
+    }
+}
+
+impl PartialEq for Substr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Substr {}
+
+impl PartialEq<str> for Substr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Substr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for Substr {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Substr {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl std::hash::Hash for Substr {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl std::fmt::Debug for Substr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Substr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+/// Position in the parsed source. Binary sources have no lines, there the
+/// column is the byte offset (starting at 1) and a hex dump is shown instead.
 #[derive(Debug)]
 pub struct Position {
-    line: usize,
-    column: usize,
+    pub(crate) offset: usize,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+    utf16_column: usize,
     line_str: String,
+    /// Column of line_str that is marked, differs from column in hex dumps
+    caret: usize,
+    binary: bool,
 }
 
 impl Position {
+    /// Byte offset into the source, use it to map the position back into it
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Line number, starting at 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column in characters, starting at 1
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Column in UTF-16 code units, starting at 1, as used by LSP clients
+    pub fn utf16_column(&self) -> usize {
+        self.utf16_column
+    }
+
+    /// The line of the source that contains the position, without line break
+    pub fn line_str(&self) -> &str {
+        &self.line_str
+    }
+
     pub fn from_ptr(source: &str, pos_ptr: *const u8) -> Self {
         let source_ptr = source.as_ptr() as usize;
         let pos_ptr = pos_ptr as usize;
         assert!(pos_ptr >= source_ptr);
         let slice_len = pos_ptr - source_ptr;
 
-        let line = source[..slice_len].chars().filter(|&c| c == '\n').count() + 1;
+        let line = source[..slice_len].matches('\n').count() + 1;
         let line_start = source[..slice_len].rfind('\n').map_or(0, |pos| pos + 1);
-        let column = source[line_start..slice_len].chars().count() + 1;
+        Self::in_line(source, slice_len, line, line_start)
+    }
 
-        let line_len = source[line_start..]
-            .find('\r')
-            .or(source[line_start..].find('\n'))
-            .unwrap_or(source[line_start..].len());
-        let line_str = source[line_start..line_start + line_len].to_owned();
+    /// Build the position from the line it is in, see from_ptr and LineIndex
+    pub(crate) fn in_line(source: &str, offset: usize, line: usize, line_start: usize) -> Self {
+        let column = source[line_start..offset].chars().count() + 1;
+        let utf16_column = source[line_start..offset].encode_utf16().count() + 1;
+
+        let line_str = source[line_start..].split('\n').next().unwrap();
+        let line_str = line_str.strip_suffix('\r').unwrap_or(line_str).to_owned();
 
         Self {
+            offset,
             line,
             column,
+            utf16_column,
             line_str,
+            caret: column,
+            binary: false,
+        }
+    }
+
+    pub fn from_byte_ptr(source: &[u8], pos_ptr: *const u8) -> Self {
+        let source_ptr = source.as_ptr() as usize;
+        let pos_ptr = pos_ptr as usize;
+        assert!(pos_ptr >= source_ptr);
+        let offset = pos_ptr - source_ptr;
+
+        // Show the 16 byte row that contains the position
+        let row_start = offset - offset % 16;
+        let row_end = (row_start + 16).min(source.len());
+        let line_str = HexBytes(&source[row_start..row_end]).to_string();
+
+        Self {
+            offset,
+            line: 1,
+            column: offset + 1,
+            utf16_column: offset + 1,
+            line_str,
+            caret: 3 * (offset - row_start) + 1,
+            binary: true,
         }
     }
 }
 
 impl Display for Position {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.binary {
+            let offset = self.column - 1;
+            writeln!(f, " --> byte {offset:#x}")?;
+            let row = format!("{:#06x}", offset - offset % 16);
+            writeln!(f, "{row} | {}", self.line_str)?;
+            let pad = " ".repeat(row.len());
+            return write!(f, "{pad} | {}^", " ".repeat(self.caret - 1));
+        }
         let pad = " ".repeat(self.line.ilog10() as usize + 1);
         writeln!(f, " --> line {}, column {}", self.line, self.column)?;
         writeln!(f, "{pad} |")?;
         writeln!(f, "{} | {}", self.line, self.line_str)?;
-        write!(f, "{pad} | {}^", " ".repeat(self.column - 1))
+        write!(f, "{pad} | {}^", " ".repeat(self.caret - 1))
+    }
+}
+
+/// Byte offsets of all line starts of a source, used to build many positions
+/// in it without counting the lines from the start of the source every time
+pub struct LineIndex<'a> {
+    source: &'a str,
+    line_starts: LineStarts,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            line_starts: LineStarts::new(source.as_bytes()),
+        }
+    }
+
+    /// Position at the byte offset, which must be at a char boundary
+    pub fn position(&self, offset: usize) -> Position {
+        let (line, line_start) = self.line_starts.line(offset);
+        Position::in_line(self.source, offset, line, line_start)
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.starts.len()
+    }
+
+    /// Line number (starting at 1) of the byte offset and the offset the line starts at
+    pub(crate) fn line(&self, offset: usize) -> (usize, usize) {
+        self.line_starts.line(offset)
+    }
+}
+
+/// The line starts of a LineIndex without the source. Also used for the
+/// spans and error positions of a running parse, see state.rs.
+pub(crate) struct LineStarts {
+    starts: Vec<usize>,
+    /// Number of chars before every multiple of CHECKPOINT bytes, so columns
+    /// are counted from the nearest checkpoint instead of the line start
+    chars: Vec<usize>,
+    /// Number of bytes of the source that are indexed, see extend()
+    scanned: usize,
+}
+
+const CHECKPOINT: usize = 256;
+
+impl Default for LineStarts {
+    fn default() -> Self {
+        Self {
+            starts: vec![0],
+            chars: vec![0],
+            scanned: 0,
+        }
+    }
+}
+
+impl LineStarts {
+    pub(crate) fn new(source: &[u8]) -> Self {
+        let mut line_starts = Self::default();
+        line_starts.extend(source, source.len());
+        line_starts
+    }
+
+    /// Index the source up to at least the byte offset. A running parse only
+    /// indexes as much of the source as it needs, streams parse every record
+    /// in a large buffer.
+    pub(crate) fn extend(&mut self, source: &[u8], offset: usize) {
+        while self.scanned < offset {
+            let end = (self.scanned + CHECKPOINT).min(source.len());
+            let block = &source[self.scanned..end];
+            let newlines = block.iter().enumerate().filter(|&(_, &b)| b == b'\n');
+            let scanned = self.scanned;
+            self.starts.extend(newlines.map(|(i, _)| scanned + i + 1));
+            self.chars
+                .push(self.chars[self.chars.len() - 1] + count_chars(block));
+            self.scanned = end;
+        }
+    }
+
+    /// Line number (starting at 1) of the byte offset and the offset the line starts at
+    pub(crate) fn line(&self, offset: usize) -> (usize, usize) {
+        let line = self.starts.partition_point(|&start| start <= offset);
+        (line, self.starts[line - 1])
+    }
+
+    /// Column in chars (starting at 1) of the byte offset into the source
+    pub(crate) fn column(&self, source: &[u8], offset: usize) -> usize {
+        let (_, line_start) = self.line(offset);
+        self.chars_before(source, offset) - self.chars_before(source, line_start) + 1
+    }
+
+    fn chars_before(&self, source: &[u8], offset: usize) -> usize {
+        let checkpoint = offset / CHECKPOINT;
+        self.chars[checkpoint] + count_chars(&source[checkpoint * CHECKPOINT..offset])
+    }
+}
+
+/// Number of chars in utf-8 text, which are all bytes except continuation bytes
+fn count_chars(text: &[u8]) -> usize {
+    text.iter().filter(|&&b| b & 0xc0 != 0x80).count()
+}
+
+/// Formats bytes as space separated hex values
+struct HexBytes<'a>(&'a [u8]);
+
+impl Display for HexBytes<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,629 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use crate::input::Input;
+use crate::result::{Expected, LineStarts, Position, RawEzpcError, Span, Substr};
+use crate::trace::{Outcome, TraceEvent};
+
+// Parsers only see the remaining input, not the source it was sliced from.
+// Everything that needs to know about the whole source (or that must live for
+// exactly one parse_all / match_all call) is stored in a stack of frames here.
+// A stack is needed because a map / convert closure might start a nested parse.
+
+struct Frame {
+    source_ptr: *const u8,
+    source_len: usize,
+    /// Binary sources have no lines, see Position
+    text: bool,
+    /// Position of the source in the whole input, if it is only a chunk of it
+    base: Base,
+    /// Set if a matcher looked at the end of the source, see hit_end()
+    end_reached: bool,
+    /// Built lazily on span and position requests, as far as they need it
+    line_starts: Option<LineStarts>,
+    /// Copy of a text that contains the source at the offset, made on the
+    /// first substr() request. Shared by all substrings of the parse.
+    shared: Option<(Arc<str>, usize)>,
+    /// Furthest position at which a matcher failed and what it expected there
+    furthest: *const u8,
+    expected: Vec<Expected>,
+    /// Expectations are not recorded while this is > 0, see silent()
+    silent: usize,
+    /// Errors that parsing recovered from, see recover_with()
+    diagnostics: Vec<RawEzpcError>,
+    /// Results of memoized parsers, keyed by parser id and input position
+    memo: HashMap<(usize, *const u8), Box<dyn Any>>,
+    /// Wrapped parsers that are currently being applied, see lr_enter()
+    growing: Vec<Growing>,
+    /// Current recursion depth of wrapped parsers and wrapped matchers
+    parser_depth: usize,
+    matcher_depth: usize,
+    /// Set while a trace is recorded, see trace_start()
+    trace: Option<Recorder>,
+}
+
+/// Byte offset and number of lines of the input that came before the source,
+/// and the number of chars (and UTF-16 code units) before it in its first line
+#[derive(Default, Clone, Copy)]
+pub(crate) struct Base {
+    pub(crate) offset: usize,
+    pub(crate) lines: usize,
+    pub(crate) column: usize,
+    pub(crate) utf16_column: usize,
+}
+
+pub(crate) enum Wrapped {
+    Parser,
+    Matcher,
+}
+
+struct Growing {
+    id: usize,
+    pos: *const u8,
+    /// Result the parser returns when it is called left recursively
+    seed: Option<Box<dyn Any>>,
+    recursed: bool,
+}
+
+struct Recorder {
+    events: Vec<TraceEvent>,
+    /// Number of parsers that were entered but not exited yet
+    depth: usize,
+    /// Logical clock, counts all entries and exits
+    step: usize,
+}
+
+/// Number of traces that are being recorded on all threads. Checked before
+/// the frames are accessed, so parsers are not slowed down by tracing support.
+static TRACING: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static FRAMES: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the frame again, even if a user provided closure panics
+struct FrameGuard;
+
+impl Drop for FrameGuard {
+    fn drop(&mut self) {
+        let frame = FRAMES.with(|frames| frames.borrow_mut().pop());
+        if frame.is_some_and(|frame| frame.trace.is_some()) {
+            TRACING.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Execute f with a fresh frame for the given source. All parsing of source
+/// must happen inside of f, the frame is removed when f returns.
+pub(crate) fn run<I: Input + ?Sized, R>(source: &I, f: impl FnOnce() -> R) -> R {
+    run_chunk(source, Base::default(), f)
+}
+
+/// Like run(), but the source is a chunk of a larger input starting at base
+pub(crate) fn run_chunk<I: Input + ?Sized, R>(source: &I, base: Base, f: impl FnOnce() -> R) -> R {
+    FRAMES.with(|frames| {
+        frames.borrow_mut().push(Frame {
+            source_ptr: source.as_ptr(),
+            source_len: source.len(),
+            text: I::TEXT,
+            base,
+            end_reached: false,
+            line_starts: None,
+            shared: None,
+            furthest: std::ptr::null(),
+            expected: Vec::new(),
+            silent: 0,
+            diagnostics: Vec::new(),
+            memo: HashMap::new(),
+            growing: Vec::new(),
+            parser_depth: 0,
+            matcher_depth: 0,
+            trace: None,
+        })
+    });
+    let _guard = FrameGuard;
+    f()
+}
+
+/// Build the span of the input between the two pointers, which must both point
+/// into the source of the innermost running parse.
+pub(crate) fn span(start: *const u8, end: *const u8) -> Span {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        let frame = frames
+            .last_mut()
+            .expect("spans can only be created while parsing");
+        frame.span(start, end)
+    })
+}
+
+/// Printable position of ptr, which must point into the source of the
+/// innermost running parse. Uses the same line index as span(), so the
+/// source is not rescanned for every error.
+pub(crate) fn position(ptr: *const u8) -> Position {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        let frame = frames
+            .last_mut()
+            .expect("positions can only be created while parsing");
+        frame.position(ptr)
+    })
+}
+
+/// The text between the two pointers, which must both point into the text
+/// source of the innermost running parse, see Substr
+pub(crate) fn substr(start: *const u8, end: *const u8) -> Substr {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        let frame = frames
+            .last_mut()
+            .expect("substrings can only be created while parsing");
+        frame.substr(start, end)
+    })
+}
+
+/// Let the substrings of the innermost running parse share a copy of a larger
+/// text that contains its source at offset. Used by streams, whose records
+/// are parsed from the same buffer until more input is read.
+pub(crate) fn share_source(text: Arc<str>, offset: usize) {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            frame.shared = Some((text, offset));
+        }
+    })
+}
+
+/// The copy that the substrings of the innermost running parse share, if one
+/// was made, and the offset of the source in it
+pub(crate) fn shared_source() -> Option<(Arc<str>, usize)> {
+    FRAMES.with(|frames| frames.borrow().last()?.shared.clone())
+}
+
+/// Called by matchers whose result might change if the input was longer than
+/// the source, because they looked at its end. Used for streaming input.
+pub(crate) fn hit_end() {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            frame.end_reached = true;
+        }
+    })
+}
+
+pub(crate) fn end_reached() -> bool {
+    FRAMES.with(|frames| {
+        frames
+            .borrow()
+            .last()
+            .is_some_and(|frame| frame.end_reached)
+    })
+}
+
+/// Record that something was expected at pos, but the input did not match.
+/// Only the expectations at the furthest position of the parse are kept.
+pub(crate) fn expected(pos: *const u8, expected: Expected) {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            if frame.silent > 0 || pos < frame.furthest {
+                return;
+            }
+            if pos > frame.furthest {
+                frame.furthest = pos;
+                frame.expected.clear();
+            }
+            if !frame.expected.contains(&expected) {
+                frame.expected.push(expected);
+            }
+        }
+    })
+}
+
+/// Snapshot of the expectations, used to relabel what a parser expected
+pub(crate) struct Mark {
+    furthest: *const u8,
+    len: usize,
+}
+
+pub(crate) fn mark() -> Mark {
+    FRAMES.with(|frames| match frames.borrow().last() {
+        Some(frame) => Mark {
+            furthest: frame.furthest,
+            len: frame.expected.len(),
+        },
+        None => Mark {
+            furthest: std::ptr::null(),
+            len: 0,
+        },
+    })
+}
+
+/// If nothing got further than start since the mark was taken, replace all
+/// expectations recorded after the mark with the label.
+pub(crate) fn relabel(mark: Mark, start: *const u8, label: &'static str) {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            if frame.silent > 0 || frame.furthest != start {
+                return;
+            }
+            if mark.furthest == start {
+                frame.expected.truncate(mark.len);
+            } else {
+                frame.expected.clear();
+            }
+            if !frame.expected.contains(&Expected::Label(label)) {
+                frame.expected.push(Expected::Label(label));
+            }
+        }
+    })
+}
+
+/// Execute f without recording expectations. Used for matchers that succeed
+/// when their inner matcher fails, like reject().
+pub(crate) fn silent<R>(f: impl FnOnce() -> R) -> R {
+    let update = |update: fn(usize) -> usize| {
+        FRAMES.with(|frames| {
+            if let Some(frame) = frames.borrow_mut().last_mut() {
+                frame.silent = update(frame.silent);
+            }
+        })
+    };
+    update(|silent| silent + 1);
+    let result = f();
+    update(|silent| silent - 1);
+    result
+}
+
+/// Expectations recorded by a parser, stored with memoized results
+#[derive(Clone)]
+pub(crate) struct Expectations {
+    furthest: *const u8,
+    expected: Vec<Expected>,
+}
+
+/// Execute f and return the expectations it recorded, as if nothing had been
+/// recorded before and no matter if it runs silent. They are also recorded in
+/// the frame as usual, see replay().
+pub(crate) fn recording<R>(f: impl FnOnce() -> R) -> (R, Expectations) {
+    let none = Expectations {
+        furthest: std::ptr::null(),
+        expected: Vec::new(),
+    };
+    let (outer, silent) = swap_expectations(none, 0);
+    let result = f();
+    let (recorded, _) = swap_expectations(outer, silent);
+    replay(&recorded);
+    (result, recorded)
+}
+
+/// Record the expectations again, e.g. when a memoized result is reused
+pub(crate) fn replay(expectations: &Expectations) {
+    for &exp in &expectations.expected {
+        expected(expectations.furthest, exp);
+    }
+}
+
+fn swap_expectations(expectations: Expectations, silent: usize) -> (Expectations, usize) {
+    FRAMES.with(|frames| match frames.borrow_mut().last_mut() {
+        Some(frame) => {
+            let previous = Expectations {
+                furthest: std::mem::replace(&mut frame.furthest, expectations.furthest),
+                expected: std::mem::replace(&mut frame.expected, expectations.expected),
+            };
+            (previous, std::mem::replace(&mut frame.silent, silent))
+        }
+        None => (expectations, silent),
+    })
+}
+
+/// Returns the furthest position of a failed match and what was expected there
+pub(crate) fn furthest_expected() -> (*const u8, Vec<Expected>) {
+    FRAMES.with(|frames| match frames.borrow_mut().last_mut() {
+        Some(frame) => (frame.furthest, std::mem::take(&mut frame.expected)),
+        None => (std::ptr::null(), Vec::new()),
+    })
+}
+
+/// Record an error that parsing recovered from
+pub(crate) fn diagnostic(err: RawEzpcError) {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            frame.diagnostics.push(err);
+        }
+    })
+}
+
+pub(crate) fn take_diagnostics() -> Vec<RawEzpcError> {
+    FRAMES.with(|frames| match frames.borrow_mut().last_mut() {
+        Some(frame) => std::mem::take(&mut frame.diagnostics),
+        None => Vec::new(),
+    })
+}
+
+/// Returns the memoized value stored for the key, if there is any
+pub(crate) fn memo_get<T: Clone + 'static>(key: (usize, *const u8)) -> Option<T> {
+    FRAMES.with(|frames| {
+        let frames = frames.borrow();
+        // Zero sized parsers of different types can share an address,
+        // a value of the wrong type is treated like a missing one
+        frames.last()?.memo.get(&key)?.downcast_ref().cloned()
+    })
+}
+
+pub(crate) fn memo_insert<T: 'static>(key: (usize, *const u8), value: T) {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            frame.memo.insert(key, Box::new(value));
+        }
+    })
+}
+
+/// Increase the recursion depth of wrapped parsers or matchers. Returns false
+/// without changing the depth if it already exceeds max_depth.
+pub(crate) fn depth_enter(wrapped: Wrapped, max_depth: usize) -> bool {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        let Some(frame) = frames.last_mut() else {
+            return true;
+        };
+        let depth = match wrapped {
+            Wrapped::Parser => &mut frame.parser_depth,
+            Wrapped::Matcher => &mut frame.matcher_depth,
+        };
+        if *depth > max_depth {
+            false
+        } else {
+            *depth += 1;
+            true
+        }
+    })
+}
+
+pub(crate) fn depth_exit(wrapped: Wrapped) {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            match wrapped {
+                Wrapped::Parser => frame.parser_depth -= 1,
+                Wrapped::Matcher => frame.matcher_depth -= 1,
+            }
+        }
+    })
+}
+
+/// Start recording a trace in the innermost frame, see trace_take()
+pub(crate) fn trace_start() {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            if frame.trace.is_none() {
+                TRACING.fetch_add(1, Ordering::Relaxed);
+            }
+            frame.trace = Some(Recorder {
+                events: Vec::new(),
+                depth: 0,
+                step: 0,
+            });
+        }
+    })
+}
+
+/// Stop recording and return the events in the order the parsers were entered
+pub(crate) fn trace_take() -> Vec<TraceEvent> {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        match frames.last_mut().and_then(|frame| frame.trace.take()) {
+            Some(recorder) => {
+                TRACING.fetch_sub(1, Ordering::Relaxed);
+                recorder.events
+            }
+            None => Vec::new(),
+        }
+    })
+}
+
+/// Returns if a trace might be recorded, cheap enough to call in every apply
+#[inline]
+pub(crate) fn tracing() -> bool {
+    TRACING.load(Ordering::Relaxed) > 0
+}
+
+/// Record that a parser was applied at pos. Returns the index of its event,
+/// which must be passed to trace_exit(), or None if no trace is recorded.
+pub(crate) fn trace_enter(name: &'static str, pos: *const u8) -> Option<usize> {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        let frame = frames.last_mut()?;
+        let start = frame.base.offset + frame.offset(pos);
+        let recorder = frame.trace.as_mut()?;
+        recorder.events.push(TraceEvent {
+            name,
+            depth: recorder.depth,
+            start,
+            end: start,
+            outcome: Outcome::Ok,
+            enter_step: recorder.step,
+            exit_step: recorder.step,
+        });
+        recorder.depth += 1;
+        recorder.step += 1;
+        Some(recorder.events.len() - 1)
+    })
+}
+
+/// Record the outcome of a parser, end is where it stopped or failed
+pub(crate) fn trace_exit(index: usize, outcome: Outcome, end: *const u8) {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        let Some(frame) = frames.last_mut() else {
+            return;
+        };
+        let end = frame.base.offset + frame.offset(end);
+        if let Some(recorder) = frame.trace.as_mut() {
+            let event = &mut recorder.events[index];
+            event.end = end;
+            event.outcome = outcome;
+            event.exit_step = recorder.step;
+            recorder.depth -= 1;
+            recorder.step += 1;
+        }
+    })
+}
+
+// Left recursion is resolved by growing a seed (Warth et al. 2008): if a
+// wrapped parser is applied again at the same position while it is still in
+// progress, the recursive call returns the seed instead, which is a mismatch
+// at first. If that happened, the parser is re-applied with the last result
+// as seed as long as it keeps consuming more input.
+
+/// Called by wrapped parsers before they are applied. Returns Some(seed) if
+/// the parser with this id is already in progress at pos, otherwise it is
+/// marked as in progress until lr_exit() is called.
+pub(crate) fn lr_enter<T: Clone + 'static>(id: usize, pos: *const u8) -> Option<Option<T>> {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        let frame = frames.last_mut()?;
+        // Nested parsers never start before their parents
+        for growing in frame.growing.iter_mut().rev() {
+            if growing.pos < pos {
+                break;
+            }
+            if growing.id == id {
+                growing.recursed = true;
+                let seed = growing.seed.as_ref();
+                return Some(seed.map(|seed| seed.downcast_ref::<T>().unwrap().clone()));
+            }
+        }
+        frame.growing.push(Growing {
+            id,
+            pos,
+            seed: None,
+            recursed: false,
+        });
+        None
+    })
+}
+
+/// Returns if the innermost parser in progress was called left recursively
+pub(crate) fn lr_recursed() -> bool {
+    FRAMES.with(|frames| {
+        let frames = frames.borrow();
+        let growing = frames.last().and_then(|frame| frame.growing.last());
+        growing.is_some_and(|growing| growing.recursed)
+    })
+}
+
+pub(crate) fn lr_set_seed<T: 'static>(seed: T) {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        if let Some(growing) = frames.last_mut().and_then(|frame| frame.growing.last_mut()) {
+            growing.seed = Some(Box::new(seed));
+        }
+    })
+}
+
+pub(crate) fn lr_exit() {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            frame.growing.pop();
+        }
+    })
+}
+
+/// Returns if a result at pos might depend on a left recursion seed. Those
+/// results are only temporary and must not be memoized.
+pub(crate) fn lr_involved(pos: *const u8) -> bool {
+    FRAMES.with(|frames| {
+        let frames = frames.borrow();
+        let Some(frame) = frames.last() else {
+            return false;
+        };
+        frame
+            .growing
+            .iter()
+            .rev()
+            .take_while(|growing| growing.pos >= pos)
+            .any(|growing| growing.recursed)
+    })
+}
+
+impl Frame {
+    fn source(&self) -> &[u8] {
+        // SAFETY: the frame is created from a &str or &[u8] in run() and removed
+        // by the FrameGuard before run() returns, so the source outlives the frame.
+        unsafe { std::slice::from_raw_parts(self.source_ptr, self.source_len) }
+    }
+
+    fn offset(&self, ptr: *const u8) -> usize {
+        let offset = (ptr as usize).wrapping_sub(self.source_ptr as usize);
+        assert!(offset <= self.source_len);
+        offset
+    }
+
+    /// The line index of a text source up to offset and the source itself
+    fn line_starts(&mut self, offset: usize) -> (&LineStarts, &[u8]) {
+        let mut line_starts = self.line_starts.take().unwrap_or_default();
+        line_starts.extend(self.source(), offset);
+        self.line_starts = Some(line_starts);
+        (self.line_starts.as_ref().unwrap(), self.source())
+    }
+
+    fn position(&mut self, ptr: *const u8) -> Position {
+        if !self.text {
+            return Position::from_byte_ptr(self.source(), ptr);
+        }
+        let offset = self.offset(ptr);
+        let (line_starts, source) = self.line_starts(offset);
+        let (line, line_start) = line_starts.line(offset);
+        // SAFETY: text frames are created from a &str in run()
+        let source = unsafe { std::str::from_utf8_unchecked(source) };
+        Position::in_line(source, offset, line, line_start)
+    }
+
+    fn substr(&mut self, start: *const u8, end: *const u8) -> Substr {
+        let start = self.offset(start);
+        let end = self.offset(end);
+        if self.shared.is_none() {
+            // SAFETY: substrings are only built by parsers of text
+            let source = unsafe { std::str::from_utf8_unchecked(self.source()) };
+            self.shared = Some((source.into(), 0));
+        }
+        let (text, offset) = self.shared.as_ref().unwrap();
+        Substr {
+            source: text.clone(),
+            start: offset + start,
+            end: offset + end,
+        }
+    }
+
+    fn span(&mut self, start: *const u8, end: *const u8) -> Span {
+        let start = self.offset(start);
+        let end = self.offset(end);
+
+        if !self.text {
+            return Span {
+                start: self.base.offset + start,
+                end: self.base.offset + end,
+                line: self.base.lines + 1,
+                column: start + 1,
+            };
+        }
+
+        let (line_starts, source) = self.line_starts(start);
+        let (line, _) = line_starts.line(start);
+        let mut column = line_starts.column(source, start);
+        if line == 1 {
+            column += self.base.column;
+        }
+
+        Span {
+            start: self.base.offset + start,
+            end: self.base.offset + end,
+            line: self.base.lines + line,
+            column,
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::input::Input;
+use crate::json::string;
+use crate::result::{MatchResult, ParseResult, RawEzpcError};
+use crate::state;
+
+/// Every parser entry and exit of a parse_all_traced / match_all_traced call.
+/// Named parsers (see named()) are recorded, as well as repetitions, lists,
+/// wrapped parsers, seq(), one_of_tags(), the `+` and `|` of two parsers or
+/// two matchers and the `|` of Parts. A parser and a matcher joined with `+`
+/// only record their parts.
+/// Display prints one line per parser, indented by nesting depth:
+/// `object 0..12 ok`
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    events: Vec<TraceEvent>,
+}
+
+/// A parser that was applied, in the order the parsers were entered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEvent {
+    pub name: &'static str,
+    /// Number of parsers it is nested in
+    pub depth: usize,
+    /// Byte offset at which the parser was applied
+    pub start: usize,
+    /// Byte offset at which the parser stopped, or where it failed
+    pub end: usize,
+    pub outcome: Outcome,
+    pub(crate) enter_step: usize,
+    pub(crate) exit_step: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Ok,
+    Mismatch,
+    /// Fatal errors, including exceeded recursion limits
+    Fatal,
+}
+
+impl Trace {
+    pub(crate) fn new(events: Vec<TraceEvent>) -> Self {
+        Self { events }
+    }
+
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// Serialize in the Chrome trace event format, which can be opened with
+    /// chrome://tracing or ui.perfetto.dev. Timestamps count the parser entries
+    /// and exits instead of measuring time, so the trace is reproducible.
+    pub fn to_chrome_json(&self) -> String {
+        let events: Vec<_> = self
+            .events
+            .iter()
+            .map(|event| {
+                format!(
+                    concat!(
+                        r#"{{"name":{},"ph":"X","ts":{},"dur":{},"pid":1,"tid":1,"#,
+                        r#""args":{{"start":{},"end":{},"outcome":"{}"}}}}"#
+                    ),
+                    string(event.name),
+                    event.enter_step,
+                    event.exit_step - event.enter_step,
+                    event.start,
+                    event.end,
+                    event.outcome
+                )
+            })
+            .collect();
+        format!(r#"{{"traceEvents":[{}]}}"#, events.join(","))
+    }
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for event in &self.events {
+            writeln!(
+                f,
+                "{}{} {}..{} {}",
+                "  ".repeat(event.depth),
+                event.name,
+                event.start,
+                event.end,
+                event.outcome
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            Outcome::Ok => "ok",
+            Outcome::Mismatch => "mismatch",
+            Outcome::Fatal => "fatal",
+        })
+    }
+}
+
+/// Apply the parser and record it in the trace, if one is recorded
+#[inline]
+pub(crate) fn parse<'a, I: Input + ?Sized, O>(
+    name: &'static str,
+    input: &'a I,
+    apply: impl FnOnce() -> ParseResult<'a, O, I>,
+) -> ParseResult<'a, O, I> {
+    if !state::tracing() {
+        return apply();
+    }
+    let index = state::trace_enter(name, input.as_ptr());
+    let result = apply();
+    if let Some(index) = index {
+        let (outcome, end) = match &result {
+            Ok((_, rest)) => (Outcome::Ok, rest.as_ptr()),
+            Err(err) => failure(err),
+        };
+        state::trace_exit(index, outcome, end);
+    }
+    result
+}
+
+/// Like parse(), but for matchers
+#[inline]
+pub(crate) fn matches<'a, I: Input + ?Sized>(
+    name: &'static str,
+    input: &'a I,
+    apply: impl FnOnce() -> MatchResult<'a, I>,
+) -> MatchResult<'a, I> {
+    if !state::tracing() {
+        return apply();
+    }
+    let index = state::trace_enter(name, input.as_ptr());
+    let result = apply();
+    if let Some(index) = index {
+        let (outcome, end) = match &result {
+            Ok(rest) => (Outcome::Ok, rest.as_ptr()),
+            Err(err) => failure(err),
+        };
+        state::trace_exit(index, outcome, end);
+    }
+    result
+}
+
+fn failure(err: &RawEzpcError) -> (Outcome, *const u8) {
+    match err {
+        RawEzpcError::Mismatch { pos } => (Outcome::Mismatch, *pos),
+        RawEzpcError::Fatal { pos, .. } | RawEzpcError::Recursion { pos, .. } => {
+            (Outcome::Fatal, *pos)
+        }
+    }
+}
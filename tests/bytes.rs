@@ -0,0 +1,93 @@
+use ezpc::bytes::{be, byte_in, le, length_prefixed, tag, take};
+use ezpc::*;
+
+#[derive(Debug, PartialEq)]
+struct Chunk {
+    id: u32,
+    data: Vec<u8>,
+}
+
+fn file() -> Parser<impl Parse<[u8], Output = (u16, Vec<Chunk>)>> {
+    let chunk = le::<u32>() + length_prefixed(be::<u16>());
+    let chunk = chunk.map(|(id, data)| Chunk { id, data });
+    tag(b"EZ\x00") + be::<u16>() + take(2) + chunk.repeat(0..) + eof()
+}
+
+#[test]
+fn parse_binary_file() {
+    let source = b"EZ\x00\x01\x02..\x07\x00\x00\x00\x00\x03abc\xff\x00\x00\x00\x00\x00";
+    let (version, chunks) = file().parse_bytes(source).unwrap();
+    assert_eq!(version, 0x0102);
+    assert_eq!(
+        chunks,
+        [
+            Chunk {
+                id: 7,
+                data: b"abc".to_vec()
+            },
+            Chunk {
+                id: 255,
+                data: Vec::new()
+            }
+        ]
+    );
+
+    let digits = byte_in(b'0'..=b'9').repeat(1..).map(|bytes| bytes.len());
+    assert_eq!(digits.parse_bytes(b"0189").unwrap(), 4);
+    assert!(byte_in(..b'0').match_bytes(b"0").is_err());
+    assert_eq!(
+        (le::<i16>() + be::<f32>())
+            .parse_bytes(b"\xfe\xff\x3f\x80\x00\x00")
+            .unwrap(),
+        (-2, 1.0)
+    );
+}
+
+#[test]
+fn binary_errors() {
+    let err = file().parse_bytes(b"EZ\x01").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parsing stopped before reaching end of input, expected bytes `45 5a 00`:\n \
+         --> byte 0x0\n\
+         0x0000 | 45 5a 01\n       \
+         | ^"
+    );
+
+    // The payload is one byte short
+    let source = b"EZ\x00\x00\x01..\x07\x00\x00\x00\x00\x04abc";
+    let err = file().parse_bytes(source).unwrap_err();
+    let EzpcError::PartialParse { expected, .. } = &err else {
+        panic!("expected a partial parse, got {err}")
+    };
+    assert_eq!(expected, &[Expected::Bytes(4)]);
+    assert!(err.to_string().contains("--> byte 0xd\n"));
+    assert!(err
+        .to_string()
+        .ends_with("\n       |                                        ^"));
+}
+
+#[test]
+fn wrapped_binary_parsers() {
+    // A list of items, each either a byte or a nested list
+    fn item() -> Parser<impl Parse<[u8], Output = usize>> {
+        let list = tag(b"[")
+            + item
+                .wrap(10)
+                .repeat(0..)
+                .map(|items| items.into_iter().sum())
+            + tag(b"]");
+        list | byte_in(b'0'..=b'9').map(|_| 1)
+    }
+    assert_eq!(item().parse_bytes(b"[1[23][[4]]]").unwrap(), 4);
+
+    // The caret lines up with offsets that need more than four hex digits
+    let mut source = vec![b'['; 0x12345];
+    source.push(b'x');
+    let err = (take(0x12345) + tag(b"]"))
+        .match_bytes(&source)
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("\n0x12340 | 5b 5b 5b 5b 5b 78\n        |                ^"));
+}
@@ -0,0 +1,76 @@
+use ezpc::classes::*;
+use ezpc::*;
+
+#[test]
+fn ascii_classes() {
+    assert!(digit(10).repeat(1..).match_all("0123456789").is_ok());
+    assert!(digit(8).match_all("8").is_err());
+    assert!(digit(16).repeat(1..).match_all("09afAF").is_ok());
+    assert!(hex_digit().match_all("g").is_err());
+    assert!(alpha().match_all("é").is_err());
+    assert!((alnum() + ws() + alnum()).match_all("a\t1").is_ok());
+    assert!((tag("a") + newline() + tag("b") + newline())
+        .match_all("a\r\nb\n")
+        .is_ok());
+
+    let err = (tag("x") + newline()).match_all("x\r").unwrap_err();
+    assert_eq!(err.expected(), &[Expected::Label("a newline")]);
+
+    let ident = char_range('a'..='z')
+        .union(char_range('A'..='Z'))
+        .union(alpha());
+    let err = ident.match_all("_").unwrap_err();
+    assert_eq!(
+        err.expected(),
+        &[
+            Expected::CharIn('a', 'z'),
+            Expected::CharIn('A', 'Z'),
+            Expected::Label("a letter")
+        ]
+    );
+    let word = ident.union(char_range('_'..='_')).union(digit(10));
+    assert_eq!(
+        word.repeat(1..).grammar("word").to_string(),
+        "word ::= [0-9A-Z_a-z]+\n"
+    );
+}
+
+#[test]
+fn unicode_classes() {
+    assert!(general_category("Lu").match_all("Ä").is_ok());
+    assert!(general_category("Lu").match_all("ä").is_err());
+    assert!(general_category("L").repeat(1..).match_all("aÄ漢").is_ok());
+    assert!(general_category("Nd").match_all("٣").is_ok());
+    assert!(general_category("Zs").match_all("\u{3000}").is_ok());
+    assert!(general_category("Cn").match_all("\u{378}").is_ok());
+    assert!(general_category("C").match_all("\u{0}").is_ok());
+    assert_eq!(
+        general_category("Lu").grammar("upper").to_string(),
+        "upper ::= ? Lu ?\n"
+    );
+
+    let ident = xid_start() + xid_continue().repeat(0..);
+    assert!(ident.match_all("größe_2").is_ok());
+    assert!(ident.match_all("_x").is_err());
+    assert!(ident.match_all("x-y").is_err());
+
+    // The tables are generated for the Unicode version of std: letters are
+    // alphabetic, and alphabetic characters are assigned. Planes above 3
+    // have no letters.
+    let letter = general_category("L").union(general_category("Nl"));
+    let unassigned = general_category("Cn");
+    for c in (0..0x40000).filter_map(char::from_u32) {
+        let text = c.to_string();
+        if letter.match_all(&text).is_ok() {
+            assert!(c.is_alphabetic(), "{c:?} is a letter");
+        } else if c.is_alphabetic() {
+            assert!(unassigned.match_all(&text).is_err(), "{c:?} is assigned");
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "unknown general category `Lx`")]
+fn unknown_category() {
+    general_category("Lx");
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "derive")]
+
+use ezpc::*;
+
+fn number() -> Parser<impl Parse<Output = i64>> {
+    (tag("-").opt() + one_of("0123456789").repeat(1..)).convert(|s| s.parse(), "Invalid number:")
+}
+
+#[derive(Parse, Debug, Clone, PartialEq)]
+enum Expr {
+    Number(#[ezpc(with = "number")] i64),
+    #[ezpc(tag = "(", after = ")")]
+    Call {
+        #[ezpc(with = "name")]
+        name: String,
+        #[ezpc(tag = " ", sep = " ")]
+        args: Vec<Expr>,
+    },
+    Quote(#[ezpc(tag = "'")] Box<Expr>),
+    #[ezpc(tag = "nil")]
+    Nil,
+}
+
+fn name() -> Parser<impl Parse<Output = String>> {
+    is_a(char::is_alphabetic).repeat(1..).map(|s| s.to_owned())
+}
+
+#[test]
+fn derive_enum() {
+    assert_eq!(Expr::parser().parse_all("-12").unwrap(), Expr::Number(-12));
+    assert_eq!(
+        Expr::parser().parse_all("(add 1 '(neg 2) nil)").unwrap(),
+        Expr::Call {
+            name: "add".to_owned(),
+            args: vec![
+                Expr::Number(1),
+                Expr::Quote(Box::new(Expr::Call {
+                    name: "neg".to_owned(),
+                    args: vec![Expr::Number(2)],
+                })),
+                Expr::Nil,
+            ],
+        }
+    );
+    let err = Expr::parser().parse_all("(add 1 )").unwrap_err();
+    assert_eq!(err.message(), "Expected args after \" \"");
+}
+
+#[derive(Parse, Debug, PartialEq)]
+struct Time(
+    #[ezpc(with = "two_digits")] u8,
+    #[ezpc(tag = ":", with = "two_digits")] u8,
+    #[ezpc(tag = ":", with = "two_digits")] Option<u8>,
+);
+
+fn two_digits() -> Parser<impl Parse<Output = u8>> {
+    one_of("0123456789")
+        .repeat(2)
+        .convert(|s| s.parse(), "Invalid number:")
+}
+
+#[derive(Parse, Debug, PartialEq)]
+#[ezpc(after = ";")]
+struct Schedule {
+    #[ezpc(tag = "at ")]
+    times: Vec<Time>,
+}
+
+#[test]
+fn derive_struct() {
+    assert_eq!(
+        Time::parser().parse_all("12:30").unwrap(),
+        Time(12, 30, None)
+    );
+    assert_eq!(
+        Schedule::parser()
+            .parse_all("at 12:30:5908:00:00;")
+            .unwrap(),
+        Schedule {
+            times: vec![Time(12, 30, Some(59)), Time(8, 0, Some(0))]
+        }
+    );
+    assert!(Schedule::parser().parse_all("at ;").is_ok());
+    assert!(Schedule::parser().parse_all("at 12;").is_err());
+}
@@ -0,0 +1,96 @@
+use ezpc::*;
+
+fn string() -> Matcher<impl Match> {
+    tag("\"") + none_of("\"").repeat(0..) + tag("\"").fatal("Unclosed string:")
+}
+
+fn assignment() -> Matcher<impl Match> {
+    is_a(char::is_alphabetic).repeat(1..) + tag(" = ") + string() + tag("\n")
+}
+
+#[test]
+fn render_error_with_context() {
+    let source = "a = \"x\"\nb = \"y\"\nc = 1\nd = \"z\"\n";
+    let err = assignment().repeat(0..).match_all(source).unwrap_err();
+
+    assert_eq!(
+        Diagnostic::new(&err, source).to_string(),
+        "error: Parsing stopped before reaching end of input, expected `\\\"`\n \
+         --> line 3, column 5\n  \
+         |\n\
+         2 | b = \"y\"\n\
+         3 | c = 1\n  \
+         |     ^\n\
+         4 | d = \"z\"\n  \
+         |"
+    );
+    assert_eq!(
+        Diagnostic::new(&err, source)
+            .context(0)
+            .color(true)
+            .to_string(),
+        "\x1b[1;31merror\x1b[0m\x1b[1m: Parsing stopped before reaching end of input, \
+         expected `\\\"`\x1b[0m\n \
+         \x1b[1;34m-->\x1b[0m line 3, column 5\n  \
+         \x1b[1;34m|\x1b[0m\n\
+         \x1b[1;34m3 |\x1b[0m c = 1\n  \
+         \x1b[1;34m|\x1b[0m     \x1b[1;31m^\x1b[0m\n  \
+         \x1b[1;34m|\x1b[0m"
+    );
+}
+
+#[test]
+fn render_spans_and_labels() {
+    let source = "x = \"abc\n\n\ny = 1\n";
+    let err = assignment().repeat(0..).match_all(source).unwrap_err();
+    assert!(matches!(err, EzpcError::Fatal { .. }));
+
+    let opened = Span {
+        start: 4,
+        end: 5,
+        line: 1,
+        column: 5,
+    };
+    assert_eq!(
+        Diagnostic::new(&err, source)
+            .label(opened, "string opened here")
+            .context(0)
+            .to_string(),
+        "error: Unclosed string\n \
+         --> line 5, column 1\n  \
+         |\n\
+         1 | x = \"abc\n  \
+         |     - string opened here\n\
+         ...\n\
+         5 | \n  \
+         | ^\n  \
+         |"
+    );
+
+    let multiline = Span {
+        start: 4,
+        end: 13,
+        line: 1,
+        column: 5,
+    };
+    let rendered = Diagnostic::new(&err, source)
+        .span(multiline)
+        .context(0)
+        .to_string();
+    assert_eq!(
+        rendered.lines().skip(1).collect::<Vec<_>>(),
+        [
+            " --> line 1, column 5",
+            "  |",
+            "1 | x = \"abc",
+            "  |     ^^^^",
+            "2 | ",
+            "  | ^",
+            "3 | ",
+            "  | ^",
+            "4 | y = 1",
+            "  | ^^",
+            "  |"
+        ]
+    );
+}
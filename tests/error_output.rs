@@ -0,0 +1,44 @@
+use ezpc::*;
+
+fn key_value() -> Matcher<impl Match> {
+    let key = is_a(char::is_alphanumeric).repeat(1..);
+    let string = tag("\"") + none_of("\"\n").repeat(0..) + tag("\"").fatal("Unclosed \"string\":");
+    (key + tag(" = ") + string + tag("\n")).repeat(0..)
+}
+
+#[test]
+fn errors_as_json() {
+    let err = key_value().match_all("a = \"x\"\nb = \"ä\n").unwrap_err();
+    assert_eq!(
+        err.to_json("dir\\a.conf"),
+        r#"{"file":"dir\\a.conf","kind":"fatal","message":"Unclosed \"string\"","offset":15,"line":2,"column":7}"#
+    );
+
+    let err = key_value().match_all("a = \"x\"\nb: 1\n").unwrap_err();
+    assert_eq!(
+        err.to_json("b.conf"),
+        r#"{"file":"b.conf","kind":"partial","message":"Parsing stopped before reaching end of input, expected ` = `","offset":9,"line":2,"column":2,"expected":["` = `"]}"#
+    );
+}
+
+#[test]
+fn errors_as_sarif() {
+    let err = key_value().match_all("a = \"x\n").unwrap_err();
+    let sarif = to_sarif("conflint", [("a.conf", &err)]);
+    assert_eq!(
+        sarif,
+        concat!(
+            r#"{"version":"2.1.0","$schema":"https://json.schemastore.org/sarif-2.1.0.json","#,
+            r#""runs":[{"tool":{"driver":{"name":"conflint","rules":"#,
+            r#"[{"id":"partial"},{"id":"fatal"},{"id":"recursion"}]}},"#,
+            r#""columnKind":"unicodeCodePoints","results":[{"ruleId":"fatal","level":"error","#,
+            r#""message":{"text":"Unclosed \"string\""},"locations":[{"physicalLocation":"#,
+            r#"{"artifactLocation":{"uri":"a.conf"},"#,
+            r#""region":{"startLine":1,"startColumn":7,"byteOffset":6}}}]}]}]}"#
+        )
+    );
+    assert!(to_sarif("conflint", []).ends_with(r#""results":[]}]}"#));
+
+    let sarif = to_sarif("conflint", [("conf/my #1 ö.conf", &err)]);
+    assert!(sarif.contains(r#"{"uri":"conf/my%20%231%20%C3%B6.conf"}"#));
+}
@@ -0,0 +1,59 @@
+use ezpc::*;
+
+fn expected_of(err: EzpcError) -> Vec<String> {
+    match err {
+        EzpcError::PartialParse { expected, .. } => {
+            expected.iter().map(|exp| exp.to_string()).collect()
+        }
+        _ => panic!("expected a partial parse, got {err}"),
+    }
+}
+
+fn array() -> Parser<impl Parse<Output = Vec<u32>>> {
+    let number = one_of("0123456789")
+        .repeat(1..)
+        .label("number")
+        .map(|s| s.parse().unwrap());
+    tag("[") + list(number, tag(","), "Expected a number:") + tag("]")
+}
+
+#[test]
+fn expected_set_is_merged_across_choice() {
+    let err = array().parse_all("[1,2;").unwrap_err();
+    assert_eq!(
+        expected_of(err),
+        ["a character of `0123456789`", "`,`", "`]`"]
+    );
+
+    let err = (tag("a").val(1) | tag("b").val(2) | tag("c").val(3))
+        .parse_all("d")
+        .unwrap_err();
+    assert_eq!(
+        err.to_string().lines().next().unwrap(),
+        "Parsing stopped before reaching end of input, expected one of `a`, `b`, `c`:"
+    );
+}
+
+#[test]
+fn expected_set_contains_labels_and_eof() {
+    let err = array().parse_all("[x]").unwrap_err();
+    assert_eq!(expected_of(err), ["number"]);
+
+    let err = array().parse_all("[1]]").unwrap_err();
+    assert_eq!(expected_of(err), ["end of input"]);
+
+    let err = (one_of("ab") + none_of("\n"))
+        .match_all("ab\n")
+        .unwrap_err();
+    assert_eq!(expected_of(err), ["end of input"]);
+    let err = (one_of("ab") + none_of("\n")).match_all("a\n").unwrap_err();
+    assert_eq!(expected_of(err), ["a character not in `\\n`"]);
+}
+
+#[test]
+fn rejected_matchers_are_not_expected() {
+    let int = (tag("0") + one_of("0123456789").reject("Leading zero:"))
+        | (one_of("123456789") + one_of("0123456789").repeat(0..));
+    let err = int.match_all("0x").unwrap_err();
+    assert_eq!(expected_of(err), ["end of input"]);
+}
@@ -0,0 +1,78 @@
+use std::error::Error;
+use std::num::ParseIntError;
+
+use ezpc::*;
+
+fn first_line(err: &EzpcError) -> String {
+    err.to_string().lines().next().unwrap().to_owned()
+}
+
+#[test]
+fn messages_built_from_input() {
+    let esc = tag("\\")
+        + one_of("nt").fatal_with(|rest: &str| match rest.chars().next() {
+            Some(c) => format!("Illegal escape `\\{c}`:"),
+            None => "Unfinished escape:".to_owned(),
+        });
+    let err = esc.match_all("\\q").unwrap_err();
+    assert_eq!(first_line(&err), "Illegal escape `\\q`:");
+    assert!(err.to_string().ends_with("\n  |  ^"));
+    let err = esc.match_all("\\").unwrap_err();
+    assert_eq!(first_line(&err), "Unfinished escape:");
+
+    let keyword = (tag("if") | tag("else")).reject_with(|kw: &str| format!("`{kw}` is reserved:"));
+    let ident = keyword + is_a(char::is_alphabetic).repeat(1..);
+    assert!(ident.match_all("foo").is_ok());
+    let err = ident.match_all("else").unwrap_err();
+    assert_eq!(first_line(&err), "`else` is reserved:");
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn convert_forwards_source() {
+    let byte = || {
+        one_of("0123456789")
+            .repeat(1..)
+            .convert_source(|s| s.parse::<u8>(), "Not a byte:")
+    };
+    assert_eq!(byte().parse_all("255").unwrap(), 255);
+
+    let err = byte().parse_all("256").unwrap_err();
+    assert_eq!(first_line(&err), "Not a byte:");
+    let source = err.source().expect("the conversion error");
+    let source = source.downcast_ref::<ParseIntError>().unwrap();
+    assert_eq!(source, &"256".parse::<u8>().unwrap_err());
+
+    let sum = (byte() + tag("+") + byte()).convert_source(
+        |(a, b)| {
+            a.checked_add(b)
+                .ok_or_else(|| "256".parse::<u8>().unwrap_err())
+        },
+        "Sum is not a byte:",
+    );
+    let err = sum.parse_all("200+100").unwrap_err();
+    assert_eq!(first_line(&err), "Sum is not a byte:");
+    assert!(err.source().unwrap().is::<ParseIntError>());
+}
+
+/// Error that can't be sent to another thread, so it can't be a source()
+#[derive(Debug)]
+struct LocalError(std::rc::Rc<str>);
+
+impl std::fmt::Display for LocalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for LocalError {}
+
+#[test]
+fn convert_without_source() {
+    let word = is_a(char::is_alphabetic)
+        .repeat(1..)
+        .convert(|s| Err::<(), _>(LocalError(s.into())), "Unknown word:");
+    let err = word.parse_all("foo").unwrap_err();
+    assert_eq!(first_line(&err), "Unknown word:");
+    assert!(err.source().is_none());
+}
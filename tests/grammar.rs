@@ -0,0 +1,70 @@
+use ezpc::*;
+
+fn number() -> Parser<impl Parse<Output = f64>> {
+    (tag("-").opt()
+        + one_of("0123456789").repeat(1..)
+        + (tag(".") + one_of("0123456789").repeat(1..)).opt())
+    .convert(|s| s.parse::<f64>(), "Invalid number:")
+}
+
+fn value() -> Parser<impl Parse<Output = Vec<f64>>> {
+    let array = tag("[") + list(value.wrap(10), tag(","), "Expected a value:") + tag("]");
+    number().map(|n| vec![n]) | array.map(|values| values.concat())
+}
+
+#[test]
+fn grammar_as_ebnf() {
+    let grammar = (value.wrap(10) + eof()).grammar("document");
+    assert_eq!(
+        grammar.to_ebnf(),
+        "document ::= value ? end of input ?\n\
+         value    ::= \"-\"? [0123456789]+ (\".\" [0123456789]+)? | \"[\" value (\",\" value)* \"]\"\n"
+    );
+    assert_eq!(grammar.iter().count(), 2);
+    assert!(matches!(grammar.get("value"), Some(GrammarNode::Choice(_))));
+
+    let keyword = tag("if") | tag("else");
+    let ident = keyword.reject("Keyword:") + is_a(char::is_alphabetic).repeat(1..4);
+    let escaped = tag("\\") + none_of("]-\n") + tag("\t\"x").repeat(2);
+    assert_eq!(
+        (ident + escaped).grammar("token").to_string(),
+        "token ::= !(\"if\" | \"else\") ? is_alphabetic ?{1,4} #x5C [^#x5D#x2D#xA] (#x9 '\"x'){2}\n"
+    );
+    assert_eq!(
+        tag("say \"it's\"").grammar("quote").to_string(),
+        "quote ::= \"say \" #x22 \"it's\" #x22\n"
+    );
+}
+
+mod toml {
+    use ezpc::*;
+
+    pub fn value() -> Matcher<impl Match> {
+        tag("true") | (tag("[") + value.wrap(10) + tag("]"))
+    }
+}
+
+#[test]
+fn rules_of_the_same_name() {
+    let grammar = (value.wrap(10) + tag("=") + toml::value.wrap(10)).grammar("pair");
+    assert_eq!(
+        grammar.to_ebnf(),
+        "pair       ::= value \"=\" toml.value\n\
+         value      ::= \"-\"? [0123456789]+ (\".\" [0123456789]+)? | \"[\" value (\",\" value)* \"]\"\n\
+         toml.value ::= \"true\" | \"[\" toml.value \"]\"\n"
+    );
+}
+
+#[test]
+fn grammar_as_railroad_diagram() {
+    let svg = (value.wrap(10) + eof()).grammar("document").to_svg();
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    assert!(svg.contains(r#"<text class="title" x="10" y="20">document</text>"#));
+    assert!(svg.contains(">value</text>"));
+    assert!(svg.contains(">&quot;[&quot;</text>"));
+    assert_eq!(svg.matches("<g").count(), svg.matches("</g>").count());
+    // The recursive rule is drawn once, it is referenced twice
+    assert_eq!(svg.matches(r#"class="title""#).count(), 2);
+    assert_eq!(svg.matches(r#"<rect class="rule""#).count(), 3);
+}
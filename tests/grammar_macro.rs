@@ -0,0 +1,75 @@
+use ezpc::*;
+
+grammar! {
+    /// A JSON-like array of numbers and words
+    pub value = number / word / array;
+    array = "[" ws (value ws ("," ws value ws)*)? "]";
+    number = "-"? ['0'..='9']+ ("." ['0'..='9']+)?;
+    word = ['a'..='z' 'A'..='Z' "_"]+;
+    ws = [" \t\n"]*;
+}
+
+#[test]
+fn grammar_macro_matches() {
+    assert!(value().match_all("[1, [-2.5, ab_c], []]").is_ok());
+    assert!(value().match_all("[ 1 ,2 ]").is_ok());
+    assert!(value().match_all("-").is_err());
+
+    let err = value().match_all("[1, 2.]").unwrap_err();
+    assert_eq!(err.position().column(), 7);
+    assert!(err.expected().contains(&Expected::CharIn('0', '9')));
+    assert_eq!(
+        Expected::CharIn('0', '9').to_string(),
+        "a character in `0`..=`9`"
+    );
+
+    // Rules can be used like any other matcher
+    let pair = (number() + tag("=") + value()).map(|s| s.len());
+    assert_eq!(pair.parse_all("-1=[x]").unwrap(), 6);
+}
+
+grammar! {
+    #![max_depth = 3]
+    nested = "(" nested? ")" / [^"()"];
+}
+
+#[test]
+fn grammar_macro_rules() {
+    assert!(nested().match_all("((x))").is_ok());
+    let err = nested().match_all("((((((x))))))").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Recursion);
+
+    assert_eq!(
+        value().grammar("value").to_ebnf(),
+        "value  ::= number | word | array\n\
+         number ::= \"-\"? [0-9]+ (\".\" [0-9]+)?\n\
+         word   ::= ([a-z] | [A-Z] | [_])+\n\
+         array  ::= \"[\" ws (value ws (\",\" ws value ws)*)? \"]\"\n\
+         ws     ::= [#x20#x9#xA]*\n"
+    );
+}
+
+fn blank() -> Matcher<impl Match> {
+    one_of(" ").repeat(0..)
+}
+
+grammar! {
+    /// Nested lists of numbers, summed up
+    sum -> u32 = digits / list;
+    list -> u32 = "(" blank (sum blank)* ")" => |items: Vec<u32>| items.iter().sum();
+    digits -> u32 = ['0'..='9']+ => |s: &str| s.parse().unwrap();
+}
+
+#[test]
+fn grammar_macro_parsers() {
+    assert_eq!(sum().parse_all("(1 (2 3) () 40)").unwrap(), 46);
+    assert_eq!(sum().parse_all("7").unwrap(), 7);
+
+    // Only the rules of the grammar are wrapped, blank() is not a rule
+    assert_eq!(
+        sum().grammar("sum").to_ebnf(),
+        "sum    ::= digits | list\n\
+         digits ::= [0-9]+\n\
+         list   ::= \"(\" [#x20]* (sum [#x20]*)* \")\"\n"
+    );
+}
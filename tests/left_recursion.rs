@@ -0,0 +1,52 @@
+use ezpc::*;
+
+fn number() -> Parser<impl Parse<Output = i64>> {
+    one_of("0123456789").repeat(1..).map(|s| s.parse().unwrap())
+}
+
+// expr = expr "-" term | term
+fn expr() -> Parser<impl Parse<Output = i64>> {
+    (expr.wrap(20) + tag("-") + term.wrap(20)).map(|(a, b)| a - b) | term.wrap(20)
+}
+
+// term = term "/" number | number
+fn term() -> Parser<impl Parse<Output = i64>> {
+    (term.wrap(20) + tag("/") + number().fatal("Expected a number:")).map(|(a, b)| a / b) | number()
+}
+
+#[test]
+fn left_associative_operators() {
+    assert_eq!(expr.wrap(20).parse_all("7").unwrap(), 7);
+    assert_eq!(expr.wrap(20).parse_all("10-3-2").unwrap(), 5);
+    assert_eq!(expr.wrap(20).parse_all("100/5/2-4-3/3").unwrap(), 5);
+    // Deeper than max_depth, because the left recursion does not nest
+    let long = "1".to_owned() + &"-1".repeat(100);
+    assert_eq!(expr.wrap(20).parse_all(&long).unwrap(), -99);
+
+    assert!(expr.wrap(20).parse_all("10-").is_err());
+    let err = expr.wrap(20).parse_all("10/x").unwrap_err();
+    assert!(err.to_string().starts_with("Expected a number:"));
+}
+
+#[test]
+fn left_recursion_with_memo() {
+    fn list() -> Parser<impl Parse<Output = Vec<char>>> {
+        let item = one_of("abc").map(|s| s.chars().next().unwrap());
+        (list.wrap(10).memo() + tag(",") + item).map(|(mut list, item)| {
+            list.push(item);
+            list
+        }) | one_of("abc").map(|s| s.chars().collect())
+    }
+
+    assert_eq!(list.wrap(10).parse_all("a,b,c").unwrap(), ['a', 'b', 'c']);
+}
+
+#[test]
+fn left_recursive_matcher() {
+    fn ab() -> Matcher<impl Match> {
+        (ab.wrap(5) + tag("b")) | tag("a")
+    }
+
+    assert!(ab.wrap(5).match_all("abbbbbbbbbb").is_ok());
+    assert!(ab.wrap(5).match_all("ba").is_err());
+}
@@ -0,0 +1,47 @@
+use ezpc::*;
+
+fn digit() -> Matcher<impl Match> {
+    one_of("0123456789")
+}
+
+#[test]
+fn intersection_and_negation() {
+    // A number without leading zero
+    let number = (digit() & !tag("0")) + digit().repeat(0..);
+    assert!(number.match_all("120").is_ok());
+    assert!(number.match_all("0").is_err());
+
+    // The negation is a plain mismatch, which lets alternatives apply
+    let keyword = tag("if") + !is_a(char::is_alphanumeric);
+    let ident = (keyword.val(None) | is_a(char::is_alphanumeric).repeat(1..).string().map(Some))
+        .parse_all("iffy");
+    assert_eq!(ident.unwrap(), Some("iffy".to_owned()));
+
+    // The negated matcher is not reported as expected
+    let err = (tag("a") + !tag("b") + tag("c"))
+        .match_all("ab")
+        .unwrap_err();
+    assert_eq!(err.position().column(), 2);
+    assert_eq!(err.expected(), &[]);
+
+    assert_eq!(
+        ((digit() & !tag("0")) + !eof())
+            .grammar("nonzero")
+            .to_string(),
+        "nonzero ::= &(!\"0\") [0123456789] !? end of input ?\n"
+    );
+}
+
+#[test]
+fn peek() {
+    let pair = tag("(").peek() + tag("(") + tag("x") + tag(")").peek() + tag(")");
+    assert!(pair.match_all("(x)").is_ok());
+    assert!(pair.match_all("x)").is_err());
+
+    let first = one_of("ab").char().peek() + is_a(char::is_alphabetic).repeat(1..).string();
+    assert_eq!(first.parse_all("abc").unwrap(), ('a', "abc".to_owned()));
+    assert_eq!(
+        first.grammar("word").to_string(),
+        "word ::= &[ab] ? is_alphabetic ?+\n"
+    );
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "lsp")]
+
+use ezpc::lsp::LanguageServer;
+use ezpc::*;
+
+/// Scripted client: frames the messages, runs the server on them and returns
+/// the content of all messages the server sent back
+fn session(server: &LanguageServer, messages: &[&str]) -> Vec<String> {
+    let mut input = Vec::new();
+    for message in messages {
+        input.extend(format!("Content-Length: {}\r\n\r\n{message}", message.len()).bytes());
+    }
+    let mut output = Vec::new();
+    server.serve(&input[..], &mut output).unwrap();
+
+    let mut output = String::from_utf8(output).unwrap();
+    let mut replies = Vec::new();
+    while let Some((header, rest)) = output.split_once("\r\n\r\n") {
+        let length: usize = header
+            .strip_prefix("Content-Length: ")
+            .unwrap()
+            .parse()
+            .unwrap();
+        replies.push(rest[..length].to_owned());
+        output = rest[length..].to_owned();
+    }
+    replies
+}
+
+fn server() -> LanguageServer {
+    let assignment = is_a(char::is_alphabetic).repeat(1..)
+        + tag(" = ")
+        + one_of("0123456789").repeat(1..).fatal("Expected a number:")
+        + tag("\n");
+    LanguageServer::for_matcher("assignments", assignment.repeat(0..))
+}
+
+#[test]
+fn publish_diagnostics_on_open_and_change() {
+    let replies = session(
+        &server(),
+        &[
+            r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"capabilities":{}}}"#,
+            r#"{"jsonrpc":"2.0","method":"initialized","params":{}}"#,
+            r#"{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{"textDocument":
+                {"uri":"file:///a.txt","languageId":"txt","version":1,"text":"a = 1\nb = x\n"}}}"#,
+            r#"{"jsonrpc":"2.0","method":"textDocument/didChange","params":{"textDocument":
+                {"uri":"file:///a.txt","version":2},"contentChanges":[{"text":"a = 1\nb = 2\n"}]}}"#,
+            r#"{"jsonrpc":"2.0","method":"textDocument/didClose","params":{"textDocument":
+                {"uri":"file:///a.txt"}}}"#,
+            r#"{"jsonrpc":"2.0","id":"end","method":"shutdown"}"#,
+            r#"{"jsonrpc":"2.0","method":"exit"}"#,
+            r#"{"jsonrpc":"2.0","id":3,"method":"shutdown"}"#,
+        ],
+    );
+
+    assert_eq!(
+        replies,
+        [
+            r#"{"jsonrpc":"2.0","id":1,"result":{"capabilities":{"textDocumentSync":1},"serverInfo":{"name":"assignments"}}}"#,
+            concat!(
+                r#"{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"uri":"file:///a.txt","diagnostics":["#,
+                r#"{"range":{"start":{"line":1,"character":4},"end":{"line":1,"character":5}},"#,
+                r#""severity":1,"code":"fatal","source":"assignments","message":"Expected a number"}]}}"#
+            ),
+            r#"{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"uri":"file:///a.txt","diagnostics":[]}}"#,
+            r#"{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"uri":"file:///a.txt","diagnostics":[]}}"#,
+            r#"{"jsonrpc":"2.0","id":"end","result":null}"#,
+        ]
+    );
+}
+
+#[test]
+fn utf16_ranges_and_invalid_messages() {
+    let replies = session(
+        &server(),
+        &[
+            r#"{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{"textDocument":
+                {"uri":"u","text":"😀"}}}"#,
+            r#"{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{"textDocument":
+                {"uri":"u","text":"a = 1"}}}"#,
+            r#"{"jsonrpc":"2.0","id":2,"method":"#,
+            r#"{"jsonrpc":"2.0","id":3,"method":"textDocument/hover"}"#,
+        ],
+    );
+
+    assert_eq!(replies.len(), 4);
+    // The emoji is a single char, but two UTF-16 code units
+    assert!(replies[0]
+        .contains(r#""range":{"start":{"line":0,"character":0},"end":{"line":0,"character":2}}"#));
+    assert!(replies[0].contains(r#""code":"partial""#));
+    // A missing line break at the end of the text is an empty range
+    assert!(replies[1]
+        .contains(r#""range":{"start":{"line":0,"character":5},"end":{"line":0,"character":5}}"#));
+    assert_eq!(
+        replies[2],
+        r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32700,"message":"Invalid JSON"}}"#
+    );
+    assert_eq!(
+        replies[3],
+        r#"{"jsonrpc":"2.0","id":3,"error":{"code":-32601,"message":"Unknown method `textDocument/hover`"}}"#
+    );
+}
+
+#[test]
+fn reject_messages_without_length() {
+    let shutdown = r#"{"jsonrpc":"2.0","id":1,"method":"shutdown"}"#;
+    let input = format!(
+        "Content-Length: {}\r\n\r\n{shutdown}Content-Type: application/json\r\n\r\n{shutdown}",
+        shutdown.len()
+    );
+    let mut output = Vec::new();
+    let err = server().serve(input.as_bytes(), &mut output).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    // Messages before it are still answered
+    let reply = r#"{"jsonrpc":"2.0","id":1,"result":null}"#;
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        format!("Content-Length: {}\r\n\r\n{reply}", reply.len())
+    );
+
+    let input = "Content-Length: x\r\n\r\n{}";
+    let err = server().serve(input.as_bytes(), Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,63 @@
+use ezpc::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Every nesting level tries the nested parser twice, because the first
+// alternative only fails at its very end. Without memoization this doubles
+// the work for every level.
+
+static LEAF_COUNT: AtomicUsize = AtomicUsize::new(0);
+static MEMO_LEAF_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn nested() -> Parser<impl Parse<Output = usize>> {
+    let inner = || tag("(") + nested.wrap(50) + tag(")");
+    let leaf = tag("x").map(|_| LEAF_COUNT.fetch_add(1, Ordering::Relaxed));
+    (inner() + tag("b")).map(|n| n + 1) | (inner() + tag("c")).map(|n| n + 1) | leaf.val(0)
+}
+
+fn memo_nested() -> Parser<impl Parse<Output = usize>> {
+    let inner = || tag("(") + memo_nested.wrap(50).memo() + tag(")");
+    let leaf = tag("x").map(|_| MEMO_LEAF_COUNT.fetch_add(1, Ordering::Relaxed));
+    (inner() + tag("b")).map(|n| n + 1) | (inner() + tag("c")).map(|n| n + 1) | leaf.val(0)
+}
+
+#[test]
+fn memo_bounds_backtracking() {
+    let depth = 12;
+    let source = "(".repeat(depth) + "x" + &")c".repeat(depth);
+
+    assert_eq!(nested.wrap(50).parse_all(&source).unwrap(), depth);
+    assert_eq!(LEAF_COUNT.load(Ordering::Relaxed), 1 << depth);
+
+    // Both alternatives memoize the same wrapped parser and share its cache
+    assert_eq!(
+        memo_nested.wrap(50).memo().parse_all(&source).unwrap(),
+        depth
+    );
+    assert_eq!(MEMO_LEAF_COUNT.load(Ordering::Relaxed), 1);
+
+    // The cache only lives for a single parse
+    assert_eq!(memo_nested.wrap(50).parse_all(&source).unwrap(), depth);
+    assert_eq!(MEMO_LEAF_COUNT.load(Ordering::Relaxed), 2);
+}
+
+fn value() -> Matcher<impl Match> {
+    let item = || value.wrap(5).memo();
+    tag("1") | (tag("[") + !(item() + tag(";")) + item() + tag("]"))
+}
+
+#[test]
+fn memo_caches_errors() {
+    let parser = tag("a").memo().fatal("Expected a:") | tag("b").memo();
+    let err = parser.match_all("b").unwrap_err();
+    assert!(err.to_string().starts_with("Expected a:"));
+
+    let parser = (tag("a").memo() + tag("b")) | (tag("a").memo() + tag("c"));
+    assert!(parser.match_all("ac").is_ok());
+    assert!(parser.match_all("ad").is_err());
+
+    // The expectations are replayed with a cached result, even if the first
+    // application didn't record them because it was inside a negation
+    let err = value.wrap(5).match_all("[[2").unwrap_err();
+    assert_eq!(err.position().column(), 3);
+    assert_eq!(err.expected(), &[Expected::Tag("1"), Expected::Tag("[")]);
+}
@@ -0,0 +1,57 @@
+use ezpc::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Lt,
+    Le,
+    Shl,
+    ShlAssign,
+    Gt,
+}
+
+fn op() -> Parser<impl Parse<Output = Op>> {
+    one_of_tags(&[
+        ("<", Op::Lt),
+        ("<=", Op::Le),
+        ("<<", Op::Shl),
+        ("<<=", Op::ShlAssign),
+        (">", Op::Gt),
+    ])
+}
+
+#[test]
+fn longest_match() {
+    let ops = (op() + tag(" ").opt()).repeat(0..);
+    assert_eq!(
+        ops.parse_all("<<= < <= > << <").unwrap(),
+        vec![Op::ShlAssign, Op::Lt, Op::Le, Op::Gt, Op::Shl, Op::Lt]
+    );
+    // Shorter tags are not tried if the rest of the input mismatches
+    assert_eq!((op() + tag("=x")).parse_all("<<=x").ok(), None);
+    assert_eq!((op() + tag("x")).parse_all("<<x").unwrap(), Op::Shl);
+    assert_eq!(
+        op().grammar("op").to_string(),
+        "op ::= \"<\" | \"<=\" | \"<<\" | \"<<=\" | \">\"\n"
+    );
+}
+
+#[test]
+fn expected_alternatives() {
+    let err = op().parse_all("=").unwrap_err();
+    assert_eq!(
+        err.expected(),
+        &[
+            Expected::Tag("<"),
+            Expected::Tag("<="),
+            Expected::Tag("<<"),
+            Expected::Tag("<<="),
+            Expected::Tag(">"),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "tag `<=` is given twice")]
+fn duplicate_tag() {
+    one_of_tags(&[("<=", 1), ("<", 2), ("<=", 3)]);
+}
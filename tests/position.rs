@@ -0,0 +1,48 @@
+use ezpc::*;
+
+fn list() -> Matcher<impl Match> {
+    tag("[") + none_of("]\n").repeat(0..) + tag("]").fatal("Unclosed list:") + tag("\n").opt()
+}
+
+#[test]
+fn error_accessors() {
+    let source = "[a,b]\n[😀,c";
+    let err = list().repeat(0..).match_all(source).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Fatal);
+    assert_eq!(err.message(), "Unclosed list");
+    assert!(err.expected().is_empty());
+
+    let pos = err.position();
+    assert_eq!(pos.offset(), source.len());
+    assert_eq!(pos.line(), 2);
+    assert_eq!(pos.column(), 5);
+    assert_eq!(pos.utf16_column(), 6);
+    assert_eq!(pos.line_str(), "[😀,c");
+
+    let err = list().match_all("[a]]").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::PartialParse);
+    assert_eq!(err.position().offset(), 3);
+    assert!(err
+        .message()
+        .starts_with("Parsing stopped before reaching end of input"));
+    assert!(!err.expected().is_empty());
+}
+
+#[test]
+fn line_index_matches_from_ptr() {
+    let source = "ab\r\ncä\n\n😀x";
+    let index = LineIndex::new(source);
+    assert_eq!(index.line_count(), 4);
+
+    for (offset, _) in source.char_indices().chain([(source.len(), ' ')]) {
+        let expected = Position::from_ptr(source, source[offset..].as_ptr());
+        let pos = index.position(offset);
+        assert_eq!(pos.offset(), offset);
+        assert_eq!(pos.line(), expected.line());
+        assert_eq!(pos.column(), expected.column());
+        assert_eq!(pos.utf16_column(), expected.utf16_column());
+        assert_eq!(pos.line_str(), expected.line_str());
+    }
+    assert_eq!(index.position(1).line_str(), "ab");
+    assert_eq!(index.position(source.len()).utf16_column(), 4);
+}
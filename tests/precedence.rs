@@ -0,0 +1,69 @@
+use ezpc::*;
+
+// Builds a fully parenthesized string, which makes the tree structure visible
+fn expr() -> Parser<impl Parse<Output = String>> {
+    let atom = one_of("abcxyz").map(|s| s.to_owned())
+        | (tag("(") + expr.wrap(20) + tag(")").fatal("Missing ')':"));
+    let bin = |op: &'static str| move |a, b| format!("({a} {op} {b})");
+
+    precedence(atom, 20)
+        .infix(tag("+"), 1, Assoc::Left, bin("+"))
+        .infix(tag("-"), 1, Assoc::Left, bin("-"))
+        .infix(tag("*"), 2, Assoc::Left, bin("*"))
+        .infix(tag("^"), 4, Assoc::Right, bin("^"))
+        .prefix(tag("-"), 3, |a| format!("(-{a})"))
+        .postfix(tag("!"), 5, |a| format!("({a}!)"))
+        .postfix(tag("?"), 0, |a| format!("({a}?)"))
+}
+
+fn power() -> Parser<impl Parse<Output = String> + Send + Sync> {
+    let atom =
+        one_of("0123456789").map(|s| s.to_owned()) | (tag("(") + power.wrap_sync(20) + tag(")"));
+    // `*` is tried first and leaves `*3` of `2**3`, which is no operand
+    precedence(atom, 20)
+        .infix(tag("*"), 1, Assoc::Left, |a, b| format!("({a} * {b})"))
+        .infix(tag("**"), 2, Assoc::Right, |a, b| format!("({a} ** {b})"))
+}
+
+#[test]
+fn precedence_and_associativity() {
+    let parse = |s| expr().parse_all(s).unwrap();
+    assert_eq!(parse("a"), "a");
+    assert_eq!(parse("a+b*c"), "(a + (b * c))");
+    assert_eq!(parse("a*b+c"), "((a * b) + c)");
+    assert_eq!(parse("a-b-c"), "((a - b) - c)");
+    assert_eq!(parse("a^b^c"), "(a ^ (b ^ c))");
+    assert_eq!(parse("-a*b"), "((-a) * b)");
+    assert_eq!(parse("-a^b"), "(-(a ^ b))");
+    assert_eq!(parse("--a!"), "(-(-(a!)))");
+    assert_eq!(parse("a+b?"), "((a + b)?)");
+    assert_eq!(parse("(a+b)*c!"), "((a + b) * (c!))");
+}
+
+#[test]
+fn precedence_errors() {
+    // The trailing operator is not part of the expression
+    assert!(matches!(
+        expr().parse_all("a+").unwrap_err(),
+        EzpcError::PartialParse { .. }
+    ));
+    assert!(matches!(
+        expr().parse_all("(a+b").unwrap_err(),
+        EzpcError::Fatal { .. }
+    ));
+    assert!(matches!(
+        expr().parse_all(&"-".repeat(30)).unwrap_err(),
+        EzpcError::Recursion { .. }
+    ));
+}
+
+#[test]
+fn overlapping_operators() {
+    let parser = power.wrap_sync(20);
+    assert_eq!(parser.parse_all("2**3").unwrap(), "(2 ** 3)");
+    std::thread::scope(|scope| {
+        let parser = &parser;
+        let result = scope.spawn(move || parser.parse_all("(2*3**4)*5").unwrap());
+        assert_eq!(result.join().unwrap(), "((2 * (3 ** 4)) * 5)");
+    });
+}
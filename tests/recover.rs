@@ -0,0 +1,64 @@
+use ezpc::*;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Statement {
+    Assign(String, u32),
+    Invalid,
+}
+
+fn statement() -> Parser<impl Parse<Output = Statement>> {
+    let name = is_a(|c| c.is_ascii_lowercase())
+        .repeat(1..)
+        .map(|s| s.to_owned());
+    let number = one_of("0123456789")
+        .repeat(1..)
+        .map(|s| s.parse().unwrap())
+        .fatal("Expected a number:");
+    (name + tag(" = ").fatal("Expected '=':") + number + tag("\n").fatal("Expected newline:"))
+        .map(|(name, value)| Statement::Assign(name, value))
+        .recover_with(skip_until(tag("\n")) + tag("\n"), Statement::Invalid)
+}
+
+#[test]
+fn recover_multiple_errors() {
+    let source = "a = 1\nb = x\nc = 3\nd - 4\ne = 5\n";
+    let (stmts, errors) = statement().repeat(0..).parse_all_recover(source);
+
+    assert_eq!(
+        stmts.unwrap(),
+        [
+            Statement::Assign("a".to_owned(), 1),
+            Statement::Invalid,
+            Statement::Assign("c".to_owned(), 3),
+            Statement::Invalid,
+            Statement::Assign("e".to_owned(), 5),
+        ]
+    );
+    let messages: Vec<_> = errors.iter().map(|err| err.to_string()).collect();
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].starts_with("Expected a number:\n --> line 2, column 5"));
+    assert!(messages[1].starts_with("Expected '=':\n --> line 4, column 2"));
+
+    // parse_all still stops with the first error
+    let err = statement().repeat(0..).parse_all(source).unwrap_err();
+    assert_eq!(err.to_string(), messages[0]);
+}
+
+#[test]
+fn unrecoverable_errors_are_returned_last() {
+    let (stmts, errors) = statement().repeat(0..).parse_all_recover("a = x\n!");
+    assert_eq!(stmts.unwrap(), [Statement::Invalid]);
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[1], EzpcError::PartialParse { .. }));
+
+    // The recovery matcher doesn't apply without a newline, so nothing is recovered
+    let (stmts, errors) = statement().repeat(0..).parse_all_recover("a = x");
+    assert!(stmts.is_none());
+    assert_eq!(errors.len(), 1);
+
+    let errors = (tag("a") + tag("b").fatal("Expected b:") + tag(";"))
+        .recover_with(skip_until(tag(";")) + tag(";"))
+        .repeat(0..)
+        .match_all_recover("ab;ax;ac;");
+    assert_eq!(errors.len(), 2);
+}
@@ -0,0 +1,69 @@
+use ezpc::*;
+
+#[test]
+fn tokens() {
+    let number =
+        || regex(r"-?(0|[1-9]\d*)(\.\d+)?([eE][+-]?\d+)?").map(|s| s.parse::<f64>().unwrap());
+    let list =
+        (number() + (tag(",") + regex(r"\s*") + number()).repeat(0..)).map(|(first, mut rest)| {
+            rest.insert(0, first);
+            rest
+        });
+    assert_eq!(
+        list.parse_all("1,-2.5,  3e2,0").unwrap(),
+        vec![1.0, -2.5, 300.0, 0.0]
+    );
+    assert!(number().parse_all("01").is_err());
+
+    let ident = regex(r"[\p{L}_][\p{L}\p{Nd}_]*");
+    assert!(ident.match_all("größe_2").is_ok());
+    let port = regex(r"\d+").convert(|s| s.parse::<u16>(), "port out of range");
+    assert_eq!(port.parse_all("8080").unwrap(), 8080);
+    assert!(port.parse_all("80800").is_err());
+
+    // The longest match wins, unlike with backtracking engines
+    let keyword = regex("for|foreach") | regex("(?:x|y)+");
+    assert!(keyword.match_all("foreach").is_ok());
+    assert!(keyword.match_all("xyyx").is_ok());
+}
+
+#[test]
+fn expected_and_grammar() {
+    let hex = tag("0x") + regex("[0-9a-fA-F]{1,8}");
+    let err = hex.match_all("0xg").unwrap_err();
+    assert_eq!(err.expected(), &[Expected::Regex("[0-9a-fA-F]{1,8}")]);
+    assert!(hex.match_all("0x123456789").is_err());
+    assert_eq!(
+        hex.grammar("hex").to_string(),
+        "hex ::= \"0x\" ? /[0-9a-fA-F]{1,8}/ ?\n"
+    );
+}
+
+#[test]
+fn invalid_patterns() {
+    let message = |pattern: &'static str| {
+        let panic = std::panic::catch_unwind(|| regex(pattern)).err().unwrap();
+        panic.downcast_ref::<String>().unwrap().clone()
+    };
+    assert_eq!(message("a(b"), "invalid regex `a(b`: unclosed group");
+    assert_eq!(
+        message("*a"),
+        "invalid regex `*a`: nothing to repeat with `*`"
+    );
+    assert_eq!(
+        message("a+?"),
+        "invalid regex `a+?`: lazy and possessive quantifiers are not supported"
+    );
+    assert_eq!(
+        message("[z-a]"),
+        "invalid regex `[z-a]`: range out of order in character class"
+    );
+    assert_eq!(
+        message(r"\p{Xx}"),
+        r"invalid regex `\p{Xx}`: unknown general category `Xx`"
+    );
+    assert_eq!(
+        message("a$"),
+        "invalid regex `a$`: anchor `$` is not supported, the regex is anchored at the start"
+    );
+}
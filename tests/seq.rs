@@ -0,0 +1,38 @@
+use ezpc::*;
+
+fn digits() -> Parser<impl Parse<Output = u32>> {
+    one_of("0123456789")
+        .repeat(1..)
+        .convert(|s| s.parse(), "Invalid number:")
+}
+
+#[test]
+fn seq_flat_output() {
+    let time = seq((digits(), tag(":"), digits(), tag(":"), digits()));
+    assert_eq!(time.parse_all("12:30:59").unwrap(), (12, 30, 59));
+    assert!(time.parse_all("12:30").is_err());
+
+    // A single parser is not wrapped in a tuple, matchers only output ()
+    let sign = seq((tag("-"), digits()));
+    assert_eq!(sign.parse_all("-7").unwrap(), 7);
+    assert_eq!(seq((tag("a"), tag("b"))).parse_all("ab").unwrap(), ());
+
+    let eight = seq((
+        digits(),
+        tag(","),
+        digits().opt(),
+        tag(","),
+        one_of("ab").map(|s| s.to_owned()),
+        is_a(char::is_alphabetic).map(|s| s.chars().next().unwrap()),
+        digits(),
+        tag("."),
+    ));
+    assert_eq!(
+        eight.parse_all("1,,bc2.").unwrap(),
+        (1, None, "b".to_owned(), 'c', 2)
+    );
+    assert_eq!(
+        seq((tag("("), eight, tag(")"))).grammar("eight").to_string(),
+        "eight ::= \"(\" [0123456789]+ \",\" ([0123456789]+)? \",\" [ab] ? is_alphabetic ? [0123456789]+ \".\" \")\"\n"
+    );
+}
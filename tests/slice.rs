@@ -0,0 +1,56 @@
+use ezpc::*;
+use std::io::Read;
+
+fn word() -> Parser<impl Parse<Output = Substr>> {
+    is_a(char::is_alphanumeric).repeat(1..).slice()
+}
+
+#[test]
+fn slices_of_words() {
+    let source = "héllo  wörld 42";
+    let words = (word() + one_of(" ").repeat(0..)).repeat(0..);
+    let words = words.parse_all(source).unwrap();
+    assert_eq!(words, ["héllo", "wörld", "42"]);
+    // All substrings point into one copy of the source
+    let offset = words[1].as_ptr() as usize - words[0].as_ptr() as usize;
+    assert_eq!(offset, source.find("wörld").unwrap());
+
+    let other = String::from("other words");
+    let words = (word() + tag(" ").opt()).repeat(0..).parse_all(&other);
+    drop(other);
+    assert_eq!(words.unwrap(), ["other", "words"]);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tree {
+    Leaf(Substr),
+    Node(Vec<Tree>),
+}
+
+fn tree() -> Parser<impl Parse<Output = Tree>> {
+    let node = tag("(") + list(tree.wrap(10), tag(" "), "Expected a tree:") + tag(")");
+    word().map(Tree::Leaf) | node.map(Tree::Node)
+}
+
+#[test]
+fn slices_in_recursive_grammars() {
+    let Tree::Node(trees) = tree().parse_all("(a (bc d) (e))").unwrap() else {
+        panic!("expected a node")
+    };
+    assert_eq!(trees[0], Tree::Leaf("a".into()));
+    assert_eq!(
+        trees[1],
+        Tree::Node(vec![Tree::Leaf("bc".into()), Tree::Leaf("d".into())])
+    );
+}
+
+#[test]
+fn slices_of_streamed_records() {
+    // The second read continues the record of the first one
+    let reader = "alpha beta ga".as_bytes().chain("mma delta ".as_bytes());
+    let words: Vec<_> = (word() + tag(" "))
+        .stream(reader)
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(words, ["alpha", "beta", "gamma", "delta"]);
+}
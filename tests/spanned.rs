@@ -0,0 +1,88 @@
+use ezpc::*;
+
+#[derive(Clone, Debug)]
+enum Node {
+    Number(Span),
+    List(Vec<(Node, Span)>),
+}
+
+fn node_inner() -> Parser<impl Parse<Output = Node>> {
+    let number = one_of("0123456789").repeat(1..).spanned().map(Node::Number);
+    let items = list(
+        node.wrap(10).spanned(),
+        tag(",") + ws(),
+        "Expected a list item:",
+    );
+    let list = (tag("[") + items + tag("]")).map(Node::List);
+    number | list
+}
+
+fn node() -> Parser<impl Parse<Output = Node>> {
+    node_inner()
+}
+
+fn ws() -> Matcher<impl Match> {
+    one_of(" \n").repeat(0..)
+}
+
+#[test]
+fn spans_of_nested_lists() {
+    let source = "[1, [22,\n  333], 4]";
+    let Node::List(items) = node.wrap(10).parse_all(source).unwrap() else {
+        panic!("expected a list")
+    };
+
+    let spans: Vec<_> = items.iter().map(|(_, span)| span.slice(source)).collect();
+    assert_eq!(spans, ["1", "[22,\n  333]", "4"]);
+
+    let Node::List(inner) = &items[1].0 else {
+        panic!("expected a nested list")
+    };
+    let Node::Number(span) = inner[1].0 else {
+        panic!("expected a number")
+    };
+    assert_eq!(span.slice(source), "333");
+    assert_eq!((span.start, span.end), (11, 14));
+    assert_eq!((span.line, span.column), (2, 3));
+    assert_eq!(span, inner[1].1);
+}
+
+#[test]
+fn spans_of_sequences_and_repeats() {
+    let source = "äb cd";
+    let word = || is_a(|c| c.is_alphabetic()).repeat(1..).spanned();
+    let (spans, span) = (word() + (tag(" ") + word()).repeat(0..))
+        .map(|(head, mut tail)| {
+            tail.insert(0, head);
+            tail
+        })
+        .spanned()
+        .parse_all(source)
+        .unwrap();
+
+    assert_eq!((span.start, span.end), (0, source.len()));
+    assert_eq!((spans[1].start, spans[1].end), (4, 6));
+    assert_eq!((spans[1].line, spans[1].column), (1, 4));
+    assert!(tag("").spanned().parse_all("").unwrap().is_empty());
+}
+
+#[test]
+fn span_columns_on_long_lines() {
+    let line = ["ä1", "b22", "€333"].repeat(200).join(" ");
+    let source = format!("{line}\n  {line}");
+    let word = is_a(|c| !c.is_whitespace()).repeat(1..).spanned();
+    let spans = (word + one_of(" \n").repeat(0..))
+        .repeat(1..)
+        .parse_all(&source)
+        .unwrap();
+
+    assert_eq!(spans.len(), 1200);
+    for span in spans {
+        let line_start = source[..span.start].rfind('\n').map_or(0, |i| i + 1);
+        let column = source[line_start..span.start].chars().count() + 1;
+        assert_eq!(
+            (span.line, span.column),
+            (1 + (line_start > 0) as usize, column)
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use ezpc::*;
+use std::io::Read;
+
+/// Reader that returns at most chunk bytes per read, to test chunk boundaries
+struct Trickle<'a> {
+    data: &'a [u8],
+    chunk: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.chunk.min(buf.len()).min(self.data.len());
+        buf[..len].copy_from_slice(&self.data[..len]);
+        self.data = &self.data[len..];
+        Ok(len)
+    }
+}
+
+fn record() -> Parser<impl Parse<Output = ((String, i64), Span)>> {
+    let name = is_a(|c| c.is_alphabetic())
+        .repeat(1..)
+        .map(|s| s.to_owned());
+    let number = one_of("0123456789")
+        .repeat(1..)
+        .convert(|s| s.parse(), "Number too large:");
+    let ws = || one_of(" \n").repeat(0..);
+    ws() + (name + tag(" = ") + number.fatal("Expected a number:")).spanned() + tag(";") + ws()
+}
+
+#[test]
+fn stream_records_across_chunks() {
+    let source = "äpfel = 1;\n  birnen = 22; kürbis = 333;\n\nx = 4;";
+    for chunk in 1..8 {
+        let reader = Trickle {
+            data: source.as_bytes(),
+            chunk,
+        };
+        let records: Vec<_> = record().stream(reader).map(Result::unwrap).collect();
+
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[1].0, ("birnen".to_owned(), 22));
+        for ((_, span), expected) in records.iter().zip(["äpfel", "birnen", "kürbis", "x"]) {
+            assert!(span.slice(source).starts_with(expected));
+        }
+        let spans: Vec<_> = records
+            .iter()
+            .map(|(_, span)| (span.line, span.column))
+            .collect();
+        assert_eq!(spans, [(1, 1), (2, 3), (2, 16), (4, 1)]);
+    }
+}
+
+#[test]
+fn stream_errors() {
+    let source = "a = 1;\nb = 2;\nc = x;\nd = 4;";
+    let reader = Trickle {
+        data: source.as_bytes(),
+        chunk: 3,
+    };
+    let parser = record();
+    let mut records = parser.stream(reader);
+    assert!(records.next().unwrap().is_ok());
+    assert!(records.next().unwrap().is_ok());
+    let err = records.next().unwrap().unwrap_err().to_string();
+    assert!(err.starts_with("Expected a number:\n --> line 3, column 5"));
+    assert!(records.next().is_none());
+
+    // Incomplete record at the end of the input
+    let mut records = parser.stream("a = 1;\nb = 2".as_bytes());
+    assert!(records.next().unwrap().is_ok());
+    assert!(matches!(
+        records.next(),
+        Some(Err(StreamError::Parse(EzpcError::PartialParse { .. })))
+    ));
+
+    let mut records = parser.stream(&[b'a', b' ', b'=', b' ', 0xFF][..]);
+    assert!(matches!(
+        records.next(),
+        Some(Err(StreamError::InvalidUtf8 { offset: 4 }))
+    ));
+}
+
+/// Reader of endless records on a single line
+struct Endless {
+    offset: usize,
+}
+
+impl Read for Endless {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        for byte in buf.iter_mut() {
+            *byte = b"x = 1; "[self.offset % 7];
+            self.offset += 1;
+        }
+        Ok(buf.len())
+    }
+}
+
+#[test]
+fn stream_without_newlines() {
+    // Only the parsed records are released, not whole lines, so the input
+    // is not kept in memory and positions are counted from the last record
+    let parser = record();
+    let records = parser.stream(Endless { offset: 0 }).take(50_000);
+    for (i, record) in records.enumerate() {
+        let (_, span) = record.unwrap();
+        assert_eq!((span.line, span.column), (1, 7 * i + 1));
+    }
+
+    let source = "a = 1; äb = 2; c = x;";
+    let mut records = parser.stream(Trickle {
+        data: source.as_bytes(),
+        chunk: 4,
+    });
+    assert!(records.next().unwrap().is_ok());
+    assert!(records.next().unwrap().is_ok());
+    let err = records.next().unwrap().unwrap_err();
+    let StreamError::Parse(err) = err else {
+        panic!("expected a parse error")
+    };
+    assert_eq!(err.position().offset(), 20);
+    assert_eq!(err.position().column(), 20);
+    assert_eq!(err.position().utf16_column(), 20);
+}
+
+#[test]
+fn stream_long_records() {
+    // A record is parsed again whenever it reaches the end of the buffer, so
+    // count the characters the parser looks at
+    static CHARS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let name = is_a(|c| {
+        CHARS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        c.is_alphabetic()
+    })
+    .repeat(1..);
+    let parser = (name + tag(";")).map(|_| ());
+
+    let len = 1 << 20;
+    let source = "x".repeat(len) + ";y;";
+    let records: Vec<_> = parser.stream(source.as_bytes()).collect();
+    assert_eq!(records.len(), 2);
+    assert!(records.iter().all(Result::is_ok));
+    assert!(CHARS.load(std::sync::atomic::Ordering::Relaxed) < 3 * len);
+}
@@ -0,0 +1,48 @@
+use ezpc::*;
+use std::sync::LazyLock;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Number(u32),
+    List(Vec<Value>),
+}
+
+fn value() -> Parser<impl Parse<Output = Value> + Send + Sync> {
+    let number = one_of("0123456789")
+        .repeat(1..)
+        .map(|s| Value::Number(s.parse().unwrap()));
+    let items = list(value.wrap_sync(20), tag(","), "Expected a value:");
+    let list = (tag("[") + (items | tag("").val(Vec::new())) + tag("]")).map(Value::List);
+    number | list
+}
+
+fn brackets() -> Matcher<impl Match + Send + Sync> {
+    (tag("(") + brackets.wrap_sync(20) + tag(")")).repeat(0..)
+}
+
+static VALUE: LazyLock<Parser<SyncWrappedParser<Value>>> = LazyLock::new(|| value.wrap_sync(20));
+
+#[test]
+fn share_recursive_parser_between_threads() {
+    let parser = value.wrap_sync(20);
+    let matcher = brackets.wrap_sync(20);
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| VALUE.parse_all("[1,2]").unwrap());
+        scope.spawn(|| VALUE.parse_all("[3,[4]]").unwrap());
+        for i in 0..4 {
+            let (parser, matcher) = (&parser, &matcher);
+            scope.spawn(move || {
+                let nested = "[".repeat(i * 5) + "[1,[]]" + &"]".repeat(i * 5);
+                assert!(parser.parse_all(&nested).is_ok());
+                assert!(matcher.match_all("(()(()))()").is_ok());
+                // Each parse call has its own recursion depth
+                let too_deep = "[".repeat(30) + &"]".repeat(30);
+                assert!(matches!(
+                    parser.parse_all(&too_deep),
+                    Err(EzpcError::Recursion { .. })
+                ));
+            });
+        }
+    });
+}
@@ -0,0 +1,53 @@
+use ezpc::*;
+
+#[test]
+fn case_insensitive() {
+    assert!(tag_ascii_ci("select").match_all("SeLeCt").is_ok());
+    assert!(tag_ascii_ci("straße").match_all("STRASSE").is_err());
+    assert!(tag_ci("straße").match_all("STRASSE").is_ok());
+    assert!(tag_ci("Ångström").match_all("a\u{30A}NGSTRÖM").is_ok());
+    assert!(tag_ci("ΣΑΣ").match_all("σας").is_ok());
+
+    let err = (tag_ci("select") + tag(" *"))
+        .match_all("SELEKT *")
+        .unwrap_err();
+    assert_eq!(err.expected(), &[Expected::TagCi("select")]);
+    assert_eq!(
+        err.to_string().lines().next().unwrap(),
+        "Parsing stopped before reaching end of input, expected `select` in any case:"
+    );
+    assert_eq!(
+        tag_ci("select").grammar("select").to_string(),
+        "select ::= ? \"select\" in any case ?\n"
+    );
+}
+
+#[test]
+fn normalized() {
+    let cafe = tag_normalized("café") + eof();
+    assert!(cafe.match_all("caf\u{E9}").is_ok());
+    assert!(cafe.match_all("cafe\u{301}").is_ok());
+    assert!(tag_normalized("e").match_all("e\u{301}").is_err());
+    // Combining marks are compared in canonical order
+    assert!(tag_normalized("\u{1EB9}\u{301}")
+        .match_all("e\u{301}\u{323}")
+        .is_ok());
+    assert!(tag_normalized("한")
+        .match_all("\u{1112}\u{1161}\u{11AB}")
+        .is_ok());
+}
+
+#[test]
+fn keywords() {
+    let boolean = keyword("true") | keyword("false");
+    assert!(boolean.match_all("true").is_ok());
+    assert!((keyword("false") + tag(" ")).match_all("false ").is_ok());
+    let err = boolean.match_all("trueish").unwrap_err();
+    assert_eq!(
+        err.expected(),
+        &[Expected::Tag("true"), Expected::Tag("false")]
+    );
+    assert!(keyword("if").match_all("ifé").is_err());
+    assert!((keyword_ci("null") + tag(",")).match_all("NULL,").is_ok());
+    assert!(keyword_ci("null").match_all("Nullable").is_err());
+}
@@ -0,0 +1,25 @@
+use ezpc::*;
+
+#[test]
+fn text_building() {
+    let word = is_a(char::is_alphabetic).repeat(1..).string();
+    assert_eq!(word.parse_all("abc").unwrap(), "abc");
+    assert_eq!(one_of("xyz").char().parse_all("y").unwrap(), 'y');
+    assert!(tag("").char().parse_all("").is_err());
+
+    let escape = tag("\\") + (tag("n").val('\n') | tag("t").val('\t'));
+    let string =
+        tag("\"") + (none_of("\\\"").repeat(1..).part() | escape).repeat_string(0..) + tag("\"");
+    assert_eq!(string.parse_all(r#""a\tb\n""#).unwrap(), "a\tb\n");
+    assert_eq!(string.parse_all(r#""""#).unwrap(), "");
+
+    let chars = (tag("'") + none_of("'").char().opt() + tag("'")).repeat_string(2..3);
+    assert_eq!(chars.parse_all("'a''''c'").unwrap(), "ac");
+    assert!(chars.parse_all("'a'").is_err());
+
+    // Matchers push the text they matched, the line continuation nothing
+    let line = (is_a(char::is_alphabetic).part() | tag("\\\n").val("")).repeat_string(1..);
+    assert_eq!(line.parse_all("ab\\\nc").unwrap(), "abc");
+    assert!(line.parse_all("a\\b").is_err());
+    assert_eq!(one_of("ab").repeat_string(2).parse_all("ba").unwrap(), "ba");
+}
@@ -0,0 +1,97 @@
+use ezpc::*;
+
+fn value() -> Parser<impl Parse<Output = String>> {
+    let number = one_of("0123456789")
+        .repeat(1..)
+        .map(|s| s.to_owned())
+        .named("number");
+    let word = is_a(char::is_alphabetic)
+        .repeat(1..)
+        .map(|s| s.to_owned())
+        .named("word");
+    (number | word).named("value")
+}
+
+fn values() -> Parser<impl Parse<Output = Vec<String>>> {
+    list(value(), tag(","), "Expected a value:")
+}
+
+#[test]
+fn trace_as_text() {
+    let (result, trace) = values().parse_all_traced("ab,1");
+    assert_eq!(result.unwrap(), ["ab", "1"]);
+    assert_eq!(
+        trace.to_string(),
+        "list 0..4 ok\n\
+         \x20 value 0..2 ok\n\
+         \x20   or 0..2 ok\n\
+         \x20     number 0..0 mismatch\n\
+         \x20       repeat 0..0 mismatch\n\
+         \x20     word 0..2 ok\n\
+         \x20       repeat 0..2 ok\n\
+         \x20 value 3..4 ok\n\
+         \x20   or 3..4 ok\n\
+         \x20     number 3..4 ok\n\
+         \x20       repeat 3..4 ok\n"
+    );
+
+    let (result, trace) = values().parse_all_traced("ab,");
+    assert_eq!(result.unwrap_err().message(), "Expected a value");
+    let last = trace.events().last().unwrap();
+    assert_eq!((last.name, last.depth, last.end), ("repeat", 4, 3));
+    assert_eq!(last.outcome, Outcome::Mismatch);
+    assert_eq!(trace.events()[0].outcome, Outcome::Fatal);
+
+    // Tracing is limited to the traced call
+    assert!(values().parse_all("ab,1").is_ok());
+    let (_, trace) = (tag("a").named("a") + tag("b")).match_all_traced("ab");
+    assert_eq!(trace.to_string(), "and 0..2 ok\n  a 0..1 ok\n");
+}
+
+#[test]
+fn trace_matchers() {
+    let pair = (tag("a") + tag("b")).named("pair");
+    let (result, trace) = (pair | tag("c")).repeat(1..).match_all_traced("abc");
+    assert!(result.is_ok());
+    assert_eq!(
+        trace.to_string(),
+        "repeat 0..3 ok\n\
+         \x20 or 0..2 ok\n\
+         \x20   pair 0..2 ok\n\
+         \x20     and 0..2 ok\n\
+         \x20 or 2..3 ok\n\
+         \x20   pair 2..2 mismatch\n\
+         \x20     and 2..2 mismatch\n\
+         \x20 or 3..3 mismatch\n\
+         \x20   pair 3..3 mismatch\n\
+         \x20     and 3..3 mismatch\n"
+    );
+}
+
+fn nested() -> Parser<impl Parse<Output = usize>> {
+    (tag("(") + nested.wrap(10) + tag(")")).map(|depth| depth + 1) | tag("x").val(0)
+}
+
+#[test]
+fn trace_wrapped_as_chrome_json() {
+    let (result, trace) = nested.wrap(10).parse_all_traced("(x)");
+    assert_eq!(result.unwrap(), 1);
+
+    let names: Vec<_> = trace.events().iter().map(|e| e.name).collect();
+    assert_eq!(names.len(), 4);
+    assert!(names[0].ends_with("nested") && names[2].ends_with("nested"));
+    assert_eq!((names[1], names[3]), ("or", "or"));
+    assert_eq!(
+        trace.events().iter().map(|e| e.depth).collect::<Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+
+    let json = trace.to_chrome_json();
+    assert!(json.starts_with(r#"{"traceEvents":[{"name":""#));
+    assert!(json.contains(
+        r#""ph":"X","ts":0,"dur":7,"pid":1,"tid":1,"args":{"start":0,"end":3,"outcome":"ok"}}"#
+    ));
+    assert!(json.ends_with(
+        r#"{"name":"or","ph":"X","ts":3,"dur":1,"pid":1,"tid":1,"args":{"start":1,"end":2,"outcome":"ok"}}]}"#
+    ));
+}